@@ -105,6 +105,15 @@ pub fn compile(matrix: PatternMatrix, paths: Vec<ScrutineePath>) -> DecisionTree
     let col = pick_column(&matrix);
     let path = paths[col].clone();
 
+    // 3a. OR-PATTERN EXPANSION: a row with `p1 | p2` at the chosen column
+    // becomes one row per alternative (same arm, guard, and bindings), in
+    // order. Specialization then never sees an or-pattern at `col`, and each
+    // alternative keeps its own sub-pattern structure — merging alternatives
+    // column-wise would accept mixtures like `(1, 4)` for `(1, 2) | (3, 4)`.
+    if matrix.iter().any(|row| has_or_at(&row.patterns[col])) {
+        return compile(expand_or_rows(&matrix, col), paths);
+    }
+
     // 3b. SINGLE-CONSTRUCTOR DECOMPOSITION: Tuple and Struct patterns are
     // "single-constructor" types — there's only one shape they can be.
     // They don't need a runtime test (no Switch), just decomposition into
@@ -147,6 +156,49 @@ pub fn compile(matrix: PatternMatrix, paths: Vec<ScrutineePath>) -> DecisionTree
     }
 }
 
+// Or-pattern expansion
+
+/// Whether a pattern is an or-pattern, possibly under `name @`.
+fn has_or_at(pat: &FlatPattern) -> bool {
+    match pat {
+        FlatPattern::Or(_) => true,
+        FlatPattern::At { inner, .. } => has_or_at(inner),
+        _ => false,
+    }
+}
+
+/// Replace each row whose pattern at `col` is an or-pattern with one row
+/// per alternative, preserving row order (and therefore arm priority).
+///
+/// Nested or-patterns (`(1 | 2) | 3`) and `name @ (a | b)` are flattened
+/// recursively; the `@` binding is kept on every expanded alternative.
+fn expand_or_rows(matrix: &PatternMatrix, col: usize) -> PatternMatrix {
+    let mut expanded = Vec::with_capacity(matrix.len());
+    for row in matrix {
+        for alt in or_alternatives(&row.patterns[col]) {
+            let mut new_row = row.clone();
+            new_row.patterns[col] = alt;
+            expanded.push(new_row);
+        }
+    }
+    expanded
+}
+
+/// The flattened alternatives of a pattern (itself, if not an or-pattern).
+fn or_alternatives(pat: &FlatPattern) -> Vec<FlatPattern> {
+    match pat {
+        FlatPattern::Or(alts) => alts.iter().flat_map(or_alternatives).collect(),
+        FlatPattern::At { name, inner } => or_alternatives(inner)
+            .into_iter()
+            .map(|alt| FlatPattern::At {
+                name: *name,
+                inner: Box::new(alt),
+            })
+            .collect(),
+        _ => vec![pat.clone()],
+    }
+}
+
 // Column selection
 
 /// Choose the best column to split on.
@@ -316,13 +368,8 @@ fn decompose_single_ctor_pattern(pat: &FlatPattern, sub_count: usize) -> Vec<Fla
             vec![FlatPattern::Wildcard; sub_count]
         }
         FlatPattern::At { inner, .. } => decompose_single_ctor_pattern(inner, sub_count),
-        FlatPattern::Or(alts) => {
-            // Use the first alternative's decomposition.
-            if let Some(first) = alts.first() {
-                decompose_single_ctor_pattern(first, sub_count)
-            } else {
-                vec![FlatPattern::Wildcard; sub_count]
-            }
+        FlatPattern::Or(_) => {
+            unreachable!("or-pattern at a decomposed column — rows should be expanded first")
         }
         _ => vec![FlatPattern::Wildcard; sub_count],
     }
@@ -453,18 +500,7 @@ fn test_values_from_pattern(pat: &FlatPattern) -> Vec<TestValue> {
             start,
             end,
             inclusive,
-        } => {
-            if let (Some(lo), Some(hi)) = (start, end) {
-                vec![TestValue::IntRange {
-                    lo: *lo,
-                    hi: *hi,
-                    inclusive: *inclusive,
-                }]
-            } else {
-                // Open-ended ranges are treated as wildcards for decision purposes.
-                vec![]
-            }
-        }
+        } => vec![range_test_value(*start, *end, *inclusive)],
         FlatPattern::Or(alts) => {
             let mut result = Vec::new();
             for alt in alts {
@@ -476,6 +512,23 @@ fn test_values_from_pattern(pat: &FlatPattern) -> Vec<TestValue> {
     }
 }
 
+/// Build the `IntRange` test for a range pattern.
+///
+/// Half-open ranges get the missing bound filled in: `..hi` starts at
+/// `i64::MIN` and `lo..` runs to `i64::MAX` inclusive, so they test and
+/// specialize like any other range.
+fn range_test_value(start: Option<i64>, end: Option<i64>, inclusive: bool) -> TestValue {
+    let (hi, inclusive) = match end {
+        Some(hi) => (hi, inclusive),
+        None => (i64::MAX, true),
+    };
+    TestValue::IntRange {
+        lo: start.unwrap_or(i64::MIN),
+        hi,
+        inclusive,
+    }
+}
+
 /// A key for deduplicating test values.
 fn constructor_key_for_test_value(tv: &TestValue) -> u64 {
     use std::hash::{Hash, Hasher};
@@ -669,10 +722,6 @@ enum SpecResult {
 /// `expected_sub_count` is the number of sub-patterns that this specialization
 /// should produce for wildcard expansion (determined by scanning the matrix
 /// for the first concrete constructor pattern).
-#[expect(
-    clippy::too_many_lines,
-    reason = "exhaustive (FlatPattern, TestValue) specialization dispatch"
-)]
 fn specialize_pattern(pat: &FlatPattern, tv: &TestValue, expected_sub_count: usize) -> SpecResult {
     match (pat, tv) {
         // Wildcards and bindings match any test value.
@@ -762,54 +811,18 @@ fn specialize_pattern(pat: &FlatPattern, tv: &TestValue, expected_sub_count: usi
                 end,
                 inclusive,
             },
-            TestValue::IntRange {
-                lo,
-                hi,
-                inclusive: tv_incl,
-            },
+            TestValue::IntRange { .. },
         ) => {
-            if start.as_ref() == Some(lo) && end.as_ref() == Some(hi) && *inclusive == *tv_incl {
+            if range_test_value(*start, *end, *inclusive) == *tv {
                 SpecResult::Match(vec![])
             } else {
                 SpecResult::NoMatch
             }
         }
 
-        // Or-pattern: combine sub-patterns from ALL matching alternatives.
-        (FlatPattern::Or(alts), tv) => {
-            let matching: Vec<Vec<FlatPattern>> = alts
-                .iter()
-                .filter_map(|alt| {
-                    if let SpecResult::Match(subs) = specialize_pattern(alt, tv, expected_sub_count)
-                    {
-                        Some(subs)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            match matching.len() {
-                0 => SpecResult::NoMatch,
-                1 => {
-                    // SAFETY: matching.len() == 1, so into_iter().next() is always Some.
-                    #[expect(clippy::unwrap_used, reason = "Length checked to be 1")]
-                    let single = matching.into_iter().next().unwrap();
-                    SpecResult::Match(single)
-                }
-                _ => {
-                    // Multiple alternatives matched: combine sub-patterns
-                    // element-wise into Or patterns.
-                    let combined: Vec<FlatPattern> = (0..expected_sub_count)
-                        .map(|col| {
-                            let col_pats: Vec<FlatPattern> =
-                                matching.iter().map(|subs| subs[col].clone()).collect();
-                            FlatPattern::Or(col_pats)
-                        })
-                        .collect();
-                    SpecResult::Match(combined)
-                }
-            }
+        // Or-patterns never reach specialization (see `expand_or_rows`).
+        (FlatPattern::Or(_), _) => {
+            unreachable!("or-pattern at a specialized column — rows should be expanded first")
         }
 
         // At-pattern: match on the inner pattern, keep the binding.
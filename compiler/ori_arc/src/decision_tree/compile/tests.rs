@@ -108,6 +108,63 @@ fn compile_int_with_default() {
     }
 }
 
+#[test]
+fn compile_half_open_ranges() {
+    // match n { ..0 -> a, 10.. -> b, _ -> c }
+    let m = matrix(vec![
+        (
+            vec![FlatPattern::Range {
+                start: None,
+                end: Some(0),
+                inclusive: false,
+            }],
+            0,
+        ),
+        (
+            vec![FlatPattern::Range {
+                start: Some(10),
+                end: None,
+                inclusive: false,
+            }],
+            1,
+        ),
+        (vec![FlatPattern::Wildcard], 2),
+    ]);
+    let tree = compile(m, paths(1));
+
+    let DecisionTree::Switch {
+        test_kind, edges, ..
+    } = &tree
+    else {
+        panic!("expected Switch, got {tree:?}");
+    };
+    assert_eq!(*test_kind, TestKind::IntRange);
+    assert_eq!(
+        edges[0].0,
+        TestValue::IntRange {
+            lo: i64::MIN,
+            hi: 0,
+            inclusive: false,
+        }
+    );
+    assert!(matches!(
+        edges[0].1,
+        DecisionTree::Leaf { arm_index: 0, .. }
+    ));
+    assert_eq!(
+        edges[1].0,
+        TestValue::IntRange {
+            lo: 10,
+            hi: i64::MAX,
+            inclusive: true,
+        }
+    );
+    assert!(matches!(
+        edges[1].1,
+        DecisionTree::Leaf { arm_index: 1, .. }
+    ));
+}
+
 // Enum variant matching
 
 #[test]
@@ -356,6 +413,99 @@ fn compile_or_pattern() {
     }
 }
 
+/// Walk a decision tree over an all-int tuple scrutinee, returning the
+/// leaf that `values` reaches (`None` for `Fail`).
+fn leaf_for_int_tuple<'t>(tree: &'t DecisionTree, values: &[i64]) -> Option<&'t DecisionTree> {
+    match tree {
+        DecisionTree::Leaf { .. } => Some(tree),
+        DecisionTree::Switch {
+            path,
+            edges,
+            default,
+            ..
+        } => {
+            let [PathInstruction::TupleIndex(i)] = path.as_slice() else {
+                panic!("expected a tuple-element path, got {path:?}");
+            };
+            let value = values[*i as usize];
+            let next = edges
+                .iter()
+                .find(|(tv, _)| *tv == TestValue::Int(value))
+                .map(|(_, subtree)| subtree)
+                .or(default.as_deref())?;
+            leaf_for_int_tuple(next, values)
+        }
+        DecisionTree::Guard { .. } | DecisionTree::Fail => None,
+    }
+}
+
+fn arm_for_int_tuple(tree: &DecisionTree, values: &[i64]) -> Option<usize> {
+    match leaf_for_int_tuple(tree, values)? {
+        DecisionTree::Leaf { arm_index, .. } => Some(*arm_index),
+        _ => None,
+    }
+}
+
+#[test]
+fn compile_or_pattern_tuple_alternatives() {
+    // match t { (1, 2) | (3, 4) -> a, _ -> b }
+    // Each alternative must match as a whole: (1, 4) and (3, 2) fall through.
+    let tuple = |a, b| FlatPattern::Tuple(vec![FlatPattern::LitInt(a), FlatPattern::LitInt(b)]);
+    let m = matrix(vec![
+        (vec![FlatPattern::Or(vec![tuple(1, 2), tuple(3, 4)])], 0),
+        (vec![FlatPattern::Wildcard], 1),
+    ]);
+    let tree = compile(m, paths(1));
+
+    assert_eq!(arm_for_int_tuple(&tree, &[1, 2]), Some(0));
+    assert_eq!(arm_for_int_tuple(&tree, &[3, 4]), Some(0));
+    assert_eq!(arm_for_int_tuple(&tree, &[1, 4]), Some(1));
+    assert_eq!(arm_for_int_tuple(&tree, &[3, 2]), Some(1));
+    assert_eq!(arm_for_int_tuple(&tree, &[5, 5]), Some(1));
+}
+
+#[test]
+fn compile_or_pattern_tuple_bindings_follow_alternative() {
+    // match t { (x, 1) | (1, x) -> x, _ -> 0 }
+    // `x` binds element 0 via the first alternative, element 1 via the second.
+    let name_x = Name::from_raw(1);
+    let m = matrix(vec![
+        (
+            vec![FlatPattern::Or(vec![
+                FlatPattern::Tuple(vec![FlatPattern::Binding(name_x), FlatPattern::LitInt(1)]),
+                FlatPattern::Tuple(vec![FlatPattern::LitInt(1), FlatPattern::Binding(name_x)]),
+            ])],
+            0,
+        ),
+        (vec![FlatPattern::Wildcard], 1),
+    ]);
+    let tree = compile(m, paths(1));
+
+    let bindings = |values: &[i64]| match leaf_for_int_tuple(&tree, values) {
+        Some(DecisionTree::Leaf {
+            arm_index: 0,
+            bindings,
+        }) => bindings.clone(),
+        other => panic!("expected arm 0 for {values:?}, got {other:?}"),
+    };
+    // (7, 1) matches the first alternative: x = element 0.
+    assert_eq!(
+        bindings(&[7, 1]),
+        vec![(name_x, vec![PathInstruction::TupleIndex(0)])]
+    );
+    // (1, 7) matches only the second alternative: x = element 1.
+    assert_eq!(
+        bindings(&[1, 7]),
+        vec![(name_x, vec![PathInstruction::TupleIndex(1)])]
+    );
+    // (1, 1) matches both; the first alternative wins.
+    assert_eq!(
+        bindings(&[1, 1]),
+        vec![(name_x, vec![PathInstruction::TupleIndex(0)])]
+    );
+    assert_eq!(arm_for_int_tuple(&tree, &[2, 2]), Some(1));
+}
+
 // pick_column heuristic
 
 #[test]
@@ -46,7 +46,8 @@ pub(crate) struct EmitContext {
 ///   strings), emits an if-else chain of `Branch` terminators.
 ///
 /// - **`Leaf`**: Binds pattern variables by resolving paths from the root scrutinee,
-///   then lowers the arm body and jumps to the merge block.
+///   then lowers the arm body and jumps to the merge block. Bindings are
+///   scoped to the arm.
 ///
 /// - **`Guard`**: Binds variables, evaluates the guard expression, then branches
 ///   to the body block (if guard passes) or the `on_fail` subtree (if it fails).
@@ -328,7 +329,7 @@ fn emit_leaf(
     ctx: &mut EmitContext,
 ) {
     // Bind pattern variables by resolving paths from root scrutinee.
-    bind_pattern_variables(lowerer, bindings, ctx);
+    let outer = bind_pattern_variables(lowerer, bindings, ctx);
 
     // Lower the arm body and jump to merge block.
     let body_expr = ctx.arm_bodies[arm_index];
@@ -338,6 +339,7 @@ fn emit_leaf(
             .builder
             .terminate_jump(ctx.merge_block, vec![body_val]);
     }
+    lowerer.scope = outer;
 }
 
 // Guard emission
@@ -352,7 +354,7 @@ fn emit_guard(
     ctx: &mut EmitContext,
 ) {
     // Bind pattern variables.
-    bind_pattern_variables(lowerer, bindings, ctx);
+    let outer = bind_pattern_variables(lowerer, bindings, ctx);
 
     // Evaluate the guard expression.
     let guard_result = lowerer.lower_expr(guard);
@@ -373,7 +375,9 @@ fn emit_guard(
             .terminate_jump(ctx.merge_block, vec![body_val]);
     }
 
-    // Guard failed: continue matching.
+    // Guard failed: continue matching without this arm's bindings — a
+    // later arm binding (or reading an outer) `x` must not see ours.
+    lowerer.scope = outer;
     lowerer.builder.position_at(fail_block);
     emit_tree(lowerer, on_fail, ctx);
 }
@@ -415,15 +419,21 @@ fn resolve_path(
 // Binding
 
 /// Bind pattern variables by resolving their paths from the root scrutinee.
+///
+/// Returns the scope as it was before binding. Callers restore it once the
+/// arm is emitted so bindings never leak into sibling arms, a guard's
+/// fall-through, or the code after the match.
 fn bind_pattern_variables(
     lowerer: &mut crate::lower::ArcLowerer<'_>,
     bindings: &[(Name, ScrutineePath)],
     ctx: &EmitContext,
-) {
+) -> crate::lower::ArcScope {
+    let outer = lowerer.scope.clone();
     for (name, path) in bindings {
         let var = resolve_path(lowerer, ctx.root_scrutinee, path, ctx.span);
         lowerer.scope.bind(*name, var);
     }
+    outer
 }
 
 // Literal emission
@@ -139,6 +139,7 @@ impl ArcLowerer<'_> {
                 pool: self.pool,
                 scope: lambda_scope,
                 loop_ctx: None,
+                hash_length: None,
                 problems: &mut lambda_problems,
                 lambdas: self.lambdas,
             };
@@ -162,21 +162,45 @@ impl ArcLowerer<'_> {
         span: Span,
     ) -> ArcVarId {
         let recv = self.lower_expr(receiver);
-        let idx_var = self.lower_expr(index);
+        let idx_var = self.lower_index_operand(recv, receiver, index);
         let index_fn = self.interner.intern("__index");
         self.builder
             .emit_apply(ty, index_fn, vec![recv, idx_var], Some(span))
     }
 
+    /// Lower the index of `receiver[index]`, binding `#` to the receiver's length.
+    ///
+    /// Lists `{len, cap, data}` and strings `{len, data}` both keep their length
+    /// in field 0. Other receivers (maps, tuples) leave `#` unbound.
+    pub(crate) fn lower_index_operand(
+        &mut self,
+        recv: ArcVarId,
+        receiver: CanId,
+        index: CanId,
+    ) -> ArcVarId {
+        let recv_ty = self.expr_type(receiver);
+        let old_hash = self.hash_length.take();
+        if matches!(self.pool.tag(recv_ty), Tag::List | Tag::Str) {
+            self.hash_length = Some(self.builder.emit_project(Idx::INT, recv, 0, None));
+        }
+        let idx_var = self.lower_expr(index);
+        self.hash_length = old_hash;
+        idx_var
+    }
+
     // Range
 
     /// Lower a range expression to ARC IR.
+    ///
+    /// Builds `(start, end, inclusive)`, matching the LLVM range layout
+    /// `{i64, i64, i1}`. An omitted start is 0 and an omitted end is
+    /// `i64::MAX`; a step is not represented yet.
     pub(crate) fn lower_range(
         &mut self,
         start: CanId,
         end: CanId,
-        step: CanId,
-        _inclusive: bool,
+        _step: CanId,
+        inclusive: bool,
         ty: Idx,
         span: Span,
     ) -> ArcVarId {
@@ -193,12 +217,11 @@ impl ArcLowerer<'_> {
             self.builder
                 .emit_let(Idx::INT, ArcValue::Literal(LitValue::Int(i64::MAX)), None)
         });
-        args.push(if step.is_valid() {
-            self.lower_expr(step)
-        } else {
-            self.builder
-                .emit_let(Idx::INT, ArcValue::Literal(LitValue::Int(1)), None)
-        });
+        args.push(self.builder.emit_let(
+            Idx::BOOL,
+            ArcValue::Literal(LitValue::Bool(inclusive)),
+            None,
+        ));
         self.builder
             .emit_construct(ty, CtorKind::Tuple, args, Some(span))
     }
@@ -2,7 +2,7 @@ use ori_ir::canon::{CanArena, CanExpr, CanNode, CanonResult};
 use ori_ir::{Name, Span, StringInterner, TypeId};
 use ori_types::{Idx, Pool};
 
-use crate::ir::{ArcInstr, CtorKind};
+use crate::ir::{ArcInstr, ArcValue, CtorKind};
 
 #[test]
 fn lower_tuple() {
@@ -104,3 +104,84 @@ fn lower_none() {
         }
     ));
 }
+
+#[test]
+fn lower_index_binds_hash_to_receiver_length() {
+    let interner = StringInterner::new();
+    let mut pool = Pool::new();
+    let list_ty = pool.list(Idx::INT);
+    let int_ty = TypeId::from_raw(Idx::INT.raw());
+    let mut arena = CanArena::with_capacity(200);
+
+    // xs[# - 1]
+    let xs_name = interner.intern("xs");
+    let xs = arena.push(CanNode::new(
+        CanExpr::Ident(xs_name),
+        Span::new(0, 2),
+        TypeId::from_raw(list_ty.raw()),
+    ));
+    let hash = arena.push(CanNode::new(CanExpr::HashLength, Span::new(3, 4), int_ty));
+    let one = arena.push(CanNode::new(CanExpr::Int(1), Span::new(7, 8), int_ty));
+    let sub = arena.push(CanNode::new(
+        CanExpr::Binary {
+            op: ori_ir::BinaryOp::Sub,
+            left: hash,
+            right: one,
+        },
+        Span::new(3, 8),
+        int_ty,
+    ));
+    let index = arena.push(CanNode::new(
+        CanExpr::Index {
+            receiver: xs,
+            index: sub,
+        },
+        Span::new(0, 9),
+        int_ty,
+    ));
+
+    let canon = CanonResult {
+        arena,
+        constants: ori_ir::canon::ConstantPool::new(),
+        decision_trees: ori_ir::canon::DecisionTreePool::default(),
+        root: index,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let mut problems = Vec::new();
+    let (func, _) = super::super::super::lower_function_can(
+        Name::from_raw(1),
+        &[(xs_name, list_ty)],
+        Idx::INT,
+        index,
+        &canon,
+        &interner,
+        &pool,
+        &mut problems,
+    );
+    assert!(problems.is_empty());
+
+    let body = &func.blocks[0].body;
+    let Some(len) = body.iter().find_map(|instr| match instr {
+        ArcInstr::Project {
+            dst, field: 0, ty, ..
+        } if *ty == Idx::INT => Some(*dst),
+        _ => None,
+    }) else {
+        panic!("missing length projection for `#`: {body:?}");
+    };
+
+    // The subtraction's left operand is the projected length
+    let uses_len = body.iter().any(|instr| {
+        matches!(
+            instr,
+            ArcInstr::Let {
+                value: ArcValue::PrimOp { args, .. },
+                ..
+            } if args.first() == Some(&len)
+        )
+    });
+    assert!(uses_len, "`#` must lower to the list length: {body:?}");
+}
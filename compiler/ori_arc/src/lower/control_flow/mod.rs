@@ -78,7 +78,55 @@ impl ArcLowerer<'_> {
         _span: Span,
     ) -> ArcVarId {
         let cond_var = self.lower_expr(cond);
+        self.lower_branches(
+            cond_var,
+            |this| this.lower_expr(then_branch),
+            |this| {
+                if else_branch.is_valid() {
+                    this.lower_expr(else_branch)
+                } else {
+                    this.emit_unit()
+                }
+            },
+            ty,
+        )
+    }
+
+    /// Lower `a && b` / `a || b` without evaluating `b` when `a` decides.
+    ///
+    /// Desugars to `if a then b else false` and `if a then true else b`.
+    pub(crate) fn lower_short_circuit(
+        &mut self,
+        is_and: bool,
+        left: CanId,
+        right: CanId,
+        ty: Idx,
+    ) -> ArcVarId {
+        let lhs = self.lower_expr(left);
+        let short = move |this: &mut Self| {
+            this.builder
+                .emit_let(ty, ArcValue::Literal(LitValue::Bool(!is_and)), None)
+        };
+        let eval_rhs = move |this: &mut Self| this.lower_expr(right);
+        if is_and {
+            self.lower_branches(lhs, eval_rhs, short, ty)
+        } else {
+            self.lower_branches(lhs, short, eval_rhs, ty)
+        }
+    }
 
+    /// Branch on `cond_var` into two arms and merge their values.
+    ///
+    /// Each arm runs from the pre-branch scope. Mutable bindings reassigned
+    /// in either arm become merge-block parameters, so later reads see the
+    /// value from whichever arm ran.
+    fn lower_branches(
+        &mut self,
+        cond_var: ArcVarId,
+        then_arm: impl FnOnce(&mut Self) -> ArcVarId,
+        else_arm: impl FnOnce(&mut Self) -> ArcVarId,
+        ty: Idx,
+    ) -> ArcVarId {
         let then_block = self.builder.new_block();
         let else_block = self.builder.new_block();
         let merge_block = self.builder.new_block();
@@ -105,7 +153,7 @@ impl ArcLowerer<'_> {
         // lowering left us, not from the original entry block.
         self.builder.position_at(then_block);
         self.scope = pre_scope.clone();
-        let then_val = self.lower_expr(then_branch);
+        let then_val = then_arm(self);
         let then_scope = self.scope.clone();
         let then_terminated = self.builder.is_terminated();
         let then_exit = self.builder.current_block();
@@ -113,11 +161,7 @@ impl ArcLowerer<'_> {
         // Else branch (same continuation-block reasoning as above).
         self.builder.position_at(else_block);
         self.scope = pre_scope.clone();
-        let else_val = if else_branch.is_valid() {
-            self.lower_expr(else_branch)
-        } else {
-            self.emit_unit()
-        };
+        let else_val = else_arm(self);
         let else_scope = self.scope.clone();
         let else_terminated = self.builder.is_terminated();
         let else_exit = self.builder.current_block();
@@ -219,6 +263,9 @@ impl ArcLowerer<'_> {
     // For
 
     /// Lower `For { binding, iter, guard, body }` — range iteration.
+    ///
+    /// Counts `i` from the range's start while `i < end`, or `i <= end` when
+    /// the range is inclusive (`lo..=hi`).
     pub(crate) fn lower_for(
         &mut self,
         binding: Name,
@@ -236,13 +283,15 @@ impl ArcLowerer<'_> {
 
         let start = self.builder.emit_project(Idx::INT, iter_val, 0, None);
         let end = self.builder.emit_project(Idx::INT, iter_val, 1, None);
+        let inclusive = self.builder.emit_project(Idx::BOOL, iter_val, 2, None);
 
         self.builder.terminate_jump(header_block, vec![start]);
 
         self.builder.position_at(header_block);
         let i_var = self.builder.add_block_param(header_block, Idx::INT);
 
-        let in_bounds = self.builder.emit_let(
+        // i < end || (inclusive && i == end)
+        let below_end = self.builder.emit_let(
             Idx::BOOL,
             ArcValue::PrimOp {
                 op: PrimOp::Binary(ori_ir::BinaryOp::Lt),
@@ -250,6 +299,30 @@ impl ArcLowerer<'_> {
             },
             None,
         );
+        let at_end = self.builder.emit_let(
+            Idx::BOOL,
+            ArcValue::PrimOp {
+                op: PrimOp::Binary(ori_ir::BinaryOp::Eq),
+                args: vec![i_var, end],
+            },
+            None,
+        );
+        let at_incl_end = self.builder.emit_let(
+            Idx::BOOL,
+            ArcValue::PrimOp {
+                op: PrimOp::Binary(ori_ir::BinaryOp::And),
+                args: vec![inclusive, at_end],
+            },
+            None,
+        );
+        let in_bounds = self.builder.emit_let(
+            Idx::BOOL,
+            ArcValue::PrimOp {
+                op: PrimOp::Binary(ori_ir::BinaryOp::Or),
+                args: vec![below_end, at_incl_end],
+            },
+            None,
+        );
 
         if guard.is_valid() {
             let guarded_block = self.builder.new_block();
@@ -367,7 +440,7 @@ impl ArcLowerer<'_> {
             }
             CanExpr::Index { receiver, index } => {
                 let recv = self.lower_expr(receiver);
-                let idx_var = self.lower_expr(index);
+                let idx_var = self.lower_index_operand(recv, receiver, index);
                 let setter_fn = self.interner.intern("__set_index");
                 self.builder
                     .emit_apply(Idx::UNIT, setter_fn, vec![recv, idx_var, rhs], Some(span));
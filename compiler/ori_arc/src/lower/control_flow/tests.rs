@@ -3,7 +3,7 @@ use ori_ir::{Mutability, Name, Span, StringInterner, TypeId};
 use ori_types::Idx;
 use ori_types::Pool;
 
-use crate::ir::ArcTerminator;
+use crate::ir::{ArcFunction, ArcInstr, ArcTerminator, ArcValue, ArcVarId, LitValue};
 
 #[test]
 fn lower_block_with_let() {
@@ -202,3 +202,258 @@ fn lower_loop_produces_header_and_exit() {
     assert!(problems.is_empty(), "problems: {problems:?}");
     assert!(func.blocks.len() >= 3);
 }
+
+/// `for i in 1..=5 do ()` — the header accepts `i == end` for inclusive ranges.
+#[test]
+fn lower_for_inclusive_range_tests_end() {
+    let interner = StringInterner::new();
+    let mut pool = Pool::new();
+    let range_ty = pool.range(Idx::INT);
+    let mut arena = CanArena::with_capacity(200);
+
+    let lo = arena.push(CanNode::new(
+        CanExpr::Int(1),
+        Span::new(9, 10),
+        TypeId::from_raw(Idx::INT.raw()),
+    ));
+    let hi = arena.push(CanNode::new(
+        CanExpr::Int(5),
+        Span::new(13, 14),
+        TypeId::from_raw(Idx::INT.raw()),
+    ));
+    let range = arena.push(CanNode::new(
+        CanExpr::Range {
+            start: lo,
+            end: hi,
+            step: ori_ir::canon::CanId::INVALID,
+            inclusive: true,
+        },
+        Span::new(9, 14),
+        TypeId::from_raw(range_ty.raw()),
+    ));
+    let body = arena.push(CanNode::new(
+        CanExpr::Unit,
+        Span::new(18, 20),
+        TypeId::from_raw(Idx::UNIT.raw()),
+    ));
+    let for_expr = arena.push(CanNode::new(
+        CanExpr::For {
+            label: Name::EMPTY,
+            binding: Name::from_raw(100),
+            iter: range,
+            guard: ori_ir::canon::CanId::INVALID,
+            body,
+            is_yield: false,
+        },
+        Span::new(0, 20),
+        TypeId::from_raw(Idx::UNIT.raw()),
+    ));
+
+    let canon = CanonResult {
+        arena,
+        constants: ori_ir::canon::ConstantPool::new(),
+        decision_trees: ori_ir::canon::DecisionTreePool::default(),
+        root: for_expr,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let mut problems = Vec::new();
+    let (func, _) = super::super::super::lower_function_can(
+        Name::from_raw(1),
+        &[],
+        Idx::UNIT,
+        for_expr,
+        &canon,
+        &interner,
+        &pool,
+        &mut problems,
+    );
+    assert!(problems.is_empty(), "problems: {problems:?}");
+
+    let instrs: Vec<_> = func.blocks.iter().flat_map(|b| &b.body).collect();
+    let has_binary = |op| {
+        instrs.iter().any(|instr| {
+            matches!(
+                instr,
+                ArcInstr::Let {
+                    value: ArcValue::PrimOp {
+                        op: crate::ir::PrimOp::Binary(o),
+                        ..
+                    },
+                    ..
+                } if *o == op
+            )
+        })
+    };
+    assert!(has_binary(ori_ir::BinaryOp::Lt));
+    assert!(has_binary(ori_ir::BinaryOp::Eq));
+    assert!(has_binary(ori_ir::BinaryOp::Or));
+
+    // The range carries its inclusivity as a bool literal
+    assert!(instrs.iter().any(|instr| matches!(
+        instr,
+        ArcInstr::Let {
+            value: ArcValue::Literal(LitValue::Bool(true)),
+            ..
+        }
+    )));
+}
+
+/// `{ let x = 7; match 5 { x if x > 10 -> 0, _ -> x } }`
+///
+/// The first arm's guard fails, so the second arm must read the outer
+/// `x` (7), not the scrutinee bound by the failed arm (5).
+#[test]
+fn lower_match_guard_binding_does_not_leak_into_next_arm() {
+    let interner = StringInterner::new();
+    let pool = Pool::new();
+    let mut arena = CanArena::with_capacity(200);
+    let int_ty = TypeId::from_raw(Idx::INT.raw());
+
+    let x_name = Name::from_raw(100);
+    let lit7 = arena.push(CanNode::new(CanExpr::Int(7), Span::new(10, 11), int_ty));
+    let pat = arena.push_binding_pattern(CanBindingPattern::Name {
+        name: x_name,
+        mutable: Mutability::Immutable,
+    });
+    let let_expr = arena.push(CanNode::new(
+        CanExpr::Let {
+            pattern: pat,
+            init: lit7,
+            mutable: Mutability::Immutable,
+        },
+        Span::new(2, 12),
+        TypeId::from_raw(Idx::UNIT.raw()),
+    ));
+
+    let scrutinee = arena.push(CanNode::new(CanExpr::Int(5), Span::new(20, 21), int_ty));
+    let guard_x = arena.push(CanNode::new(
+        CanExpr::Ident(x_name),
+        Span::new(30, 31),
+        int_ty,
+    ));
+    let lit10 = arena.push(CanNode::new(CanExpr::Int(10), Span::new(34, 36), int_ty));
+    let guard = arena.push(CanNode::new(
+        CanExpr::Binary {
+            op: ori_ir::BinaryOp::Gt,
+            left: guard_x,
+            right: lit10,
+        },
+        Span::new(30, 36),
+        TypeId::from_raw(Idx::BOOL.raw()),
+    ));
+    let arm0 = arena.push(CanNode::new(CanExpr::Int(0), Span::new(40, 41), int_ty));
+    let arm1 = arena.push(CanNode::new(
+        CanExpr::Ident(x_name),
+        Span::new(48, 49),
+        int_ty,
+    ));
+    let arms = arena.push_expr_list(&[arm0, arm1]);
+
+    let mut decision_trees = ori_ir::canon::DecisionTreePool::default();
+    let tree = decision_trees.push(ori_ir::canon::DecisionTree::Guard {
+        arm_index: 0,
+        bindings: vec![(x_name, vec![])],
+        guard,
+        on_fail: Box::new(ori_ir::canon::DecisionTree::Leaf {
+            arm_index: 1,
+            bindings: vec![],
+        }),
+    });
+
+    let match_expr = arena.push(CanNode::new(
+        CanExpr::Match {
+            scrutinee,
+            decision_tree: tree,
+            arms,
+        },
+        Span::new(14, 50),
+        int_ty,
+    ));
+    let stmts = arena.push_expr_list(&[let_expr]);
+    let block = arena.push(CanNode::new(
+        CanExpr::Block {
+            stmts,
+            result: match_expr,
+        },
+        Span::new(0, 52),
+        int_ty,
+    ));
+
+    let canon = CanonResult {
+        arena,
+        constants: ori_ir::canon::ConstantPool::new(),
+        decision_trees,
+        root: block,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let mut problems = Vec::new();
+    let (func, _) = super::super::super::lower_function_can(
+        Name::from_raw(1),
+        &[],
+        Idx::INT,
+        block,
+        &canon,
+        &interner,
+        &pool,
+        &mut problems,
+    );
+    assert!(problems.is_empty(), "problems: {problems:?}");
+
+    let outer_x = literal_var(&func, 7);
+    let scrut = literal_var(&func, 5);
+    let jump_args = merge_values(&func);
+    assert!(
+        jump_args.contains(&outer_x) && !jump_args.contains(&scrut),
+        "fallback arm must yield the outer `x`, not the failed arm's binding: {jump_args:?}"
+    );
+}
+
+/// The value each single-argument `Jump` passes, looking through copies.
+fn merge_values(func: &ArcFunction) -> Vec<ArcVarId> {
+    func.blocks
+        .iter()
+        .filter_map(|b| match &b.terminator {
+            ArcTerminator::Jump { args, .. } if args.len() == 1 => Some(copy_source(func, args[0])),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The variable defined by `let dst = <int literal n>`.
+fn literal_var(func: &ArcFunction, n: i64) -> ArcVarId {
+    func.blocks
+        .iter()
+        .flat_map(|b| &b.body)
+        .find_map(|instr| match instr {
+            ArcInstr::Let {
+                dst,
+                value: ArcValue::Literal(LitValue::Int(v)),
+                ..
+            } if *v == n => Some(*dst),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("no literal {n}"))
+}
+
+/// Identifier reads lower to `let dst = src` copies; follow one back to the
+/// variable it reads.
+fn copy_source(func: &ArcFunction, var: ArcVarId) -> ArcVarId {
+    func.blocks
+        .iter()
+        .flat_map(|b| &b.body)
+        .find_map(|instr| match instr {
+            ArcInstr::Let {
+                dst,
+                value: ArcValue::Var(src),
+                ..
+            } if *dst == var => Some(*src),
+            _ => None,
+        })
+        .unwrap_or(var)
+}
@@ -40,6 +40,8 @@ pub struct ArcLowerer<'a> {
     pub(crate) pool: &'a Pool,
     pub(crate) scope: ArcScope,
     pub(crate) loop_ctx: Option<LoopContext>,
+    /// Length of the list or string being indexed, for `#` inside `[...]`.
+    pub(crate) hash_length: Option<ArcVarId>,
     pub(crate) problems: &'a mut Vec<ArcProblem>,
     pub(crate) lambdas: &'a mut Vec<ArcFunction>,
 }
@@ -109,7 +111,16 @@ impl ArcLowerer<'_> {
                 ArcValue::Literal(LitValue::Size { value, unit }),
                 Some(span),
             ),
-            CanExpr::Unit | CanExpr::HashLength | CanExpr::FunctionRef(_) => {
+            CanExpr::HashLength => {
+                if let Some(len) = self.hash_length {
+                    len
+                } else {
+                    tracing::warn!("HashLength (#) used outside index expression");
+                    self.builder
+                        .emit_let(ty, ArcValue::Literal(LitValue::Int(0)), Some(span))
+                }
+            }
+            CanExpr::Unit | CanExpr::FunctionRef(_) => {
                 self.builder
                     .emit_let(ty, ArcValue::Literal(LitValue::Unit), Some(span))
             }
@@ -283,6 +294,11 @@ impl ArcLowerer<'_> {
         ty: Idx,
         span: Span,
     ) -> ArcVarId {
+        match op {
+            ori_ir::BinaryOp::And => return self.lower_short_circuit(true, left, right, ty),
+            ori_ir::BinaryOp::Or => return self.lower_short_circuit(false, left, right, ty),
+            _ => {}
+        }
         let lhs = self.lower_expr(left);
         let rhs = self.lower_expr(right);
         self.builder.emit_let(
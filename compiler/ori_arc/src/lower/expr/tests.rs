@@ -166,6 +166,86 @@ fn lower_binary_op() {
     }
 }
 
+/// `lhs && rhs` / `lhs || rhs` evaluate `rhs` only in a branch of their own.
+#[test]
+fn lower_logical_ops_short_circuit() {
+    for op in [ori_ir::BinaryOp::And, ori_ir::BinaryOp::Or] {
+        let mut arena = CanArena::with_capacity(100);
+        let left = arena.push(CanNode::new(
+            ori_ir::canon::CanExpr::Ident(Name::from_raw(50)),
+            Span::new(0, 1),
+            TypeId::from_raw(Idx::BOOL.raw()),
+        ));
+        let right = arena.push(CanNode::new(
+            ori_ir::canon::CanExpr::Int(7),
+            Span::new(5, 6),
+            TypeId::from_raw(Idx::BOOL.raw()),
+        ));
+        let expr = arena.push(CanNode::new(
+            ori_ir::canon::CanExpr::Binary { op, left, right },
+            Span::new(0, 6),
+            TypeId::from_raw(Idx::BOOL.raw()),
+        ));
+        let canon = CanonResult {
+            arena,
+            constants: ori_ir::canon::ConstantPool::new(),
+            decision_trees: ori_ir::canon::DecisionTreePool::default(),
+            root: expr,
+            roots: vec![],
+            method_roots: vec![],
+            problems: vec![],
+        };
+
+        let interner = StringInterner::new();
+        let pool = Pool::new();
+        let mut problems = Vec::new();
+        let (func, _) = lower_function_can(
+            Name::from_raw(1),
+            &[(Name::from_raw(50), Idx::BOOL)],
+            Idx::BOOL,
+            expr,
+            &canon,
+            &interner,
+            &pool,
+            &mut problems,
+        );
+        assert!(problems.is_empty(), "unexpected problems: {problems:?}");
+
+        let is_rhs = |instr: &ArcInstr| {
+            matches!(
+                instr,
+                ArcInstr::Let {
+                    value: ArcValue::Literal(LitValue::Int(7)),
+                    ..
+                }
+            )
+        };
+        assert!(
+            !func.blocks[0].body.iter().any(is_rhs),
+            "{op:?}: rhs evaluated before the branch"
+        );
+        assert!(matches!(
+            func.blocks[0].terminator,
+            ArcTerminator::Branch { .. }
+        ));
+        assert!(func.blocks.iter().flat_map(|b| &b.body).any(is_rhs));
+        assert!(!func
+            .blocks
+            .iter()
+            .flat_map(|b| &b.body)
+            .any(|instr| matches!(
+                instr,
+                ArcInstr::Let {
+                    value: ArcValue::PrimOp {
+                        op: PrimOp::Binary(ori_ir::BinaryOp::And | ori_ir::BinaryOp::Or),
+                        ..
+                    },
+                    ..
+                }
+            )));
+    }
+}
+
 #[test]
 fn lower_unary_op() {
     let mut arena = CanArena::with_capacity(100);
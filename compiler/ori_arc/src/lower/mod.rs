@@ -522,6 +522,7 @@ pub fn lower_function_can(
         pool,
         scope,
         loop_ctx: None,
+        hash_length: None,
         problems,
         lambdas: &mut lambdas,
     };
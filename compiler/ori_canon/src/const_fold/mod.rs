@@ -101,6 +101,7 @@ fn is_pure_binary(op: BinaryOp) -> bool {
             | BinaryOp::Div
             | BinaryOp::Mod
             | BinaryOp::FloorDiv
+            | BinaryOp::Pow
             | BinaryOp::Eq
             | BinaryOp::NotEq
             | BinaryOp::Lt
@@ -267,6 +268,11 @@ fn fold_binary(op: BinaryOp, left: &ConstValue, right: &ConstValue) -> Option<Co
                 }
             })
         }
+        // Negative exponent or overflow: defer to runtime.
+        (BinaryOp::Pow, ConstValue::Int(a), ConstValue::Int(b)) => {
+            let exp = u32::try_from(*b).ok()?;
+            a.checked_pow(exp).map(ConstValue::Int)
+        }
 
         // Float arithmetic.
         (BinaryOp::Add, ConstValue::Float(a), ConstValue::Float(b)) => Some(ConstValue::Float(
@@ -157,6 +157,59 @@ fn no_fold_integer_overflow() {
     );
 }
 
+#[test]
+fn fold_int_power() {
+    let mut arena = ExprArena::new();
+    let left = arena.alloc_expr(Expr::new(ExprKind::Int(2), Span::DUMMY));
+    let right = arena.alloc_expr(Expr::new(ExprKind::Int(10), Span::DUMMY));
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Binary {
+            op: BinaryOp::Pow,
+            left,
+            right,
+        },
+        Span::DUMMY,
+    ));
+
+    let type_result = test_type_result(vec![Idx::INT, Idx::INT, Idx::INT]);
+    let pool = ori_types::Pool::new();
+    let interner = test_interner();
+
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    match result.arena.kind(result.root) {
+        CanExpr::Constant(cid) => {
+            assert_eq!(*result.constants.get(*cid), ConstValue::Int(1024));
+        }
+        other => panic!("expected Constant(1024), got {other:?}"),
+    }
+}
+
+#[test]
+fn no_fold_negative_exponent() {
+    // 2 ** -1 should NOT be folded (runtime error).
+    let mut arena = ExprArena::new();
+    let left = arena.alloc_expr(Expr::new(ExprKind::Int(2), Span::DUMMY));
+    let right = arena.alloc_expr(Expr::new(ExprKind::Int(-1), Span::DUMMY));
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Binary {
+            op: BinaryOp::Pow,
+            left,
+            right,
+        },
+        Span::DUMMY,
+    ));
+
+    let type_result = test_type_result(vec![Idx::INT, Idx::INT, Idx::INT]);
+    let pool = ori_types::Pool::new();
+    let interner = test_interner();
+
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    assert!(
+        matches!(result.arena.kind(result.root), CanExpr::Binary { .. }),
+        "negative exponent should not be folded"
+    );
+}
+
 #[test]
 fn fold_bool_and() {
     let mut arena = ExprArena::new();
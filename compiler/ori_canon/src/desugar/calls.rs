@@ -0,0 +1,323 @@
+//! Named-argument call desugaring — `CallNamed` / `MethodCallNamed`.
+//!
+//! Arguments are placed into the callee's declared parameter order and
+//! omitted parameters are filled from their defaults. When placement moves
+//! a written argument, the receiver and every non-constant argument are
+//! hoisted into `let` temporaries so they still evaluate left to right
+//! (spec §9, "Argument Evaluation"): `f(b: g(), a: h())` runs `g()` before
+//! `h()`, and `r().m(b: g(), a: h())` runs `r()` before both.
+
+use ori_ir::canon::{CanBindingPattern, CanExpr, CanId};
+use ori_ir::{CallArgRange, ExprId, Mutability, Name, Span, TypeId};
+
+use crate::lower::Lowerer;
+
+/// Lowered call arguments in parameter order.
+struct OrderedArgs {
+    /// Argument values, one per parameter (plus any surplus for recovery).
+    args: Vec<CanId>,
+    /// `let` temporaries that must run before the call, in written order.
+    /// Empty when no argument was moved.
+    hoisted: Vec<CanId>,
+}
+
+impl Lowerer<'_> {
+    // CallNamed → Call
+
+    /// Desugar `CallNamed { func, args: CallArgRange }` to `Call { func, args: CanRange }`.
+    ///
+    /// Named arguments are reordered to match the function signature's parameter
+    /// order. If the function signature is unavailable (error recovery, lambdas),
+    /// arguments are kept in source order.
+    pub(crate) fn desugar_call_named(
+        &mut self,
+        func: ExprId,
+        args: CallArgRange,
+        span: Span,
+        ty: TypeId,
+    ) -> CanId {
+        let func_kind = *self.src.expr_kind(func);
+        let lowered_func = self.lower_expr(func);
+
+        // Get source call arguments (copy out to avoid borrow conflict).
+        let src_args = self.src.get_call_args(args);
+        let src_args: Vec<(Option<Name>, ExprId)> =
+            src_args.iter().map(|a| (a.name, a.value)).collect();
+
+        // Try to resolve the function signature for reordering and default filling.
+        let params = self.resolve_func_params(func_kind);
+
+        let ordered = self.reorder_and_lower_args(&src_args, params.as_deref());
+        let args_range = self.arena.push_expr_list(&ordered.args);
+
+        let call = self.push(
+            CanExpr::Call {
+                func: lowered_func,
+                args: args_range,
+            },
+            span,
+            ty,
+        );
+        self.wrap_hoisted(&ordered.hoisted, call, span, ty)
+    }
+
+    // MethodCallNamed → MethodCall
+
+    /// Desugar `MethodCallNamed { receiver, method, args }` to `MethodCall`.
+    ///
+    /// Same reordering logic as `CallNamed` but looks up the method signature
+    /// from `impl_sigs`.
+    pub(crate) fn desugar_method_call_named(
+        &mut self,
+        receiver: ExprId,
+        method: Name,
+        args: CallArgRange,
+        span: Span,
+        ty: TypeId,
+    ) -> CanId {
+        let lowered_receiver = self.lower_expr(receiver);
+
+        // Get source call arguments.
+        let src_args = self.src.get_call_args(args);
+        let src_args: Vec<(Option<Name>, ExprId)> =
+            src_args.iter().map(|a| (a.name, a.value)).collect();
+
+        // Try to resolve the method signature for reordering and default filling.
+        let params = self.resolve_method_params(method);
+
+        let mut ordered = self.reorder_and_lower_args(&src_args, params.as_deref());
+        // The receiver is written before the arguments, so once any argument
+        // is hoisted the receiver must be hoisted ahead of them.
+        let lowered_receiver =
+            if ordered.hoisted.is_empty() || self.is_constant_arg(lowered_receiver) {
+                lowered_receiver
+            } else {
+                let (stmt, temp_ref) = self.hoist_temp(lowered_receiver, self.name_recv_temp);
+                ordered.hoisted.insert(0, stmt);
+                temp_ref
+            };
+        let args_range = self.arena.push_expr_list(&ordered.args);
+
+        let call = self.push(
+            CanExpr::MethodCall {
+                receiver: lowered_receiver,
+                method,
+                args: args_range,
+            },
+            span,
+            ty,
+        );
+        self.wrap_hoisted(&ordered.hoisted, call, span, ty)
+    }
+
+    /// Reorder named arguments to match parameter order, filling omitted
+    /// parameters with their default expressions.
+    ///
+    /// If `params` is available, arguments with names are placed in the
+    /// corresponding parameter position. Unnamed/positional arguments fill
+    /// remaining slots left-to-right. Empty slots are filled by lowering the
+    /// parameter's default expression. If `params` is `None`, arguments stay
+    /// in source order (fallback for lambdas and error recovery).
+    fn reorder_and_lower_args(
+        &mut self,
+        src_args: &[(Option<Name>, ExprId)],
+        params: Option<&[(Name, Option<ExprId>)]>,
+    ) -> OrderedArgs {
+        match params {
+            Some(params) if !params.is_empty() => {
+                // Build positional slots matching parameter count.
+                let mut slots: Vec<Option<CanId>> = vec![None; params.len()];
+                let mut unnamed = Vec::new();
+                let mut written = Vec::with_capacity(src_args.len());
+
+                for &(name, value) in src_args {
+                    let lowered = self.lower_expr(value);
+                    written.push(lowered);
+                    if let Some(arg_name) = name {
+                        // Find the parameter position by name.
+                        if let Some(pos) = params.iter().position(|(p, _)| *p == arg_name) {
+                            slots[pos] = Some(lowered);
+                        } else {
+                            // Unknown param name — append as-is (error recovery).
+                            unnamed.push(lowered);
+                        }
+                    } else {
+                        unnamed.push(lowered);
+                    }
+                }
+
+                // Fill empty slots: first try unnamed positional args, then defaults.
+                let mut unnamed_iter = unnamed.into_iter();
+                for (i, slot) in slots.iter_mut().enumerate() {
+                    if slot.is_none() {
+                        if let Some(val) = unnamed_iter.next() {
+                            *slot = Some(val);
+                        } else if let Some(default_expr) = params[i].1 {
+                            // Lower the default expression from the function signature.
+                            *slot = Some(self.lower_expr(default_expr));
+                        }
+                    }
+                }
+
+                // Collect: all slots (filled by named args, positional args, or defaults),
+                // then any remaining unnamed args (error recovery — more args than params).
+                let mut args: Vec<CanId> = slots.into_iter().flatten().collect();
+                args.extend(unnamed_iter);
+                let hoisted = self.hoist_moved_args(&written, &mut args);
+                OrderedArgs { args, hoisted }
+            }
+            _ => {
+                // No signature available — keep source order.
+                let args = src_args
+                    .iter()
+                    .map(|&(_, value)| self.lower_expr(value))
+                    .collect();
+                OrderedArgs {
+                    args,
+                    hoisted: Vec::new(),
+                }
+            }
+        }
+    }
+
+    /// Preserve written-order evaluation for arguments that reordering moved.
+    ///
+    /// If the written arguments already appear in `args` in written order,
+    /// nothing is hoisted. Otherwise every written argument that is not a
+    /// constant is bound to a fresh temporary (in written order) and its slot
+    /// in `args` is replaced by a reference to that temporary. Identifiers
+    /// are hoisted too: an earlier argument may reassign a mutable binding.
+    fn hoist_moved_args(&mut self, written: &[CanId], args: &mut [CanId]) -> Vec<CanId> {
+        let placed: Vec<CanId> = args
+            .iter()
+            .copied()
+            .filter(|id| written.contains(id))
+            .collect();
+        if placed == written {
+            return Vec::new();
+        }
+
+        let mut hoisted = Vec::new();
+        for (i, &arg) in written.iter().enumerate() {
+            if self.is_constant_arg(arg) {
+                continue;
+            }
+            // `<` can't start an identifier, so the temporary can't shadow
+            // a user binding the other arguments read.
+            let temp = self.interner.intern(&format!("<arg{i}>"));
+            let (stmt, temp_ref) = self.hoist_temp(arg, temp);
+            hoisted.push(stmt);
+            if let Some(slot) = args.iter_mut().find(|slot| **slot == arg) {
+                *slot = temp_ref;
+            }
+        }
+        hoisted
+    }
+
+    /// Bind `value` to the temporary `temp`, returning the `let` and a
+    /// reference to the temporary.
+    fn hoist_temp(&mut self, value: CanId, temp: Name) -> (CanId, CanId) {
+        let span = self.arena.span(value);
+        let ty = self.arena.ty(value);
+        let pattern = self.arena.push_binding_pattern(CanBindingPattern::Name {
+            name: temp,
+            mutable: Mutability::Immutable,
+        });
+        let stmt = self.push(
+            CanExpr::Let {
+                pattern,
+                init: value,
+                mutable: Mutability::Immutable,
+            },
+            span,
+            TypeId::UNIT,
+        );
+        (stmt, self.push(CanExpr::Ident(temp), span, ty))
+    }
+
+    /// Whether `id` is a literal or constant, so its position relative to
+    /// other arguments cannot change its value.
+    fn is_constant_arg(&self, id: CanId) -> bool {
+        matches!(
+            self.arena.kind(id),
+            CanExpr::Int(_)
+                | CanExpr::Float(_)
+                | CanExpr::Bool(_)
+                | CanExpr::Str(_)
+                | CanExpr::Char(_)
+                | CanExpr::Duration { .. }
+                | CanExpr::Size { .. }
+                | CanExpr::Unit
+                | CanExpr::Constant(_)
+                | CanExpr::Const(_)
+                | CanExpr::FunctionRef(_)
+                | CanExpr::TypeRef(_)
+        )
+    }
+
+    /// Wrap `call` in a block that first runs the `hoisted` temporaries.
+    fn wrap_hoisted(&mut self, hoisted: &[CanId], call: CanId, span: Span, ty: TypeId) -> CanId {
+        if hoisted.is_empty() {
+            return call;
+        }
+        let stmts = self.arena.push_expr_list(hoisted);
+        self.push(
+            CanExpr::Block {
+                stmts,
+                result: call,
+            },
+            span,
+            ty,
+        )
+    }
+
+    /// Try to resolve parameter info (names + defaults) from a function expression.
+    ///
+    /// `self(...)` inside a function body (the `recurse` step) resolves to the
+    /// enclosing function's signature.
+    fn resolve_func_params(
+        &self,
+        func_kind: ori_ir::ExprKind,
+    ) -> Option<Vec<(Name, Option<ExprId>)>> {
+        let (ori_ir::ExprKind::Ident(name) | ori_ir::ExprKind::FunctionRef(name)) = func_kind
+        else {
+            return None;
+        };
+        let name = match self.current_function {
+            Some(current) if name == self.name_self => current,
+            _ => name,
+        };
+        self.typed.function(name).map(|sig| {
+            sig.param_names
+                .iter()
+                .zip(
+                    sig.param_defaults
+                        .iter()
+                        .copied()
+                        .chain(std::iter::repeat(None)),
+                )
+                .map(|(&name, default)| (name, default))
+                .collect()
+        })
+    }
+
+    /// Try to resolve parameter info (names + defaults) from a method signature.
+    fn resolve_method_params(&self, method: Name) -> Option<Vec<(Name, Option<ExprId>)>> {
+        self.typed
+            .impl_sigs
+            .iter()
+            .find(|(name, _)| *name == method)
+            .map(|(_, sig)| {
+                sig.param_names
+                    .iter()
+                    .zip(
+                        sig.param_defaults
+                            .iter()
+                            .copied()
+                            .chain(std::iter::repeat(None)),
+                    )
+                    .map(|(&name, default)| (name, default))
+                    .collect()
+            })
+    }
+}
@@ -67,6 +67,275 @@ fn desugar_call_named_source_order_fallback() {
     }
 }
 
+/// Signature for `@name (a: int, b: int) -> int`.
+fn two_param_sig(name: Name, a: Name, b: Name) -> ori_types::FunctionSig {
+    ori_types::FunctionSig {
+        name,
+        type_params: vec![],
+        const_params: vec![],
+        param_names: vec![a, b],
+        param_types: vec![Idx::INT, Idx::INT],
+        return_type: Idx::INT,
+        capabilities: vec![],
+        is_public: false,
+        is_test: false,
+        is_main: false,
+        type_param_bounds: vec![],
+        where_clauses: vec![],
+        generic_param_mapping: vec![],
+        required_params: 2,
+        param_defaults: vec![None, None],
+    }
+}
+
+/// The written first argument of a reversed named call.
+#[derive(Clone, Copy)]
+enum FirstArg {
+    /// The call `g()`.
+    Call,
+    /// The literal `2`.
+    Literal,
+    /// The variable `x`.
+    Ident,
+}
+
+/// Build `sub(b: <first>, a: h())` with `sub` declared as `(a, b)`.
+///
+/// With `method_receiver`, builds `r().sub(b: <first>, a: h())` instead,
+/// with `sub` registered as a method.
+fn build_reversed_named_call(
+    arena: &mut ExprArena,
+    interner: &SharedInterner,
+    first: FirstArg,
+    method_receiver: bool,
+) -> (ExprId, TypeCheckResult) {
+    let sub = interner.intern("sub");
+    let a = interner.intern("a");
+    let b = interner.intern("b");
+
+    let alloc_call = |arena: &mut ExprArena, callee: &str, at: u32| {
+        let func = arena.alloc_expr(Expr::new(
+            ExprKind::Ident(interner.intern(callee)),
+            Span::new(at, at + 1),
+        ));
+        let args = arena.alloc_expr_list([]);
+        arena.alloc_expr(Expr::new(
+            ExprKind::Call { func, args },
+            Span::new(at, at + 3),
+        ))
+    };
+
+    let callee = if method_receiver {
+        alloc_call(arena, "r", 0)
+    } else {
+        arena.alloc_expr(Expr::new(ExprKind::Ident(sub), Span::new(0, 3)))
+    };
+    let b_value = match first {
+        FirstArg::Call => alloc_call(arena, "g", 7),
+        FirstArg::Literal => arena.alloc_expr(Expr::new(ExprKind::Int(2), Span::new(7, 8))),
+        FirstArg::Ident => arena.alloc_expr(Expr::new(
+            ExprKind::Ident(interner.intern("x")),
+            Span::new(7, 8),
+        )),
+    };
+    let a_value = alloc_call(arena, "h", 15);
+    let args = arena.alloc_call_args([
+        CallArg {
+            name: Some(b),
+            value: b_value,
+            is_spread: false,
+            span: Span::new(4, 10),
+        },
+        CallArg {
+            name: Some(a),
+            value: a_value,
+            is_spread: false,
+            span: Span::new(12, 18),
+        },
+    ]);
+    let kind = if method_receiver {
+        ExprKind::MethodCallNamed {
+            receiver: callee,
+            method: sub,
+            args,
+        }
+    } else {
+        ExprKind::CallNamed { func: callee, args }
+    };
+    let root = arena.alloc_expr(Expr::new(kind, Span::new(0, 19)));
+
+    let expr_count = root.index() + 1;
+    let mut type_result = test_type_result(vec![Idx::INT; expr_count]);
+    if method_receiver {
+        type_result
+            .typed
+            .impl_sigs
+            .push((sub, two_param_sig(sub, a, b)));
+    } else {
+        type_result.typed.functions.push(two_param_sig(sub, a, b));
+    }
+    (root, type_result)
+}
+
+#[test]
+fn desugar_call_named_hoists_moved_args_in_written_order() {
+    // `sub(b: g(), a: h())` → `{ let <arg0> = g(); let <arg1> = h(); sub(<arg1>, <arg0>) }`
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let (root, type_result) =
+        build_reversed_named_call(&mut arena, &interner, FirstArg::Call, false);
+
+    let pool = ori_types::Pool::new();
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    let CanExpr::Block {
+        stmts,
+        result: call,
+    } = *result.arena.kind(result.root)
+    else {
+        panic!("expected Block, got {:?}", result.arena.kind(result.root));
+    };
+
+    let stmts = result.arena.get_expr_list(stmts);
+    assert_eq!(stmts.len(), 2);
+    let callee_of_init = |stmt: CanId| {
+        let CanExpr::Let { init, .. } = *result.arena.kind(stmt) else {
+            panic!("expected Let, got {:?}", result.arena.kind(stmt));
+        };
+        let CanExpr::Call { func, .. } = *result.arena.kind(init) else {
+            panic!("expected Call, got {:?}", result.arena.kind(init));
+        };
+        *result.arena.kind(func)
+    };
+    // Written order: g() first, then h().
+    assert_eq!(
+        callee_of_init(stmts[0]),
+        CanExpr::Ident(interner.intern("g"))
+    );
+    assert_eq!(
+        callee_of_init(stmts[1]),
+        CanExpr::Ident(interner.intern("h"))
+    );
+
+    let CanExpr::Call { args, .. } = *result.arena.kind(call) else {
+        panic!("expected Call, got {:?}", result.arena.kind(call));
+    };
+    let args = result.arena.get_expr_list(args);
+    // Parameter order: a = h() (written second), b = g() (written first).
+    assert_eq!(
+        *result.arena.kind(args[0]),
+        CanExpr::Ident(interner.intern("<arg1>"))
+    );
+    assert_eq!(
+        *result.arena.kind(args[1]),
+        CanExpr::Ident(interner.intern("<arg0>"))
+    );
+}
+
+#[test]
+fn desugar_call_named_skips_hoisting_trivial_args() {
+    // `sub(b: 2, a: h())` → `{ let <arg1> = h(); sub(<arg1>, 2) }`
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let (root, type_result) =
+        build_reversed_named_call(&mut arena, &interner, FirstArg::Literal, false);
+
+    let pool = ori_types::Pool::new();
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    let CanExpr::Block {
+        stmts,
+        result: call,
+    } = *result.arena.kind(result.root)
+    else {
+        panic!("expected Block, got {:?}", result.arena.kind(result.root));
+    };
+    assert_eq!(result.arena.get_expr_list(stmts).len(), 1);
+
+    let CanExpr::Call { args, .. } = *result.arena.kind(call) else {
+        panic!("expected Call, got {:?}", result.arena.kind(call));
+    };
+    let args = result.arena.get_expr_list(args);
+    assert_eq!(
+        *result.arena.kind(args[0]),
+        CanExpr::Ident(interner.intern("<arg1>"))
+    );
+    assert_eq!(*result.arena.kind(args[1]), CanExpr::Int(2));
+}
+
+#[test]
+fn desugar_call_named_hoists_ident_args() {
+    // `sub(b: x, a: h())` → `{ let <arg0> = x; let <arg1> = h(); sub(<arg1>, <arg0>) }`
+    // An identifier is not order-independent: `h()` may reassign `x`.
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let (root, type_result) =
+        build_reversed_named_call(&mut arena, &interner, FirstArg::Ident, false);
+
+    let pool = ori_types::Pool::new();
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    let CanExpr::Block { stmts, .. } = *result.arena.kind(result.root) else {
+        panic!("expected Block, got {:?}", result.arena.kind(result.root));
+    };
+    let stmts = result.arena.get_expr_list(stmts);
+    assert_eq!(stmts.len(), 2);
+    let CanExpr::Let { init, .. } = *result.arena.kind(stmts[0]) else {
+        panic!("expected Let, got {:?}", result.arena.kind(stmts[0]));
+    };
+    assert_eq!(
+        *result.arena.kind(init),
+        CanExpr::Ident(interner.intern("x"))
+    );
+}
+
+#[test]
+fn desugar_method_call_named_hoists_receiver_first() {
+    // `r().sub(b: g(), a: h())` →
+    // `{ let <recv> = r(); let <arg0> = g(); let <arg1> = h(); <recv>.sub(<arg1>, <arg0>) }`
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let (root, type_result) =
+        build_reversed_named_call(&mut arena, &interner, FirstArg::Call, true);
+
+    let pool = ori_types::Pool::new();
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    let CanExpr::Block {
+        stmts,
+        result: call,
+    } = *result.arena.kind(result.root)
+    else {
+        panic!("expected Block, got {:?}", result.arena.kind(result.root));
+    };
+
+    let stmts = result.arena.get_expr_list(stmts);
+    let inits: Vec<CanExpr> = stmts
+        .iter()
+        .map(|&stmt| {
+            let CanExpr::Let { init, .. } = *result.arena.kind(stmt) else {
+                panic!("expected Let, got {:?}", result.arena.kind(stmt));
+            };
+            let CanExpr::Call { func, .. } = *result.arena.kind(init) else {
+                panic!("expected Call, got {:?}", result.arena.kind(init));
+            };
+            *result.arena.kind(func)
+        })
+        .collect();
+    assert_eq!(
+        inits,
+        vec![
+            CanExpr::Ident(interner.intern("r")),
+            CanExpr::Ident(interner.intern("g")),
+            CanExpr::Ident(interner.intern("h")),
+        ]
+    );
+
+    let CanExpr::MethodCall { receiver, .. } = *result.arena.kind(call) else {
+        panic!("expected MethodCall, got {:?}", result.arena.kind(call));
+    };
+    assert_eq!(
+        *result.arena.kind(receiver),
+        CanExpr::Ident(interner.intern("<recv>"))
+    );
+}
+
 #[test]
 fn desugar_template_literal_simple() {
     // `hello {name}!` → "hello".concat(name.to_str()).concat("!")
@@ -130,6 +130,7 @@ pub(crate) fn check_exhaustiveness(
                 arm_span,
                 match_span,
                 arm_index: i,
+                covered_by: None,
             });
         }
     }
@@ -139,7 +139,7 @@ impl Lowerer<'_> {
         span: Span,
         ty: TypeId,
     ) -> CanId {
-        let range = self.lower_field_inits(fields);
+        let range = self.lower_field_inits(fields, ty);
         self.push(
             CanExpr::Struct {
                 name,
@@ -153,10 +153,12 @@ impl Lowerer<'_> {
     /// Lower struct field initializers from the source arena.
     ///
     /// Handles the shorthand syntax: `FieldInit { name, value: None }` is
-    /// desugared to `CanExpr::Ident(name)` (implicit variable reference).
+    /// desugared to `CanExpr::Ident(name)` (implicit variable reference),
+    /// typed from the field's declaration in `struct_ty`.
     pub(crate) fn lower_field_inits(
         &mut self,
         range: ori_ir::FieldInitRange,
+        struct_ty: TypeId,
     ) -> ori_ir::canon::CanFieldRange {
         let src_fields = self.src.get_field_inits(range);
         if src_fields.is_empty() {
@@ -171,29 +173,50 @@ impl Lowerer<'_> {
         let mut can_fields = Vec::with_capacity(src_fields.len());
 
         for (name, value, field_span) in src_fields {
-            let value = match value {
-                Some(expr_id) => self.lower_expr(expr_id),
-                // Shorthand: `Point { x }` → synthesize `Ident(x)`.
-                None => self.push(
-                    CanExpr::Ident(name),
-                    field_span,
-                    Self::resolve_field_type(name),
-                ),
-            };
+            let value = self.lower_field_value(value, name, field_span, struct_ty);
             can_fields.push(CanField { name, value });
         }
 
         self.arena.push_fields(&can_fields)
     }
 
-    /// Resolve the type for a field shorthand reference.
+    /// Lower a single field initializer value.
     ///
-    /// For `Point { x }`, we need the type of the variable `x`.
-    /// This is best-effort — falls back to ERROR if we can't determine it.
-    fn resolve_field_type(_name: Name) -> TypeId {
-        // The synthesized Ident will get its type from context.
-        // For now, use ERROR as the type — it will be refined once
-        // the evaluator/codegen looks up the variable binding.
-        TypeId::ERROR
+    /// Shorthand `Point { x }` (no value) synthesizes `Ident(x)`.
+    pub(crate) fn lower_field_value(
+        &mut self,
+        value: Option<ExprId>,
+        name: Name,
+        span: Span,
+        struct_ty: TypeId,
+    ) -> CanId {
+        if let Some(expr_id) = value {
+            return self.lower_expr(expr_id);
+        }
+        let ty = self.struct_field_type(struct_ty, name);
+        self.push(CanExpr::Ident(name), span, ty)
+    }
+
+    /// Resolve the declared type of `field` on the struct type `struct_ty`.
+    ///
+    /// Synthesized field expressions (shorthand `Point { x }`) have no
+    /// `ExprId`, so the type checker never recorded a type for them. The
+    /// field's type is read from the struct layout in the pool — the same
+    /// resolution the backends use — falling back to `ERROR` when the struct
+    /// type cannot be resolved (error recovery).
+    pub(crate) fn struct_field_type(&self, struct_ty: TypeId, field: Name) -> TypeId {
+        let idx = self
+            .pool
+            .resolve_fully(ori_types::Idx::from_raw(struct_ty.raw()));
+        if self.pool.tag(idx) != ori_types::Tag::Struct {
+            return TypeId::ERROR;
+        }
+        self.pool
+            .struct_fields(idx)
+            .into_iter()
+            .find(|(name, _)| *name == field)
+            .map_or(TypeId::ERROR, |(_, field_ty)| {
+                TypeId::from_raw(field_ty.raw())
+            })
     }
 }
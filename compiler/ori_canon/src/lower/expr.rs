@@ -47,7 +47,7 @@ impl Lowerer<'_> {
                     self.push(CanExpr::Ident(name), span, ty)
                 }
             }
-            ExprKind::Const(name) => self.push(CanExpr::Const(name), span, ty),
+            ExprKind::Const(name) => self.lower_const_ref(name, span, ty),
             ExprKind::SelfRef => self.push(CanExpr::SelfRef, span, ty),
             ExprKind::FunctionRef(name) => self.push(CanExpr::FunctionRef(name), span, ty),
             ExprKind::HashLength => self.push(CanExpr::HashLength, span, ty),
@@ -1,10 +1,34 @@
-//! Miscellaneous lowering helpers — cast target extraction, type name mapping.
+//! Miscellaneous lowering helpers — module constant references, cast target
+//! extraction, type name mapping.
 
-use ori_ir::{Name, StringInterner, TypeId};
+use ori_ir::canon::{CanExpr, CanId};
+use ori_ir::{Name, Span, StringInterner, TypeId};
 
 use super::Lowerer;
 
 impl Lowerer<'_> {
+    // Module Constant References
+
+    /// Lower a `$name` reference.
+    ///
+    /// A constant defined in this module is replaced by its lowered
+    /// initializer, so constant folding turns `$limit + 1` into a single
+    /// pool value and neither backend needs storage for it. Other names
+    /// (imported constants, or a cyclic definition already reported by the
+    /// type checker) stay as `CanExpr::Const`.
+    pub(super) fn lower_const_ref(&mut self, name: Name, span: Span, ty: TypeId) -> CanId {
+        let Some(&value) = self.module_consts.get(&name) else {
+            return self.push(CanExpr::Const(name), span, ty);
+        };
+        if self.expanding_consts.contains(&name) {
+            return self.push(CanExpr::Const(name), span, ty);
+        }
+        self.expanding_consts.push(name);
+        let id = self.lower_expr(value);
+        self.expanding_consts.pop();
+        id
+    }
+
     // Cast Target Name Extraction
 
     /// Extract the target type name from a `ParsedTypeId` for `Cast` expressions.
@@ -87,6 +87,7 @@ pub fn lower_module(
     interner: &ori_ir::StringInterner,
 ) -> CanonResult {
     let mut lowerer = Lowerer::new(src, &type_result.typed, pool, interner);
+    lowerer.module_consts = module.consts.iter().map(|c| (c.name, c.value)).collect();
     let mut roots = Vec::with_capacity(module.functions.len() + module.tests.len());
 
     // Group functions by name to detect multi-clause definitions.
@@ -105,6 +106,7 @@ pub fn lower_module(
             continue; // Already handled this group.
         }
         let group = &func_groups[&func.name];
+        lowerer.current_function = Some(func.name);
         if group.len() == 1 {
             // Single clause — lower body and parameter defaults.
             if func.body.is_valid() {
@@ -127,6 +129,7 @@ pub fn lower_module(
             });
         }
     }
+    lowerer.current_function = None;
 
     // Lower each test body into the same arena (tests have no defaults).
     for test in &module.tests {
@@ -260,12 +263,21 @@ pub(crate) struct Lowerer<'a> {
     pub(super) decision_trees: DecisionTreePool,
     /// Pattern problems accumulated during exhaustiveness checking.
     pub(crate) problems: Vec<ori_ir::canon::PatternProblem>,
+    /// Module function whose body is being lowered, if any.
+    /// Accessed by: desugar (`self(...)` calls inside `recurse`)
+    pub(crate) current_function: Option<Name>,
+    /// Initializers of this module's `$name` constants, inlined at each use.
+    /// Accessed by: lower
+    pub(super) module_consts: rustc_hash::FxHashMap<Name, ExprId>,
+    /// Constants whose initializers are currently being inlined (cycle guard).
+    pub(super) expanding_consts: Vec<Name>,
 
     // Pre-interned method names for desugaring.
     // Accessed by: lower, desugar
     pub(crate) name_to_str: Name,
     pub(crate) name_concat: Name,
     pub(crate) name_merge: Name,
+    pub(crate) name_self: Name,
 
     // Pre-interned builtin type names for TypeRef detection.
     pub(super) name_duration: Name,
@@ -274,6 +286,11 @@ pub(crate) struct Lowerer<'a> {
     // Pre-interned names for collection specialization.
     pub(crate) name_collect: Name,
     pub(crate) name_collect_set: Name,
+
+    // Pre-interned temporary for a hoisted named-call receiver; not a valid
+    // identifier, so it never shadows a user binding.
+    // Accessed by: desugar
+    pub(crate) name_recv_temp: Name,
 }
 
 impl<'a> Lowerer<'a> {
@@ -302,14 +319,19 @@ impl<'a> Lowerer<'a> {
             constants: ConstantPool::new(),
             decision_trees: DecisionTreePool::new(),
             problems: Vec::new(),
+            current_function: None,
+            module_consts: rustc_hash::FxHashMap::default(),
+            expanding_consts: Vec::new(),
             name_to_str: interner.intern("to_str"),
             name_concat: interner.intern("concat"),
             name_merge: interner.intern("merge"),
+            name_self: interner.intern("self"),
             name_duration: interner.intern("Duration"),
             name_size: interner.intern("Size"),
             name_collect: interner.intern("collect"),
             name_collect_set: interner
                 .intern(ori_ir::builtin_constants::iterator::COLLECT_SET_METHOD),
+            name_recv_temp: interner.intern("<recv>"),
         }
     }
 
@@ -5,9 +5,9 @@
 //! pattern destructuring, parameter lists, and function expressions.
 
 use ori_ir::canon::{
-    CanBindingPattern, CanBindingPatternId, CanExpr, CanId, CanNamedExpr, CanParam,
+    CanBindingPattern, CanBindingPatternId, CanExpr, CanId, CanNamedExpr, CanParam, PatternProblem,
 };
-use ori_ir::{ExprId, Name, Span, TypeId};
+use ori_ir::{ExprId, MatchArm, MatchPattern, Name, Span, TypeId};
 
 use super::Lowerer;
 
@@ -67,7 +67,9 @@ impl Lowerer<'_> {
             self.pool,
             self.interner,
         );
-        self.problems.extend(check.problems);
+        let mut problems = check.problems;
+        self.attribute_to_catch_all(&mut problems, src_arms);
+        self.problems.extend(problems);
 
         let dt_id = self.decision_trees.push(tree);
 
@@ -94,6 +96,41 @@ impl Lowerer<'_> {
 
     // Multi-Clause Function Lowering
 
+    /// Point redundant-arm problems at the arm that shadows them.
+    ///
+    /// When an earlier arm is an unguarded catch-all (`_` or a lowercase
+    /// binding), every later arm is dead because of it; recording its span
+    /// lets the diagnostic say which arm to look at. A guarded catch-all can
+    /// fall through, so it never counts.
+    fn attribute_to_catch_all(&self, problems: &mut [PatternProblem], arms: &[MatchArm]) {
+        let is_catch_all = |arm: &MatchArm| {
+            arm.guard.is_none()
+                && match arm.pattern {
+                    MatchPattern::Wildcard => true,
+                    MatchPattern::Binding(name) => self
+                        .interner
+                        .lookup(name)
+                        .starts_with(|c: char| c.is_ascii_lowercase() || c == '_'),
+                    _ => false,
+                }
+        };
+        let Some(catch_all) = arms.iter().position(is_catch_all) else {
+            return;
+        };
+        for problem in problems {
+            if let PatternProblem::RedundantArm {
+                arm_index,
+                covered_by,
+                ..
+            } = problem
+            {
+                if *arm_index > catch_all {
+                    *covered_by = Some(arms[catch_all].span);
+                }
+            }
+        }
+    }
+
     /// Lower a group of same-name functions into a single body with a
     /// synthesized match expression.
     ///
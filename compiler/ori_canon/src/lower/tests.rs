@@ -1,6 +1,7 @@
 use super::*;
 use ori_ir::ast::{BinaryOp, Expr};
-use ori_ir::{ExprKind, StringInterner};
+use ori_ir::canon::PatternProblem;
+use ori_ir::{ExprKind, MatchPattern, StringInterner};
 use ori_types::Idx;
 
 /// Create a minimal `TypeCheckResult` for testing.
@@ -262,3 +263,204 @@ fn lower_call_positional() {
         other => panic!("expected Call, got {other:?}"),
     }
 }
+
+#[test]
+fn lower_struct_shorthand_uses_declared_field_type() {
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let point = interner.intern("Point");
+    let x = interner.intern("x");
+    let label = interner.intern("label");
+
+    // Point { x: 1, label } — explicit field mixed with shorthand.
+    let one = arena.alloc_expr(Expr::new(ExprKind::Int(1), Span::new(11, 12)));
+    let fields = arena.alloc_field_inits([
+        ori_ir::FieldInit {
+            name: x,
+            value: Some(one),
+            span: Span::new(8, 12),
+        },
+        ori_ir::FieldInit {
+            name: label,
+            value: None,
+            span: Span::new(14, 19),
+        },
+    ]);
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Struct {
+            name: point,
+            fields,
+        },
+        Span::new(0, 21),
+    ));
+
+    let mut pool = ori_types::Pool::new();
+    let point_ty = pool.struct_type(point, &[(x, Idx::INT), (label, Idx::STR)]);
+    let type_result = test_type_result(vec![Idx::INT, point_ty]);
+
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    match result.arena.kind(result.root) {
+        CanExpr::Struct { fields, .. } => {
+            let fields = result.arena.get_fields(*fields);
+            assert_eq!(fields.len(), 2);
+            assert_eq!(result.arena.ty(fields[0].value), TypeId::INT);
+            assert_eq!(*result.arena.kind(fields[1].value), CanExpr::Ident(label));
+            assert_eq!(result.arena.ty(fields[1].value), TypeId::STR);
+        }
+        other => panic!("expected Struct, got {other:?}"),
+    }
+}
+
+#[test]
+fn lower_struct_shorthand_unresolved_type_falls_back_to_error() {
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let point = interner.intern("Point");
+    let x = interner.intern("x");
+
+    let fields = arena.alloc_field_inits([ori_ir::FieldInit {
+        name: x,
+        value: None,
+        span: Span::new(8, 9),
+    }]);
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Struct {
+            name: point,
+            fields,
+        },
+        Span::new(0, 11),
+    ));
+
+    let type_result = test_type_result(vec![Idx::ERROR]);
+    let pool = ori_types::Pool::new();
+
+    let result = lower(&arena, &type_result, &pool, root, &interner);
+    match result.arena.kind(result.root) {
+        CanExpr::Struct { fields, .. } => {
+            let fields = result.arena.get_fields(*fields);
+            assert_eq!(result.arena.ty(fields[0].value), TypeId::ERROR);
+        }
+        other => panic!("expected Struct, got {other:?}"),
+    }
+}
+
+#[test]
+fn lower_module_const_ref_inlines_and_folds() {
+    // let $limit = 10; ... $limit + 1 → Constant(11)
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let limit = interner.intern("limit");
+
+    let init = arena.alloc_expr(Expr::new(ExprKind::Int(10), Span::new(14, 16)));
+    let left = arena.alloc_expr(Expr::new(ExprKind::Const(limit), Span::new(30, 36)));
+    let right = arena.alloc_expr(Expr::new(ExprKind::Int(1), Span::new(39, 40)));
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Binary {
+            op: BinaryOp::Add,
+            left,
+            right,
+        },
+        Span::new(30, 40),
+    ));
+
+    let type_result = test_type_result(vec![Idx::INT; 4]);
+    let pool = ori_types::Pool::new();
+    let mut lowerer = Lowerer::new(&arena, &type_result.typed, &pool, &interner);
+    lowerer.module_consts.insert(limit, init);
+    let can_root = lowerer.lower_expr(root);
+    let result = lowerer.finish(can_root);
+
+    match result.arena.kind(result.root) {
+        CanExpr::Constant(cid) => {
+            assert_eq!(
+                *result.constants.get(*cid),
+                ori_ir::canon::ConstValue::Int(11)
+            );
+        }
+        other => panic!("expected Constant(11), got {other:?}"),
+    }
+}
+
+#[test]
+fn lower_unknown_or_cyclic_const_ref_stays_const() {
+    // $a = $a (cyclic), and $b is not defined in this module.
+    let mut arena = ExprArena::new();
+    let interner = test_interner();
+    let a = interner.intern("a");
+    let b = interner.intern("b");
+
+    let a_init = arena.alloc_expr(Expr::new(ExprKind::Const(a), Span::new(9, 11)));
+    let a_ref = arena.alloc_expr(Expr::new(ExprKind::Const(a), Span::new(20, 22)));
+    let b_ref = arena.alloc_expr(Expr::new(ExprKind::Const(b), Span::new(30, 32)));
+
+    let type_result = test_type_result(vec![Idx::INT; 3]);
+    let pool = ori_types::Pool::new();
+    let mut lowerer = Lowerer::new(&arena, &type_result.typed, &pool, &interner);
+    lowerer.module_consts.insert(a, a_init);
+
+    let lowered_a = lowerer.lower_expr(a_ref);
+    assert_eq!(*lowerer.arena.kind(lowered_a), CanExpr::Const(a));
+    assert!(lowerer.expanding_consts.is_empty());
+
+    let lowered_b = lowerer.lower_expr(b_ref);
+    assert_eq!(*lowerer.arena.kind(lowered_b), CanExpr::Const(b));
+}
+
+/// Lower `match 5 { <arms> }` where each arm is `(pattern, guarded, body)`.
+///
+/// Guards are the bool literal `true`; literal patterns are int literals.
+fn lower_int_match(arms: &[(Option<i64>, bool, i64)]) -> CanonResult {
+    let mut arena = ExprArena::new();
+    let mut types = Vec::new();
+    let mut alloc = |arena: &mut ExprArena, kind: ExprKind, ty: Idx, at: u32| {
+        types.push(ty);
+        arena.alloc_expr(Expr::new(kind, Span::new(at, at + 1)))
+    };
+
+    let scrutinee = alloc(&mut arena, ExprKind::Int(5), Idx::INT, 6);
+    let mut src_arms = Vec::new();
+    for (i, &(literal, guarded, body)) in arms.iter().enumerate() {
+        let at = 10 * (u32::try_from(i).unwrap_or(0) + 1);
+        let pattern = match literal {
+            Some(v) => MatchPattern::Literal(alloc(&mut arena, ExprKind::Int(v), Idx::INT, at)),
+            None => MatchPattern::Wildcard,
+        };
+        let guard = guarded.then(|| alloc(&mut arena, ExprKind::Bool(true), Idx::BOOL, at + 2));
+        let body = alloc(&mut arena, ExprKind::Int(body), Idx::INT, at + 5);
+        src_arms.push(ori_ir::MatchArm {
+            pattern,
+            guard,
+            body,
+            span: Span::new(at, at + 6),
+        });
+    }
+    let arms = arena.alloc_arms(src_arms);
+    let root = alloc(&mut arena, ExprKind::Match { scrutinee, arms }, Idx::INT, 0);
+
+    let type_result = test_type_result(types);
+    let interner = test_interner();
+    let pool = ori_types::Pool::new();
+    lower(&arena, &type_result, &pool, root, &interner)
+}
+
+#[test]
+fn lower_match_arm_after_wildcard_is_redundant() {
+    // match 5 { _ -> 0, 1 -> 1 }
+    let result = lower_int_match(&[(None, false, 0), (Some(1), false, 1)]);
+    assert_eq!(
+        result.problems,
+        vec![PatternProblem::RedundantArm {
+            arm_span: Span::new(20, 26),
+            match_span: Span::new(0, 1),
+            arm_index: 1,
+            covered_by: Some(Span::new(10, 16)),
+        }]
+    );
+}
+
+#[test]
+fn lower_match_arm_after_guarded_wildcard_is_live() {
+    // match 5 { _.match(true) -> 0, 1 -> 1, _ -> 2 }
+    let result = lower_int_match(&[(None, true, 0), (Some(1), false, 1), (None, false, 2)]);
+    assert!(result.problems.is_empty(), "{:?}", result.problems);
+}
@@ -40,6 +40,25 @@ fn run_void_main_returns_empty_output() {
     assert_eq!(output.printed, "hello\n");
 }
 
+#[test]
+fn run_print_formats_scalar_messages() {
+    let source = concat!(
+        "@main () -> void = {\n",
+        "    print(msg: 42);\n",
+        "    print(msg: true);\n",
+        "    print(msg: 'a');\n",
+        "    print(msg: byte(65));\n",
+        "}\n",
+    );
+    let output = compile_and_run(source, &default_config());
+    assert!(
+        output.success,
+        "expected success, got phase={:?}, diagnostics={:?}",
+        output.error_phase, output.diagnostics
+    );
+    assert_eq!(output.printed, "42\ntrue\na\n0x41\n");
+}
+
 #[test]
 fn run_parse_error_reports_phase() {
     let output = compile_and_run("@main () -> int = {", &default_config());
@@ -155,3 +155,25 @@ fn test_diagnostic_display_cross_file() {
     // Should still have --> for same-file primary
     assert!(output.contains("-->"));
 }
+
+#[test]
+fn test_multipart_suggestion_shares_applicability() {
+    // Wrap `x` in `int(...)`: two edits, one suggestion
+    let suggestion = Suggestion::machine_applicable("convert to int", Span::new(4, 4), "int(")
+        .with_substitution(Span::new(5, 5), ")");
+    let diag = Diagnostic::error(ErrorCode::E2001)
+        .with_message("type mismatch")
+        .with_structured_suggestion(suggestion);
+
+    assert_eq!(diag.structured_suggestions.len(), 1);
+    let fix = &diag.structured_suggestions[0];
+    assert_eq!(fix.applicability, Applicability::MachineApplicable);
+    assert_eq!(
+        fix.substitutions,
+        vec![
+            Substitution::new(Span::new(4, 4), "int("),
+            Substitution::new(Span::new(5, 5), ")"),
+        ]
+    );
+    assert!(diag.has_machine_applicable_fix());
+}
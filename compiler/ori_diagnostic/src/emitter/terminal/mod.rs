@@ -450,7 +450,7 @@ impl<'src, W: Write> TerminalEmitter<'src, W> {
 
         for (line_num, label_indices) in &lines_to_render {
             // Add blank gutter line between non-consecutive lines or at start
-            if prev_line.is_none() || prev_line.is_some_and(|p| p + 1 < *line_num) {
+            if prev_line.is_none_or(|p| p + 1 < *line_num) {
                 self.write_gutter(gutter_width);
                 let _ = writeln!(self.writer);
             }
@@ -84,6 +84,7 @@ define_error_codes! {
     E0013, "Standalone backslash";
     E0014, "Decimal not representable as whole base units";
     E0015, "Reserved-future keyword used as identifier";
+    E0016, "Unterminated block comment";
     E0911, "Floating-point duration/size literal not supported";
 
     // Parser Errors (E1xxx)
@@ -144,6 +145,8 @@ define_error_codes! {
     E2037, "Multiple Into implementations apply";
     E2038, "Type does not implement Printable";
     E2039, "Cannot assign to immutable binding";
+    E2040, "Or-pattern alternatives bind different names";
+    E2041, "Undefined loop label";
 
     // Pattern Errors (E3xxx)
     E3001, "Unknown pattern";
@@ -202,6 +205,7 @@ define_error_codes! {
     // Parser Warnings (W1xxx)
     W1001, "Detached doc comment";
     W1002, "Unknown calling convention in extern block";
+    W1003, "Mixed tabs and spaces in indentation";
 
     // Type Checker Warnings (W2xxx)
     W2001, "Infinite iterator consumed without bound";
@@ -62,6 +62,7 @@ static DOCS: &[(ErrorCode, &str)] = &[
     (ErrorCode::E0003, include_str!("E0003.md")),
     (ErrorCode::E0004, include_str!("E0004.md")),
     (ErrorCode::E0005, include_str!("E0005.md")),
+    (ErrorCode::E0016, include_str!("E0016.md")),
     // Parser errors (E1xxx)
     (ErrorCode::E1001, include_str!("E1001.md")),
     (ErrorCode::E1002, include_str!("E1002.md")),
@@ -117,6 +118,8 @@ static DOCS: &[(ErrorCode, &str)] = &[
     (ErrorCode::E2036, include_str!("E2036.md")),
     (ErrorCode::E2037, include_str!("E2037.md")),
     (ErrorCode::E2038, include_str!("E2038.md")),
+    (ErrorCode::E2040, include_str!("E2040.md")),
+    (ErrorCode::E2041, include_str!("E2041.md")),
     // Pattern errors (E3xxx)
     (ErrorCode::E3001, include_str!("E3001.md")),
     (ErrorCode::E3002, include_str!("E3002.md")),
@@ -124,6 +127,8 @@ static DOCS: &[(ErrorCode, &str)] = &[
     // Internal errors (E9xxx)
     (ErrorCode::E9001, include_str!("E9001.md")),
     (ErrorCode::E9002, include_str!("E9002.md")),
+    // Parser warnings (W1xxx)
+    (ErrorCode::W1003, include_str!("W1003.md")),
     // Type checker warnings (W2xxx)
     (ErrorCode::W2001, include_str!("W2001.md")),
 ];
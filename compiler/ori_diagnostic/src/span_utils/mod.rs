@@ -6,15 +6,16 @@
 //! ## Performance
 //!
 //! For repeated lookups on the same source, use [`LineOffsetTable`] which
-//! pre-computes line offsets for O(log L) lookup instead of O(n) scanning.
+//! wraps an [`ori_ir::LineIndex`] for O(log L) lookup instead of O(n) scanning.
 
-use ori_ir::Span;
+use ori_ir::{LineIndex, Span};
 
 /// Pre-computed line offset table for efficient line/column lookup.
 ///
-/// Builds a table of byte offsets for each line start, enabling O(log L)
-/// binary search lookups instead of O(n) linear scans. Essential when
-/// processing multiple diagnostics with multiple labels each.
+/// Wraps an [`ori_ir::LineIndex`] for O(log L) binary search lookups instead
+/// of O(n) linear scans, adding the character-based columns and line-text
+/// helpers diagnostics need. Essential when processing multiple diagnostics
+/// with multiple labels each.
 ///
 /// # Example
 ///
@@ -30,48 +31,32 @@ use ori_ir::Span;
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct LineOffsetTable {
-    /// Byte offset of each line start (0-indexed lines internally).
-    /// offsets[0] = 0 (line 1 starts at byte 0)
-    /// offsets[1] = byte after first \n (line 2 start)
-    /// etc.
-    offsets: Vec<u32>,
+    index: LineIndex,
 }
 
 impl LineOffsetTable {
     /// Build a line offset table from source text.
     ///
     /// Scans the source once to find all newlines, O(n) construction
-    /// for O(log L) lookups where L is the number of lines.
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "source files are limited to u32::MAX bytes"
-    )]
+    /// for O(log L) lookups where L is the number of lines. Prefer
+    /// [`LineOffsetTable::from_index`] when the lexer's index is at hand.
     pub fn build(source: &str) -> Self {
-        let mut offsets = vec![0u32];
-        for (i, byte) in source.as_bytes().iter().enumerate() {
-            if *byte == b'\n' {
-                // Next line starts at byte after the newline
-                offsets.push((i + 1) as u32);
-            }
+        LineOffsetTable {
+            index: LineIndex::new(source),
         }
-        LineOffsetTable { offsets }
+    }
+
+    /// Wrap an existing line index, such as the one produced while lexing.
+    pub fn from_index(index: LineIndex) -> Self {
+        LineOffsetTable { index }
     }
 
     /// Get 1-based line number from a byte offset using binary search.
     ///
     /// Returns the line number (1-indexed) containing the given byte offset.
     #[inline]
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "line count limited by source file size (u32)"
-    )]
     pub fn line_from_offset(&self, offset: u32) -> u32 {
-        // Binary search for the largest line start <= offset
-        let line_idx = match self.offsets.binary_search(&offset) {
-            Ok(exact) => exact,                      // Exact match: offset is at line start
-            Err(insert) => insert.saturating_sub(1), // Insert point: line is before
-        };
-        (line_idx as u32) + 1 // Convert to 1-based
+        self.index.line_col(offset).0
     }
 
     /// Get 1-based (line, column) from a byte offset.
@@ -80,8 +65,7 @@ impl LineOffsetTable {
     /// from the start of the line. Uses binary search for line lookup.
     pub fn offset_to_line_col(&self, source: &str, offset: u32) -> (u32, u32) {
         let line = self.line_from_offset(offset);
-        let line_idx = (line - 1) as usize;
-        let line_start = self.offsets.get(line_idx).copied().unwrap_or(0) as usize;
+        let line_start = self.index.line_start(line).unwrap_or(0) as usize;
         let offset = offset as usize;
 
         // Column is 1-based, counting characters from line start to offset
@@ -95,10 +79,7 @@ impl LineOffsetTable {
     ///
     /// Returns `None` if the line number is out of range.
     pub fn line_start_offset(&self, line: u32) -> Option<u32> {
-        if line == 0 {
-            return None;
-        }
-        self.offsets.get((line - 1) as usize).copied()
+        self.index.line_start(line)
     }
 
     /// Get the byte offset of a line's end (exclusive, before `\n`).
@@ -128,7 +109,7 @@ impl LineOffsetTable {
 
     /// Get the number of lines in the source.
     pub fn line_count(&self) -> usize {
-        self.offsets.len()
+        self.index.line_count()
     }
 }
 
@@ -244,3 +244,13 @@ fn test_line_text_unicode() {
     assert_eq!(table.line_text(source, 1), Some("αβγ"));
     assert_eq!(table.line_text(source, 2), Some("δε"));
 }
+
+#[test]
+fn test_line_offset_table_from_index() {
+    let source = "αβ\nγ";
+    let table = LineOffsetTable::from_index(ori_ir::LineIndex::new(source));
+    // Columns count characters even though the index stores byte offsets
+    assert_eq!(table.offset_to_line_col(source, 4), (1, 3));
+    assert_eq!(table.offset_to_line_col(source, 5), (2, 1));
+    assert_eq!(table.line_text(source, 2), Some("γ"));
+}
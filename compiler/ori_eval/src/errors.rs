@@ -124,6 +124,12 @@ pub fn filter_entries_not_implemented() -> EvalError {
     })
 }
 
+/// Wall-clock time limit exceeded (e.g. a test stuck in an infinite loop).
+#[cold]
+pub fn timed_out(limit: std::time::Duration) -> EvalError {
+    EvalError::new(format!("timeout: exceeded time limit of {limit:?}"))
+}
+
 /// Const-eval budget exceeded.
 #[cold]
 pub fn budget_exceeded(calls: usize, budget: u32) -> EvalError {
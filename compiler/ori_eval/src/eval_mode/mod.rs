@@ -10,6 +10,9 @@
 //! - **Go** operand modes — `constant_`, `value`, `builtin`, `invalid`
 //! - **Zig** `block.isComptime()` — switches compile-time vs runtime evaluation
 
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
 /// Evaluation mode — determines interpreter behavior via match dispatch.
 ///
 /// Enum (not trait) for Salsa compatibility: `Clone, Eq, Hash, Debug` required.
@@ -110,6 +113,33 @@ pub struct ModeState {
     /// When `None`, all counter increments are no-ops (zero cost in production).
     /// When `Some`, counters are incremented during evaluation and printed at the end.
     counters: Option<crate::diagnostics::EvalCounters>,
+    /// Wall-clock deadline for the current evaluation (set per test by `ori test`).
+    ///
+    /// Checked cooperatively on function calls and loop iterations, so a
+    /// runaway test fails instead of hanging the run.
+    deadline: Option<Deadline>,
+}
+
+/// Deadline checks between two reads of the clock.
+///
+/// `Instant::now()` costs far more than the call or loop iteration it
+/// guards, so `check_deadline` only samples it every this many checks.
+const DEADLINE_SAMPLE_INTERVAL: u32 = 1024;
+
+thread_local! {
+    /// Deadline checks made on this thread since the last `set_time_limit`.
+    ///
+    /// Per thread rather than per `ModeState`: every call runs in a fresh
+    /// child state, so a per-state count would never reach the sample
+    /// interval in deeply branching recursion.
+    static DEADLINE_TICKS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// A wall-clock limit and the instant it expires.
+#[derive(Clone, Copy, Debug)]
+struct Deadline {
+    at: Instant,
+    limit: Duration,
 }
 
 impl ModeState {
@@ -120,11 +150,13 @@ impl ModeState {
                 call_count: 0,
                 budget: Some(*budget),
                 counters: None,
+                deadline: None,
             },
             _ => Self {
                 call_count: 0,
                 budget: None,
                 counters: None,
+                deadline: None,
             },
         }
     }
@@ -134,14 +166,54 @@ impl ModeState {
     /// Fresh counters (zeroed) are created if the parent has profiling enabled,
     /// ensuring child calls are tracked. The caller is responsible for merging
     /// child counters back via `merge_child_counters` after the call returns.
+    /// The parent's deadline is shared unchanged.
     pub fn child(mode: &EvalMode, parent: &ModeState) -> Self {
         let mut state = Self::new(mode);
         if parent.counters.is_some() {
             state.counters = Some(crate::diagnostics::EvalCounters::default());
         }
+        state.deadline = parent.deadline;
         state
     }
 
+    /// Start (or clear, with `None`) a wall-clock time limit measured from now.
+    ///
+    /// A limit too large to represent as an `Instant` is treated as no limit.
+    pub fn set_time_limit(&mut self, limit: Option<Duration>) {
+        DEADLINE_TICKS.with(|ticks| ticks.set(0));
+        self.deadline = limit.and_then(|limit| {
+            Some(Deadline {
+                at: Instant::now().checked_add(limit)?,
+                limit,
+            })
+        });
+    }
+
+    /// Check the wall-clock deadline, if one is set.
+    ///
+    /// The clock is read on the first check after `set_time_limit` and then
+    /// once every `DEADLINE_SAMPLE_INTERVAL` checks, so a timeout is reported
+    /// slightly after the limit rather than exactly at it.
+    /// Returns `Ok(())` when no time limit is active.
+    #[inline]
+    pub fn check_deadline(&self) -> Result<(), TimedOut> {
+        let Some(deadline) = self.deadline else {
+            return Ok(());
+        };
+        let tick = DEADLINE_TICKS.with(|ticks| {
+            let tick = ticks.get();
+            ticks.set(tick.wrapping_add(1));
+            tick
+        });
+        if tick.is_multiple_of(DEADLINE_SAMPLE_INTERVAL) && Instant::now() >= deadline.at {
+            Err(TimedOut {
+                limit: deadline.limit,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     /// Enable performance counters (activated by `--profile` CLI flag).
     pub fn enable_counters(&mut self) {
         self.counters = Some(crate::diagnostics::EvalCounters::default());
@@ -224,6 +296,13 @@ pub struct BudgetExceeded {
     pub calls: usize,
 }
 
+/// Error returned when the wall-clock time limit is exceeded.
+#[derive(Debug)]
+pub struct TimedOut {
+    /// The configured time limit.
+    pub limit: Duration,
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "Tests use unwrap for brevity")]
 mod tests;
@@ -156,6 +156,57 @@ fn mode_state_budget_exceeded_has_correct_values() {
     assert_eq!(err.calls, 2);
 }
 
+#[test]
+fn mode_state_no_deadline_by_default() {
+    let state = ModeState::new(&EvalMode::TestRun {
+        only_attached: false,
+    });
+    assert!(state.check_deadline().is_ok());
+}
+
+#[test]
+fn mode_state_deadline_expires() {
+    let mut state = ModeState::new(&EvalMode::TestRun {
+        only_attached: false,
+    });
+    state.set_time_limit(Some(Duration::ZERO));
+    let err = state.check_deadline().unwrap_err();
+    assert_eq!(err.limit, Duration::ZERO);
+
+    state.set_time_limit(None);
+    assert!(state.check_deadline().is_ok());
+}
+
+#[test]
+fn mode_state_deadline_not_yet_reached() {
+    let mut state = ModeState::new(&EvalMode::Interpret);
+    state.set_time_limit(Some(Duration::from_hours(1)));
+    assert!(state.check_deadline().is_ok());
+}
+
+#[test]
+fn mode_state_deadline_sampled() {
+    let mut state = ModeState::new(&EvalMode::Interpret);
+    state.set_time_limit(Some(Duration::ZERO));
+    assert!(state.check_deadline().is_err());
+    // The clock is not read again until the next sample
+    for _ in 1..DEADLINE_SAMPLE_INTERVAL {
+        assert!(state.check_deadline().is_ok());
+    }
+    assert!(state.check_deadline().is_err());
+}
+
+#[test]
+fn child_inherits_deadline() {
+    let mode = EvalMode::TestRun {
+        only_attached: false,
+    };
+    let mut parent = ModeState::new(&mode);
+    parent.set_time_limit(Some(Duration::ZERO));
+    let child = ModeState::child(&mode, &parent);
+    assert!(child.check_deadline().is_err());
+}
+
 // === EvalCounters integration tests ===
 
 #[test]
@@ -15,7 +15,7 @@ use crate::errors::{
     index_out_of_bounds, invalid_tuple_field, no_field_on_struct, no_member_in_module,
     tuple_index_out_of_bounds, undefined_variable,
 };
-use crate::{ControlAction, Environment, EvalError, EvalResult, Value};
+use crate::{ControlAction, Environment, EvalError, EvalResult, RangeValue, Value};
 
 /// Evaluate an identifier lookup.
 ///
@@ -76,6 +76,7 @@ pub fn eval_index(value: Value, index: Value) -> EvalResult {
                 .cloned()
                 .ok_or_else(|| ControlAction::from(index_out_of_bounds(raw)))
         }
+        (Value::List(items), Value::Range(range)) => slice_list(&items, &range),
         (Value::Str(s), Value::Int(i)) => {
             // String indexing returns a single-codepoint str (not char)
             let raw = i.raw();
@@ -99,6 +100,35 @@ pub fn eval_index(value: Value, index: Value) -> EvalResult {
     }
 }
 
+/// Evaluate `items[start..end]`.
+///
+/// Slices copy: the result is a fresh list holding clones of the selected
+/// elements, so later mutation of either list never shows through the other.
+/// Both bounds must lie within `0..=len` with `start <= end`; only step-1
+/// ranges are accepted. An unbounded end (`xs[1..]`) slices to the end.
+fn slice_list(items: &[Value], range: &RangeValue) -> EvalResult {
+    if range.step != 1 {
+        return Err(cannot_index("list", "stepped Range").into());
+    }
+    let len = i64::try_from(items.len()).map_err(|_| collection_too_large())?;
+    let end = match range.end {
+        Some(end) if range.inclusive => end.saturating_add(1),
+        Some(end) => end,
+        None => len,
+    };
+    if range.start < 0 || range.start > len {
+        return Err(index_out_of_bounds(range.start).into());
+    }
+    if end < range.start || end > len {
+        return Err(index_out_of_bounds(range.end.unwrap_or(end)).into());
+    }
+    // Both bounds lie in 0..=len, so the conversions cannot fail.
+    let (Ok(start), Ok(end)) = (usize::try_from(range.start), usize::try_from(end)) else {
+        return Err(collection_too_large().into());
+    };
+    Ok(Value::list(items[start..end].to_vec()))
+}
+
 /// Evaluate field access.
 pub fn eval_field_access(value: Value, field: Name, interner: &StringInterner) -> EvalResult {
     match value {
@@ -10,6 +10,7 @@
 
 use std::sync::Arc;
 
+use super::cache_pattern::CacheStore;
 use super::resolvers::{
     BuiltinMethodResolver, CollectionMethodResolver, MethodDispatcher, MethodResolverKind,
     UserRegistryResolver,
@@ -241,6 +242,7 @@ impl<'a> InterpreterBuilder<'a> {
             method_dispatcher,
             imported_arena,
             print_handler,
+            cache_store: SharedMutableRegistry::new(CacheStore::default()),
             scope_ownership: self.scope_ownership,
             builtin_method_names,
             source_file_path: self.source_file_path,
@@ -0,0 +1,142 @@
+//! The `cache(key: ..., op: ..., ttl: ...)` pattern.
+//!
+//! Entries live in a [`CacheStore`] shared by every interpreter of one
+//! evaluation run (child interpreters clone the handle), so repeated
+//! evaluations of one `cache` expression with equal keys share a result,
+//! even across function calls. Each `cache` expression has its own entries:
+//! equal keys at two call sites never see each other's values. Keys are
+//! compared by [`Value::to_map_key`], the same identity map keys use.
+//! `ori test` clears the store before each test.
+
+use std::time::{Duration, Instant};
+
+use ori_ir::canon::{CanId, CanNamedExprRange};
+use ori_patterns::{EvalError, EvalResult, Value};
+use rustc_hash::FxHashMap;
+use smallvec::SmallVec;
+
+use super::can_eval::find_prop_can_id;
+use super::Interpreter;
+use crate::errors::map_key_not_hashable;
+use crate::ControlAction;
+
+/// Memoized `cache` results, keyed by call site and [`Value::to_map_key`].
+#[derive(Debug, Default)]
+pub(crate) struct CacheStore {
+    sites: FxHashMap<CacheSite, FxHashMap<String, CacheEntry>>,
+}
+
+/// The `cache` expression an entry was stored by.
+///
+/// `CanId`s are only unique within one canonical module, so the site also
+/// records which module's `CanonResult` the expression belongs to. Modules
+/// stay loaded for a whole evaluation run, so the address is stable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CacheSite {
+    canon: usize,
+    expr: CanId,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    value: Value,
+    /// `None` when no `ttl` was given: the entry never expires.
+    expires_at: Option<Instant>,
+}
+
+impl CacheStore {
+    /// The stored value for `key` at `site`, unless it is missing or expired.
+    fn get(&self, site: CacheSite, key: &str, now: Instant) -> Option<Value> {
+        let entry = self.sites.get(&site)?.get(key)?;
+        match entry.expires_at {
+            Some(deadline) if now >= deadline => None,
+            _ => Some(entry.value.clone()),
+        }
+    }
+
+    fn insert(&mut self, site: CacheSite, key: String, value: Value, expires_at: Option<Instant>) {
+        self.sites
+            .entry(site)
+            .or_default()
+            .insert(key, CacheEntry { value, expires_at });
+    }
+
+    /// Drop every entry.
+    pub(crate) fn clear(&mut self) {
+        self.sites.clear();
+    }
+}
+
+impl Interpreter<'_> {
+    /// Evaluate a `cache(key: ..., op: ..., ttl: ...)` expression.
+    ///
+    /// `op` is evaluated lazily: only on a miss, and never on a hit. A
+    /// function-valued `op` is called with no arguments. Without a `key`,
+    /// or with a zero or negative `ttl`, `op` always runs and nothing is stored.
+    pub(super) fn eval_can_cache(&mut self, can_id: CanId, props: CanNamedExprRange) -> EvalResult {
+        let named: SmallVec<[_; 8]> =
+            SmallVec::from_slice(self.canon_ref().arena.get_named_exprs(props));
+        let pn = self.prop_names;
+
+        // `op` per the spec; `operation` is the older spelling
+        let op_name = if named.iter().any(|ne| ne.name == pn.op) {
+            pn.op
+        } else {
+            pn.operation
+        };
+        let op_id = find_prop_can_id(&named, op_name, self.interner)?;
+        let key_id = named.iter().find(|ne| ne.name == pn.key).map(|ne| ne.value);
+        let ttl_id = named.iter().find(|ne| ne.name == pn.ttl).map(|ne| ne.value);
+
+        let Some(key_id) = key_id else {
+            return self.eval_cache_op(op_id);
+        };
+        let key = self.eval_can(key_id)?;
+        let key = key
+            .to_map_key()
+            .map_err(|_| ControlAction::from(map_key_not_hashable()))?;
+
+        let ttl = match ttl_id {
+            Some(id) => match self.eval_can(id)? {
+                Value::Duration(nanos) => Some(nanos),
+                other => {
+                    return Err(EvalError::new(format!(
+                        "cache: ttl must be a Duration, got {}",
+                        other.type_name()
+                    ))
+                    .into())
+                }
+            },
+            None => None,
+        };
+        if ttl.is_some_and(|nanos| nanos <= 0) {
+            return self.eval_cache_op(op_id);
+        }
+
+        let site = CacheSite {
+            canon: std::ptr::from_ref(self.canon_ref()).addr(),
+            expr: can_id,
+        };
+        let now = Instant::now();
+        if let Some(hit) = self.cache_store.read().get(site, &key, now) {
+            return Ok(hit);
+        }
+
+        let value = self.eval_cache_op(op_id)?;
+        let expires_at = ttl
+            .and_then(|nanos| u64::try_from(nanos).ok())
+            .and_then(|nanos| now.checked_add(Duration::from_nanos(nanos)));
+        self.cache_store
+            .write()
+            .insert(site, key, value.clone(), expires_at);
+        Ok(value)
+    }
+
+    /// Evaluate `op`, calling it when it is a function.
+    fn eval_cache_op(&mut self, op_id: ori_ir::canon::CanId) -> EvalResult {
+        match self.eval_can(op_id)? {
+            op @ (Value::Function(_) | Value::FunctionVal(_, _)) => self.eval_call(&op, &[]),
+            value => Ok(value),
+        }
+    }
+}
@@ -52,7 +52,7 @@ impl Interpreter<'_> {
     /// Panics if `self.canon` is `None`. Callers must ensure canonical IR is set
     /// before calling `eval_can`.
     #[inline]
-    fn canon_ref(&self) -> &CanonResult {
+    pub(super) fn canon_ref(&self) -> &CanonResult {
         // Invariant: eval_can is only called when canon is known to be Some.
         // This is enforced by the call sites (function_call sets canon before calling).
         #[expect(clippy::expect_used, reason = "Invariant: eval_can requires canon")]
@@ -430,7 +430,7 @@ impl Interpreter<'_> {
             // Special Forms
             CanExpr::FunctionExp { kind, props } => {
                 let span = self.can_span(can_id);
-                self.eval_can_function_exp(kind, props)
+                self.eval_can_function_exp(can_id, kind, props)
                     .map_err(|e| Self::attach_span(e, span))
             }
 
@@ -999,6 +999,7 @@ impl Interpreter<'_> {
             let (lower, _) = current_iter.size_hint();
             let mut results = Vec::with_capacity(lower);
             loop {
+                self.check_deadline()?;
                 let (item, new_iter) = self.eval_iter_next(current_iter)?;
                 current_iter = new_iter;
                 let Some(val) = item else { break };
@@ -1034,6 +1035,7 @@ impl Interpreter<'_> {
         } else {
             // for...do: iterate for side effects
             loop {
+                self.check_deadline()?;
                 let (item, new_iter) = self.eval_iter_next(current_iter)?;
                 current_iter = new_iter;
                 let Some(val) = item else { break };
@@ -1070,6 +1072,7 @@ impl Interpreter<'_> {
         use crate::exec::control::{to_loop_action, LoopAction};
 
         loop {
+            self.check_deadline()?;
             match self.eval_can(body) {
                 Ok(_) => {}
                 Err(e) => match to_loop_action(e) {
@@ -1113,19 +1116,17 @@ impl Interpreter<'_> {
     /// In canonical IR, `FunctionExp` props are `CanNamedExpr` (name + `CanId`).
     /// We evaluate all props eagerly, then delegate to the existing pattern
     /// registry via the legacy `EvalContext` path by bridging the evaluated values.
-    #[expect(
-        clippy::too_many_lines,
-        reason = "exhaustive FunctionExpKind dispatch across all built-in function expression kinds"
-    )]
     fn eval_can_function_exp(
         &mut self,
+        can_id: CanId,
         kind: FunctionExpKind,
         props: ori_ir::canon::CanNamedExprRange,
     ) -> EvalResult {
-        // Catch and Recurse require lazy evaluation — their props must NOT
+        // Catch, Cache, and Recurse require lazy evaluation — their props must NOT
         // be pre-evaluated because evaluation order and error handling matter.
         match kind {
             FunctionExpKind::Catch => return self.eval_can_catch(props),
+            FunctionExpKind::Cache => return self.eval_can_cache(can_id, props),
             FunctionExpKind::Recurse => return self.eval_can_recurse(props),
             _ => {}
         }
@@ -1166,24 +1167,14 @@ impl Interpreter<'_> {
             FunctionExpKind::Unreachable => {
                 Err(EvalError::new("reached unreachable code".to_string()).into())
             }
-            // Catch and Recurse handled above via early return
-            FunctionExpKind::Catch | FunctionExpKind::Recurse => unreachable!(),
+            // Catch, Cache, and Recurse handled above via early return
+            FunctionExpKind::Catch | FunctionExpKind::Cache | FunctionExpKind::Recurse => {
+                unreachable!()
+            }
 
             // Stub patterns — honest stubs that evaluate args via the canonical
             // path and emit tracing::warn! so they're impossible to miss in logs.
             // Real implementations are roadmap items.
-            FunctionExpKind::Cache => {
-                tracing::warn!(
-                    "pattern 'cache' is a stub — operation is called without memoization"
-                );
-                let operation = find_prop_value(&values, pn.operation, self.interner)?;
-                match operation {
-                    Value::Function(_) | Value::FunctionVal(_, _) => {
-                        self.eval_call(&operation, &[])
-                    }
-                    _ => Ok(operation),
-                }
-            }
             FunctionExpKind::Parallel => {
                 tracing::warn!("pattern 'parallel' is a stub — tasks are executed sequentially");
                 let tasks = find_prop_value(&values, pn.tasks, self.interner)?;
@@ -1216,8 +1207,11 @@ impl Interpreter<'_> {
             }
             FunctionExpKind::Timeout => {
                 tracing::warn!("pattern 'timeout' is a stub — no timeout enforcement");
-                let operation = find_prop_value(&values, pn.operation, self.interner)?;
-                Ok(Value::ok(operation))
+                // `op` per the spec (named in the error when both are missing);
+                // `operation` is the older spelling
+                find_prop_value(&values, pn.operation, self.interner)
+                    .or_else(|_| find_prop_value(&values, pn.op, self.interner))
+                    .map(Value::ok)
             }
             FunctionExpKind::With => {
                 tracing::warn!(
@@ -1363,7 +1357,7 @@ fn find_prop_value(
 }
 
 /// Look up an unevaluated prop's `CanId` by interned `Name` (for lazy evaluation).
-fn find_prop_can_id(
+pub(super) fn find_prop_can_id(
     named: &[ori_ir::canon::CanNamedExpr],
     name: Name,
     interner: &ori_ir::StringInterner,
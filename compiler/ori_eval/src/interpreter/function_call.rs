@@ -32,6 +32,7 @@ impl Interpreter<'_> {
         if let Err(exceeded) = self.mode_state.check_budget() {
             return Err(crate::errors::budget_exceeded(exceeded.calls, exceeded.budget).into());
         }
+        self.check_deadline()?;
 
         match func {
             Value::Function(f) => {
@@ -104,6 +104,9 @@ impl TypeNames {
 pub(crate) struct PropNames {
     pub(crate) msg: Name,
     pub(crate) operation: Name,
+    pub(crate) op: Name,
+    pub(crate) key: Name,
+    pub(crate) ttl: Name,
     pub(crate) tasks: Name,
     pub(crate) acquire: Name,
     pub(crate) action: Name,
@@ -121,6 +124,9 @@ impl PropNames {
         Self {
             msg: interner.intern("msg"),
             operation: interner.intern("operation"),
+            op: interner.intern("op"),
+            key: interner.intern("key"),
+            ttl: interner.intern("ttl"),
             tasks: interner.intern("tasks"),
             acquire: interner.intern("acquire"),
             action: interner.intern("action"),
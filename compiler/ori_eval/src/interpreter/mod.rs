@@ -47,6 +47,7 @@
 //! with the callee's arena.
 
 mod builder;
+mod cache_pattern;
 mod can_eval;
 mod derived_methods;
 mod format;
@@ -169,6 +170,8 @@ pub struct Interpreter<'a> {
     /// - `TestRun`: buffer for capture
     /// - `ConstEval`: silent (discards output)
     pub(crate) print_handler: SharedPrintHandler,
+    /// Results memoized by the `cache` pattern, shared with child interpreters.
+    pub(crate) cache_store: SharedMutableRegistry<cache_pattern::CacheStore>,
     /// Scope ownership for RAII-style panic-safe scope cleanup.
     ///
     /// When `Owned`, the interpreter was created for a function/method call
@@ -322,6 +325,29 @@ impl<'a> Interpreter<'a> {
             .map(crate::diagnostics::EvalCounters::report)
     }
 
+    /// Limit wall-clock time for subsequent evaluation (`None` removes the limit).
+    ///
+    /// The clock starts now. Function calls and loop iterations fail with a
+    /// timeout error once the limit is exceeded.
+    pub fn set_time_limit(&mut self, limit: Option<std::time::Duration>) {
+        self.mode_state.set_time_limit(limit);
+    }
+
+    /// Drop every stored `cache` result, so the next evaluation starts cold.
+    ///
+    /// The store is shared with child interpreters, which see the reset too.
+    pub fn clear_cache(&mut self) {
+        self.cache_store.write().clear();
+    }
+
+    /// Fail with a timeout error if the wall-clock deadline has passed.
+    #[inline]
+    fn check_deadline(&self) -> Result<(), ControlAction> {
+        self.mode_state
+            .check_deadline()
+            .map_err(|timed_out| crate::errors::timed_out(timed_out.limit).into())
+    }
+
     /// Attach a span to an error if it doesn't already have one.
     ///
     /// Only attaches spans to `ControlAction::Error` variants; control flow
@@ -450,6 +476,7 @@ impl<'a> Interpreter<'a> {
             method_dispatcher: self.method_dispatcher.clone(),
             imported_arena: imported_arena.clone(),
             print_handler: self.print_handler.clone(),
+            cache_store: self.cache_store.clone(),
             scope_ownership: ScopeOwnership::Owned,
             canon: canon.or_else(|| self.canon.clone()),
         }
@@ -596,8 +623,8 @@ fn is_builtin_indexable(value: &Value) -> bool {
 /// Map a binary operator to its pre-interned trait method name.
 ///
 /// Returns `Some(Name)` for operators that have trait implementations,
-/// or `None` for comparison, logical, range, and null-coalescing operators
-/// which use direct evaluation.
+/// or `None` for comparison, logical, range, null-coalescing, and power
+/// operators which use direct evaluation.
 fn binary_op_to_method(op: BinaryOp, names: OpNames) -> Option<Name> {
     match op {
         // Arithmetic operators
@@ -614,7 +641,7 @@ fn binary_op_to_method(op: BinaryOp, names: OpNames) -> Option<Name> {
         BinaryOp::BitXor => Some(names.bit_xor),
         BinaryOp::Shl => Some(names.shift_left),
         BinaryOp::Shr => Some(names.shift_right),
-        // Comparison, logical, range, and null-coalescing operators
+        // Comparison, logical, range, null-coalescing, and power operators
         // use direct evaluation (no trait method)
         BinaryOp::Eq
         | BinaryOp::NotEq
@@ -626,7 +653,8 @@ fn binary_op_to_method(op: BinaryOp, names: OpNames) -> Option<Name> {
         | BinaryOp::Or
         | BinaryOp::Range
         | BinaryOp::RangeInclusive
-        | BinaryOp::Coalesce => None,
+        | BinaryOp::Coalesce
+        | BinaryOp::Pow => None,
     }
 }
 
@@ -53,7 +53,7 @@ pub use ori_patterns::{
 
 pub use diagnostics::{CallFrame, CallStack, EvalCounters};
 pub use environment::{AssignError, Environment, LocalScope, Mutability, Scope};
-pub use eval_mode::{BudgetExceeded, EvalMode, ModeState};
+pub use eval_mode::{BudgetExceeded, EvalMode, ModeState, TimedOut};
 pub use method_key::MethodKey;
 pub use methods::{dispatch_builtin_method_str, EVAL_BUILTIN_METHODS};
 pub use operators::evaluate_binary;
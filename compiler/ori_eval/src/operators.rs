@@ -123,6 +123,8 @@ fn eval_int_binary(a: ScalarInt, b: ScalarInt, op: BinaryOp) -> EvalResult {
             Value::Int,
             "floor division",
         ),
+        BinaryOp::Pow if b.raw() < 0 => Err(negative_exponent(b.raw()).into()),
+        BinaryOp::Pow => checked_arith(a.checked_pow(b), Value::Int, "exponentiation"),
         BinaryOp::Eq => Ok(Value::Bool(a == b)),
         BinaryOp::NotEq => Ok(Value::Bool(a != b)),
         BinaryOp::Lt => Ok(Value::Bool(a < b)),
@@ -153,6 +155,7 @@ fn eval_float_binary(a: f64, b: f64, op: BinaryOp) -> EvalResult {
         BinaryOp::Sub => Ok(Value::Float(a - b)),
         BinaryOp::Mul => Ok(Value::Float(a * b)),
         BinaryOp::Div => Ok(Value::Float(a / b)),
+        BinaryOp::Pow => Ok(Value::Float(a.powf(b))),
         // Use partial_cmp for IEEE 754 compliant comparisons
         // (NaN != NaN, -0.0 == 0.0)
         BinaryOp::Eq => Ok(Value::Bool(
@@ -429,6 +432,12 @@ fn shift_out_of_range(amount: i64) -> EvalError {
     EvalError::new(format!("shift amount {amount} out of range (0-63)"))
 }
 
+/// Integer exponent is negative (`int ** int` never produces a fraction).
+#[cold]
+fn negative_exponent(exp: i64) -> EvalError {
+    EvalError::new(format!("negative exponent {exp} in integer power"))
+}
+
 /// Binary operations on struct values.
 ///
 /// Structs support equality comparison. The comparison is structural:
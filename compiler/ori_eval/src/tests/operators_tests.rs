@@ -386,6 +386,58 @@ fn rem_both_negative() {
     );
 }
 
+#[test]
+fn pow_int() {
+    assert_eq!(
+        evaluate_binary(Value::int(2), Value::int(10), BinaryOp::Pow).unwrap(),
+        Value::int(1024)
+    );
+    assert_eq!(
+        evaluate_binary(Value::int(-3), Value::int(3), BinaryOp::Pow).unwrap(),
+        Value::int(-27)
+    );
+}
+
+#[test]
+fn pow_zero_exponent_is_one() {
+    assert_eq!(
+        evaluate_binary(Value::int(0), Value::int(0), BinaryOp::Pow).unwrap(),
+        Value::int(1)
+    );
+}
+
+#[test]
+fn pow_huge_exponent_of_unit_base() {
+    assert_eq!(
+        evaluate_binary(Value::int(-1), Value::int(i64::MAX), BinaryOp::Pow).unwrap(),
+        Value::int(-1)
+    );
+}
+
+#[test]
+fn overflow_pow() {
+    assert_overflow(2, 63, BinaryOp::Pow);
+    assert_overflow(10, i64::MAX, BinaryOp::Pow);
+}
+
+#[test]
+fn pow_negative_exponent_message() {
+    let result = evaluate_binary(Value::int(2), Value::int(-1), BinaryOp::Pow);
+    assert!(result
+        .unwrap_err()
+        .into_eval_error()
+        .message
+        .contains("negative exponent"));
+}
+
+#[test]
+fn pow_float() {
+    assert_eq!(
+        evaluate_binary(Value::Float(2.0), Value::Float(0.5), BinaryOp::Pow).unwrap(),
+        Value::Float(std::f64::consts::SQRT_2)
+    );
+}
+
 #[test]
 fn shl_boundary_63_overflows() {
     // Per spec: `1 << 63` should panic due to signed overflow.
@@ -233,7 +233,7 @@ impl CommentIndex {
 /// Regular comments keep their original position.
 fn sort_comments_by_kind(mut refs: Vec<CommentRef>) -> Vec<CommentRef> {
     // If all regular comments, preserve order
-    if refs.iter().all(|r| r.kind == CommentKind::Regular) {
+    if refs.iter().all(|r| !r.kind.is_doc()) {
         return refs;
     }
 
@@ -247,9 +247,14 @@ fn sort_comments_by_kind(mut refs: Vec<CommentRef>) -> Vec<CommentRef> {
 /// Ensures proper formatting:
 /// - Space after `//`
 /// - Doc comment markers normalized
+/// - Block comments reproduced verbatim
 pub fn format_comment<I: StringLookup>(comment: &Comment, interner: &I) -> String {
     let content = interner.lookup(comment.content);
-    format!("//{content}")
+    if comment.kind == CommentKind::Block {
+        format!("/*{content}*/")
+    } else {
+        format!("//{content}")
+    }
 }
 
 /// Reorder member doc comments to match function parameter order.
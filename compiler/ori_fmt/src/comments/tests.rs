@@ -71,11 +71,36 @@ fn test_sort_comments_by_kind() {
 
     let sorted = sort_comments_by_kind(refs);
 
-    // Description (sort_order=0) should come before Example (sort_order=3)
+    // Description (sort_order=0) should come before Example (sort_order=5)
     assert_eq!(sorted[0].index, 1);
     assert_eq!(sorted[1].index, 0);
 }
 
+#[test]
+fn test_sort_returns_and_throws() {
+    // Warning, Throws, Returns -> Returns, Throws, Warning
+    let refs = vec![
+        CommentRef {
+            index: 0,
+            kind: CommentKind::DocWarning,
+        },
+        CommentRef {
+            index: 1,
+            kind: CommentKind::DocThrows,
+        },
+        CommentRef {
+            index: 2,
+            kind: CommentKind::DocReturns,
+        },
+    ];
+
+    let sorted = sort_comments_by_kind(refs);
+
+    assert_eq!(sorted[0].index, 2);
+    assert_eq!(sorted[1].index, 1);
+    assert_eq!(sorted[2].index, 0);
+}
+
 #[test]
 fn test_sort_regular_comments_preserved() {
     let refs = vec![
@@ -61,6 +61,7 @@ fn binary_op_str(op: BinaryOp) -> &'static str {
         BinaryOp::Div => "/",
         BinaryOp::Mod => "%",
         BinaryOp::FloorDiv => "div",
+        BinaryOp::Pow => "**",
         BinaryOp::Eq => "==",
         BinaryOp::NotEq => "!=",
         BinaryOp::Lt => "<",
@@ -98,7 +99,7 @@ fn unary_op_str(op: UnaryOp) -> &'static str {
 /// Returns true when the operand is:
 /// - A binary expression with lower precedence (higher number) than the parent
 /// - A binary expression with equal precedence on the "wrong" side for associativity
-///   (all binary ops are left-associative except `??` which is right-associative)
+///   (all binary ops are left-associative except `**` and `??` which are right-associative)
 /// - A lambda, let, or if expression (always needs parens as binary operand)
 fn needs_binary_parens(
     arena: &ExprArena,
@@ -116,7 +117,7 @@ fn needs_binary_parens(
             match child_prec.cmp(&parent_prec) {
                 std::cmp::Ordering::Greater => true,
                 std::cmp::Ordering::Equal => {
-                    let is_right_assoc = matches!(parent_op, BinaryOp::Coalesce);
+                    let is_right_assoc = matches!(parent_op, BinaryOp::Pow | BinaryOp::Coalesce);
                     if is_right_assoc {
                         is_left
                     } else {
@@ -386,7 +386,7 @@ pub fn apply_regions(source: &str, mut regions: Vec<FormattedRegion>) -> String
 
     // Sort by start position (descending) to apply from end to start
     // This preserves earlier byte offsets as we replace
-    regions.sort_by(|a, b| b.original_start.cmp(&a.original_start));
+    regions.sort_by_key(|r| std::cmp::Reverse(r.original_start));
 
     let mut result = source.to_string();
 
@@ -368,15 +368,17 @@ impl From<&TokenKind> for TokenCategory {
     )]
     fn from(kind: &TokenKind) -> Self {
         match kind {
-            TokenKind::Int(_) => TokenCategory::Int,
+            TokenKind::SuffixedNumber(_, suffix) if suffix.is_float() => TokenCategory::Float,
+            TokenKind::Int(_) | TokenKind::SuffixedNumber(_, _) => TokenCategory::Int,
             TokenKind::Float(_) => TokenCategory::Float,
             TokenKind::String(_)
+            | TokenKind::ByteString(_)
             | TokenKind::TemplateHead(_)
             | TokenKind::TemplateMiddle(_)
             | TokenKind::TemplateTail(_)
             | TokenKind::TemplateFull(_)
             | TokenKind::FormatSpec(_) => TokenCategory::String,
-            TokenKind::Char(_) => TokenCategory::Char,
+            TokenKind::Char(_) | TokenKind::Byte(_) => TokenCategory::Char,
             TokenKind::Duration(_, _) => TokenCategory::Duration,
             TokenKind::Size(_, _) => TokenCategory::Size,
             // Keywords treated as identifiers for spacing purposes
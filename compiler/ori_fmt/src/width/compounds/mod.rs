@@ -50,6 +50,10 @@ pub(super) fn size_width(value: u64, unit: SizeUnit) -> usize {
         SizeUnit::Megabytes => 2, // "mb"
         SizeUnit::Gigabytes => 2, // "gb"
         SizeUnit::Terabytes => 2, // "tb"
+        SizeUnit::Kibibytes => 3, // "kib"
+        SizeUnit::Mebibytes => 3, // "mib"
+        SizeUnit::Gibibytes => 3, // "gib"
+        SizeUnit::Tebibytes => 3, // "tib"
     };
 
     value_w + unit_w
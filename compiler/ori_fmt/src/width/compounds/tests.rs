@@ -44,3 +44,9 @@ fn test_size_width_megabytes() {
 fn test_size_width_gigabytes() {
     assert_eq!(size_width(2, SizeUnit::Gigabytes), 3); // "2gb"
 }
+
+#[test]
+fn test_size_width_binary_units() {
+    assert_eq!(size_width(1, SizeUnit::Kibibytes), 4); // "1kib"
+    assert_eq!(size_width(64, SizeUnit::Mebibytes), 5); // "64mib"
+}
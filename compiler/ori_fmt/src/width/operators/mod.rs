@@ -20,6 +20,8 @@ pub(super) fn binary_op_width(op: BinaryOp) -> usize {
         BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => 1,
         // Floor division: div
         BinaryOp::FloorDiv => 3,
+        // Power: **
+        BinaryOp::Pow => 2,
         // Two-character comparison: == !=
         BinaryOp::Eq | BinaryOp::NotEq => 2,
         // Single-character comparison: < >
@@ -22,6 +22,7 @@ pub enum BinaryOp {
     Div,
     Mod,
     FloorDiv,
+    Pow,
 
     // Comparison
     Eq,
@@ -61,6 +62,7 @@ impl BinaryOp {
             Self::Div => "/",
             Self::Mod => "%",
             Self::FloorDiv => "div",
+            Self::Pow => "**",
             // Comparison
             Self::Eq => "==",
             Self::NotEq => "!=",
@@ -90,44 +92,47 @@ impl BinaryOp {
     /// Used by the formatter to determine when parentheses are needed.
     ///
     /// Precedence levels (from operator-rules.md):
-    /// - 3: `*` `/` `%` `div`
-    /// - 4: `+` `-`
-    /// - 5: `<<` `>>`
-    /// - 6: `..` `..=`
-    /// - 7: `<` `>` `<=` `>=`
-    /// - 8: `==` `!=`
-    /// - 9: `&`
-    /// - 10: `^`
-    /// - 11: `|`
-    /// - 12: `&&`
-    /// - 13: `||`
-    /// - 14: `??`
+    /// - 3: `**`
+    /// - 4: `*` `/` `%` `div`
+    /// - 5: `+` `-`
+    /// - 6: `<<` `>>`
+    /// - 7: `..` `..=`
+    /// - 8: `<` `>` `<=` `>=`
+    /// - 9: `==` `!=`
+    /// - 10: `&`
+    /// - 11: `^`
+    /// - 12: `|`
+    /// - 13: `&&`
+    /// - 14: `||`
+    /// - 15: `??`
     pub const fn precedence(self) -> u8 {
         match self {
-            // Multiplicative (highest binary precedence)
-            Self::Mul | Self::Div | Self::Mod | Self::FloorDiv => 3,
+            // Power (highest binary precedence)
+            Self::Pow => 3,
+            // Multiplicative
+            Self::Mul | Self::Div | Self::Mod | Self::FloorDiv => 4,
             // Additive
-            Self::Add | Self::Sub => 4,
+            Self::Add | Self::Sub => 5,
             // Shift
-            Self::Shl | Self::Shr => 5,
+            Self::Shl | Self::Shr => 6,
             // Range
-            Self::Range | Self::RangeInclusive => 6,
+            Self::Range | Self::RangeInclusive => 7,
             // Comparison
-            Self::Lt | Self::LtEq | Self::Gt | Self::GtEq => 7,
+            Self::Lt | Self::LtEq | Self::Gt | Self::GtEq => 8,
             // Equality
-            Self::Eq | Self::NotEq => 8,
+            Self::Eq | Self::NotEq => 9,
             // Bitwise AND
-            Self::BitAnd => 9,
+            Self::BitAnd => 10,
             // Bitwise XOR
-            Self::BitXor => 10,
+            Self::BitXor => 11,
             // Bitwise OR
-            Self::BitOr => 11,
+            Self::BitOr => 12,
             // Logical AND
-            Self::And => 12,
+            Self::And => 13,
             // Logical OR
-            Self::Or => 13,
+            Self::Or => 14,
             // Coalesce (lowest binary precedence)
-            Self::Coalesce => 14,
+            Self::Coalesce => 15,
         }
     }
 }
@@ -136,7 +141,7 @@ impl BinaryOp {
     /// Map this operator to its trait method name for operator overloading.
     ///
     /// Only operators with corresponding trait methods are mapped; comparison,
-    /// logical, range, and coalesce operators return `None`.
+    /// logical, range, coalesce, and power operators return `None`.
     ///
     /// This is the **single source of truth** — `ori_types` (type checker) and
     /// `ori_llvm` (codegen) both call this instead of maintaining parallel mappings.
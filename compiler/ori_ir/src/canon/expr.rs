@@ -503,5 +503,8 @@ pub enum PatternProblem {
         match_span: Span,
         /// Zero-based index of the redundant arm.
         arm_index: usize,
+        /// Span of the earlier unguarded catch-all arm (`_` or a plain
+        /// binding) that shadows this one, when there is one.
+        covered_by: Option<Span>,
     },
 }
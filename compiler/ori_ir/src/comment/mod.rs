@@ -53,6 +53,8 @@ impl fmt::Debug for Comment {
 /// Doc comments have special markers that affect formatting order:
 /// - `#` Description (must come first)
 /// - `* name:` Member (parameter/field) documentation
+/// - `@returns` Return value documentation
+/// - `@throws` Error documentation
 /// - `!` Warning/panic documentation
 /// - `>` Example code
 ///
@@ -64,6 +66,10 @@ impl fmt::Debug for Comment {
 pub enum CommentKind {
     /// Regular comment: `// any text`
     Regular,
+    /// Block comment: `/* any text */`, content kept verbatim
+    ///
+    /// Never a doc comment, even directly before a declaration.
+    Block,
     /// Description doc comment: `// #Description text`
     DocDescription,
     /// Member doc comment: `// * name: description`
@@ -71,6 +77,10 @@ pub enum CommentKind {
     /// Also produced by legacy `@param` and `@field` markers.
     /// Works for both function parameters and struct fields.
     DocMember,
+    /// Return value doc comment: `// @returns description`
+    DocReturns,
+    /// Error doc comment: `// @throws description`
+    DocThrows,
     /// Warning/panic doc comment: `// !Warning text`
     DocWarning,
     /// Example doc comment: `// >example() -> result`
@@ -81,23 +91,26 @@ impl CommentKind {
     /// Get the sort order for doc comment kinds.
     /// Lower numbers should appear first.
     ///
-    /// Order: Description(0) -> Member(1) -> Warning(2) -> Example(3)
+    /// Order: Description(0) -> Member(1) -> Returns(2) -> Throws(3)
+    /// -> Warning(4) -> Example(5)
     /// Regular comments have order 100 to sort after doc comments.
     #[inline]
     pub fn sort_order(self) -> u8 {
         match self {
             CommentKind::DocDescription => 0,
             CommentKind::DocMember => 1,
-            CommentKind::DocWarning => 2,
-            CommentKind::DocExample => 3,
-            CommentKind::Regular => 100,
+            CommentKind::DocReturns => 2,
+            CommentKind::DocThrows => 3,
+            CommentKind::DocWarning => 4,
+            CommentKind::DocExample => 5,
+            CommentKind::Regular | CommentKind::Block => 100,
         }
     }
 
     /// Check if this is any kind of doc comment.
     #[inline]
     pub fn is_doc(self) -> bool {
-        !matches!(self, CommentKind::Regular)
+        !matches!(self, CommentKind::Regular | CommentKind::Block)
     }
 }
 
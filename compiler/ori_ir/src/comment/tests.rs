@@ -21,8 +21,10 @@ fn test_comment_creation() {
 fn test_comment_kind_sort_order() {
     // Description comes first
     assert!(CommentKind::DocDescription.sort_order() < CommentKind::DocMember.sort_order());
-    // Member comes before Warning
-    assert!(CommentKind::DocMember.sort_order() < CommentKind::DocWarning.sort_order());
+    // Member, then Returns, then Throws, then Warning
+    assert!(CommentKind::DocMember.sort_order() < CommentKind::DocReturns.sort_order());
+    assert!(CommentKind::DocReturns.sort_order() < CommentKind::DocThrows.sort_order());
+    assert!(CommentKind::DocThrows.sort_order() < CommentKind::DocWarning.sort_order());
     // Warning comes before Example
     assert!(CommentKind::DocWarning.sort_order() < CommentKind::DocExample.sort_order());
     // Regular comments come last
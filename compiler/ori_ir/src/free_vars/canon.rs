@@ -0,0 +1,290 @@
+//! Free-variable analysis over canonical IR.
+//!
+//! Same scoping rules as the AST analysis. Match arms bind the names of
+//! their decision-tree leaves, and `with` binds the provided capability.
+
+use crate::canon::{
+    CanBindingPattern, CanBindingPatternId, CanExpr, CanId, CanonResult, DecisionTree,
+    ScrutineePath,
+};
+use crate::Name;
+
+use super::Scopes;
+
+/// Names referenced in `body` but bound neither by `params` nor inside
+/// `body`, each with the first node that references it.
+///
+/// The node gives callers the reference's resolved type. Order and
+/// shadowing follow [`free_vars`](super::free_vars).
+pub fn canon_free_vars(body: CanId, canon: &CanonResult, params: &[Name]) -> Vec<(Name, CanId)> {
+    let mut collector = CanFreeVars {
+        canon,
+        scopes: Scopes::new(params),
+    };
+    collector.visit(body);
+    collector.scopes.free
+}
+
+struct CanFreeVars<'a> {
+    canon: &'a CanonResult,
+    scopes: Scopes<CanId>,
+}
+
+impl CanFreeVars<'_> {
+    fn visit_list(&mut self, ids: &[CanId]) {
+        for &id in ids {
+            self.visit(id);
+        }
+    }
+
+    #[expect(
+        clippy::too_many_lines,
+        reason = "dispatch table — each arm is 1-3 lines"
+    )]
+    fn visit(&mut self, id: CanId) {
+        if !id.is_valid() {
+            return;
+        }
+
+        let canon = self.canon;
+        match *canon.arena.kind(id) {
+            CanExpr::Ident(name) => self.scopes.reference(name, id),
+            CanExpr::Binary { left, right, .. } => {
+                self.visit(left);
+                self.visit(right);
+            }
+            CanExpr::Call { func, args } => {
+                self.visit(func);
+                self.visit_list(canon.arena.get_expr_list(args));
+            }
+            CanExpr::MethodCall { receiver, args, .. } => {
+                self.visit(receiver);
+                self.visit_list(canon.arena.get_expr_list(args));
+            }
+            CanExpr::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                self.visit(cond);
+                self.visit(then_branch);
+                self.visit(else_branch);
+            }
+            CanExpr::Block { stmts, result } => {
+                let mark = self.scopes.enter();
+                self.visit_list(canon.arena.get_expr_list(stmts));
+                self.visit(result);
+                self.scopes.leave(mark);
+            }
+            // Binds for the rest of the enclosing block, after `init`
+            CanExpr::Let { pattern, init, .. } => {
+                self.visit(init);
+                self.bind_pattern(pattern);
+            }
+            CanExpr::Lambda { params, body } => {
+                let mark = self.scopes.enter();
+                for param in canon.arena.get_params(params) {
+                    self.scopes.bind(param.name);
+                }
+                self.visit(body);
+                self.scopes.leave(mark);
+            }
+            CanExpr::For {
+                binding,
+                iter,
+                guard,
+                body,
+                ..
+            } => {
+                self.visit(iter);
+                let mark = self.scopes.enter();
+                self.scopes.bind(binding);
+                self.visit(guard);
+                self.visit(body);
+                self.scopes.leave(mark);
+            }
+            CanExpr::Match {
+                scrutinee,
+                decision_tree,
+                arms,
+            } => {
+                self.visit(scrutinee);
+                let arm_bodies = canon.arena.get_expr_list(arms);
+                let mut arm_names = vec![Vec::new(); arm_bodies.len()];
+                let mut guards = Vec::new();
+                collect_arm_bindings(
+                    canon.decision_trees.get(decision_tree),
+                    &mut arm_names,
+                    &mut guards,
+                );
+                // Each arm body and guard sees only its own arm's bindings.
+                for (arm_index, guard) in guards {
+                    self.visit_in_arm(&arm_names[arm_index], guard);
+                }
+                for (names, &body) in arm_names.iter().zip(arm_bodies) {
+                    self.visit_in_arm(names, body);
+                }
+            }
+            CanExpr::WithCapability {
+                capability,
+                provider,
+                body,
+            } => {
+                self.visit(provider);
+                let mark = self.scopes.enter();
+                self.scopes.bind(capability);
+                self.visit(body);
+                self.scopes.leave(mark);
+            }
+            CanExpr::Index { receiver, index } => {
+                self.visit(receiver);
+                self.visit(index);
+            }
+            CanExpr::Assign { target, value } => {
+                self.visit(target);
+                self.visit(value);
+            }
+            CanExpr::Range {
+                start, end, step, ..
+            } => {
+                self.visit(start);
+                self.visit(end);
+                self.visit(step);
+            }
+            CanExpr::Unary { operand: e, .. }
+            | CanExpr::Field { receiver: e, .. }
+            | CanExpr::Loop { body: e, .. }
+            | CanExpr::Cast { expr: e, .. }
+            | CanExpr::FormatWith { expr: e, .. }
+            | CanExpr::Ok(e)
+            | CanExpr::Err(e)
+            | CanExpr::Some(e)
+            | CanExpr::Try(e)
+            | CanExpr::Await(e)
+            | CanExpr::Unsafe(e)
+            | CanExpr::Break { value: e, .. }
+            | CanExpr::Continue { value: e, .. } => self.visit(e),
+            CanExpr::Tuple(range) | CanExpr::List(range) => {
+                self.visit_list(canon.arena.get_expr_list(range));
+            }
+            CanExpr::Struct { fields, .. } => {
+                for field in canon.arena.get_fields(fields) {
+                    self.visit(field.value);
+                }
+            }
+            CanExpr::Map(entries) => {
+                for entry in canon.arena.get_map_entries(entries) {
+                    self.visit(entry.key);
+                    self.visit(entry.value);
+                }
+            }
+            CanExpr::FunctionExp { props, .. } => {
+                for prop in canon.arena.get_named_exprs(props) {
+                    self.visit(prop.value);
+                }
+            }
+            // Leaf expressions — no free variables
+            CanExpr::Constant(_)
+            | CanExpr::Int(_)
+            | CanExpr::Float(_)
+            | CanExpr::Bool(_)
+            | CanExpr::Char(_)
+            | CanExpr::Str(_)
+            | CanExpr::Unit
+            | CanExpr::None
+            | CanExpr::Error
+            | CanExpr::SelfRef
+            | CanExpr::FunctionRef(_)
+            | CanExpr::TypeRef(_)
+            | CanExpr::Const(_)
+            | CanExpr::HashLength
+            | CanExpr::Duration { .. }
+            | CanExpr::Size { .. } => {}
+        }
+    }
+
+    fn visit_in_arm(&mut self, names: &[Name], id: CanId) {
+        let mark = self.scopes.enter();
+        for &name in names {
+            self.scopes.bind(name);
+        }
+        self.visit(id);
+        self.scopes.leave(mark);
+    }
+
+    fn bind_pattern(&mut self, pattern: CanBindingPatternId) {
+        let canon = self.canon;
+        match canon.arena.get_binding_pattern(pattern) {
+            CanBindingPattern::Name { name, .. } => self.scopes.bind(*name),
+            CanBindingPattern::Wildcard => {}
+            CanBindingPattern::Tuple(elements) => {
+                for &sub in canon.arena.get_binding_pattern_list(*elements) {
+                    self.bind_pattern(sub);
+                }
+            }
+            CanBindingPattern::Struct { fields } => {
+                for field in canon.arena.get_field_bindings(*fields) {
+                    self.bind_pattern(field.pattern);
+                }
+            }
+            CanBindingPattern::List { elements, rest } => {
+                for &sub in canon.arena.get_binding_pattern_list(*elements) {
+                    self.bind_pattern(sub);
+                }
+                if let Some((name, _)) = rest {
+                    self.scopes.bind(*name);
+                }
+            }
+        }
+    }
+}
+
+/// Record the names each match arm binds, and every arm guard.
+///
+/// All leaves of one arm bind the same names (at different paths for
+/// or-patterns), so the first leaf reached for an arm is enough.
+fn collect_arm_bindings(
+    tree: &DecisionTree,
+    arm_names: &mut [Vec<Name>],
+    guards: &mut Vec<(usize, CanId)>,
+) {
+    match tree {
+        DecisionTree::Switch { edges, default, .. } => {
+            for (_, subtree) in edges {
+                collect_arm_bindings(subtree, arm_names, guards);
+            }
+            if let Some(default) = default {
+                collect_arm_bindings(default, arm_names, guards);
+            }
+        }
+        DecisionTree::Leaf {
+            arm_index,
+            bindings,
+        } => record_arm_names(*arm_index, bindings, arm_names),
+        DecisionTree::Guard {
+            arm_index,
+            bindings,
+            guard,
+            on_fail,
+        } => {
+            record_arm_names(*arm_index, bindings, arm_names);
+            if !guards.iter().any(|&(_, g)| g == *guard) {
+                guards.push((*arm_index, *guard));
+            }
+            collect_arm_bindings(on_fail, arm_names, guards);
+        }
+        DecisionTree::Fail => {}
+    }
+}
+
+fn record_arm_names(
+    arm_index: usize,
+    bindings: &[(Name, ScrutineePath)],
+    arm_names: &mut [Vec<Name>],
+) {
+    if let Some(names) = arm_names.get_mut(arm_index) {
+        if names.is_empty() {
+            names.extend(bindings.iter().map(|(name, _)| *name));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Free-variable analysis for lambda capture.
+//!
+//! [`free_vars`] reports the identifiers a lambda body references without
+//! binding them: the names a closure must capture from its enclosing scope.
+//! Built on the [`Visitor`], with scope tracking for every construct that
+//! binds names (`let`, blocks, lambdas, `for`, match arms).
+//! [`canon_free_vars`] runs the same analysis over canonical IR for the
+//! backends, which no longer have the AST.
+//!
+//! The analysis is purely syntactic. A reported name may also resolve to a
+//! module-level item or builtin; callers filter against their own scope.
+
+mod canon;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::ast::{BindingPattern, Expr, ExprKind, FunctionSeq, MatchArm, MatchPattern, Stmt};
+use crate::visitor::{walk_expr, walk_stmt, Visitor};
+use crate::{ExprArena, ExprId, Name, StmtKind};
+
+pub use canon::canon_free_vars;
+
+/// Names referenced in `body` but bound neither by `params` nor inside `body`.
+///
+/// Names are returned once each, in order of first reference. Shadowing is
+/// respected: a `let` binds only the code after it in the same block, so
+/// `let y = y` still reports `y` free, and a binding ends with its block.
+pub fn free_vars(body: ExprId, arena: &ExprArena, params: &[Name]) -> Vec<Name> {
+    let mut collector = FreeVars {
+        scopes: Scopes::new(params),
+    };
+    arena.visit(body, &mut collector);
+    collector
+        .scopes
+        .free
+        .into_iter()
+        .map(|(name, ())| name)
+        .collect()
+}
+
+/// Scope tracking shared by the AST and canonical analyses.
+///
+/// `R` is what is recorded for each free name alongside it: nothing for the
+/// AST, the first referencing node for canonical IR.
+struct Scopes<R> {
+    /// Names in scope, innermost last. Scopes truncate back on exit.
+    bound: Vec<Name>,
+    /// Number of entries in `bound` per name, so lookups are O(1).
+    depth: FxHashMap<Name, u32>,
+    /// Free names in first-reference order.
+    free: Vec<(Name, R)>,
+    seen: FxHashSet<Name>,
+}
+
+impl<R> Scopes<R> {
+    fn new(params: &[Name]) -> Self {
+        let mut scopes = Scopes {
+            bound: Vec::with_capacity(params.len()),
+            depth: FxHashMap::default(),
+            free: Vec::new(),
+            seen: FxHashSet::default(),
+        };
+        for &name in params {
+            scopes.bind(name);
+        }
+        scopes
+    }
+
+    fn bind(&mut self, name: Name) {
+        self.bound.push(name);
+        *self.depth.entry(name).or_insert(0) += 1;
+    }
+
+    /// Start a scope; pass the result to [`Scopes::leave`] on exit.
+    fn enter(&self) -> usize {
+        self.bound.len()
+    }
+
+    fn leave(&mut self, mark: usize) {
+        for name in self.bound.drain(mark..) {
+            if let Some(count) = self.depth.get_mut(&name) {
+                *count -= 1;
+                if *count == 0 {
+                    self.depth.remove(&name);
+                }
+            }
+        }
+    }
+
+    fn reference(&mut self, name: Name, at: R) {
+        if !self.depth.contains_key(&name) && self.seen.insert(name) {
+            self.free.push((name, at));
+        }
+    }
+}
+
+struct FreeVars {
+    scopes: Scopes<()>,
+}
+
+impl FreeVars {
+    fn bind_pattern(&mut self, pattern: &BindingPattern) {
+        match pattern {
+            BindingPattern::Name { name, .. } => self.scopes.bind(*name),
+            BindingPattern::Wildcard => {}
+            BindingPattern::Tuple(elements) => {
+                for element in elements {
+                    self.bind_pattern(element);
+                }
+            }
+            BindingPattern::Struct { fields } => {
+                for field in fields {
+                    match &field.pattern {
+                        Some(sub) => self.bind_pattern(sub),
+                        None => self.scopes.bind(field.name),
+                    }
+                }
+            }
+            BindingPattern::List { elements, rest } => {
+                for element in elements {
+                    self.bind_pattern(element);
+                }
+                if let Some((name, _)) = rest {
+                    self.scopes.bind(*name);
+                }
+            }
+        }
+    }
+
+    fn bind_match_pattern(&mut self, pattern: &MatchPattern, arena: &ExprArena) {
+        match pattern {
+            MatchPattern::Binding(name) => self.scopes.bind(*name),
+            MatchPattern::At { name, pattern } => {
+                self.scopes.bind(*name);
+                self.bind_match_pattern(arena.get_match_pattern(*pattern), arena);
+            }
+            MatchPattern::Variant { inner: list, .. }
+            | MatchPattern::Tuple(list)
+            | MatchPattern::Or(list) => {
+                for &id in arena.get_match_pattern_list(*list) {
+                    self.bind_match_pattern(arena.get_match_pattern(id), arena);
+                }
+            }
+            MatchPattern::Struct { fields, .. } => {
+                for (name, sub) in fields {
+                    match sub {
+                        Some(id) => self.bind_match_pattern(arena.get_match_pattern(*id), arena),
+                        None => self.scopes.bind(*name),
+                    }
+                }
+            }
+            MatchPattern::List { elements, rest } => {
+                for &id in arena.get_match_pattern_list(*elements) {
+                    self.bind_match_pattern(arena.get_match_pattern(id), arena);
+                }
+                if let Some(name) = rest {
+                    self.scopes.bind(*name);
+                }
+            }
+            MatchPattern::Wildcard | MatchPattern::Literal(_) | MatchPattern::Range { .. } => {}
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for FreeVars {
+    fn visit_expr(&mut self, expr: &Expr, arena: &'ast ExprArena) {
+        match &expr.kind {
+            ExprKind::Ident(name) => self.scopes.reference(*name, ()),
+            ExprKind::Block { .. } => {
+                let mark = self.scopes.enter();
+                walk_expr(self, expr, arena);
+                self.scopes.leave(mark);
+            }
+            // Binds for the rest of the enclosing block, after `init`
+            ExprKind::Let { pattern, init, .. } => {
+                self.visit_expr_id(*init, arena);
+                self.bind_pattern(arena.get_binding_pattern(*pattern));
+            }
+            ExprKind::Lambda { params, body, .. } => {
+                let mark = self.scopes.enter();
+                for param in arena.get_params(*params) {
+                    self.scopes.bind(param.name);
+                }
+                self.visit_expr_id(*body, arena);
+                self.scopes.leave(mark);
+            }
+            ExprKind::For {
+                binding,
+                iter,
+                guard,
+                body,
+                ..
+            } => {
+                self.visit_expr_id(*iter, arena);
+                let mark = self.scopes.enter();
+                self.scopes.bind(*binding);
+                if guard.is_present() {
+                    self.visit_expr_id(*guard, arena);
+                }
+                self.visit_expr_id(*body, arena);
+                self.scopes.leave(mark);
+            }
+            _ => walk_expr(self, expr, arena),
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast Stmt, arena: &'ast ExprArena) {
+        match &stmt.kind {
+            StmtKind::Let { pattern, init, .. } => {
+                self.visit_expr_id(*init, arena);
+                self.bind_pattern(arena.get_binding_pattern(*pattern));
+            }
+            StmtKind::Expr(_) => walk_stmt(self, stmt, arena),
+        }
+    }
+
+    fn visit_match_arm(&mut self, arm: &'ast MatchArm, arena: &'ast ExprArena) {
+        let mark = self.scopes.enter();
+        // Literal and range patterns may hold expressions of their own
+        self.visit_match_pattern(&arm.pattern, arena);
+        self.bind_match_pattern(&arm.pattern, arena);
+        if let Some(guard) = arm.guard {
+            self.visit_expr_id(guard, arena);
+        }
+        self.visit_expr_id(arm.body, arena);
+        self.scopes.leave(mark);
+    }
+
+    fn visit_function_seq(&mut self, seq: &'ast FunctionSeq, arena: &'ast ExprArena) {
+        // `try { let ... }` statements scope like a block
+        let mark = self.scopes.enter();
+        crate::visitor::walk_function_seq(self, seq, arena);
+        self.scopes.leave(mark);
+    }
+}
+
+#[cfg(test)]
+mod tests;
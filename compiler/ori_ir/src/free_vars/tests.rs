@@ -0,0 +1,227 @@
+use super::{canon_free_vars, free_vars};
+use crate::ast::{BinaryOp, BindingPattern, Expr, ExprKind, Param, Stmt};
+use crate::canon::{CanExpr, CanId, CanNode, CanonResult, DecisionTree};
+use crate::{ExprArena, ExprId, Mutability, Name, ParsedTypeId, Span, StmtKind, TypeId};
+
+const X: Name = Name::new(0, 1);
+const Y: Name = Name::new(0, 2);
+const Z: Name = Name::new(0, 3);
+const W: Name = Name::new(0, 4);
+
+fn sp() -> Span {
+    Span::new(0, 1)
+}
+
+fn ident(arena: &mut ExprArena, name: Name) -> ExprId {
+    arena.alloc_expr(Expr::new(ExprKind::Ident(name), sp()))
+}
+
+fn int(arena: &mut ExprArena, value: i64) -> ExprId {
+    arena.alloc_expr(Expr::new(ExprKind::Int(value), sp()))
+}
+
+fn add(arena: &mut ExprArena, left: ExprId, right: ExprId) -> ExprId {
+    arena.alloc_expr(Expr::new(
+        ExprKind::Binary {
+            op: BinaryOp::Add,
+            left,
+            right,
+        },
+        sp(),
+    ))
+}
+
+/// `{ let name = init; result }`
+fn block_with_let(arena: &mut ExprArena, name: Name, init: ExprId, result: ExprId) -> ExprId {
+    let pattern = arena.alloc_binding_pattern(BindingPattern::Name {
+        name,
+        mutable: Mutability::Immutable,
+    });
+    let start = arena.alloc_stmt(Stmt::new(
+        StmtKind::Let {
+            pattern,
+            ty: ParsedTypeId::INVALID,
+            init,
+            mutable: Mutability::Immutable,
+        },
+        sp(),
+    ));
+    let start = u32::try_from(start.index()).unwrap_or_else(|_| panic!("stmt index overflow"));
+    let stmts = arena.alloc_stmt_range(start, 1);
+    arena.alloc_expr(Expr::new(ExprKind::Block { stmts, result }, sp()))
+}
+
+fn lambda(arena: &mut ExprArena, param: Name, body: ExprId) -> ExprId {
+    let params = arena.alloc_params([Param {
+        name: param,
+        pattern: None,
+        ty: None,
+        default: None,
+        is_variadic: false,
+        span: sp(),
+    }]);
+    arena.alloc_expr(Expr::new(
+        ExprKind::Lambda {
+            params,
+            ret_ty: ParsedTypeId::INVALID,
+            body,
+        },
+        sp(),
+    ))
+}
+
+#[test]
+fn unbound_reference_is_free() {
+    // x -> x + y
+    let mut arena = ExprArena::new();
+    let (x, y) = (ident(&mut arena, X), ident(&mut arena, Y));
+    let body = add(&mut arena, x, y);
+    assert_eq!(free_vars(body, &arena, &[X]), vec![Y]);
+}
+
+#[test]
+fn let_inside_body_binds() {
+    // x -> { let y = 1; x + y }
+    let mut arena = ExprArena::new();
+    let one = int(&mut arena, 1);
+    let (x, y) = (ident(&mut arena, X), ident(&mut arena, Y));
+    let sum = add(&mut arena, x, y);
+    let body = block_with_let(&mut arena, Y, one, sum);
+    assert!(free_vars(body, &arena, &[X]).is_empty());
+}
+
+#[test]
+fn let_initializer_sees_outer_binding() {
+    // x -> { let y = y; y }
+    let mut arena = ExprArena::new();
+    let (init, result) = (ident(&mut arena, Y), ident(&mut arena, Y));
+    let body = block_with_let(&mut arena, Y, init, result);
+    assert_eq!(free_vars(body, &arena, &[X]), vec![Y]);
+}
+
+#[test]
+fn binding_ends_with_its_block() {
+    // x -> { let _ = { let y = 1; y }; y }
+    let mut arena = ExprArena::new();
+    let one = int(&mut arena, 1);
+    let inner_y = ident(&mut arena, Y);
+    let inner = block_with_let(&mut arena, Y, one, inner_y);
+    let outer_y = ident(&mut arena, Y);
+    let body = block_with_let(&mut arena, Z, inner, outer_y);
+    assert_eq!(free_vars(body, &arena, &[X]), vec![Y]);
+}
+
+#[test]
+fn nested_lambda_params_are_bound() {
+    // x -> z -> x + z + w + w
+    let mut arena = ExprArena::new();
+    let (x, z) = (ident(&mut arena, X), ident(&mut arena, Z));
+    let (w1, w2) = (ident(&mut arena, W), ident(&mut arena, W));
+    let xz = add(&mut arena, x, z);
+    let xzw = add(&mut arena, xz, w1);
+    let inner_body = add(&mut arena, xzw, w2);
+    let body = lambda(&mut arena, Z, inner_body);
+    assert_eq!(free_vars(body, &arena, &[X]), vec![W]);
+}
+
+#[test]
+fn leaving_shadow_keeps_outer_binding() {
+    // y -> { let _ = { let y = 1; y }; y }
+    let mut arena = ExprArena::new();
+    let one = int(&mut arena, 1);
+    let inner_y = ident(&mut arena, Y);
+    let inner = block_with_let(&mut arena, Y, one, inner_y);
+    let outer_y = ident(&mut arena, Y);
+    let body = block_with_let(&mut arena, Z, inner, outer_y);
+    assert!(free_vars(body, &arena, &[Y]).is_empty());
+}
+
+// Canonical IR
+
+fn can(canon: &mut CanonResult, kind: CanExpr) -> CanId {
+    canon.arena.push(CanNode::new(kind, sp(), TypeId::INT))
+}
+
+fn can_add(canon: &mut CanonResult, left: CanId, right: CanId) -> CanId {
+    can(
+        canon,
+        CanExpr::Binary {
+            op: BinaryOp::Add,
+            left,
+            right,
+        },
+    )
+}
+
+/// `match scrutinee { arms... }` over a hand-built decision tree.
+fn can_match(
+    canon: &mut CanonResult,
+    scrutinee: CanId,
+    tree: DecisionTree,
+    arms: &[CanId],
+) -> CanId {
+    let decision_tree = canon.decision_trees.push(tree);
+    let arms = canon.arena.push_expr_list(arms);
+    can(
+        canon,
+        CanExpr::Match {
+            scrutinee,
+            decision_tree,
+            arms,
+        },
+    )
+}
+
+#[test]
+fn canon_reports_first_reference() {
+    // x -> x + y + y
+    let mut canon = CanonResult::empty();
+    let x = can(&mut canon, CanExpr::Ident(X));
+    let y1 = can(&mut canon, CanExpr::Ident(Y));
+    let y2 = can(&mut canon, CanExpr::Ident(Y));
+    let xy = can_add(&mut canon, x, y1);
+    let body = can_add(&mut canon, xy, y2);
+    assert_eq!(canon_free_vars(body, &canon, &[X]), vec![(Y, y1)]);
+}
+
+#[test]
+fn canon_match_arm_bindings_are_bound() {
+    // () -> match y { w -> w + z }
+    let mut canon = CanonResult::empty();
+    let y = can(&mut canon, CanExpr::Ident(Y));
+    let w = can(&mut canon, CanExpr::Ident(W));
+    let z = can(&mut canon, CanExpr::Ident(Z));
+    let arm = can_add(&mut canon, w, z);
+    let tree = DecisionTree::Leaf {
+        arm_index: 0,
+        bindings: vec![(W, Vec::new())],
+    };
+    let body = can_match(&mut canon, y, tree, &[arm]);
+    assert_eq!(canon_free_vars(body, &canon, &[]), vec![(Y, y), (Z, z)]);
+}
+
+#[test]
+fn canon_arm_binding_scoped_to_its_arm() {
+    // () -> match y { w if w + x -> w, _ -> w }
+    let mut canon = CanonResult::empty();
+    let y = can(&mut canon, CanExpr::Ident(Y));
+    let guard_w = can(&mut canon, CanExpr::Ident(W));
+    let x = can(&mut canon, CanExpr::Ident(X));
+    let guard = can_add(&mut canon, guard_w, x);
+    let first = can(&mut canon, CanExpr::Ident(W));
+    let second = can(&mut canon, CanExpr::Ident(W));
+    let tree = DecisionTree::Guard {
+        arm_index: 0,
+        bindings: vec![(W, Vec::new())],
+        guard,
+        on_fail: Box::new(DecisionTree::Leaf {
+            arm_index: 1,
+            bindings: Vec::new(),
+        }),
+    };
+    let body = can_match(&mut canon, y, tree, &[first, second]);
+    assert_eq!(
+        canon_free_vars(body, &canon, &[]),
+        vec![(Y, y), (X, x), (W, second)]
+    );
+}
@@ -254,6 +254,39 @@ impl ChangeMarker {
         }
     }
 
+    /// Map a position in the new text back to the old text.
+    ///
+    /// The inverse of [`adjust_position`](Self::adjust_position). Positions
+    /// inside the rewritten region have no old counterpart and map to
+    /// `affected_start`, so any declaration covering them intersects the region.
+    #[inline]
+    pub fn old_position(&self, pos: u32) -> u32 {
+        let new_end = i64::from(self.affected_end) + self.delta;
+        if pos < self.affected_start {
+            pos
+        } else if i64::from(pos) >= new_end {
+            #[allow(
+                clippy::cast_sign_loss,
+                clippy::cast_possible_truncation,
+                reason = "Bounds-checked: pos is at or past the shifted region end"
+            )]
+            {
+                (i64::from(pos) - self.delta) as u32
+            }
+        } else {
+            self.affected_start
+        }
+    }
+
+    /// Mark everything from the start of the affected region onward as changed.
+    ///
+    /// Used when an edit changes the structure of the following text, such as
+    /// an unbalanced brace, so no later declaration may be reused.
+    #[inline]
+    pub fn extend_to_end(&mut self) {
+        self.affected_end = u32::MAX;
+    }
+
     /// Adjust a span from old text to new text.
     ///
     /// Returns `None` if the span intersects the affected region (must reparse).
@@ -262,6 +295,9 @@ impl ChangeMarker {
     pub fn adjust_span(&self, span: Span) -> Option<Span> {
         if self.intersects(span) {
             None
+        } else if self.is_before(span) {
+            // A span ending exactly at an insertion point stays put
+            Some(span)
         } else {
             Some(Span::new(
                 self.adjust_position(span.start),
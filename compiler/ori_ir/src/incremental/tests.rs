@@ -195,6 +195,45 @@ fn test_change_marker_edge_cases() {
     assert_eq!(marker.adjust_position(99), 99); // Before - unchanged
     assert_eq!(marker.adjust_position(100), 110); // At insertion point - shifted
     assert_eq!(marker.adjust_position(101), 111); // After - shifted
+
+    // A span ending at the insertion point lies before it and keeps its end
+    assert_eq!(
+        marker.adjust_span(Span::new(50, 100)),
+        Some(Span::new(50, 100))
+    );
+    assert_eq!(
+        marker.adjust_span(Span::new(100, 120)),
+        Some(Span::new(110, 130))
+    );
+}
+
+#[test]
+fn test_change_marker_old_position() {
+    // Old region [100, 110) rewritten as [100, 115) in the new text
+    let marker = ChangeMarker::new(100, 110, 5);
+    assert_eq!(marker.old_position(50), 50); // Before - unchanged
+    assert_eq!(marker.old_position(105), 100); // Rewritten - region start
+    assert_eq!(marker.old_position(115), 110); // New region end
+    assert_eq!(marker.old_position(215), 210); // After - unshifted
+
+    // Round-trips with adjust_position outside the region
+    for pos in [0, 99, 110, 300] {
+        assert_eq!(marker.old_position(marker.adjust_position(pos)), pos);
+    }
+
+    // Shrinking edit
+    let marker = ChangeMarker::new(100, 110, -5);
+    assert_eq!(marker.old_position(104), 100);
+    assert_eq!(marker.old_position(105), 110);
+}
+
+#[test]
+fn test_change_marker_extend_to_end() {
+    let mut marker = ChangeMarker::new(100, 110, 1);
+    assert!(!marker.intersects(Span::new(200, 250)));
+    marker.extend_to_end();
+    assert!(marker.intersects(Span::new(200, 250)));
+    assert!(!marker.intersects(Span::new(0, 100)));
 }
 
 #[test]
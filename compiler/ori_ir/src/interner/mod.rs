@@ -1,7 +1,8 @@
 //! Sharded string interner for efficient identifier storage.
 //!
-//! Provides O(1) interning and lookup with thread-safe concurrent access
-//! via per-shard locking.
+//! Provides O(1) interning and lookup with thread-safe concurrent access.
+//! Interning locks only the shard that owns the string; lookup takes no
+//! lock at all, since each shard's strings live in append-only storage.
 
 // Arc is needed here for SharedInterner - the interner must be shared across
 // threads for concurrent compilation and query execution.
@@ -10,26 +11,40 @@
     reason = "Arc required for SharedInterner thread-safety"
 )]
 
+use std::hash::Hasher;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use parking_lot::RwLock;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
 
 use super::Name;
 
+mod slots;
+mod stats;
+
+use slots::StringSlots;
+pub use stats::InternerStats;
+
 /// Per-shard storage for interned strings.
 struct InternShard {
-    /// Map from string content to local index.
-    map: FxHashMap<&'static str, u32>,
-    /// Storage for string contents.
-    strings: Vec<&'static str>,
+    /// Map from string content to local index. Its write lock also
+    /// serializes appends to `strings`.
+    map: RwLock<FxHashMap<&'static str, u32>>,
+    /// Storage for string contents, indexed by local index. Read lock-free.
+    strings: StringSlots,
+    /// Interns that found the string already present (see `stats()`).
+    /// Kept per shard so concurrent hits on different shards don't share
+    /// a cache line.
+    hits: AtomicUsize,
+    /// Total length in bytes of the strings stored in this shard.
+    bytes: AtomicUsize,
 }
 
 /// Error when interning a string fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InternError {
-    /// Shard exceeded capacity (over 4 billion strings).
+    /// Shard exceeded capacity (over `Name::MAX_LOCAL` strings).
     ShardOverflow { shard_idx: usize, count: usize },
 }
 
@@ -42,8 +57,8 @@ impl std::fmt::Display for InternError {
                 shard_idx,
                 count,
                 count,
-                u32::MAX,
-                u32::MAX
+                Name::MAX_LOCAL,
+                Name::MAX_LOCAL
             ),
         }
     }
@@ -52,32 +67,137 @@ impl std::fmt::Display for InternError {
 impl std::error::Error for InternError {}
 
 impl InternShard {
-    fn new() -> Self {
+    fn with_capacity(capacity: usize) -> Self {
+        let strings = StringSlots::new();
+        strings.reserve(capacity);
         Self {
-            map: FxHashMap::default(),
-            strings: Vec::with_capacity(256),
+            map: RwLock::new(FxHashMap::with_capacity_and_hasher(
+                capacity,
+                rustc_hash::FxBuildHasher,
+            )),
+            strings,
+            hits: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
         }
     }
 
-    fn with_empty() -> Self {
-        let mut shard = Self::new();
+    fn with_empty(capacity: usize) -> Self {
+        let shard = Self::with_capacity(capacity);
         // Pre-intern empty string at index 0
         let empty: &'static str = "";
-        shard.map.insert(empty, 0);
-        shard.strings.push(empty);
+        shard.map.write().insert(empty, 0);
+        shard.strings.set(0, empty);
         shard
     }
+
+    /// Append a string under the held write lock, returning its local index.
+    fn insert(
+        &self,
+        map: &mut FxHashMap<&'static str, u32>,
+        shard_idx: usize,
+        s: &'static str,
+    ) -> Result<u32, InternError> {
+        let count = map.len();
+        let local = u32::try_from(count)
+            .ok()
+            .filter(|&local| local <= Name::MAX_LOCAL)
+            .ok_or(InternError::ShardOverflow { shard_idx, count })?;
+        // Publish the string before its index can escape through the map
+        self.strings.set(count, s);
+        map.insert(s, local);
+        self.bytes.fetch_add(s.len(), Ordering::Relaxed);
+        Ok(local)
+    }
+
+    /// Record an intern that found its string already present.
+    #[inline]
+    fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
+/// Ori keywords and common identifiers, pre-interned by every interner.
+const KEYWORDS: &[&str] = &[
+    // Reserved keywords
+    "async",
+    "break",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mut",
+    "pub",
+    "self",
+    "Self",
+    "then",
+    "trait",
+    "true",
+    "type",
+    "use",
+    "uses",
+    "void",
+    "where",
+    "with",
+    "yield",
+    // Pattern keywords
+    "cache",
+    "catch",
+    "collect",
+    "filter",
+    "find",
+    "fold",
+    "map",
+    "parallel",
+    "recurse",
+    "retry",
+    "run",
+    "timeout",
+    "try",
+    "validate",
+    // Primitive types
+    "int",
+    "float",
+    "bool",
+    "str",
+    "char",
+    "byte",
+    "Never",
+    // Common types
+    "Option",
+    "Result",
+    "Some",
+    "None",
+    "Ok",
+    "Err",
+    "Error",
+    // Common functions
+    "main",
+    "print",
+    "len",
+    "compare",
+    "panic",
+    "assert",
+    "assert_eq",
+];
+
 /// Sharded string interner for concurrent access.
 ///
 /// Provides O(1) lookup and equality comparison for interned strings.
 ///
 /// # Thread Safety
-/// Uses `RwLock` per shard for concurrent read/write access.
+/// Interning takes a per-shard `RwLock` (read for hits, write for inserts).
+/// Lookup is lock-free. A `Name` is never reused or reassigned, so names
+/// compare by their `u32` value across threads.
 /// Can be wrapped in Arc for sharing across threads.
 pub struct StringInterner {
-    shards: [RwLock<InternShard>; Name::NUM_SHARDS],
+    shards: [InternShard; Name::NUM_SHARDS],
     /// Total count of interned strings across all shards (O(1) `len()`).
     total_count: AtomicUsize,
 }
@@ -85,11 +205,26 @@ pub struct StringInterner {
 impl StringInterner {
     /// Create a new interner with pre-interned keywords.
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an interner sized for about `capacity` strings beyond the
+    /// pre-interned keywords.
+    ///
+    /// Strings spread over shards by hash, so each shard reserves twice its
+    /// even share to absorb skew. Use this for large builds where the
+    /// identifier count is known from a previous run (see `stats()`).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let per_shard = if capacity == 0 {
+            0
+        } else {
+            (capacity + KEYWORDS.len()).div_ceil(Name::NUM_SHARDS) * 2
+        };
         let shards = std::array::from_fn(|i| {
             if i == 0 {
-                RwLock::new(InternShard::with_empty())
+                InternShard::with_empty(per_shard)
             } else {
-                RwLock::new(InternShard::new())
+                InternShard::with_capacity(per_shard)
             }
         });
 
@@ -118,31 +253,24 @@ impl StringInterner {
         let shard = &self.shards[shard_idx];
 
         // Fast path: check if already interned
-        {
-            let guard = shard.read();
-            if let Some(&local) = guard.map.get(s) {
-                return Ok(Name::new(shard_idx_u32, local));
-            }
+        if let Some(&local) = shard.map.read().get(s) {
+            shard.record_hit();
+            return Ok(Name::new(shard_idx_u32, local));
         }
 
         // Slow path: need to insert
-        let mut guard = shard.write();
+        let mut map = shard.map.write();
 
         // Double-check after acquiring write lock
-        if let Some(&local) = guard.map.get(s) {
+        if let Some(&local) = map.get(s) {
+            shard.record_hit();
             return Ok(Name::new(shard_idx_u32, local));
         }
 
         // Leak the string to get 'static lifetime
         let owned: String = s.to_owned();
         let leaked: &'static str = Box::leak(owned.into_boxed_str());
-
-        let local = u32::try_from(guard.strings.len()).map_err(|_| InternError::ShardOverflow {
-            shard_idx,
-            count: guard.strings.len(),
-        })?;
-        guard.strings.push(leaked);
-        guard.map.insert(leaked, local);
+        let local = shard.insert(&mut map, shard_idx, leaked)?;
 
         // Increment total count (Relaxed is fine - we don't need ordering guarantees)
         self.total_count.fetch_add(1, Ordering::Relaxed);
@@ -175,30 +303,23 @@ impl StringInterner {
         let shard = &self.shards[shard_idx];
 
         // Fast path: check if already interned
-        {
-            let guard = shard.read();
-            if let Some(&local) = guard.map.get(s.as_str()) {
-                return Ok(Name::new(shard_idx_u32, local));
-            }
+        if let Some(&local) = shard.map.read().get(s.as_str()) {
+            shard.record_hit();
+            return Ok(Name::new(shard_idx_u32, local));
         }
 
         // Slow path: need to insert
-        let mut guard = shard.write();
+        let mut map = shard.map.write();
 
         // Double-check after acquiring write lock
-        if let Some(&local) = guard.map.get(s.as_str()) {
+        if let Some(&local) = map.get(s.as_str()) {
+            shard.record_hit();
             return Ok(Name::new(shard_idx_u32, local));
         }
 
         // Leak the owned string directly (no extra allocation)
         let leaked: &'static str = Box::leak(s.into_boxed_str());
-
-        let local = u32::try_from(guard.strings.len()).map_err(|_| InternError::ShardOverflow {
-            shard_idx,
-            count: guard.strings.len(),
-        })?;
-        guard.strings.push(leaked);
-        guard.map.insert(leaked, local);
+        let local = shard.insert(&mut map, shard_idx, leaked)?;
 
         self.total_count.fetch_add(1, Ordering::Relaxed);
 
@@ -219,10 +340,13 @@ impl StringInterner {
     }
 
     /// Look up the string for a Name.
+    ///
+    /// Lock-free: never blocks on concurrent interning.
+    ///
+    /// # Panics
+    /// Panics if `name` was not produced by this interner.
     pub fn lookup(&self, name: Name) -> &str {
-        let shard = &self.shards[name.shard()];
-        let guard = shard.read();
-        guard.strings[name.local()]
+        self.lookup_static(name)
     }
 
     /// Look up the string for a Name, returning a `'static` reference.
@@ -231,83 +355,14 @@ impl StringInterner {
     /// Use this when you need to store the string reference without lifetime concerns,
     /// such as in `Cow<'static, str>` for zero-copy string values.
     pub fn lookup_static(&self, name: Name) -> &'static str {
-        let shard = &self.shards[name.shard()];
-        let guard = shard.read();
-        guard.strings[name.local()]
+        self.shards[name.shard()]
+            .strings
+            .get(name.local())
+            .unwrap_or_else(|| panic!("{name:?} was not interned by this interner"))
     }
 
     /// Pre-intern all Ori keywords and common identifiers.
     fn pre_intern_keywords(&self) {
-        const KEYWORDS: &[&str] = &[
-            // Reserved keywords
-            "async",
-            "break",
-            "continue",
-            "do",
-            "else",
-            "false",
-            "for",
-            "if",
-            "impl",
-            "in",
-            "let",
-            "loop",
-            "match",
-            "mut",
-            "pub",
-            "self",
-            "Self",
-            "then",
-            "trait",
-            "true",
-            "type",
-            "use",
-            "uses",
-            "void",
-            "where",
-            "with",
-            "yield",
-            // Pattern keywords
-            "cache",
-            "catch",
-            "collect",
-            "filter",
-            "find",
-            "fold",
-            "map",
-            "parallel",
-            "recurse",
-            "retry",
-            "run",
-            "timeout",
-            "try",
-            "validate",
-            // Primitive types
-            "int",
-            "float",
-            "bool",
-            "str",
-            "char",
-            "byte",
-            "Never",
-            // Common types
-            "Option",
-            "Result",
-            "Some",
-            "None",
-            "Ok",
-            "Err",
-            "Error",
-            // Common functions
-            "main",
-            "print",
-            "len",
-            "compare",
-            "panic",
-            "assert",
-            "assert_eq",
-        ];
-
         for kw in KEYWORDS {
             self.intern(kw);
         }
@@ -324,13 +379,24 @@ impl StringInterner {
     }
 
     /// Compute shard for a string based on its hash.
+    ///
+    /// Hashes the whole string so identifiers sharing a long prefix
+    /// (`local_var_1`, `local_var_2`, ...) still spread across shards.
     #[inline]
     fn shard_for(s: &str) -> usize {
-        let mut hash = 0u32;
-        for byte in s.bytes().take(8) {
-            hash = hash.wrapping_mul(31).wrapping_add(u32::from(byte));
+        // The empty string is pre-interned in shard 0 as `Name::EMPTY`
+        if s.is_empty() {
+            return 0;
         }
-        (hash as usize) % Name::NUM_SHARDS
+        let mut hasher = FxHasher::default();
+        hasher.write(s.as_bytes());
+        // High bits are the best mixed in an Fx hash
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "shifted down to the top log2(NUM_SHARDS) bits"
+        )]
+        let top = (hasher.finish() >> (u64::BITS - Name::NUM_SHARDS.trailing_zeros())) as usize;
+        top % Name::NUM_SHARDS
     }
 }
 
@@ -392,7 +458,7 @@ impl StringLookup for StringInterner {
 ///
 /// # Thread Safety
 /// Uses `Arc` internally for thread-safe reference counting. The underlying
-/// `StringInterner` uses per-shard `RwLocks` for concurrent access.
+/// `StringInterner` locks per shard for interning and reads lock-free.
 #[derive(Clone)]
 pub struct SharedInterner(Arc<StringInterner>);
 
@@ -0,0 +1,92 @@
+//! Append-only string storage with lock-free reads.
+//!
+//! Each interner shard stores its strings in geometrically growing segments.
+//! A segment is allocated once and never moves, so a published slot can be
+//! read without taking the shard lock. Writers are serialized by the shard's
+//! map lock and fill slots strictly in index order.
+
+use std::sync::OnceLock;
+
+use super::Name;
+
+/// Length of the first segment; segment `k` holds `FIRST_SEGMENT_LEN << k` slots.
+const FIRST_SEGMENT_LEN: usize = 256;
+
+/// Enough segments to address every local index up to `Name::MAX_LOCAL`.
+const NUM_SEGMENTS: usize = segments_for(Name::MAX_LOCAL as usize + 1);
+
+/// Number of segments needed to hold `len` slots.
+const fn segments_for(len: usize) -> usize {
+    let mut segments = 0;
+    let mut capacity = 0;
+    while capacity < len {
+        capacity += FIRST_SEGMENT_LEN << segments;
+        segments += 1;
+    }
+    segments
+}
+
+/// Map a local index to its `(segment, offset)` position.
+#[inline]
+fn locate(index: usize) -> (usize, usize) {
+    let bucket = index / FIRST_SEGMENT_LEN + 1;
+    let segment = (usize::BITS - 1 - bucket.leading_zeros()) as usize;
+    let offset = index - FIRST_SEGMENT_LEN * ((1 << segment) - 1);
+    (segment, offset)
+}
+
+type Segment = Box<[OnceLock<&'static str>]>;
+
+/// Append-only array of interned strings for a single shard.
+pub(super) struct StringSlots {
+    segments: [OnceLock<Segment>; NUM_SEGMENTS],
+}
+
+impl StringSlots {
+    pub(super) fn new() -> Self {
+        Self {
+            segments: std::array::from_fn(|_| OnceLock::new()),
+        }
+    }
+
+    /// Allocate every segment needed to hold `len` slots up front.
+    pub(super) fn reserve(&self, len: usize) {
+        if let Some(last) = len.checked_sub(1) {
+            let (last_segment, _) = locate(last.min(Name::MAX_LOCAL as usize));
+            for segment in 0..=last_segment {
+                self.segment(segment);
+            }
+        }
+    }
+
+    /// Read the string at `index`, or `None` if it has not been published.
+    #[inline]
+    pub(super) fn get(&self, index: usize) -> Option<&'static str> {
+        let (segment, offset) = locate(index);
+        self.segments
+            .get(segment)?
+            .get()?
+            .get(offset)?
+            .get()
+            .copied()
+    }
+
+    /// Publish `s` at `index`.
+    ///
+    /// Callers must hold the shard's write lock and pass indices in order,
+    /// so each slot is written exactly once.
+    pub(super) fn set(&self, index: usize, s: &'static str) {
+        let (segment, offset) = locate(index);
+        let fresh = self.segment(segment)[offset].set(s).is_ok();
+        debug_assert!(fresh, "interner slot {index} written twice");
+    }
+
+    /// Get segment `segment`, allocating it on first use.
+    fn segment(&self, segment: usize) -> &Segment {
+        self.segments[segment].get_or_init(|| {
+            (0..FIRST_SEGMENT_LEN << segment)
+                .map(|_| OnceLock::new())
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,41 @@
+//! Interner statistics for memory diagnostics and capacity tuning.
+
+use std::sync::atomic::Ordering;
+
+use super::StringInterner;
+
+/// Snapshot of interner usage, returned by [`StringInterner::stats`].
+///
+/// Counters are read without stopping concurrent interning, so a snapshot
+/// taken mid-compilation may be slightly behind.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InternerStats {
+    /// Number of distinct strings interned, including the empty string and
+    /// pre-interned keywords.
+    pub interned: usize,
+    /// Total length in bytes of all interned strings.
+    pub bytes: usize,
+    /// Interns that returned an existing `Name` instead of storing a string.
+    pub dedup_hits: usize,
+    /// Strings the shard maps can hold before any of them must grow.
+    pub capacity: usize,
+}
+
+impl StringInterner {
+    /// Collect usage statistics across all shards.
+    ///
+    /// Feed `interned` from a previous run into [`StringInterner::with_capacity`]
+    /// to pre-size the interner for a known workload.
+    pub fn stats(&self) -> InternerStats {
+        let mut stats = InternerStats {
+            interned: self.len(),
+            ..InternerStats::default()
+        };
+        for shard in &self.shards {
+            stats.bytes += shard.bytes.load(Ordering::Relaxed);
+            stats.dedup_hits += shard.hits.load(Ordering::Relaxed);
+            stats.capacity += shard.map.read().capacity();
+        }
+        stats
+    }
+}
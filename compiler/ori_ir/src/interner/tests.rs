@@ -73,3 +73,112 @@ fn test_intern_owned_already_interned() {
 
     assert_eq!(name1, name2);
 }
+
+#[test]
+fn test_lookup_across_segment_boundaries() {
+    let interner = StringInterner::new();
+
+    // Enough strings to fill several storage segments in every shard
+    let names: Vec<Name> = (0..20_000)
+        .map(|i| interner.intern(&format!("ident_{i}")))
+        .collect();
+
+    for (i, &name) in names.iter().enumerate() {
+        assert_eq!(interner.lookup(name), format!("ident_{i}"));
+    }
+    assert_eq!(interner.intern("ident_12345"), names[12_345]);
+}
+
+#[test]
+fn test_concurrent_intern_assigns_consistent_names() {
+    use std::sync::Barrier;
+
+    const THREADS: usize = 8;
+    const WORDS: usize = 2_000;
+
+    let interner = StringInterner::new();
+    let barrier = Barrier::new(THREADS);
+
+    // Every thread interns the same words in a different order, looking up
+    // each name immediately to exercise reads racing with inserts.
+    let per_thread: Vec<Vec<(usize, Name)>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let interner = &interner;
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    barrier.wait();
+                    (0..WORDS)
+                        .map(|i| {
+                            let word = (i * 7 + t * 131) % WORDS;
+                            let text = format!("word_{word}");
+                            let name = interner.intern(&text);
+                            assert_eq!(interner.lookup(name), text);
+                            (word, name)
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| {
+                h.join()
+                    .unwrap_or_else(|_| panic!("interning thread panicked"))
+            })
+            .collect()
+    });
+
+    let mut expected = vec![None; WORDS];
+    for (word, name) in per_thread.into_iter().flatten() {
+        let slot = expected[word].get_or_insert(name);
+        assert_eq!(*slot, name, "word_{word} interned to two different names");
+    }
+
+    // All names are distinct and stable after the threads finish
+    let mut raws: Vec<u32> = expected.iter().map(|n| n.map_or(0, Name::raw)).collect();
+    raws.sort_unstable();
+    raws.dedup();
+    assert_eq!(raws.len(), WORDS);
+    for (word, name) in expected.into_iter().enumerate() {
+        let name = name.unwrap_or_else(|| panic!("word_{word} never interned"));
+        assert_eq!(interner.intern(&format!("word_{word}")), name);
+    }
+}
+
+#[test]
+fn test_with_capacity_avoids_reallocation() {
+    const WORKLOAD: usize = 5_000;
+
+    let interner = StringInterner::with_capacity(WORKLOAD);
+    let before = interner.stats().capacity;
+    assert!(before >= WORKLOAD);
+
+    for i in 0..WORKLOAD {
+        interner.intern(&format!("local_var_{i}"));
+    }
+
+    let stats = interner.stats();
+    assert_eq!(stats.capacity, before, "shard maps grew during workload");
+    assert!(stats.interned > WORKLOAD);
+}
+
+#[test]
+fn test_stats_counts_dedup_hits() {
+    let interner = StringInterner::new();
+    let start = interner.stats();
+    assert_eq!(start.dedup_hits, 0);
+
+    interner.intern("counter");
+    let after_first = interner.stats();
+    assert_eq!(after_first.interned, start.interned + 1);
+    assert_eq!(after_first.bytes, start.bytes + "counter".len());
+    assert_eq!(after_first.dedup_hits, 0);
+
+    interner.intern("counter");
+    interner.intern_owned(String::from("counter"));
+    let after_repeat = interner.stats();
+    assert_eq!(after_repeat.interned, after_first.interned);
+    assert_eq!(after_repeat.bytes, after_first.bytes);
+    assert_eq!(after_repeat.dedup_hits, 2);
+}
@@ -44,6 +44,7 @@ mod comment;
 mod derives;
 mod expr_id;
 pub mod format_spec;
+mod free_vars;
 pub mod incremental;
 mod interner;
 mod metadata;
@@ -156,15 +157,16 @@ pub use expr_id::{
     BindingPatternId, ExprId, ExprRange, FunctionExpId, FunctionSeqId, MatchPatternId,
     MatchPatternRange, ParsedTypeId, ParsedTypeRange, StmtId, StmtRange,
 };
-pub use interner::{InternError, SharedInterner, StringInterner, StringLookup};
-pub use metadata::ModuleExtra;
+pub use free_vars::{canon_free_vars, free_vars};
+pub use interner::{InternError, InternerStats, SharedInterner, StringInterner, StringLookup};
+pub use metadata::{DocAttachment, ModuleExtra};
 pub use name::Name;
 pub use parsed_type::ParsedType;
 pub use pattern_resolution::{PatternKey, PatternResolution};
-pub use span::{Span, SpanError};
+pub use span::{LineIndex, Span, SpanError};
 pub use token::{
-    DurationUnit, SizeUnit, Token, TokenCapture, TokenFlags, TokenIdx, TokenKind, TokenList,
-    TokenTag,
+    DurationUnit, NumericSuffix, SizeUnit, Token, TokenCapture, TokenFlags, TokenIdx, TokenKind,
+    TokenList, TokenSlice, TokenTag,
 };
 pub use traits::{Named, Spanned, Typed};
 pub use type_id::TypeId;
@@ -0,0 +1,81 @@
+//! Doc comment attachment.
+//!
+//! Associates each run of doc comments with the declaration directly below
+//! it, so tooling can ask "what documents this function?" instead of
+//! re-deriving it from raw comment positions.
+
+use std::ops::Range;
+
+use super::ModuleExtra;
+use crate::{Comment, Span};
+
+/// A doc comment block and the declaration it documents.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DocAttachment {
+    /// Span of the documented declaration.
+    pub decl: Span,
+    /// Indices of the block in `ModuleExtra::comments`, in source order.
+    pub comments: Range<usize>,
+}
+
+impl ModuleExtra {
+    /// Attach doc comment blocks to the declarations in `decls`.
+    ///
+    /// A declaration's block is the unbroken run of doc comments directly
+    /// above it. A blank line, a regular comment, or the end of the
+    /// previous declaration ends the run. Replaces any earlier attachments;
+    /// doc comments outside every block stay unattached.
+    ///
+    /// `decls` must be sorted by start position.
+    pub fn attach_doc_comments(&mut self, decls: &[Span]) {
+        let comments = self.comments.as_slice();
+        let mut attachments = Vec::new();
+        let mut prev_end = 0;
+
+        for &decl in decls {
+            let floor = comments.partition_point(|c| c.span.start < prev_end);
+            let end = comments.partition_point(|c| c.span.end <= decl.start);
+            let mut start = end;
+            let mut boundary = decl.start;
+            while start > floor {
+                let Some(comment) = comments.get(start - 1) else {
+                    break;
+                };
+                if !comment.kind.is_doc() || self.has_blank_line_between(comment.span.end, boundary)
+                {
+                    break;
+                }
+                start -= 1;
+                boundary = comment.span.start;
+            }
+
+            if start < end {
+                attachments.push(DocAttachment {
+                    decl,
+                    comments: start..end,
+                });
+            }
+            prev_end = prev_end.max(decl.end);
+        }
+
+        self.doc_attachments = attachments;
+    }
+
+    /// Get the doc comment block attached to the declaration at `decl`.
+    ///
+    /// Empty if the declaration has no block or attachment hasn't run.
+    pub fn attached_docs(&self, decl: Span) -> &[Comment] {
+        self.doc_attachments
+            .iter()
+            .find(|attachment| attachment.decl == decl)
+            .and_then(|attachment| self.comments.as_slice().get(attachment.comments.clone()))
+            .unwrap_or(&[])
+    }
+
+    /// Check whether the comment at `index` belongs to an attached block.
+    pub fn is_attached(&self, index: usize) -> bool {
+        self.doc_attachments
+            .iter()
+            .any(|attachment| attachment.comments.contains(&index))
+    }
+}
@@ -5,6 +5,7 @@
 //! - Blank line positions (for formatting preservation)
 //! - Newline positions (for line counting)
 //! - Trailing comma positions (for style preservation)
+//! - Doc comment attachments (which declaration each doc block documents)
 //!
 //! This metadata enables lossless roundtrip formatting and rich IDE features.
 //!
@@ -14,7 +15,11 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
-use super::{Comment, CommentKind, CommentList, Span};
+use super::{Comment, CommentList, Span};
+
+mod doc_attachment;
+
+pub use doc_attachment::DocAttachment;
 
 /// Non-semantic metadata collected during parsing.
 ///
@@ -53,6 +58,11 @@ pub struct ModuleExtra {
     /// Tracks commas that appear before closing delimiters (], ), }).
     /// Used by the formatter to preserve user's trailing comma style.
     pub trailing_commas: Vec<u32>,
+
+    /// Doc comment blocks attached to declarations, in source order.
+    ///
+    /// Filled by [`ModuleExtra::attach_doc_comments`] once declarations are known.
+    pub doc_attachments: Vec<DocAttachment>,
 }
 
 impl ModuleExtra {
@@ -74,6 +84,7 @@ impl ModuleExtra {
             blank_lines: Vec::with_capacity(source_len / 400),
             newlines: Vec::with_capacity(source_len / 40),
             trailing_commas: Vec::with_capacity(source_len / 200),
+            doc_attachments: Vec::new(),
         }
     }
 
@@ -88,9 +99,9 @@ impl ModuleExtra {
 
     /// Check if there's a regular (non-doc) comment between two positions.
     pub fn has_comment_between(&self, start: u32, end: u32) -> bool {
-        self.comments.iter().any(|c| {
-            c.span.start > start && c.span.end < end && matches!(c.kind, CommentKind::Regular)
-        })
+        self.comments
+            .iter()
+            .any(|c| c.span.start > start && c.span.end < end && !c.kind.is_doc())
     }
 
     /// Get doc comments that should attach to a declaration at `decl_start`.
@@ -114,7 +125,7 @@ impl ModuleExtra {
         let last_regular = self
             .comments
             .iter()
-            .filter(|c| c.span.end <= decl_start && matches!(c.kind, CommentKind::Regular))
+            .filter(|c| c.span.end <= decl_start && !c.kind.is_doc())
             .map(|c| c.span.end)
             .max();
 
@@ -237,6 +248,18 @@ impl ModuleExtra {
     ///
     /// Used when combining metadata from multiple parse passes or files.
     pub fn merge(&mut self, other: ModuleExtra) {
+        let offset = self.comments.len();
+        self.doc_attachments
+            .extend(
+                other
+                    .doc_attachments
+                    .into_iter()
+                    .map(|attachment| DocAttachment {
+                        comments: attachment.comments.start + offset
+                            ..attachment.comments.end + offset,
+                        ..attachment
+                    }),
+            );
         for comment in other.comments {
             self.comments.push(comment);
         }
@@ -252,6 +275,7 @@ impl Hash for ModuleExtra {
         self.blank_lines.hash(state);
         self.newlines.hash(state);
         self.trailing_commas.hash(state);
+        self.doc_attachments.hash(state);
     }
 }
 
@@ -262,6 +286,7 @@ impl fmt::Debug for ModuleExtra {
             .field("blank_lines", &self.blank_lines.len())
             .field("newlines", &self.newlines.len())
             .field("trailing_commas", &self.trailing_commas.len())
+            .field("doc_attachments", &self.doc_attachments.len())
             .finish()
     }
 }
@@ -1,5 +1,5 @@
 use super::*;
-use crate::StringInterner;
+use crate::{CommentKind, StringInterner};
 
 fn test_interner() -> StringInterner {
     StringInterner::new()
@@ -158,3 +158,49 @@ fn test_unattached_doc_comments() {
     assert_eq!(unattached.len(), 1);
     assert_eq!(unattached[0].span.start, 0);
 }
+
+#[test]
+fn test_attach_doc_comments_stops_at_previous_declaration() {
+    let interner = test_interner();
+    let mut extra = ModuleExtra::new();
+    let doc = |text: &str, start: u32, end: u32| {
+        Comment::new(
+            interner.intern(text),
+            Span::new(start, end),
+            CommentKind::DocDescription,
+        )
+    };
+    extra.comments.push(doc("First", 0, 10));
+    extra.comments.push(doc("Second", 31, 41));
+
+    // Declarations at 11..30 and 42..60, with no blank lines anywhere
+    let first = Span::new(11, 30);
+    let second = Span::new(42, 60);
+    extra.attach_doc_comments(&[first, second]);
+
+    assert_eq!(extra.attached_docs(first).len(), 1);
+    assert_eq!(extra.attached_docs(second).len(), 1);
+    assert_eq!(extra.attached_docs(second)[0].span.start, 31);
+}
+
+#[test]
+fn test_merge_offsets_doc_attachments() {
+    let interner = test_interner();
+    let comment = |start: u32| {
+        Comment::new(
+            interner.intern("Doc"),
+            Span::new(start, start + 5),
+            CommentKind::DocDescription,
+        )
+    };
+    let mut first = ModuleExtra::new();
+    first.comments.push(comment(0));
+    let mut second = ModuleExtra::new();
+    second.comments.push(comment(100));
+    second.attach_doc_comments(&[Span::new(106, 120)]);
+
+    first.merge(second);
+
+    assert_eq!(first.doc_attachments[0].comments, 1..2);
+    assert_eq!(first.attached_docs(Span::new(106, 120))[0].span.start, 100);
+}
@@ -0,0 +1,84 @@
+//! Line-start index for mapping byte offsets to line/column positions.
+
+/// Byte offsets of every line start in a source file.
+///
+/// Built once per file so spans can be mapped to line/column positions with a
+/// binary search instead of rescanning the source. Only `\n` ends a line, so
+/// CRLF files map the same way as LF files (the `\r` is the last column of
+/// its line) and a missing trailing newline needs no special case.
+///
+/// # Salsa Compatibility
+/// Has all required traits: Clone, Eq, `PartialEq`, Hash, Debug, Default
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LineIndex {
+    /// `line_starts[n]` is the byte offset where line `n + 1` starts.
+    /// Always non-empty: `line_starts[0] == 0`.
+    line_starts: Vec<u32>,
+}
+
+impl Default for LineIndex {
+    fn default() -> Self {
+        LineIndex {
+            line_starts: vec![0],
+        }
+    }
+}
+
+impl LineIndex {
+    /// Build the index by scanning `source` once for `\n` bytes.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "source files are limited to u32::MAX bytes"
+    )]
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = Vec::with_capacity(source.len() / 40 + 1);
+        line_starts.push(0);
+        line_starts.extend(
+            source
+                .bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'\n')
+                .map(|(i, _)| (i + 1) as u32),
+        );
+        LineIndex { line_starts }
+    }
+
+    /// Build the index from line starts the caller has already collected.
+    ///
+    /// The lexer records line starts as it emits newline tokens, so it uses
+    /// this instead of [`LineIndex::new`] to avoid a second pass over the
+    /// source. `line_starts` must begin with `0` and be sorted ascending.
+    pub fn from_line_starts(line_starts: Vec<u32>) -> Self {
+        debug_assert_eq!(line_starts.first(), Some(&0));
+        debug_assert!(line_starts.windows(2).all(|w| w[0] < w[1]));
+        LineIndex { line_starts }
+    }
+
+    /// Map a byte offset to a 1-based `(line, column)` pair.
+    ///
+    /// The column counts bytes from the line start, matching how spans are
+    /// measured. Offsets past the end of the source land on the last line.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "line count is bounded by source length, which fits u32"
+    )]
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let line_idx = match self.line_starts.binary_search(&offset) {
+            Ok(exact) => exact,
+            Err(insert) => insert - 1,
+        };
+        let col = offset - self.line_starts[line_idx];
+        (line_idx as u32 + 1, col + 1)
+    }
+
+    /// Byte offset where a 1-based line starts, or `None` if out of range.
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        let idx = line.checked_sub(1)?;
+        self.line_starts.get(idx as usize).copied()
+    }
+
+    /// Number of lines in the source (at least 1).
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+}
@@ -1,9 +1,14 @@
 //! Source location spans.
 //!
-//! Provides compact 8-byte span representation with all Salsa-required traits.
+//! Provides compact 8-byte span representation with all Salsa-required traits,
+//! plus a [`LineIndex`] for mapping spans to line/column positions.
 
 use std::fmt;
 
+mod line_index;
+
+pub use line_index::LineIndex;
+
 /// Error when creating a span from a range that exceeds `u32::MAX`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SpanError {
@@ -127,6 +132,13 @@ impl Span {
         }
     }
 
+    /// Merge this span with any number of others into one covering them all.
+    #[inline]
+    #[must_use]
+    pub fn merge_all(self, others: impl IntoIterator<Item = Span>) -> Span {
+        others.into_iter().fold(self, Span::merge)
+    }
+
     /// Extend span to include another position.
     #[inline]
     #[must_use]
@@ -142,6 +154,14 @@ impl Span {
     pub fn to_range(&self) -> std::ops::Range<usize> {
         self.start as usize..self.end as usize
     }
+
+    /// 1-based `(line, column)` of the span start.
+    ///
+    /// The column is a byte column; see [`LineIndex::line_col`].
+    #[inline]
+    pub fn line_col(&self, index: &LineIndex) -> (u32, u32) {
+        index.line_col(self.start)
+    }
 }
 
 impl fmt::Debug for Span {
@@ -106,6 +106,18 @@ fn test_span_merge_reversed_order() {
     assert_eq!(merged.end, 30);
 }
 
+#[test]
+fn test_span_merge_all() {
+    let a = Span::new(20, 30);
+
+    // Disjoint and overlapping spans, in any order
+    let merged = a.merge_all([Span::new(40, 50), Span::new(5, 8), Span::new(25, 45)]);
+    assert_eq!(merged, Span::new(5, 50));
+
+    // No others: unchanged
+    assert_eq!(a.merge_all([]), a);
+}
+
 #[test]
 fn test_span_point() {
     let point = Span::point(42);
@@ -174,3 +186,63 @@ fn test_span_default() {
     let default: Span = Span::default();
     assert_eq!(default, Span::DUMMY);
 }
+
+#[test]
+fn test_line_index_lf() {
+    let source = "let x = 1\nlet y = 2\n\nz";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.line_col(0), (1, 1));
+    assert_eq!(index.line_col(4), (1, 5)); // `x`
+    assert_eq!(index.line_col(9), (1, 10)); // the `\n` itself
+    assert_eq!(index.line_col(10), (2, 1));
+    assert_eq!(index.line_col(20), (3, 1)); // empty line
+    assert_eq!(index.line_col(21), (4, 1)); // `z`
+}
+
+#[test]
+fn test_line_index_crlf() {
+    let source = "a\r\nbc\r\nd";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 3);
+    assert_eq!(index.line_col(1), (1, 2)); // `\r` ends line 1
+    assert_eq!(index.line_col(3), (2, 1)); // `b`
+    assert_eq!(index.line_col(4), (2, 2)); // `c`
+    assert_eq!(index.line_col(7), (3, 1)); // `d`
+}
+
+#[test]
+fn test_line_index_no_trailing_newline() {
+    let source = "abc\ndef";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_col(6), (2, 3));
+    // EOF offset maps to one past the last column
+    assert_eq!(index.line_col(7), (2, 4));
+    assert_eq!(index.line_start(2), Some(4));
+    assert_eq!(index.line_start(3), None);
+    assert_eq!(index.line_start(0), None);
+}
+
+#[test]
+fn test_line_index_empty_source() {
+    let index = LineIndex::new("");
+    assert_eq!(index.line_count(), 1);
+    assert_eq!(index.line_col(0), (1, 1));
+    assert_eq!(LineIndex::default(), index);
+}
+
+#[test]
+fn test_span_line_col() {
+    let source = "first\n  second";
+    let index = LineIndex::new(source);
+    assert_eq!(Span::new(8, 14).line_col(&index), (2, 3));
+}
+
+#[test]
+fn test_line_index_from_line_starts() {
+    let source = "a\nbc\n";
+    assert_eq!(
+        LineIndex::from_line_starts(vec![0, 2, 5]),
+        LineIndex::new(source)
+    );
+}
@@ -4,7 +4,7 @@ use std::fmt;
 use std::hash::Hash;
 
 use super::tag::TokenTag;
-use super::units::{DurationUnit, SizeUnit};
+use super::units::{DurationUnit, NumericSuffix, SizeUnit};
 use crate::Name;
 
 /// Token kinds for Ori.
@@ -24,10 +24,22 @@ pub enum TokenKind {
     String(Name),
     /// Char literal: 'a', '\n'
     Char(char),
+    /// Byte literal: b'A', b'\xFF'
+    Byte(u8),
+    /// Byte string literal (interned): b"abc"
+    ///
+    /// Each byte is stored as the `char` with the same value (Latin-1), so
+    /// bytes above `0x7F` from `\xNN` escapes survive interning as `str`.
+    ByteString(Name),
     /// Duration literal: 100ms, 5s, 2h
     Duration(u64, DurationUnit),
     /// Size literal: 4kb, 10mb
     Size(u64, SizeUnit),
+    /// Numeric literal with a type suffix: 42i32, 255u8, 3.0f32
+    ///
+    /// The value is the integer for integer suffixes and the `f64` bits for
+    /// float suffixes, matching `Int` and `Float`.
+    SuffixedNumber(u64, NumericSuffix),
 
     /// Identifier (interned)
     Ident(Name),
@@ -312,6 +324,9 @@ impl TokenKind {
     // Special (121-127)
     pub const TAG_NEWLINE: u8 = TokenTag::Newline as u8;
     pub const TAG_ERROR: u8 = TokenTag::Error as u8;
+    pub const TAG_BYTE: u8 = TokenTag::Byte as u8;
+    pub const TAG_BYTE_STRING: u8 = TokenTag::ByteString as u8;
+    pub const TAG_SUFFIXED_NUMBER: u8 = TokenTag::SuffixedNumber as u8;
     pub const TAG_EOF: u8 = TokenTag::Eof as u8;
 
     /// Get a unique index for this token's discriminant (0-115).
@@ -335,6 +350,9 @@ impl TokenKind {
             Self::Float(_) => TokenTag::Float as u8,
             Self::String(_) => TokenTag::String as u8,
             Self::Char(_) => TokenTag::Char as u8,
+            Self::Byte(_) => TokenTag::Byte as u8,
+            Self::ByteString(_) => TokenTag::ByteString as u8,
+            Self::SuffixedNumber(_, _) => TokenTag::SuffixedNumber as u8,
             Self::Duration(_, _) => TokenTag::Duration as u8,
             Self::Size(_, _) => TokenTag::Size as u8,
             Self::TemplateHead(_) => TokenTag::TemplateHead as u8,
@@ -636,10 +654,12 @@ impl TokenKind {
     )]
     pub fn display_name(&self) -> &'static str {
         match self {
-            TokenKind::Int(_) => "integer",
+            TokenKind::SuffixedNumber(_, suffix) if suffix.is_float() => "float",
+            TokenKind::Int(_) | TokenKind::SuffixedNumber(_, _) => "integer",
             TokenKind::Float(_) | TokenKind::FloatType => "float",
             TokenKind::String(_) => "string",
             TokenKind::Char(_) | TokenKind::CharType => "char",
+            TokenKind::ByteString(_) => "byte string",
             TokenKind::Duration(_, _) => "duration",
             TokenKind::Size(_, _) => "size",
             TokenKind::Ident(_) => "identifier",
@@ -683,7 +703,7 @@ impl TokenKind {
             TokenKind::IntType => "int",
             TokenKind::BoolType => "bool",
             TokenKind::StrType => "str",
-            TokenKind::ByteType => "byte",
+            TokenKind::Byte(_) | TokenKind::ByteType => "byte",
             TokenKind::NeverType => "Never",
             TokenKind::Ok => "Ok",
             TokenKind::Err => "Err",
@@ -928,6 +948,12 @@ impl fmt::Debug for TokenKind {
             TokenKind::Float(bits) => write!(f, "Float({})", f64::from_bits(*bits)),
             TokenKind::String(name) => write!(f, "String({name:?})"),
             TokenKind::Char(c) => write!(f, "Char({c:?})"),
+            TokenKind::Byte(b) => write!(f, "Byte({b:#04x})"),
+            TokenKind::ByteString(name) => write!(f, "ByteString({name:?})"),
+            TokenKind::SuffixedNumber(n, suffix) if suffix.is_float() => {
+                write!(f, "SuffixedNumber({}{suffix:?})", f64::from_bits(*n))
+            }
+            TokenKind::SuffixedNumber(n, suffix) => write!(f, "SuffixedNumber({n}{suffix:?})"),
             TokenKind::Duration(n, unit) => write!(f, "Duration({n}{unit:?})"),
             TokenKind::Size(n, unit) => write!(f, "Size({n}{unit:?})"),
             TokenKind::Ident(name) => write!(f, "Ident({name:?})"),
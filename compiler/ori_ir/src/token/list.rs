@@ -2,9 +2,10 @@
 
 use std::fmt;
 use std::hash::Hash;
+use std::ops::{Bound, RangeBounds};
 
 use super::index::TokenFlags;
-use super::{Token, TokenCapture};
+use super::{Token, TokenCapture, TokenKind};
 
 /// A list of tokens with Salsa-compatible traits.
 ///
@@ -140,6 +141,45 @@ impl TokenList {
         self.tokens.iter()
     }
 
+    /// Iterate over token kinds.
+    #[inline]
+    pub fn iter_kinds(&self) -> impl Iterator<Item = &TokenKind> {
+        self.tokens.iter().map(|t| &t.kind)
+    }
+
+    /// Find the index of the first token with the same kind as `kind`.
+    ///
+    /// Compares discriminants only (like `Cursor::check`), so
+    /// `TokenKind::Ident(Name::EMPTY)` finds any identifier.
+    #[inline]
+    pub fn find_kind(&self, kind: &TokenKind) -> Option<usize> {
+        self.slice(..).find_kind(kind)
+    }
+
+    /// Borrow a sub-range of the list as a [`TokenSlice`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or decreasing, like slice indexing.
+    #[inline]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> TokenSlice<'_> {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.tokens.len(),
+        };
+        TokenSlice {
+            tokens: &self.tokens[start..end],
+            tags: &self.tags[start..end],
+            start,
+        }
+    }
+
     /// Get tokens in a capture range.
     ///
     /// Returns an empty slice for `TokenCapture::None`.
@@ -198,6 +238,90 @@ impl TokenList {
     }
 }
 
+/// A borrowed sub-range of a [`TokenList`], created by [`TokenList::slice`].
+///
+/// Scans run over the dense tag array. Indices returned by the `find_*`
+/// methods are positions in the parent list, so recovery code can move a
+/// cursor straight to them.
+#[derive(Clone, Copy)]
+pub struct TokenSlice<'a> {
+    tokens: &'a [Token],
+    tags: &'a [u8],
+    /// Index of `tokens[0]` in the parent list.
+    start: usize,
+}
+
+impl<'a> TokenSlice<'a> {
+    /// Index in the parent list where this slice starts.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Index in the parent list just past this slice.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.start + self.tokens.len()
+    }
+
+    /// Get the number of tokens.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Check if empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Get the tokens as a plain slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [Token] {
+        self.tokens
+    }
+
+    /// Get the tags (discriminant indices) of the tokens.
+    #[inline]
+    pub fn tags(&self) -> &'a [u8] {
+        self.tags
+    }
+
+    /// Iterate over token kinds.
+    #[inline]
+    pub fn iter_kinds(&self) -> impl Iterator<Item = &'a TokenKind> {
+        self.tokens.iter().map(|t| &t.kind)
+    }
+
+    /// Find the parent-list index of the first token with the same kind as `kind`.
+    ///
+    /// Compares discriminants only (like `Cursor::check`).
+    #[inline]
+    pub fn find_kind(&self, kind: &TokenKind) -> Option<usize> {
+        let tag = kind.discriminant_index();
+        self.find_tag(|t| t == tag)
+    }
+
+    /// Find the parent-list index of the first token whose tag satisfies `pred`.
+    ///
+    /// Pair with a tag set (e.g. the parser's `TokenSet`) to skip to the
+    /// next token from a group of kinds.
+    #[inline]
+    pub fn find_tag(&self, mut pred: impl FnMut(u8) -> bool) -> Option<usize> {
+        self.tags
+            .iter()
+            .position(|&t| pred(t))
+            .map(|i| self.start + i)
+    }
+}
+
+impl fmt::Debug for TokenSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TokenSlice({}..{})", self.start, self.end())
+    }
+}
+
 impl fmt::Debug for TokenList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "TokenList({} tokens)", self.tokens.len())
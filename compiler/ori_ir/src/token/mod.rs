@@ -17,13 +17,13 @@ mod units;
 pub use capture::TokenCapture;
 pub use index::{TokenFlags, TokenIdx};
 pub use kind::TokenKind;
-pub use list::TokenList;
+pub use list::{TokenList, TokenSlice};
 pub use tag::TokenTag;
-pub use units::{DurationUnit, SizeUnit};
+pub use units::{DurationUnit, NumericSuffix, SizeUnit};
 
 /// Number of [`TokenKind`] variants. Used for bitset sizing and test verification.
 #[cfg(test)]
-pub(crate) const TOKEN_KIND_COUNT: usize = 122;
+pub(crate) const TOKEN_KIND_COUNT: usize = 125;
 
 use std::fmt;
 
@@ -64,7 +64,7 @@ impl fmt::Debug for Token {
 // These are compile-time checks that will fail the build if sizes change.
 #[cfg(target_pointer_width = "64")]
 mod size_asserts {
-    use super::{DurationUnit, SizeUnit, Token, TokenCapture, TokenKind};
+    use super::{DurationUnit, NumericSuffix, SizeUnit, Token, TokenCapture, TokenKind};
     // Token is frequently allocated in TokenList, keep it compact.
     // Contains: TokenKind (16 bytes) + Span (8 bytes) = 24 bytes
     crate::static_assert_size!(Token, 24);
@@ -74,6 +74,7 @@ mod size_asserts {
     // Compact unit types
     crate::static_assert_size!(DurationUnit, 1);
     crate::static_assert_size!(SizeUnit, 1);
+    crate::static_assert_size!(NumericSuffix, 1);
     // TokenCapture: discriminant (4 bytes) + start (4 bytes) + end (4 bytes) = 12 bytes
     // Optimized to 12 bytes thanks to niche optimization (None has no payload)
     crate::static_assert_size!(TokenCapture, 12);
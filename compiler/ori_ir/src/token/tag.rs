@@ -15,7 +15,7 @@
 /// | 74-75   | Gap (future keywords) |
 /// | 76-99   | Punctuation        |
 /// | 100-120 | Operators          |
-/// | 121-127 | Special            |
+/// | 121-127 | Special (123-124: byte literals) |
 ///
 /// This enum serves as the single source of truth for discriminant values.
 /// `TAG_*` constants and `discriminant_index()` both derive from these values.
@@ -168,8 +168,12 @@ pub enum TokenTag {
     // === Special (121-127) ===
     Newline = 121,
     Error = 122,
+    // Byte literals live here because the 0-10 literal range is full.
+    Byte = 123,
+    ByteString = 124,
+    SuffixedNumber = 125,
     Eof = 127,
-    // 123-126: reserved for future special tokens
+    // 126: reserved for future special tokens
 }
 
 // Compile-time assertion: all TokenTag values fit in 7 bits (< 128).
@@ -239,7 +243,7 @@ impl TokenTag {
             Self::KwIntType => "int",
             Self::KwBoolType => "bool",
             Self::KwStrType => "str",
-            Self::KwByteType => "byte",
+            Self::KwByteType | Self::Byte => "byte",
             Self::KwNeverType => "Never",
             Self::KwOk => "Ok",
             Self::KwErr => "Err",
@@ -306,6 +310,8 @@ impl TokenTag {
             Self::Div => "div",
             Self::Newline => "newline",
             Self::Error => "error",
+            Self::ByteString => "byte string",
+            Self::SuffixedNumber => "suffixed number",
             Self::Eof => "end of file",
         }
     }
@@ -15,8 +15,11 @@ fn test_discriminant_index_uniqueness() {
         TokenKind::Float(0),
         TokenKind::String(crate::Name::EMPTY),
         TokenKind::Char('a'),
+        TokenKind::Byte(0),
+        TokenKind::ByteString(crate::Name::EMPTY),
         TokenKind::Duration(0, DurationUnit::Seconds),
         TokenKind::Size(0, SizeUnit::Bytes),
+        TokenKind::SuffixedNumber(0, NumericSuffix::I32),
         TokenKind::Ident(crate::Name::EMPTY),
         TokenKind::Async,
         TokenKind::Break,
@@ -248,6 +251,26 @@ fn test_size_unit() {
     assert_eq!(SizeUnit::Terabytes.to_bytes(1), 1_000_000_000_000);
     assert_eq!(SizeUnit::Bytes.suffix(), "b");
     assert_eq!(SizeUnit::Terabytes.suffix(), "tb");
+    // Binary units: powers of 1024
+    assert_eq!(SizeUnit::Kibibytes.to_bytes(1), 1_024);
+    assert_eq!(SizeUnit::Mebibytes.to_bytes(2), 2_097_152);
+    assert_eq!(SizeUnit::Gibibytes.to_bytes(1), 1_073_741_824);
+    assert_eq!(SizeUnit::Tebibytes.to_bytes(1), 1_099_511_627_776);
+    assert_eq!(SizeUnit::Kibibytes.suffix(), "kib");
+}
+
+#[test]
+fn test_numeric_suffix() {
+    assert_eq!(NumericSuffix::from_suffix("u8"), Some(NumericSuffix::U8));
+    assert_eq!(NumericSuffix::from_suffix("f32"), Some(NumericSuffix::F32));
+    assert_eq!(NumericSuffix::from_suffix("i7"), None);
+    assert_eq!(NumericSuffix::I64.suffix(), "i64");
+    assert_eq!(NumericSuffix::U8.max_magnitude(), 255);
+    // Signed: one past MAX so `-128i8` can be formed by negation
+    assert_eq!(NumericSuffix::I8.max_magnitude(), 128);
+    assert_eq!(NumericSuffix::I64.max_magnitude(), 1 << 63);
+    assert!(NumericSuffix::F64.is_float());
+    assert!(!NumericSuffix::U64.is_float());
 }
 
 #[test]
@@ -264,6 +287,80 @@ fn test_token_list_operations() {
     assert_eq!(list.get(1).unwrap().kind, TokenKind::Plus);
 }
 
+/// `a + b @ c + d` with a trailing EOF.
+fn scan_list() -> TokenList {
+    TokenList::from_vec(vec![
+        Token::new(TokenKind::Ident(crate::Name::from_raw(1)), Span::new(0, 1)),
+        Token::new(TokenKind::Plus, Span::new(2, 3)),
+        Token::new(TokenKind::Ident(crate::Name::from_raw(2)), Span::new(4, 5)),
+        Token::new(TokenKind::At, Span::new(6, 7)),
+        Token::new(TokenKind::Ident(crate::Name::from_raw(3)), Span::new(8, 9)),
+        Token::new(TokenKind::Plus, Span::new(10, 11)),
+        Token::new(
+            TokenKind::Ident(crate::Name::from_raw(4)),
+            Span::new(12, 13),
+        ),
+        Token::new(TokenKind::Eof, Span::new(13, 13)),
+    ])
+}
+
+#[test]
+fn test_token_list_find_kind() {
+    let list = scan_list();
+
+    assert_eq!(list.find_kind(&TokenKind::Plus), Some(1));
+    assert_eq!(list.find_kind(&TokenKind::At), Some(3));
+    // Payloads are ignored: any identifier matches
+    assert_eq!(
+        list.find_kind(&TokenKind::Ident(crate::Name::EMPTY)),
+        Some(0)
+    );
+    assert_eq!(list.find_kind(&TokenKind::Minus), None);
+
+    // Slices report positions in the parent list
+    assert_eq!(list.slice(2..).find_kind(&TokenKind::Plus), Some(5));
+    assert_eq!(list.slice(2..5).find_kind(&TokenKind::Plus), None);
+}
+
+#[test]
+fn test_token_list_iter_kinds() {
+    let list = scan_list();
+    let kinds: Vec<_> = list.slice(1..4).iter_kinds().cloned().collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::Plus,
+            TokenKind::Ident(crate::Name::from_raw(2)),
+            TokenKind::At
+        ]
+    );
+    assert_eq!(list.iter_kinds().count(), list.len());
+}
+
+#[test]
+fn test_token_slice_bounds() {
+    let list = scan_list();
+
+    let mid = list.slice(2..=4);
+    assert_eq!((mid.start(), mid.end(), mid.len()), (2, 5, 3));
+    assert_eq!(mid.as_slice()[0].span, Span::new(4, 5));
+    assert_eq!(mid.tags(), &list.tags()[2..5]);
+
+    let all = list.slice(..);
+    assert_eq!((all.start(), all.end()), (0, list.len()));
+
+    let empty = list.slice(list.len()..);
+    assert!(empty.is_empty());
+    assert_eq!(empty.find_tag(|_| true), None);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_token_slice_out_of_bounds_panics() {
+    let list = scan_list();
+    let _ = list.slice(4..=list.len());
+}
+
 #[test]
 fn test_friendly_name_from_index() {
     // Test literals (use TokenTag values)
@@ -491,6 +588,9 @@ fn test_token_tag_name_non_empty() {
         TokenTag::Float,
         TokenTag::String,
         TokenTag::Char,
+        TokenTag::Byte,
+        TokenTag::ByteString,
+        TokenTag::SuffixedNumber,
         TokenTag::Duration,
         TokenTag::Size,
         TokenTag::TemplateHead,
@@ -613,7 +713,7 @@ fn test_token_tag_name_non_empty() {
 
     for tag in all_tags {
         let name = tag.name();
-        assert!(!name.is_empty(), "TokenTag::{tag:?} returned empty name",);
+        assert!(!name.is_empty(), "TokenTag::{tag:?} returned empty name");
     }
 }
 
@@ -1,4 +1,4 @@
-//! Duration and size unit types for literal tokens.
+//! Duration, size, and numeric type suffix types for literal tokens.
 
 use std::fmt;
 
@@ -74,10 +74,17 @@ pub enum SizeUnit {
     Megabytes,
     Gigabytes,
     Terabytes,
+    Kibibytes,
+    Mebibytes,
+    Gibibytes,
+    Tebibytes,
 }
 
 impl SizeUnit {
-    /// Byte multiplier for this unit (SI, powers of 1000).
+    /// Byte multiplier for this unit.
+    ///
+    /// `kb`/`mb`/`gb`/`tb` are SI (powers of 1000); `kib`/`mib`/`gib`/`tib`
+    /// are binary (powers of 1024).
     ///
     /// Used by the lexer to convert decimal size literals to bytes
     /// via integer arithmetic (no floats involved).
@@ -89,13 +96,17 @@ impl SizeUnit {
             SizeUnit::Megabytes => 1_000_000,
             SizeUnit::Gigabytes => 1_000_000_000,
             SizeUnit::Terabytes => 1_000_000_000_000,
+            SizeUnit::Kibibytes => 1 << 10,
+            SizeUnit::Mebibytes => 1 << 20,
+            SizeUnit::Gibibytes => 1 << 30,
+            SizeUnit::Tebibytes => 1 << 40,
         }
     }
 
-    /// Convert value to bytes using SI units (powers of 1000).
+    /// Convert value to bytes.
     ///
     /// SI units: 1kb = 1000 bytes, 1mb = 1,000,000 bytes, etc.
-    /// For exact powers of 1024, use explicit byte counts: `1024b`, `1048576b`.
+    /// Binary units: 1kib = 1024 bytes, 1mib = 1,048,576 bytes, etc.
     #[inline]
     pub fn to_bytes(self, value: u64) -> u64 {
         value * self.bytes_multiplier()
@@ -110,6 +121,10 @@ impl SizeUnit {
             SizeUnit::Megabytes => "mb",
             SizeUnit::Gigabytes => "gb",
             SizeUnit::Terabytes => "tb",
+            SizeUnit::Kibibytes => "kib",
+            SizeUnit::Mebibytes => "mib",
+            SizeUnit::Gibibytes => "gib",
+            SizeUnit::Tebibytes => "tib",
         }
     }
 }
@@ -119,3 +134,89 @@ impl fmt::Debug for SizeUnit {
         write!(f, "{}", self.suffix())
     }
 }
+
+/// Type suffix on a numeric literal (`42i32`, `255u8`, `3.0f32`).
+///
+/// # Salsa Compatibility
+/// Has all required traits: Copy, Clone, Eq, `PartialEq`, Hash, Debug
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+}
+
+impl NumericSuffix {
+    /// Look up a suffix by its source spelling (`"i32"`, `"f64"`, ...).
+    pub fn from_suffix(text: &str) -> Option<Self> {
+        Some(match text {
+            "i8" => NumericSuffix::I8,
+            "i16" => NumericSuffix::I16,
+            "i32" => NumericSuffix::I32,
+            "i64" => NumericSuffix::I64,
+            "u8" => NumericSuffix::U8,
+            "u16" => NumericSuffix::U16,
+            "u32" => NumericSuffix::U32,
+            "u64" => NumericSuffix::U64,
+            "f32" => NumericSuffix::F32,
+            "f64" => NumericSuffix::F64,
+            _ => return None,
+        })
+    }
+
+    /// Get the suffix string.
+    #[inline]
+    pub fn suffix(self) -> &'static str {
+        match self {
+            NumericSuffix::I8 => "i8",
+            NumericSuffix::I16 => "i16",
+            NumericSuffix::I32 => "i32",
+            NumericSuffix::I64 => "i64",
+            NumericSuffix::U8 => "u8",
+            NumericSuffix::U16 => "u16",
+            NumericSuffix::U32 => "u32",
+            NumericSuffix::U64 => "u64",
+            NumericSuffix::F32 => "f32",
+            NumericSuffix::F64 => "f64",
+        }
+    }
+
+    /// Whether this is a floating-point suffix.
+    #[inline]
+    pub fn is_float(self) -> bool {
+        matches!(self, NumericSuffix::F32 | NumericSuffix::F64)
+    }
+
+    /// Largest literal magnitude an integer suffix accepts.
+    ///
+    /// Signed suffixes allow one past the positive maximum so that a negated
+    /// literal can reach the type's minimum (`-128i8`). Float suffixes return
+    /// `u64::MAX`; their range is checked on the parsed value instead.
+    #[inline]
+    pub fn max_magnitude(self) -> u64 {
+        match self {
+            NumericSuffix::I8 => 1 << 7,
+            NumericSuffix::I16 => 1 << 15,
+            NumericSuffix::I32 => 1 << 31,
+            NumericSuffix::I64 => 1 << 63,
+            NumericSuffix::U8 => u64::from(u8::MAX),
+            NumericSuffix::U16 => u64::from(u16::MAX),
+            NumericSuffix::U32 => u64::from(u32::MAX),
+            NumericSuffix::U64 | NumericSuffix::F32 | NumericSuffix::F64 => u64::MAX,
+        }
+    }
+}
+
+impl fmt::Debug for NumericSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.suffix())
+    }
+}
@@ -11,6 +11,12 @@
 //! Default implementations call `walk_*` functions that traverse children.
 //! Override `visit_*` methods to add custom behavior at specific nodes.
 //!
+//! Passes that only need pre/post-order callbacks with the node's `ExprId`
+//! (free-variable analysis, tail-position tracking) can override
+//! [`Visitor::enter_expr`] and [`Visitor::leave_expr`] instead and start the
+//! traversal with [`ExprArena::visit`]. Every expression reached through
+//! `visit_expr_id` is entered before its children and left after them.
+//!
 //! # Example
 //!
 //! ```text
@@ -103,11 +109,22 @@ pub trait Visitor<'ast> {
     }
 
     /// Visit an expression by ID.
+    ///
+    /// Calls [`enter_expr`](Self::enter_expr), then `visit_expr`, then
+    /// [`leave_expr`](Self::leave_expr).
     fn visit_expr_id(&mut self, id: ExprId, arena: &'ast ExprArena) {
         let expr = arena.get_expr(id);
+        self.enter_expr(id, &expr);
         self.visit_expr(&expr, arena);
+        self.leave_expr(id, &expr);
     }
 
+    /// Called before an expression's children are visited. Default: no-op.
+    fn enter_expr(&mut self, _id: ExprId, _expr: &Expr) {}
+
+    /// Called after an expression's children are visited. Default: no-op.
+    fn leave_expr(&mut self, _id: ExprId, _expr: &Expr) {}
+
     /// Visit a statement.
     fn visit_stmt(&mut self, stmt: &'ast Stmt, arena: &'ast ExprArena) {
         walk_stmt(self, stmt, arena);
@@ -193,6 +210,13 @@ pub trait Visitor<'ast> {
     }
 }
 
+impl ExprArena {
+    /// Walk the expression tree rooted at `root` with `visitor`.
+    pub fn visit<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, root: ExprId, visitor: &mut V) {
+        visitor.visit_expr_id(root, self);
+    }
+}
+
 // Walk functions traverse children in depth-first, left-to-right order.
 // For expressions with multiple children (e.g., binary operations), the left
 // child is visited before the right. For collections (lists, tuples), elements
@@ -73,6 +73,16 @@ pub(crate) fn classify_and_normalize_comment(content: &str) -> (CommentKind, Cow
         return (CommentKind::DocMember, format!(" @field {text}").into());
     }
 
+    if let Some(text) = strip_doc_tag(trimmed, "@returns") {
+        // Returns: `// @returns  the sum` -> ` @returns the sum`
+        return (CommentKind::DocReturns, format_doc_tag("@returns", text));
+    }
+
+    if let Some(text) = strip_doc_tag(trimmed, "@throws") {
+        // Throws: `// @throws  on overflow` -> ` @throws on overflow`
+        return (CommentKind::DocThrows, format_doc_tag("@throws", text));
+    }
+
     if let Some(rest) = trimmed.strip_prefix('!') {
         // Warning: `// !Text` -> ` !Text`
         let text = rest.trim_start();
@@ -98,5 +108,27 @@ pub(crate) fn classify_and_normalize_comment(content: &str) -> (CommentKind, Cow
     }
 }
 
+/// Strip a word-like doc tag (`@returns`), returning the text after it.
+///
+/// The tag must be followed by whitespace or end the comment, so
+/// `@returnsfoo` stays a regular comment.
+fn strip_doc_tag<'a>(trimmed: &'a str, tag: &str) -> Option<&'a str> {
+    let rest = trimmed.strip_prefix(tag)?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+/// Normalize a tagged doc comment to a single space after the tag.
+fn format_doc_tag(tag: &str, text: &str) -> Cow<'static, str> {
+    if text.is_empty() {
+        format!(" {tag}").into()
+    } else {
+        format!(" {tag} {text}").into()
+    }
+}
+
 #[cfg(test)]
 mod tests;
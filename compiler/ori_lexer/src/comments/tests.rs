@@ -33,6 +33,31 @@ fn test_classify_legacy_field_as_member() {
     assert_eq!(content, " @field x coord");
 }
 
+#[test]
+fn test_classify_doc_returns() {
+    let (kind, content) = classify_and_normalize_comment(" @returns   the total");
+    assert_eq!(kind, CommentKind::DocReturns);
+    assert_eq!(content, " @returns the total");
+
+    // Bare tag has no trailing space
+    let (kind, content) = classify_and_normalize_comment(" @returns");
+    assert_eq!(kind, CommentKind::DocReturns);
+    assert_eq!(content, " @returns");
+}
+
+#[test]
+fn test_classify_doc_throws() {
+    let (kind, content) = classify_and_normalize_comment("@throws Overflow");
+    assert_eq!(kind, CommentKind::DocThrows);
+    assert_eq!(content, " @throws Overflow");
+}
+
+#[test]
+fn test_classify_tag_prefix_is_regular() {
+    let (kind, _) = classify_and_normalize_comment(" @returnsfoo");
+    assert_eq!(kind, CommentKind::Regular);
+}
+
 #[test]
 fn test_classify_doc_warning() {
     let (kind, content) = classify_and_normalize_comment(" !Panics");
@@ -6,12 +6,18 @@
 //!
 //! # Grammar Reference
 //!
-//! - String escapes (line 102): `\"` `\\` `\n` `\t` `\r` `\0`
-//! - Char escapes (line 127): `\'` `\\` `\n` `\t` `\r` `\0`
+//! - String escapes (line 102): `\"` `\\` `\n` `\t` `\r` `\0` `\xNN` `\u{...}`
+//! - Char escapes (line 127): `\'` `\\` `\n` `\t` `\r` `\0` `\xNN` `\u{...}`
+//! - Byte and byte string escapes: as for chars and strings, except `\xNN`
+//!   covers the full `u8` range and `\u{...}` is not allowed.
 //! - Template escapes (line 107): `` \` `` `\\` `\n` `\t` `\r` `\0`
 //! - Template braces (line 108): `{{` → `{`, `}}` → `}`
+//!
+//! `\xNN` is limited to ASCII (`\x00`–`\x7F`) in strings and chars: strings are
+//! UTF-8, so a lone byte above `0x7F` has no meaning, and use of `\u{...}` for
+//! non-ASCII code points keeps a single spelling for them.
 
-use crate::lex_error::LexError;
+use crate::lex_error::{LexError, LexErrorContext};
 use ori_ir::Span;
 
 /// Resolve a common escape character (shared across all contexts).
@@ -29,9 +35,67 @@ fn resolve_common_escape(c: char) -> Option<char> {
     }
 }
 
+/// Parse the two hex digits of a `\xNN` escape as a full byte.
+///
+/// `rest` starts just after the `\x`. On failure returns a reason and the
+/// bytes the malformed escape spans.
+fn parse_hex_byte(rest: &str) -> Result<u8, (&'static str, usize)> {
+    rest.get(..2)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit()))
+        .and_then(|d| u8::from_str_radix(d, 16).ok())
+        .ok_or_else(|| {
+            let consumed = rest
+                .bytes()
+                .take_while(u8::is_ascii_alphanumeric)
+                .count()
+                .min(2);
+            ("expected exactly two hex digits", consumed)
+        })
+}
+
+/// Parse the body of a `\xNN` escape in a string or char literal.
+///
+/// `rest` starts just after the `\x`. Returns the char and the bytes consumed
+/// (always 2) on success, or a reason and the bytes the malformed escape spans.
+fn parse_hex_escape(rest: &str) -> Result<(char, usize), (&'static str, usize)> {
+    match parse_hex_byte(rest)? {
+        byte if byte.is_ascii() => Ok((char::from(byte), 2)),
+        _ => Err(("value above \\x7F; use \\u{...} for non-ASCII", 2)),
+    }
+}
+
+/// Parse the body of a `\u{...}` escape.
+///
+/// `rest` starts just after the `\u`. On success returns the code point and
+/// the number of bytes of `rest` consumed (through the closing `}`). On
+/// failure returns a reason and the number of bytes the malformed escape
+/// spans, so the caller can report it and resume after it.
+fn parse_unicode_escape(rest: &str) -> Result<(char, usize), (&'static str, usize)> {
+    if !rest.starts_with('{') {
+        return Err(("expected `{` after `\\u`", 0));
+    }
+    let close = 1 + rest[1..]
+        .bytes()
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+    if rest.as_bytes().get(close) != Some(&b'}') {
+        return Err(("missing closing `}`", close));
+    }
+    let digits = &rest[1..close];
+    let consumed = close + 1;
+    if digits.is_empty() || digits.len() > 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(("expected 1 to 6 hex digits", consumed));
+    }
+    u32::from_str_radix(digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .map(|c| (c, consumed))
+        .ok_or(("not a Unicode scalar value", consumed))
+}
+
 /// Unescape a string literal's content (between the `"`s).
 ///
-/// Valid escapes per grammar line 102: `\"` `\\` `\n` `\t` `\r` `\0`.
+/// Valid escapes per grammar line 102: `\"` `\\` `\n` `\t` `\r` `\0` `\xNN` `\u{...}`.
 /// `\'` is **not** valid in strings — a `SingleQuoteEscapeInString` error is pushed.
 ///
 /// Fast path: if no backslashes, returns `None` to signal the caller can
@@ -66,6 +130,35 @@ pub(crate) fn unescape_string_v2(
                     // Use the literal quote as replacement
                     result.push('\'');
                 }
+                Some((j, kind @ ('u' | 'x'))) => {
+                    let rest = &content[j + 1..];
+                    let parsed = if kind == 'u' {
+                        parse_unicode_escape(rest)
+                    } else {
+                        parse_hex_escape(rest)
+                    };
+                    let (resolved, consumed) = match parsed {
+                        Ok(ok) => ok,
+                        Err((reason, consumed)) => {
+                            let span = Span::new(
+                                base_offset + i as u32,
+                                base_offset + (j + 1 + consumed) as u32,
+                            );
+                            errors.push(if kind == 'u' {
+                                LexError::invalid_unicode_escape(span, reason)
+                            } else {
+                                LexError::invalid_hex_escape(span, reason)
+                            });
+                            ('\u{FFFD}', consumed)
+                        }
+                    };
+                    result.push(resolved);
+                    // Skip the escape body already consumed above
+                    let end = j + 1 + consumed;
+                    while chars.as_str().len() > content.len() - end {
+                        chars.next();
+                    }
+                }
                 Some((j, esc)) => {
                     if let Some(resolved) = resolve_common_escape(esc) {
                         result.push(resolved);
@@ -100,7 +193,7 @@ pub(crate) fn unescape_string_v2(
 
 /// Unescape a char literal's content (between the `'`s).
 ///
-/// Valid escapes per grammar line 127: `\'` `\\` `\n` `\t` `\r` `\0`.
+/// Valid escapes per grammar line 127: `\'` `\\` `\n` `\t` `\r` `\0` `\xNN` `\u{...}`.
 /// `\"` is **not** valid in char literals.
 #[allow(
     clippy::cast_possible_truncation,
@@ -123,6 +216,28 @@ pub(crate) fn unescape_char_v2(
                 )));
                 '"'
             }
+            Some(kind @ ('u' | 'x')) => {
+                let parsed = if kind == 'u' {
+                    parse_unicode_escape(chars.as_str())
+                } else {
+                    parse_hex_escape(chars.as_str())
+                };
+                match parsed {
+                    // A well-formed escape is the whole literal: the raw
+                    // scanner leaves a char unterminated if anything follows it
+                    Ok((resolved, _)) => resolved,
+                    Err((reason, consumed)) => {
+                        let span = Span::new(base_offset, base_offset + 2 + consumed as u32);
+                        let err = if kind == 'u' {
+                            LexError::invalid_unicode_escape(span, reason)
+                        } else {
+                            LexError::invalid_hex_escape(span, reason)
+                        };
+                        errors.push(err.with_context(LexErrorContext::InsideChar));
+                        '\u{FFFD}'
+                    }
+                }
+            }
             Some(esc) => {
                 if let Some(resolved) = resolve_common_escape(esc) {
                     resolved
@@ -150,6 +265,85 @@ pub(crate) fn unescape_char_v2(
     }
 }
 
+/// Unescape a byte (`quote == '\''`) or byte string (`quote == '"'`) literal's
+/// content into raw bytes.
+///
+/// Escapes follow the matching char or string literal, except that `\xNN`
+/// accepts the full `u8` range and `\u{...}` is invalid. Non-ASCII source
+/// characters are errors: a byte literal spells every byte in ASCII.
+#[allow(
+    clippy::cast_possible_truncation,
+    reason = "source offsets bounded by u32 — entire source file < u32::MAX bytes"
+)]
+pub(crate) fn unescape_bytes(
+    content: &str,
+    base_offset: u32,
+    quote: char,
+    errors: &mut Vec<LexError>,
+) -> Vec<u8> {
+    let in_char = quote == '\'';
+    let mut bytes = Vec::with_capacity(content.len());
+    let mut chars = content.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        let esc_start = base_offset + i as u32;
+        if c != '\\' {
+            if let Ok(byte) = u8::try_from(c) {
+                if byte.is_ascii() {
+                    bytes.push(byte);
+                    continue;
+                }
+            }
+            let span = Span::new(esc_start, esc_start + c.len_utf8() as u32);
+            errors.push(LexError::non_ascii_in_byte_literal(span, c));
+            continue;
+        }
+
+        let Some((j, esc)) = chars.next() else {
+            // Trailing backslash
+            errors.push(LexError::invalid_string_escape(
+                Span::new(esc_start, esc_start + 1),
+                '\\',
+            ));
+            break;
+        };
+        if esc == quote {
+            bytes.push(quote as u8);
+        } else if esc == 'x' {
+            match parse_hex_byte(&content[j + 1..]) {
+                Ok(byte) => {
+                    bytes.push(byte);
+                    chars.nth(1); // skip the two hex digits
+                }
+                Err((reason, consumed)) => {
+                    let span = Span::new(esc_start, esc_start + 2 + consumed as u32);
+                    let err = LexError::invalid_hex_escape(span, reason);
+                    errors.push(if in_char {
+                        err.with_context(LexErrorContext::InsideChar)
+                    } else {
+                        err
+                    });
+                    // Hex digits are ASCII, so bytes consumed == chars consumed
+                    for _ in 0..consumed {
+                        chars.next();
+                    }
+                }
+            }
+        } else if let Some(resolved) = resolve_common_escape(esc) {
+            bytes.push(resolved as u8);
+        } else {
+            let span = Span::new(esc_start, esc_start + 1 + esc.len_utf8() as u32);
+            errors.push(if in_char {
+                LexError::invalid_char_escape(span, esc)
+            } else {
+                LexError::invalid_string_escape(span, esc)
+            });
+        }
+    }
+
+    bytes
+}
+
 /// Unescape a template literal's content (between delimiters).
 ///
 /// Valid escapes per grammar line 107: `` \` `` `\\` `\n` `\t` `\r` `\0`.
@@ -115,6 +115,121 @@ fn char_empty() {
     assert_eq!(unescape_char_v2("", 0, &mut errors), '\0');
 }
 
+// === Unicode escapes ===
+
+#[test]
+fn string_unicode_escape_emoji() {
+    let mut errors = Vec::new();
+    let result = unescape_string_v2(r"hi \u{1F600}!", 0, &mut errors);
+    assert_eq!(result.as_deref(), Some("hi \u{1F600}!"));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn char_unicode_escape_ascii() {
+    let mut errors = Vec::new();
+    assert_eq!(unescape_char_v2(r"\u{41}", 0, &mut errors), 'A');
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unicode_escape_malformed_hex() {
+    let mut errors = Vec::new();
+    let result = unescape_string_v2(r"a\u{XYZ}b", 10, &mut errors);
+    assert_eq!(result.as_deref(), Some("a\u{FFFD}b"));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind,
+        crate::lex_error::LexErrorKind::InvalidUnicodeEscape {
+            reason: "expected 1 to 6 hex digits"
+        }
+    );
+    // `\u{XYZ}` spans offsets 11..18
+    assert_eq!(errors[0].span, Span::new(11, 18));
+}
+
+#[test]
+fn unicode_escape_rejects_bad_forms() {
+    for (source, reason) in [
+        (r"\u41", "expected `{` after `\\u`"),
+        (r"\u{41", "missing closing `}`"),
+        (r"\u{}", "expected 1 to 6 hex digits"),
+        (r"\u{1000000}", "expected 1 to 6 hex digits"),
+        (r"\u{D800}", "not a Unicode scalar value"),
+        (r"\u{110000}", "not a Unicode scalar value"),
+    ] {
+        let mut errors = Vec::new();
+        unescape_string_v2(source, 0, &mut errors);
+        assert_eq!(
+            errors.iter().map(|e| &e.kind).collect::<Vec<_>>(),
+            vec![&crate::lex_error::LexErrorKind::InvalidUnicodeEscape { reason }],
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn char_unicode_escape_error_has_char_context() {
+    let mut errors = Vec::new();
+    assert_eq!(unescape_char_v2(r"\u{D800}", 0, &mut errors), '\u{FFFD}');
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].context,
+        crate::lex_error::LexErrorContext::InsideChar
+    );
+}
+
+// === Hex escapes ===
+
+#[test]
+fn string_hex_escapes() {
+    let mut errors = Vec::new();
+    let result = unescape_string_v2(r"\x41\x42", 0, &mut errors);
+    assert_eq!(result.as_deref(), Some("AB"));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn char_hex_escape() {
+    let mut errors = Vec::new();
+    assert_eq!(unescape_char_v2(r"\x7f", 0, &mut errors), '\x7f');
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn hex_escape_malformed_digit() {
+    let mut errors = Vec::new();
+    let result = unescape_string_v2(r"a\xG1b", 10, &mut errors);
+    assert_eq!(result.as_deref(), Some("a\u{FFFD}b"));
+    assert_eq!(
+        errors.iter().map(|e| (&e.kind, e.span)).collect::<Vec<_>>(),
+        vec![(
+            &crate::lex_error::LexErrorKind::InvalidHexEscape {
+                reason: "expected exactly two hex digits"
+            },
+            Span::new(11, 15)
+        )]
+    );
+}
+
+#[test]
+fn hex_escape_rejects_bad_forms() {
+    for (source, reason) in [
+        (r"\x4", "expected exactly two hex digits"),
+        (r"\xZZ", "expected exactly two hex digits"),
+        (r"\x", "expected exactly two hex digits"),
+        (r"\xFF", "value above \\x7F; use \\u{...} for non-ASCII"),
+    ] {
+        let mut errors = Vec::new();
+        unescape_string_v2(source, 0, &mut errors);
+        assert_eq!(
+            errors.iter().map(|e| &e.kind).collect::<Vec<_>>(),
+            vec![&crate::lex_error::LexErrorKind::InvalidHexEscape { reason }],
+            "{source}"
+        );
+    }
+}
+
 // === Template escapes ===
 
 #[test]
@@ -173,3 +288,54 @@ fn template_trailing_single_brace() {
     assert!(result.is_none());
     assert!(errors.is_empty());
 }
+
+// === Byte escapes ===
+
+#[test]
+fn byte_newline_escape() {
+    let mut errors = Vec::new();
+    assert_eq!(unescape_bytes(r"\n", 0, '\'', &mut errors), vec![b'\n']);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn byte_string_full_range_hex() {
+    let mut errors = Vec::new();
+    let bytes = unescape_bytes(r#"a\xFF\x00\"z"#, 0, '"', &mut errors);
+    assert_eq!(bytes, vec![b'a', 0xFF, 0x00, b'"', b'z']);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn byte_non_ascii_rejected() {
+    let mut errors = Vec::new();
+    let bytes = unescape_bytes("aλ", 10, '"', &mut errors);
+    assert_eq!(bytes, vec![b'a']);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind,
+        crate::lex_error::LexErrorKind::NonAsciiInByteLiteral { ch: 'λ' }
+    );
+    assert_eq!(errors[0].span, Span::new(11, 13));
+}
+
+#[test]
+fn byte_unicode_escape_rejected() {
+    let mut errors = Vec::new();
+    unescape_bytes(r"\u{41}", 0, '\'', &mut errors);
+    assert_eq!(
+        errors[0].kind,
+        crate::lex_error::LexErrorKind::InvalidCharEscape { escape_char: 'u' }
+    );
+}
+
+#[test]
+fn byte_malformed_hex_escape() {
+    let mut errors = Vec::new();
+    let bytes = unescape_bytes(r"\xZ1b", 0, '"', &mut errors);
+    assert_eq!(bytes, vec![b'b']);
+    assert!(matches!(
+        errors[0].kind,
+        crate::lex_error::LexErrorKind::InvalidHexEscape { .. }
+    ));
+}
@@ -15,22 +15,23 @@
 //! Each `RawTag` category has a dedicated cooking path:
 //! - **Operators/delimiters**: Direct 1:1 mapping (no data)
 //! - **Identifiers**: Keyword lookup → intern
-//! - **Numerics**: Parse value, detect overflow
-//! - **Strings/chars**: Unescape + intern
+//! - **Numerics**: Parse value, detect overflow, range-check type suffixes
+//! - **Strings/chars/bytes**: Unescape + intern
 //! - **Templates**: Unescape + intern
 //! - **Duration/size**: Parse value + detect suffix
 //! - **Errors**: Push `LexError`, return `TokenKind::Error`
 
-use ori_ir::{DurationUnit, SizeUnit, StringInterner, TokenKind};
+use ori_ir::{StringInterner, TokenKind};
 use ori_lexer_core::RawTag;
 
-use crate::cook_escape::{unescape_char_v2, unescape_string_v2, unescape_template_v2};
 use crate::keywords;
 use crate::lex_error::{LexError, LexSuggestion};
-use crate::parse_helpers::{parse_float_skip_underscores, parse_int_skip_underscores};
 use crate::unicode_confusables;
 use crate::what_is_next::{self, NextContext};
 
+mod numeric;
+mod text;
+
 /// Cooks raw tokens into parser-ready `TokenKind` values.
 ///
 /// Stateless with respect to individual tokens — each `cook()` call is
@@ -153,6 +154,8 @@ impl<'src> TokenCooker<'src> {
             RawTag::HexInt => self.cook_hex_int(offset, len),
             RawTag::BinInt => self.cook_bin_int(offset, len),
             RawTag::Float => self.cook_float(offset, len),
+            RawTag::HexFloat => self.cook_hex_float(offset, len),
+            RawTag::SuffixedNumber => self.cook_suffixed_number(offset, len),
 
             // Duration/size
             RawTag::Duration => self.cook_duration(offset, len),
@@ -160,7 +163,11 @@ impl<'src> TokenCooker<'src> {
 
             // String/char
             RawTag::String => self.cook_string(offset, len),
+            RawTag::RawString => self.cook_raw_string(offset, len),
+            RawTag::MultilineString => self.cook_multiline_string(offset, len),
             RawTag::Char => self.cook_char(offset, len),
+            RawTag::Byte => self.cook_byte(offset, len),
+            RawTag::ByteString => self.cook_byte_string(offset, len),
 
             // Template literals
             RawTag::TemplateHead => self.cook_template_head(offset, len),
@@ -172,13 +179,24 @@ impl<'src> TokenCooker<'src> {
             // Error tags
             RawTag::InvalidByte => self.cook_invalid_byte(offset, len),
             RawTag::UnterminatedString => {
-                self.errors
-                    .push(LexError::unterminated_string(span(offset, len)));
+                let err = if slice_source(self.source, offset, len).starts_with("\"\"\"") {
+                    LexError::unterminated_multiline_string(span(offset, 3))
+                } else {
+                    LexError::unterminated_string(span(offset, len))
+                };
+                self.errors.push(err);
                 TokenKind::Error
             }
             RawTag::UnterminatedChar => {
-                self.errors
-                    .push(LexError::unterminated_char(span(offset, len)));
+                // `'abc'`: the scanner found a closing quote after several
+                // characters, so this is a single-quoted string
+                let text = slice_source(self.source, offset, len);
+                let err = if len > 2 && text.ends_with('\'') {
+                    LexError::single_quote_string(span(offset, len))
+                } else {
+                    LexError::unterminated_char(span(offset, len))
+                };
+                self.errors.push(err);
                 TokenKind::Error
             }
             RawTag::UnterminatedTemplate => {
@@ -186,6 +204,11 @@ impl<'src> TokenCooker<'src> {
                     .push(LexError::unterminated_template(span(offset, len)));
                 TokenKind::Error
             }
+            RawTag::UnterminatedBlockComment => {
+                self.errors
+                    .push(LexError::unterminated_block_comment(span(offset, 2)));
+                TokenKind::Error
+            }
             RawTag::Backslash => {
                 self.errors
                     .push(LexError::standalone_backslash(span(offset, len)));
@@ -203,7 +226,11 @@ impl<'src> TokenCooker<'src> {
                 TokenKind::Error
             }
             // Trivia and interior nulls (should not reach cook — handled by driver)
-            RawTag::Whitespace | RawTag::Newline | RawTag::LineComment | RawTag::InteriorNull => {
+            RawTag::Whitespace
+            | RawTag::Newline
+            | RawTag::LineComment
+            | RawTag::BlockComment
+            | RawTag::InteriorNull => {
                 debug_assert!(
                     false,
                     "Trivia/InteriorNull tags should be handled by the driver loop, not cook()"
@@ -300,257 +327,6 @@ impl<'src> TokenCooker<'src> {
         }
         TokenKind::Ident(self.interner.intern(text))
     }
-
-    #[inline]
-    fn cook_int(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        if let Some(n) = parse_int_skip_underscores(text, 10) {
-            TokenKind::Int(n)
-        } else {
-            self.errors.push(LexError::int_overflow(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_hex_int(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip the 0x/0X prefix
-        let hex_part = &text[2..];
-        if let Some(n) = parse_int_skip_underscores(hex_part, 16) {
-            TokenKind::Int(n)
-        } else {
-            self.errors
-                .push(LexError::hex_int_overflow(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_bin_int(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip the 0b/0B prefix
-        let bin_part = &text[2..];
-        if let Some(n) = parse_int_skip_underscores(bin_part, 2) {
-            TokenKind::Int(n)
-        } else {
-            self.errors
-                .push(LexError::bin_int_overflow(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_float(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        if let Some(f) = parse_float_skip_underscores(text) {
-            TokenKind::Float(f.to_bits())
-        } else {
-            self.errors
-                .push(LexError::float_parse_error(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_duration(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-
-        // Detect suffix by matching from the end
-        let (suffix_len, unit) = detect_duration_suffix(text);
-        if suffix_len == 0 {
-            // Shouldn't happen with valid raw tokens, but be safe
-            self.errors.push(LexError::int_overflow(span(offset, len)));
-            return TokenKind::Error;
-        }
-
-        let num_part = &text[..text.len() - suffix_len];
-
-        if num_part.contains('.') {
-            // Decimal duration: convert to nanoseconds via integer arithmetic.
-            // Spec: "Decimal syntax is compile-time sugar computed via integer
-            // arithmetic — no floating-point operations are involved."
-            if let Some(nanos) = parse_decimal_unit_value(num_part, unit.nanos_multiplier()) {
-                TokenKind::Duration(nanos, DurationUnit::Nanoseconds)
-            } else {
-                self.errors
-                    .push(LexError::decimal_not_representable(span(offset, len)));
-                TokenKind::Error
-            }
-        } else if let Some(value) = parse_int_skip_underscores(num_part, 10) {
-            TokenKind::Duration(value, unit)
-        } else {
-            self.errors.push(LexError::int_overflow(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_size(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-
-        let (suffix_len, unit) = detect_size_suffix(text);
-        if suffix_len == 0 {
-            self.errors.push(LexError::int_overflow(span(offset, len)));
-            return TokenKind::Error;
-        }
-
-        let num_part = &text[..text.len() - suffix_len];
-
-        if num_part.contains('.') {
-            // Decimal size: convert to bytes via integer arithmetic.
-            if let Some(bytes) = parse_decimal_unit_value(num_part, unit.bytes_multiplier()) {
-                TokenKind::Size(bytes, SizeUnit::Bytes)
-            } else {
-                self.errors
-                    .push(LexError::decimal_not_representable(span(offset, len)));
-                TokenKind::Error
-            }
-        } else if let Some(value) = parse_int_skip_underscores(num_part, 10) {
-            TokenKind::Size(value, unit)
-        } else {
-            self.errors.push(LexError::int_overflow(span(offset, len)));
-            TokenKind::Error
-        }
-    }
-
-    fn cook_string(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip surrounding quotes
-        let content = &text[1..text.len() - 1];
-        // base_offset is one past the opening quote
-        let content_offset = offset + 1;
-
-        let name = match unescape_string_v2(content, content_offset, &mut self.errors) {
-            Some(unescaped) => self.interner.intern_owned(unescaped),
-            None => {
-                // Fast path: no escapes, intern source slice directly
-                self.interner.intern(content)
-            }
-        };
-        TokenKind::String(name)
-    }
-
-    fn cook_char(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip surrounding quotes
-        let content = &text[1..text.len() - 1];
-        let content_offset = offset + 1;
-
-        let c = unescape_char_v2(content, content_offset, &mut self.errors);
-        TokenKind::Char(c)
-    }
-
-    fn cook_template_head(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip leading ` and trailing {
-        let content = &text[1..text.len() - 1];
-        let content_offset = offset + 1;
-
-        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
-            Some(unescaped) => self.interner.intern_owned(unescaped),
-            None => self.interner.intern(content),
-        };
-        TokenKind::TemplateHead(name)
-    }
-
-    fn cook_template_middle(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip leading } and trailing {
-        let content = &text[1..text.len() - 1];
-        let content_offset = offset + 1;
-
-        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
-            Some(unescaped) => self.interner.intern_owned(unescaped),
-            None => self.interner.intern(content),
-        };
-        TokenKind::TemplateMiddle(name)
-    }
-
-    fn cook_template_tail(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip leading } and trailing `
-        let content = &text[1..text.len() - 1];
-        let content_offset = offset + 1;
-
-        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
-            Some(unescaped) => self.interner.intern_owned(unescaped),
-            None => self.interner.intern(content),
-        };
-        TokenKind::TemplateTail(name)
-    }
-
-    fn cook_format_spec(&self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // The format spec token includes the leading `:` from the scanner.
-        // Strip it to get just the spec content.
-        let content = &text[1..];
-        TokenKind::FormatSpec(self.interner.intern(content))
-    }
-
-    fn cook_template_complete(&mut self, offset: u32, len: u32) -> TokenKind {
-        let text = slice_source(self.source, offset, len);
-        // Strip both backticks
-        let content = &text[1..text.len() - 1];
-        let content_offset = offset + 1;
-
-        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
-            Some(unescaped) => self.interner.intern_owned(unescaped),
-            None => self.interner.intern(content),
-        };
-        TokenKind::TemplateFull(name)
-    }
-}
-
-/// Parse a decimal number string and convert to base units using integer arithmetic.
-///
-/// Given `num_part` (e.g., `"1.5"`) and `multiplier` (e.g., `1_000_000_000` for seconds→ns),
-/// computes the exact integer result. Returns `None` if the result is not a whole number
-/// (e.g., `1.5` nanoseconds) or on overflow.
-///
-/// Algorithm: parse integer and fractional parts separately, then combine:
-///   `result = integer_part * multiplier + fractional_digits * multiplier / 10^(num_frac_digits)`
-///
-/// The fractional contribution must divide evenly (no remainder) to be representable.
-fn parse_decimal_unit_value(num_part: &str, multiplier: u64) -> Option<u64> {
-    let mut integer_part: u64 = 0;
-    let mut frac_digits: u64 = 0;
-    let mut frac_digit_count: u32 = 0;
-    let mut in_fraction = false;
-
-    for &byte in num_part.as_bytes() {
-        match byte {
-            b'0'..=b'9' => {
-                let digit = u64::from(byte - b'0');
-                if in_fraction {
-                    frac_digits = frac_digits.checked_mul(10)?.checked_add(digit)?;
-                    frac_digit_count += 1;
-                } else {
-                    integer_part = integer_part.checked_mul(10)?.checked_add(digit)?;
-                }
-            }
-            b'.' => {
-                in_fraction = true;
-            }
-            b'_' => {}  // skip underscores
-            _ => break, // hit suffix (shouldn't happen — caller strips suffix)
-        }
-    }
-
-    // integer_contribution = integer_part * multiplier
-    let integer_contribution = integer_part.checked_mul(multiplier)?;
-
-    if frac_digit_count == 0 {
-        return Some(integer_contribution);
-    }
-
-    // frac_divisor = 10^frac_digit_count
-    let frac_divisor = 10u64.checked_pow(frac_digit_count)?;
-
-    // frac_contribution = frac_digits * multiplier / frac_divisor
-    // Must divide evenly for the result to be a whole number of base units.
-    let frac_numerator = frac_digits.checked_mul(multiplier)?;
-    if frac_numerator % frac_divisor != 0 {
-        return None; // not representable as whole base units
-    }
-    let frac_contribution = frac_numerator / frac_divisor;
-
-    integer_contribution.checked_add(frac_contribution)
 }
 
 /// Extract a str slice from source bytes at the given offset and length.
@@ -585,48 +361,6 @@ fn span(offset: u32, len: u32) -> ori_ir::Span {
     ori_ir::Span::new(offset, offset + len)
 }
 
-/// Detect duration suffix and return (`suffix_len`, unit).
-fn detect_duration_suffix(text: &str) -> (usize, DurationUnit) {
-    let bytes = text.as_bytes();
-    let n = bytes.len();
-    if n >= 2 {
-        match (bytes[n - 2], bytes[n - 1]) {
-            (b'n', b's') => return (2, DurationUnit::Nanoseconds),
-            (b'u', b's') => return (2, DurationUnit::Microseconds),
-            (b'm', b's') => return (2, DurationUnit::Milliseconds),
-            _ => {}
-        }
-    }
-    if n >= 1 {
-        match bytes[n - 1] {
-            b's' => return (1, DurationUnit::Seconds),
-            b'm' => return (1, DurationUnit::Minutes),
-            b'h' => return (1, DurationUnit::Hours),
-            _ => {}
-        }
-    }
-    (0, DurationUnit::Seconds)
-}
-
-/// Detect size suffix and return (`suffix_len`, unit).
-fn detect_size_suffix(text: &str) -> (usize, SizeUnit) {
-    let bytes = text.as_bytes();
-    let n = bytes.len();
-    if n >= 2 {
-        match (bytes[n - 2], bytes[n - 1]) {
-            (b'k', b'b') => return (2, SizeUnit::Kilobytes),
-            (b'm', b'b') => return (2, SizeUnit::Megabytes),
-            (b'g', b'b') => return (2, SizeUnit::Gigabytes),
-            (b't', b'b') => return (2, SizeUnit::Terabytes),
-            _ => {}
-        }
-    }
-    if n >= 1 && bytes[n - 1] == b'b' {
-        return (1, SizeUnit::Bytes);
-    }
-    (0, SizeUnit::Bytes)
-}
-
 #[cfg(test)]
 #[allow(
     clippy::cast_possible_truncation,
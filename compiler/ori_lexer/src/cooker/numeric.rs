@@ -0,0 +1,307 @@
+//! Cooking for numeric literals: integers, floats, type-suffixed numbers,
+//! durations, and sizes.
+//!
+//! Integer and float values are parsed with underscores skipped. Suffixed
+//! numbers are range-checked against their type. Duration
+//! and size literals with a decimal point are converted to their base unit
+//! via integer arithmetic.
+
+use ori_ir::{DurationUnit, NumericSuffix, SizeUnit, TokenKind};
+
+use super::{slice_source, span, TokenCooker};
+use crate::lex_error::LexError;
+use crate::parse_helpers::{
+    parse_float_skip_underscores, parse_hex_float_skip_underscores, parse_int_skip_underscores,
+};
+
+impl TokenCooker<'_> {
+    #[inline]
+    pub(super) fn cook_int(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        if let Some(n) = parse_int_skip_underscores(text, 10) {
+            TokenKind::Int(n)
+        } else {
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+
+    pub(super) fn cook_hex_int(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip the 0x/0X prefix
+        let hex_part = &text[2..];
+        if let Some(n) = parse_int_skip_underscores(hex_part, 16) {
+            TokenKind::Int(n)
+        } else {
+            self.errors
+                .push(LexError::hex_int_overflow(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+
+    pub(super) fn cook_bin_int(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip the 0b/0B prefix
+        let bin_part = &text[2..];
+        if let Some(n) = parse_int_skip_underscores(bin_part, 2) {
+            TokenKind::Int(n)
+        } else {
+            self.errors
+                .push(LexError::bin_int_overflow(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+
+    pub(super) fn cook_float(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        if let Some(f) = parse_float_skip_underscores(text) {
+            TokenKind::Float(f.to_bits())
+        } else {
+            self.errors
+                .push(LexError::float_parse_error(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+
+    pub(super) fn cook_hex_float(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip the 0x/0X prefix
+        match parse_hex_float_skip_underscores(&text[2..]) {
+            Some(f) if f.is_finite() => TokenKind::Float(f.to_bits()),
+            _ => {
+                self.errors
+                    .push(LexError::float_parse_error(span(offset, len)));
+                TokenKind::Error
+            }
+        }
+    }
+
+    /// Cook a literal with a type suffix (`42i32`, `3.0f32`).
+    ///
+    /// Unknown suffixes, integer suffixes on float literals, and values that
+    /// do not fit the suffix type all produce `TokenKind::Error`.
+    pub(super) fn cook_suffixed_number(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // The number part never contains `i`, `u`, or `f`
+        let split = text.find(['i', 'u', 'f']).unwrap_or(text.len());
+        let (num_part, suffix_text) = text.split_at(split);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "split is within a token, whose length fits u32"
+        )]
+        let suffix_span = span(offset + split as u32, len - split as u32);
+
+        let Some(suffix) = NumericSuffix::from_suffix(suffix_text) else {
+            self.errors.push(LexError::invalid_numeric_suffix(
+                suffix_span,
+                "unknown type suffix",
+            ));
+            return TokenKind::Error;
+        };
+        let is_float_literal = num_part.contains(['.', 'e', 'E']);
+
+        if suffix.is_float() {
+            let Some(f) = parse_float_skip_underscores(num_part) else {
+                self.errors
+                    .push(LexError::float_parse_error(span(offset, len)));
+                return TokenKind::Error;
+            };
+            if suffix == NumericSuffix::F32 && f.abs() > f64::from(f32::MAX) {
+                self.errors.push(LexError::numeric_literal_out_of_range(
+                    span(offset, len),
+                    suffix,
+                ));
+                return TokenKind::Error;
+            }
+            return TokenKind::SuffixedNumber(f.to_bits(), suffix);
+        }
+
+        if is_float_literal {
+            self.errors.push(LexError::invalid_numeric_suffix(
+                suffix_span,
+                "integer suffix on a float literal",
+            ));
+            return TokenKind::Error;
+        }
+        let Some(n) = parse_int_skip_underscores(num_part, 10) else {
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            return TokenKind::Error;
+        };
+        if n > suffix.max_magnitude() {
+            self.errors.push(LexError::numeric_literal_out_of_range(
+                span(offset, len),
+                suffix,
+            ));
+            return TokenKind::Error;
+        }
+        TokenKind::SuffixedNumber(n, suffix)
+    }
+
+    pub(super) fn cook_duration(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+
+        // Detect suffix by matching from the end
+        let (suffix_len, unit) = detect_duration_suffix(text);
+        if suffix_len == 0 {
+            // Shouldn't happen with valid raw tokens, but be safe
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            return TokenKind::Error;
+        }
+
+        let num_part = &text[..text.len() - suffix_len];
+
+        if num_part.contains('.') {
+            // Decimal duration: convert to nanoseconds via integer arithmetic.
+            // Spec: "Decimal syntax is compile-time sugar computed via integer
+            // arithmetic — no floating-point operations are involved."
+            if let Some(nanos) = parse_decimal_unit_value(num_part, unit.nanos_multiplier()) {
+                TokenKind::Duration(nanos, DurationUnit::Nanoseconds)
+            } else {
+                self.errors
+                    .push(LexError::decimal_not_representable(span(offset, len)));
+                TokenKind::Error
+            }
+        } else if let Some(value) = parse_int_skip_underscores(num_part, 10) {
+            TokenKind::Duration(value, unit)
+        } else {
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+
+    pub(super) fn cook_size(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+
+        let (suffix_len, unit) = detect_size_suffix(text);
+        if suffix_len == 0 {
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            return TokenKind::Error;
+        }
+
+        let num_part = &text[..text.len() - suffix_len];
+
+        if num_part.contains('.') {
+            // Decimal size: convert to bytes via integer arithmetic.
+            if let Some(bytes) = parse_decimal_unit_value(num_part, unit.bytes_multiplier()) {
+                TokenKind::Size(bytes, SizeUnit::Bytes)
+            } else {
+                self.errors
+                    .push(LexError::decimal_not_representable(span(offset, len)));
+                TokenKind::Error
+            }
+        } else if let Some(value) = parse_int_skip_underscores(num_part, 10) {
+            TokenKind::Size(value, unit)
+        } else {
+            self.errors.push(LexError::int_overflow(span(offset, len)));
+            TokenKind::Error
+        }
+    }
+}
+
+/// Parse a decimal number string and convert to base units using integer arithmetic.
+///
+/// Given `num_part` (e.g., `"1.5"`) and `multiplier` (e.g., `1_000_000_000` for seconds→ns),
+/// computes the exact integer result. Returns `None` if the result is not a whole number
+/// (e.g., `1.5` nanoseconds) or on overflow.
+///
+/// Algorithm: parse integer and fractional parts separately, then combine:
+///   `result = integer_part * multiplier + fractional_digits * multiplier / 10^(num_frac_digits)`
+///
+/// The fractional contribution must divide evenly (no remainder) to be representable.
+pub(super) fn parse_decimal_unit_value(num_part: &str, multiplier: u64) -> Option<u64> {
+    let mut integer_part: u64 = 0;
+    let mut frac_digits: u64 = 0;
+    let mut frac_digit_count: u32 = 0;
+    let mut in_fraction = false;
+
+    for &byte in num_part.as_bytes() {
+        match byte {
+            b'0'..=b'9' => {
+                let digit = u64::from(byte - b'0');
+                if in_fraction {
+                    frac_digits = frac_digits.checked_mul(10)?.checked_add(digit)?;
+                    frac_digit_count += 1;
+                } else {
+                    integer_part = integer_part.checked_mul(10)?.checked_add(digit)?;
+                }
+            }
+            b'.' => {
+                in_fraction = true;
+            }
+            b'_' => {}  // skip underscores
+            _ => break, // hit suffix (shouldn't happen — caller strips suffix)
+        }
+    }
+
+    // integer_contribution = integer_part * multiplier
+    let integer_contribution = integer_part.checked_mul(multiplier)?;
+
+    if frac_digit_count == 0 {
+        return Some(integer_contribution);
+    }
+
+    // frac_divisor = 10^frac_digit_count
+    let frac_divisor = 10u64.checked_pow(frac_digit_count)?;
+
+    // frac_contribution = frac_digits * multiplier / frac_divisor
+    // Must divide evenly for the result to be a whole number of base units.
+    let frac_numerator = frac_digits.checked_mul(multiplier)?;
+    if frac_numerator % frac_divisor != 0 {
+        return None; // not representable as whole base units
+    }
+    let frac_contribution = frac_numerator / frac_divisor;
+
+    integer_contribution.checked_add(frac_contribution)
+}
+
+/// Detect duration suffix and return (`suffix_len`, unit).
+pub(super) fn detect_duration_suffix(text: &str) -> (usize, DurationUnit) {
+    let bytes = text.as_bytes();
+    let n = bytes.len();
+    if n >= 2 {
+        match (bytes[n - 2], bytes[n - 1]) {
+            (b'n', b's') => return (2, DurationUnit::Nanoseconds),
+            (b'u', b's') => return (2, DurationUnit::Microseconds),
+            (b'm', b's') => return (2, DurationUnit::Milliseconds),
+            _ => {}
+        }
+    }
+    if n >= 1 {
+        match bytes[n - 1] {
+            b's' => return (1, DurationUnit::Seconds),
+            b'm' => return (1, DurationUnit::Minutes),
+            b'h' => return (1, DurationUnit::Hours),
+            _ => {}
+        }
+    }
+    (0, DurationUnit::Seconds)
+}
+
+/// Detect size suffix and return (`suffix_len`, unit).
+pub(super) fn detect_size_suffix(text: &str) -> (usize, SizeUnit) {
+    let bytes = text.as_bytes();
+    let n = bytes.len();
+    // Binary units first: `kib` also ends in `ib`, never in a bare `kb`
+    if n >= 3 && bytes[n - 2..] == *b"ib" {
+        match bytes[n - 3] {
+            b'k' => return (3, SizeUnit::Kibibytes),
+            b'm' => return (3, SizeUnit::Mebibytes),
+            b'g' => return (3, SizeUnit::Gibibytes),
+            b't' => return (3, SizeUnit::Tebibytes),
+            _ => {}
+        }
+    }
+    if n >= 2 {
+        match (bytes[n - 2], bytes[n - 1]) {
+            (b'k', b'b') => return (2, SizeUnit::Kilobytes),
+            (b'm', b'b') => return (2, SizeUnit::Megabytes),
+            (b'g', b'b') => return (2, SizeUnit::Gigabytes),
+            (b't', b'b') => return (2, SizeUnit::Terabytes),
+            _ => {}
+        }
+    }
+    if n >= 1 && bytes[n - 1] == b'b' {
+        return (1, SizeUnit::Bytes);
+    }
+    (0, SizeUnit::Bytes)
+}
@@ -1,3 +1,6 @@
+use ori_ir::{DurationUnit, NumericSuffix, SizeUnit};
+
+use super::numeric::{detect_duration_suffix, detect_size_suffix, parse_decimal_unit_value};
 use super::*;
 
 // === Operator mapping ===
@@ -140,6 +143,26 @@ fn float_literal() {
     );
 }
 
+#[test]
+fn hex_float_literal() {
+    let source = "0x1.8p3";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::HexFloat, 0, 7),
+        TokenKind::Float(12.0f64.to_bits())
+    );
+}
+
+#[test]
+fn hex_float_overflow_is_error() {
+    let source = "0x1p2000";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(cooker.cook(RawTag::HexFloat, 0, 8), TokenKind::Error);
+    assert_eq!(cooker.errors().len(), 1);
+}
+
 #[test]
 fn integer_overflow() {
     let source = "99999999999999999999999";
@@ -152,6 +175,86 @@ fn integer_overflow() {
     assert_eq!(cooker.errors().len(), 1);
 }
 
+// === Type suffixes ===
+
+#[test]
+fn suffixed_integer_u8() {
+    let source = "255u8";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::SuffixedNumber, 0, 5),
+        TokenKind::SuffixedNumber(255, NumericSuffix::U8)
+    );
+    assert!(cooker.errors().is_empty());
+}
+
+#[test]
+fn suffixed_integer_out_of_range() {
+    let source = "256u8";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(cooker.cook(RawTag::SuffixedNumber, 0, 5), TokenKind::Error);
+    assert_eq!(
+        cooker.errors()[0].kind,
+        crate::lex_error::LexErrorKind::NumericLiteralOutOfRange {
+            suffix: NumericSuffix::U8
+        }
+    );
+}
+
+#[test]
+fn suffixed_signed_allows_negation_to_min() {
+    // `-128i8` is formed by negating `128i8` in the parser
+    let source = "128i8";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::SuffixedNumber, 0, 5),
+        TokenKind::SuffixedNumber(128, NumericSuffix::I8)
+    );
+}
+
+#[test]
+fn suffixed_unknown_width_is_error() {
+    let source = "42i7";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(cooker.cook(RawTag::SuffixedNumber, 0, 4), TokenKind::Error);
+    assert_eq!(cooker.errors().len(), 1);
+    // Error points at the suffix only
+    assert_eq!(cooker.errors()[0].span, ori_ir::Span::new(2, 4));
+}
+
+#[test]
+fn suffixed_float() {
+    let source = "3.5f32";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::SuffixedNumber, 0, 6),
+        TokenKind::SuffixedNumber(3.5f64.to_bits(), NumericSuffix::F32)
+    );
+}
+
+#[test]
+fn suffixed_float_f32_overflow() {
+    let source = "1e39f32";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(cooker.cook(RawTag::SuffixedNumber, 0, 7), TokenKind::Error);
+    assert_eq!(cooker.errors().len(), 1);
+}
+
+#[test]
+fn integer_suffix_on_float_is_error() {
+    let source = "1.5u8";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(cooker.cook(RawTag::SuffixedNumber, 0, 5), TokenKind::Error);
+    assert_eq!(cooker.errors().len(), 1);
+}
+
 // === Duration/Size ===
 
 #[test]
@@ -198,6 +301,28 @@ fn size_kilobytes() {
     );
 }
 
+#[test]
+fn size_terabytes() {
+    let source = "5tb";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Size, 0, 3),
+        TokenKind::Size(5, SizeUnit::Terabytes)
+    );
+}
+
+#[test]
+fn size_kibibytes() {
+    let source = "1kib";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Size, 0, 4),
+        TokenKind::Size(1, SizeUnit::Kibibytes)
+    );
+}
+
 #[test]
 fn size_bytes() {
     let source = "100b";
@@ -309,6 +434,18 @@ fn decimal_size_megabytes() {
     );
 }
 
+#[test]
+fn decimal_size_mebibytes() {
+    // 0.5mib = 524,288 bytes
+    let source = "0.5mib";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Size, 0, 6),
+        TokenKind::Size(524_288, SizeUnit::Bytes)
+    );
+}
+
 #[test]
 fn decimal_size_bytes_error() {
     // 0.5b = 0.5 bytes — not a whole number → error
@@ -345,6 +482,145 @@ fn string_with_escapes() {
     }
 }
 
+#[test]
+fn raw_string_keeps_backslashes() {
+    let source = r#"r"a\nb""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    let cooked = cooker.cook(RawTag::RawString, 0, source.len() as u32);
+    match cooked {
+        TokenKind::String(name) => assert_eq!(interner.lookup(name), "a\\nb"),
+        other => panic!("expected String, got {other:?}"),
+    }
+    assert!(cooker.errors().is_empty());
+}
+
+#[test]
+fn raw_string_with_hashes_keeps_quotes() {
+    let source = "r#\"he said \"hi\"\"#";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    let cooked = cooker.cook(RawTag::RawString, 0, source.len() as u32);
+    match cooked {
+        TokenKind::String(name) => assert_eq!(interner.lookup(name), r#"he said "hi""#),
+        other => panic!("expected String, got {other:?}"),
+    }
+}
+
+#[test]
+fn string_unicode_escape() {
+    let source = r#""\u{1F600}!""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    let cooked = cooker.cook(RawTag::String, 0, source.len() as u32);
+    match cooked {
+        TokenKind::String(name) => assert_eq!(interner.lookup(name), "\u{1F600}!"),
+        other => panic!("expected String, got {other:?}"),
+    }
+    assert!(cooker.errors().is_empty());
+}
+
+#[test]
+fn string_malformed_unicode_escape_is_error_token() {
+    let source = r#""a\u{XYZ}b""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::String, 0, source.len() as u32),
+        TokenKind::Error
+    );
+    assert_eq!(cooker.errors().len(), 1);
+}
+
+#[test]
+fn string_hex_escapes() {
+    let source = r#""\x41\x42""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    match cooker.cook(RawTag::String, 0, source.len() as u32) {
+        TokenKind::String(name) => assert_eq!(interner.lookup(name), "AB"),
+        other => panic!("expected String, got {other:?}"),
+    }
+}
+
+#[test]
+fn char_malformed_hex_escape_is_error_token() {
+    let source = r"'\xG1'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Char, 0, source.len() as u32),
+        TokenKind::Error
+    );
+}
+
+// === Byte literals ===
+
+#[test]
+fn byte_ascii() {
+    let source = "b'A'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Byte, 0, source.len() as u32),
+        TokenKind::Byte(b'A')
+    );
+    assert!(cooker.errors().is_empty());
+}
+
+#[test]
+fn byte_escape_newline() {
+    let source = r"b'\n'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Byte, 0, source.len() as u32),
+        TokenKind::Byte(b'\n')
+    );
+}
+
+#[test]
+fn byte_non_ascii_is_error() {
+    let source = "b'λ'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Byte, 0, source.len() as u32),
+        TokenKind::Error
+    );
+    assert_eq!(cooker.errors().len(), 1);
+    assert_eq!(
+        cooker.errors()[0].kind,
+        crate::lex_error::LexErrorKind::NonAsciiInByteLiteral { ch: 'λ' }
+    );
+}
+
+#[test]
+fn byte_string_plain() {
+    let source = r#"b"abc""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    match cooker.cook(RawTag::ByteString, 0, source.len() as u32) {
+        TokenKind::ByteString(name) => assert_eq!(interner.lookup(name), "abc"),
+        other => panic!("expected ByteString, got {other:?}"),
+    }
+}
+
+#[test]
+fn byte_string_high_hex_escape() {
+    let source = r#"b"a\xFF""#;
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    match cooker.cook(RawTag::ByteString, 0, source.len() as u32) {
+        TokenKind::ByteString(name) => {
+            let bytes: Vec<u32> = interner.lookup(name).chars().map(u32::from).collect();
+            assert_eq!(bytes, vec![0x61, 0xFF]);
+        }
+        other => panic!("expected ByteString, got {other:?}"),
+    }
+    assert!(cooker.errors().is_empty());
+}
+
 // === Char literals ===
 
 #[test]
@@ -369,6 +645,28 @@ fn char_escape() {
     );
 }
 
+#[test]
+fn char_unicode_escape() {
+    let source = r"'\u{41}'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Char, 0, source.len() as u32),
+        TokenKind::Char('A')
+    );
+}
+
+#[test]
+fn char_surrogate_unicode_escape_is_error_token() {
+    let source = r"'\u{D800}'";
+    let interner = StringInterner::new();
+    let mut cooker = TokenCooker::new(source.as_bytes(), &interner);
+    assert_eq!(
+        cooker.cook(RawTag::Char, 0, source.len() as u32),
+        TokenKind::Error
+    );
+}
+
 // === Error tokens ===
 
 #[test]
@@ -447,6 +745,11 @@ fn size_suffix_detection() {
     assert_eq!(detect_size_suffix("4kb"), (2, SizeUnit::Kilobytes));
     assert_eq!(detect_size_suffix("10mb"), (2, SizeUnit::Megabytes));
     assert_eq!(detect_size_suffix("1gb"), (2, SizeUnit::Gigabytes));
+    assert_eq!(detect_size_suffix("5tb"), (2, SizeUnit::Terabytes));
+    assert_eq!(detect_size_suffix("1kib"), (3, SizeUnit::Kibibytes));
+    assert_eq!(detect_size_suffix("2mib"), (3, SizeUnit::Mebibytes));
+    assert_eq!(detect_size_suffix("3gib"), (3, SizeUnit::Gibibytes));
+    assert_eq!(detect_size_suffix("4tib"), (3, SizeUnit::Tebibytes));
     assert_eq!(detect_size_suffix("1tb"), (2, SizeUnit::Terabytes));
 }
 
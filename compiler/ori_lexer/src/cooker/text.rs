@@ -0,0 +1,181 @@
+//! Cooking for text literals: strings, chars, bytes, and templates.
+//!
+//! Each literal strips its delimiters, runs the escape processor for its
+//! context, and interns the result.
+
+use ori_ir::TokenKind;
+
+use super::{slice_source, TokenCooker};
+use crate::cook_escape::{
+    unescape_bytes, unescape_char_v2, unescape_string_v2, unescape_template_v2,
+};
+use crate::lex_error::LexErrorKind;
+
+impl TokenCooker<'_> {
+    pub(super) fn cook_string(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip surrounding quotes
+        let content = &text[1..text.len() - 1];
+        // base_offset is one past the opening quote
+        let content_offset = offset + 1;
+
+        let name = match unescape_string_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => {
+                // Fast path: no escapes, intern source slice directly
+                self.interner.intern(content)
+            }
+        };
+        if self.last_cook_had_numeric_escape_error() {
+            return TokenKind::Error;
+        }
+        TokenKind::String(name)
+    }
+
+    /// Triple-quoted strings process escapes like ordinary strings. A line
+    /// break directly after the opening `"""` is dropped; indentation is kept.
+    pub(super) fn cook_multiline_string(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        let content = &text[3..text.len() - 3];
+        let skip: u32 = if content.starts_with("\r\n") {
+            2
+        } else {
+            u32::from(content.starts_with('\n'))
+        };
+        let content = &content[skip as usize..];
+        let content_offset = offset + 3 + skip;
+
+        let name = match unescape_string_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => self.interner.intern(content),
+        };
+        if self.last_cook_had_numeric_escape_error() {
+            return TokenKind::Error;
+        }
+        TokenKind::String(name)
+    }
+
+    /// Raw strings intern their content verbatim: no escape processing.
+    pub(super) fn cook_raw_string(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip `r`, the hash delimiters, and the quotes
+        let hashes = text[1..].bytes().take_while(|&b| b == b'#').count();
+        let content = &text[2 + hashes..text.len() - 1 - hashes];
+        TokenKind::String(self.interner.intern(content))
+    }
+
+    pub(super) fn cook_char(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip surrounding quotes
+        let content = &text[1..text.len() - 1];
+        let content_offset = offset + 1;
+
+        let c = unescape_char_v2(content, content_offset, &mut self.errors);
+        if self.last_cook_had_numeric_escape_error() {
+            return TokenKind::Error;
+        }
+        TokenKind::Char(c)
+    }
+
+    pub(super) fn cook_byte(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip `b'` and the closing `'`
+        let content = &text[2..text.len() - 1];
+        let bytes = unescape_bytes(content, offset + 2, '\'', &mut self.errors);
+        match *bytes.as_slice() {
+            [byte] if !self.last_cook_had_error() => TokenKind::Byte(byte),
+            _ => TokenKind::Error,
+        }
+    }
+
+    pub(super) fn cook_byte_string(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip `b"` and the closing `"`
+        let content = &text[2..text.len() - 1];
+        if content.is_ascii() && !content.contains('\\') {
+            return TokenKind::ByteString(self.interner.intern(content));
+        }
+        let bytes = unescape_bytes(content, offset + 2, '"', &mut self.errors);
+        if self.last_cook_had_error() {
+            return TokenKind::Error;
+        }
+        // Latin-1: one char per byte, see `TokenKind::ByteString`
+        let latin1: String = bytes.into_iter().map(char::from).collect();
+        TokenKind::ByteString(self.interner.intern_owned(latin1))
+    }
+
+    /// Whether the current `cook()` reported a malformed `\u{...}` or `\xNN`
+    /// escape.
+    ///
+    /// Unlike other bad escapes (recovered as U+FFFD), a malformed numeric
+    /// escape turns the whole literal into `TokenKind::Error` so no guessed
+    /// code point reaches later phases.
+    fn last_cook_had_numeric_escape_error(&self) -> bool {
+        self.errors[self.errors_before_cook..].iter().any(|e| {
+            matches!(
+                e.kind,
+                LexErrorKind::InvalidUnicodeEscape { .. } | LexErrorKind::InvalidHexEscape { .. }
+            )
+        })
+    }
+
+    pub(super) fn cook_template_head(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip leading ` and trailing {
+        let content = &text[1..text.len() - 1];
+        let content_offset = offset + 1;
+
+        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => self.interner.intern(content),
+        };
+        TokenKind::TemplateHead(name)
+    }
+
+    pub(super) fn cook_template_middle(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip leading } and trailing {
+        let content = &text[1..text.len() - 1];
+        let content_offset = offset + 1;
+
+        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => self.interner.intern(content),
+        };
+        TokenKind::TemplateMiddle(name)
+    }
+
+    pub(super) fn cook_template_tail(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip leading } and trailing `
+        let content = &text[1..text.len() - 1];
+        let content_offset = offset + 1;
+
+        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => self.interner.intern(content),
+        };
+        TokenKind::TemplateTail(name)
+    }
+
+    pub(super) fn cook_format_spec(&self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // The format spec token includes the leading `:` from the scanner.
+        // Strip it to get just the spec content.
+        let content = &text[1..];
+        TokenKind::FormatSpec(self.interner.intern(content))
+    }
+
+    pub(super) fn cook_template_complete(&mut self, offset: u32, len: u32) -> TokenKind {
+        let text = slice_source(self.source, offset, len);
+        // Strip both backticks
+        let content = &text[1..text.len() - 1];
+        let content_offset = offset + 1;
+
+        let name = match unescape_template_v2(content, content_offset, &mut self.errors) {
+            Some(unescaped) => self.interner.intern_owned(unescaped),
+            None => self.interner.intern(content),
+        };
+        TokenKind::TemplateFull(name)
+    }
+}
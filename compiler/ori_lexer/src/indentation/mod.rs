@@ -0,0 +1,94 @@
+//! Opt-in indentation checks.
+//!
+//! The main lexer loop treats whitespace as trivia and never looks at which
+//! characters make up an indent. When [`LexOptions::check_indentation`] is
+//! set, a separate pass over the source records each line's leading
+//! whitespace run and reports runs that mix tabs and spaces. Keeping this
+//! out of the main loop leaves the default path untouched.
+
+use ori_ir::Span;
+
+/// Options for [`lex_with_options()`](crate::lex_with_options).
+///
+/// The default enables nothing, producing the same output as
+/// [`lex_with_comments()`](crate::lex_with_comments).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LexOptions {
+    /// Report lines whose indentation mixes tabs and spaces.
+    pub check_indentation: bool,
+}
+
+/// The leading whitespace of one line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndentRun {
+    /// Span of the run itself, from the line start to the first other byte.
+    pub span: Span,
+    /// The run contains at least one tab.
+    pub has_tab: bool,
+    /// The run contains at least one space.
+    pub has_space: bool,
+}
+
+impl IndentRun {
+    /// Whether the run mixes tabs and spaces.
+    #[inline]
+    pub fn is_mixed(&self) -> bool {
+        self.has_tab && self.has_space
+    }
+}
+
+/// Collect the leading whitespace run of every indented line.
+///
+/// Lines without leading whitespace are omitted, as are whitespace-only
+/// lines: trailing whitespace on a blank line is not indentation.
+pub fn indent_runs(source: &str) -> Vec<IndentRun> {
+    let bytes = source.as_bytes();
+    let mut runs = Vec::new();
+    let mut line_start = 0;
+
+    while line_start < bytes.len() {
+        let mut end = line_start;
+        let (mut has_tab, mut has_space) = (false, false);
+        while let Some(&b) = bytes.get(end) {
+            match b {
+                b'\t' => has_tab = true,
+                b' ' => has_space = true,
+                _ => break,
+            }
+            end += 1;
+        }
+
+        let line_end = bytes[end..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |i| end + i);
+        let blank = bytes[end..line_end].iter().all(|&b| b == b'\r');
+        if end > line_start && !blank {
+            runs.push(IndentRun {
+                span: Span::new(to_u32(line_start), to_u32(end)),
+                has_tab,
+                has_space,
+            });
+        }
+        line_start = line_end + 1;
+    }
+    runs
+}
+
+/// Spans of indentation runs that mix tabs and spaces.
+pub fn mixed_indentation(source: &str) -> Vec<Span> {
+    indent_runs(source)
+        .into_iter()
+        .filter(IndentRun::is_mixed)
+        .map(|run| run.span)
+        .collect()
+}
+
+/// Saturate offsets past `u32::MAX`; the lexer already reports such files.
+#[inline]
+fn to_u32(offset: usize) -> u32 {
+    u32::try_from(offset).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn tab_indented_line_is_not_mixed() {
+    let runs = indent_runs("@f () -> int = {\n\t\t1\n}\n");
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].span, Span::new(17, 19));
+    assert!(runs[0].has_tab && !runs[0].has_space);
+    assert!(mixed_indentation("@f () -> int = {\n\t\t1\n}\n").is_empty());
+}
+
+#[test]
+fn space_indented_line_is_not_mixed() {
+    let runs = indent_runs("@f () -> int = {\n    1\n}\n");
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].span, Span::new(17, 21));
+    assert!(!runs[0].has_tab && runs[0].has_space);
+    assert!(mixed_indentation("@f () -> int = {\n    1\n}\n").is_empty());
+}
+
+#[test]
+fn mixed_line_reports_exact_run() {
+    let source = "@f () -> int = {\n  \t1\n    2\n}\n";
+    assert_eq!(mixed_indentation(source), vec![Span::new(17, 20)]);
+}
+
+#[test]
+fn whitespace_only_lines_are_ignored() {
+    assert!(indent_runs("a\n \t\nb\n\t \r\n").is_empty());
+}
+
+#[test]
+fn crlf_line_endings() {
+    let source = "a\r\n \tb\r\n";
+    assert_eq!(mixed_indentation(source), vec![Span::new(3, 5)]);
+}
@@ -8,7 +8,7 @@
 //!
 //! All types derive `Clone, Eq, PartialEq, Hash, Debug` for Salsa compatibility.
 
-use ori_ir::Span;
+use ori_ir::{NumericSuffix, Span};
 
 /// A lexer error with full context for diagnostic rendering.
 ///
@@ -35,12 +35,20 @@ pub enum LexErrorKind {
     UnterminatedChar,
     /// Missing closing `` ` `` for template literal.
     UnterminatedTemplate,
+    /// Missing closing `*/` for block comment.
+    UnterminatedBlockComment,
     /// Invalid escape in a string literal (e.g., `\q`).
     InvalidStringEscape { escape_char: char },
     /// Invalid escape in a char literal.
     InvalidCharEscape { escape_char: char },
     /// Invalid escape in a template literal.
     InvalidTemplateEscape { escape_char: char },
+    /// Malformed `\u{...}` escape (e.g., `\u{XYZ}`, `\u{D800}`).
+    InvalidUnicodeEscape { reason: &'static str },
+    /// Malformed `\xNN` escape (e.g., `\xG1`, `\x4`, `\xFF`).
+    InvalidHexEscape { reason: &'static str },
+    /// Non-ASCII character in a byte or byte string literal (e.g., `b'λ'`).
+    NonAsciiInByteLiteral { ch: char },
     /// `\'` used in a string literal — not valid per grammar line 102.
     SingleQuoteEscapeInString,
     /// `\"` used in a char literal — not valid per grammar line 127.
@@ -69,6 +77,10 @@ pub enum LexErrorKind {
     TrailingUnderscore,
     /// Consecutive underscores in numeric literal (e.g., `1__000`).
     ConsecutiveUnderscores,
+    /// Unknown or misplaced type suffix (e.g., `42i7`, `1.5u8`).
+    InvalidNumericSuffix { reason: &'static str },
+    /// Suffixed literal does not fit its type (e.g., `256u8`).
+    NumericLiteralOutOfRange { suffix: NumericSuffix },
 
     // Character errors
     /// Non-printable or invalid byte in source.
@@ -173,6 +185,10 @@ pub enum DocMarker {
     Description,
     /// `* name:` member marker (also used for legacy `@param`/`@field`).
     Member,
+    /// `@returns` marker.
+    Returns,
+    /// `@throws` marker.
+    Throws,
     /// `!` warning marker.
     Warning,
     /// `>` example marker.
@@ -228,6 +244,20 @@ impl LexError {
         }
     }
 
+    /// Create an unterminated triple-quoted string error.
+    ///
+    /// `span` covers only the opening `"""`: the literal runs to EOF, so the
+    /// full token span would point at the rest of the file.
+    #[cold]
+    pub fn unterminated_multiline_string(span: Span) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::UnterminatedString,
+            context: LexErrorContext::InsideString { start: span.start },
+            suggestions: vec![LexSuggestion::text("add closing `\"\"\"`", 0)],
+        }
+    }
+
     /// Create an unterminated char error.
     #[cold]
     pub fn unterminated_char(span: Span) -> Self {
@@ -253,6 +283,19 @@ impl LexError {
         }
     }
 
+    /// Create an unterminated block comment error.
+    ///
+    /// `span` covers only the opening `/*`, since the comment runs to EOF.
+    #[cold]
+    pub fn unterminated_block_comment(span: Span) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::UnterminatedBlockComment,
+            context: LexErrorContext::TopLevel,
+            suggestions: vec![LexSuggestion::text("add closing `*/`", 0)],
+        }
+    }
+
     /// Create an invalid string escape error.
     #[cold]
     pub fn invalid_string_escape(span: Span, escape_char: char) -> Self {
@@ -261,7 +304,7 @@ impl LexError {
             kind: LexErrorKind::InvalidStringEscape { escape_char },
             context: LexErrorContext::InsideString { start: span.start },
             suggestions: vec![LexSuggestion::text(
-                r#"valid escapes are: \n, \t, \r, \", \\, \0"#,
+                r#"valid escapes are: \n, \t, \r, \", \\, \0, \xNN, \u{...}"#,
                 1,
             )],
         }
@@ -275,7 +318,7 @@ impl LexError {
             kind: LexErrorKind::InvalidCharEscape { escape_char },
             context: LexErrorContext::InsideChar,
             suggestions: vec![LexSuggestion::text(
-                r"valid escapes are: \n, \t, \r, \', \\, \0",
+                r"valid escapes are: \n, \t, \r, \', \\, \0, \xNN, \u{...}",
                 1,
             )],
         }
@@ -298,6 +341,54 @@ impl LexError {
         }
     }
 
+    /// Create a malformed unicode escape error.
+    ///
+    /// Defaults to string context; char literals override it with
+    /// [`LexError::with_context`].
+    #[cold]
+    pub fn invalid_unicode_escape(span: Span, reason: &'static str) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::InvalidUnicodeEscape { reason },
+            context: LexErrorContext::InsideString { start: span.start },
+            suggestions: vec![LexSuggestion::text(
+                r"write unicode escapes as \u{...} with 1 to 6 hex digits, e.g. \u{1F600}",
+                1,
+            )],
+        }
+    }
+
+    /// Create a malformed hex escape error.
+    ///
+    /// Defaults to string context; char literals override it with
+    /// [`LexError::with_context`].
+    #[cold]
+    pub fn invalid_hex_escape(span: Span, reason: &'static str) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::InvalidHexEscape { reason },
+            context: LexErrorContext::InsideString { start: span.start },
+            suggestions: vec![LexSuggestion::text(
+                r"write hex escapes as \xNN with two hex digits up to \x7F, e.g. \x41",
+                1,
+            )],
+        }
+    }
+
+    /// Create a non-ASCII-in-byte-literal error.
+    #[cold]
+    pub fn non_ascii_in_byte_literal(span: Span, ch: char) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::NonAsciiInByteLiteral { ch },
+            context: LexErrorContext::TopLevel,
+            suggestions: vec![LexSuggestion::text(
+                r"byte literals are ASCII only; write other bytes as \xNN escapes",
+                0,
+            )],
+        }
+    }
+
     /// Create a single-quote-in-string error.
     #[cold]
     pub fn single_quote_escape_in_string(span: Span) -> Self {
@@ -381,6 +472,34 @@ impl LexError {
         }
     }
 
+    /// Create an invalid numeric suffix error.
+    #[cold]
+    pub fn invalid_numeric_suffix(span: Span, reason: &'static str) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::InvalidNumericSuffix { reason },
+            context: LexErrorContext::NumberLiteral,
+            suggestions: vec![LexSuggestion::text(
+                "valid suffixes are i8, i16, i32, i64, u8, u16, u32, u64, f32, and f64",
+                1,
+            )],
+        }
+    }
+
+    /// Create an out-of-range error for a suffixed numeric literal.
+    #[cold]
+    pub fn numeric_literal_out_of_range(span: Span, suffix: NumericSuffix) -> Self {
+        Self {
+            span,
+            kind: LexErrorKind::NumericLiteralOutOfRange { suffix },
+            context: LexErrorContext::NumberLiteral,
+            suggestions: vec![LexSuggestion::text(
+                "use a smaller value or a wider type suffix",
+                1,
+            )],
+        }
+    }
+
     /// Create an invalid byte error.
     #[cold]
     pub fn invalid_byte(span: Span, byte: u8) -> Self {
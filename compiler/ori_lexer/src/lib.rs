@@ -36,11 +36,13 @@
 //! - [`cooker`]: Token cooking layer
 //! - [`keywords`]: Keyword resolution
 //! - [`cook_escape`]: Spec-strict escape processing
+//! - [`indentation`]: Opt-in mixed tab/space indentation checks
 //! - [`lex_error`]: Lexer error types
 
 mod comments;
 mod cook_escape;
 mod cooker;
+pub mod indentation;
 mod keywords;
 pub mod lex_error;
 mod parse_helpers;
@@ -49,10 +51,11 @@ mod what_is_next;
 
 use comments::classify_and_normalize_comment;
 use cooker::TokenCooker;
+pub use indentation::LexOptions;
 use lex_error::{DetachedDocWarning, LexError};
 use ori_ir::{
-    Comment, CommentKind, CommentList, ModuleExtra, Span, StringInterner, Token, TokenFlags,
-    TokenKind, TokenList,
+    Comment, CommentKind, CommentList, LineIndex, ModuleExtra, Span, StringInterner, Token,
+    TokenFlags, TokenKind, TokenList,
 };
 use ori_lexer_core::{EncodingIssueKind, RawScanner, RawTag, SourceBuffer};
 
@@ -73,10 +76,16 @@ pub struct LexOutput {
     pub blank_lines: Vec<u32>,
     /// Byte positions of all newlines.
     pub newlines: Vec<u32>,
+    /// Line starts of the source, for mapping spans to line/column.
+    pub line_index: LineIndex,
     /// Accumulated lexer errors.
     pub errors: Vec<LexError>,
     /// Accumulated warnings (e.g., detached doc comments).
     pub warnings: Vec<DetachedDocWarning>,
+    /// Indentation runs mixing tabs and spaces.
+    ///
+    /// Only populated when [`LexOptions::check_indentation`] is set.
+    pub mixed_indentation: Vec<Span>,
 }
 
 impl std::fmt::Debug for LexOutput {
@@ -86,8 +95,10 @@ impl std::fmt::Debug for LexOutput {
             .field("comments", &self.comments.len())
             .field("blank_lines", &self.blank_lines.len())
             .field("newlines", &self.newlines.len())
+            .field("lines", &self.line_index.line_count())
             .field("errors", &self.errors.len())
             .field("warnings", &self.warnings.len())
+            .field("mixed_indentation", &self.mixed_indentation.len())
             .finish()
     }
 }
@@ -100,8 +111,10 @@ impl LexOutput {
             comments: CommentList::new(),
             blank_lines: Vec::new(),
             newlines: Vec::new(),
+            line_index: LineIndex::default(),
             errors: Vec::new(),
             warnings: Vec::new(),
+            mixed_indentation: Vec::new(),
         }
     }
 
@@ -117,8 +130,10 @@ impl LexOutput {
             comments: CommentList::new(),
             blank_lines: Vec::with_capacity(source_len / 400),
             newlines: Vec::with_capacity(source_len / 40),
+            line_index: LineIndex::default(),
             errors: Vec::new(),
             warnings: Vec::new(),
+            mixed_indentation: Vec::new(),
         }
     }
 
@@ -142,6 +157,7 @@ impl LexOutput {
             blank_lines: self.blank_lines,
             newlines: self.newlines,
             trailing_commas: Vec::new(), // filled in by the parser
+            doc_attachments: Vec::new(),
         }
     }
 
@@ -160,6 +176,7 @@ impl LexOutput {
             blank_lines: self.blank_lines,
             newlines: self.newlines,
             trailing_commas: Vec::new(),
+            doc_attachments: Vec::new(),
         };
         (self.tokens, metadata)
     }
@@ -212,6 +229,7 @@ pub fn lex(source: &str, interner: &StringInterner) -> TokenList {
 /// - Comments (classified by type)
 /// - Blank lines (for formatting preservation)
 /// - Newlines (for line counting)
+/// - Line starts (for mapping spans to line/column)
 ///
 /// Each token carries [`TokenFlags`] metadata capturing whitespace/trivia context.
 #[expect(
@@ -240,6 +258,10 @@ pub fn lex_with_comments(source: &str, interner: &StringInterner) -> LexOutput {
     let mut offset: u32 = 0;
     let mut last_significant_was_newline = false;
 
+    // Line starts for `output.line_index`, collected alongside `newlines`
+    let mut line_starts = Vec::with_capacity(source.len() / 40 + 1);
+    line_starts.push(0);
+
     // Trivia tracking for TokenFlags
     let mut pending_flags = TokenFlags::EMPTY;
 
@@ -259,6 +281,19 @@ pub fn lex_with_comments(source: &str, interner: &StringInterner) -> LexOutput {
 
         let token_span = make_span(offset, raw.len);
 
+        // Newlines inside multi-line literals and block comments produce no
+        // Newline token, so pick up their line starts here.
+        if raw.tag.may_span_lines() {
+            let slice = &source.as_bytes()[offset as usize..(offset + raw.len) as usize];
+            line_starts.extend(
+                slice
+                    .iter()
+                    .zip(offset..)
+                    .filter(|&(&b, _)| b == b'\n')
+                    .map(|(_, pos)| pos + 1),
+            );
+        }
+
         match raw.tag {
             // Accumulate trivia flags for the next significant token
             RawTag::Whitespace => {
@@ -296,9 +331,21 @@ pub fn lex_with_comments(source: &str, interner: &StringInterner) -> LexOutput {
                 last_significant_was_newline = false;
             }
 
+            // Block comments: captured verbatim (never docs)
+            RawTag::BlockComment => {
+                let slice = &source[offset as usize..(offset + raw.len) as usize];
+                let content = interner.intern(&slice[2..slice.len() - 2]);
+                output
+                    .comments
+                    .push(Comment::new(content, token_span, CommentKind::Block));
+                pending_flags.set(TokenFlags::TRIVIA_BEFORE);
+                last_significant_was_newline = false;
+            }
+
             // Newlines: emit + track
             RawTag::Newline => {
                 output.newlines.push(token_span.start);
+                line_starts.push(token_span.end);
 
                 if last_significant_was_newline {
                     output.blank_lines.push(token_span.start);
@@ -381,6 +428,8 @@ pub fn lex_with_comments(source: &str, interner: &StringInterner) -> LexOutput {
         .tokens
         .push_with_flags(Token::new(TokenKind::Eof, eof_span), eof_flags);
 
+    output.line_index = LineIndex::from_line_starts(line_starts);
+
     // Append accumulated cooker errors to the output (preserving encoding issue
     // errors already pushed during SourceBuffer construction).
     output.errors.extend(cooker.into_errors());
@@ -388,6 +437,20 @@ pub fn lex_with_comments(source: &str, interner: &StringInterner) -> LexOutput {
     output
 }
 
+/// Lex source code like [`lex_with_comments()`], with opt-in extra checks.
+///
+/// With [`LexOptions::default()`] the output is identical to
+/// [`lex_with_comments()`]. Setting `check_indentation` fills
+/// [`LexOutput::mixed_indentation`] with the span of every line indent
+/// that mixes tabs and spaces.
+pub fn lex_with_options(source: &str, interner: &StringInterner, options: LexOptions) -> LexOutput {
+    let mut output = lex_with_comments(source, interner);
+    if options.check_indentation {
+        output.mixed_indentation = indentation::mixed_indentation(source);
+    }
+    output
+}
+
 /// Create a span from offset and byte length.
 #[inline]
 fn make_span(offset: u32, len: u32) -> Span {
@@ -413,9 +476,11 @@ fn doc_comment_marker(kind: CommentKind) -> lex_error::DocMarker {
     match kind {
         CommentKind::DocDescription => lex_error::DocMarker::Description,
         CommentKind::DocMember => lex_error::DocMarker::Member,
+        CommentKind::DocReturns => lex_error::DocMarker::Returns,
+        CommentKind::DocThrows => lex_error::DocMarker::Throws,
         CommentKind::DocWarning => lex_error::DocMarker::Warning,
         CommentKind::DocExample => lex_error::DocMarker::Example,
-        CommentKind::Regular => lex_error::DocMarker::Plain,
+        CommentKind::Regular | CommentKind::Block => lex_error::DocMarker::Plain,
     }
 }
 
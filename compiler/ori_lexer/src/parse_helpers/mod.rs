@@ -27,5 +27,48 @@ pub(crate) fn parse_float_skip_underscores(s: &str) -> Option<f64> {
     }
 }
 
+/// Parse a hex float body (after `0x`): `1.8p3`, `1p-2`, `a_b.cp+1`.
+///
+/// The mantissa is accumulated exactly (up to 32 hex digits), converted to
+/// `f64` once, then scaled by the binary exponent.
+pub(crate) fn parse_hex_float_skip_underscores(s: &str) -> Option<f64> {
+    let (mantissa_part, exp_part) = s.split_once(['p', 'P'])?;
+    let mut mantissa: u128 = 0;
+    let mut frac_digits: i32 = 0;
+    let mut in_fraction = false;
+    for c in mantissa_part.chars() {
+        match c {
+            '_' => {}
+            '.' => in_fraction = true,
+            _ => {
+                let digit = c.to_digit(16)?;
+                mantissa = mantissa.checked_mul(16)?.checked_add(u128::from(digit))?;
+                if in_fraction {
+                    frac_digits += 1;
+                }
+            }
+        }
+    }
+    let exp: i32 = exp_part.replace('_', "").parse().ok()?;
+    let exp = exp.checked_sub(frac_digits.checked_mul(4)?)?;
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "rounding the mantissa to f64 is the intended conversion"
+    )]
+    let mut value = mantissa as f64;
+    // Scale in steps so large exponents don't overflow `powi` early
+    let mut exp = exp;
+    while exp > 1000 {
+        value *= 2f64.powi(1000);
+        exp -= 1000;
+    }
+    while exp < -1000 {
+        value *= 2f64.powi(-1000);
+        exp += 1000;
+    }
+    Some(value * 2f64.powi(exp))
+}
+
 #[cfg(test)]
 mod tests;
@@ -44,3 +44,25 @@ fn test_parse_float_skip_underscores() {
     assert_eq!(parse_float_skip_underscores("1_000.5"), Some(1000.5));
     assert_eq!(parse_float_skip_underscores("1.5e10"), Some(1.5e10));
 }
+
+#[test]
+fn test_parse_float_exponent_without_fraction() {
+    assert_eq!(parse_float_skip_underscores("1e10"), Some(1e10));
+    assert_eq!(parse_float_skip_underscores("1E-3"), Some(1e-3));
+    assert_eq!(parse_float_skip_underscores("2e+2"), Some(200.0));
+    assert_eq!(parse_float_skip_underscores("1_000e3"), Some(1e6));
+}
+
+#[test]
+fn test_parse_hex_float() {
+    assert_eq!(parse_hex_float_skip_underscores("1.8p3"), Some(12.0));
+    assert_eq!(parse_hex_float_skip_underscores("1p-2"), Some(0.25));
+    assert_eq!(parse_hex_float_skip_underscores("A_0.0P+0"), Some(160.0));
+    assert_eq!(
+        parse_hex_float_skip_underscores("1.fffffffffffffp1023"),
+        Some(f64::MAX)
+    );
+    assert_eq!(parse_hex_float_skip_underscores("1p-1074"), Some(5e-324));
+    // Missing exponent
+    assert_eq!(parse_hex_float_skip_underscores("1.8"), None);
+}
@@ -439,6 +439,23 @@ fn has_error_on_integer_overflow() {
     assert!(flags[0].has_error(), "overflow int should have HAS_ERROR");
 }
 
+#[test]
+fn integer_overflow_is_classified() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("99999999999999999999999 \\", &interner);
+    let kinds: Vec<_> = output.errors.iter().map(|e| &e.kind).collect();
+    // Overflow keeps its own kind and span, separate from a stray token
+    assert_eq!(
+        kinds,
+        [
+            &lex_error::LexErrorKind::IntOverflow,
+            &lex_error::LexErrorKind::StandaloneBackslash,
+        ]
+    );
+    assert_eq!(output.errors[0].span, Span::new(0, 23));
+    assert_eq!(output.tokens[0].kind, TokenKind::Error);
+}
+
 #[test]
 fn no_error_on_valid_token() {
     let interner = StringInterner::new();
@@ -892,3 +909,332 @@ fn hashbang_followed_by_ident() {
     assert!(matches!(tokens[1].kind, TokenKind::Ident(_)));
     assert_eq!(tokens[2].kind, TokenKind::Eof);
 }
+
+// === Multiline string tests ===
+
+#[test]
+fn multiline_string_two_lines() {
+    let interner = StringInterner::new();
+    let source = "let s = \"\"\"\n  first \"quoted\"\n  second\"\"\"\nnext";
+    let output = lex_with_comments(source, &interner);
+    assert!(!output.has_errors(), "{:?}", output.errors);
+    let tok = &output.tokens[3];
+    match &tok.kind {
+        TokenKind::String(name) => {
+            assert_eq!(interner.lookup(*name), "  first \"quoted\"\n  second");
+        }
+        other => panic!("expected String, got {other:?}"),
+    }
+    assert_eq!(tok.span, Span::new(8, 40));
+    // The literal's inner line break is not a Newline token
+    assert_eq!(output.tokens[4].kind, TokenKind::Newline);
+    assert!(matches!(output.tokens[5].kind, TokenKind::Ident(_)));
+}
+
+#[test]
+fn multiline_string_unterminated() {
+    let interner = StringInterner::new();
+    let source = "let s = \"\"\"abc\ndef\n";
+    let output = lex_with_comments(source, &interner);
+    assert_eq!(output.errors.len(), 1);
+    let err = &output.errors[0];
+    assert_eq!(err.kind, lex_error::LexErrorKind::UnterminatedString);
+    // Points at the opening delimiter rather than the rest of the file
+    assert_eq!(err.span, Span::new(8, 11));
+    // One error token covering the remainder, then EOF
+    assert_eq!(output.tokens[3].kind, TokenKind::Error);
+    assert_eq!(output.tokens[3].span, Span::new(8, 19));
+    assert_eq!(output.tokens[4].kind, TokenKind::Eof);
+}
+
+// === Template literal tests ===
+
+/// Lex `source` and return its token kinds, excluding the trailing EOF.
+fn template_kinds(source: &str, interner: &StringInterner) -> Vec<TokenKind> {
+    let tokens = lex(source, interner);
+    tokens
+        .iter()
+        .map(|t| t.kind.clone())
+        .filter(|k| *k != TokenKind::Eof)
+        .collect()
+}
+
+#[test]
+fn template_interpolation_segments() {
+    let interner = StringInterner::new();
+    let kinds = template_kinds("`x={x}, y={y}!`", &interner);
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::TemplateHead(interner.intern("x=")),
+            TokenKind::Ident(interner.intern("x")),
+            TokenKind::TemplateMiddle(interner.intern(", y=")),
+            TokenKind::Ident(interner.intern("y")),
+            TokenKind::TemplateTail(interner.intern("!")),
+        ]
+    );
+}
+
+#[test]
+fn template_interpolation_expression() {
+    let interner = StringInterner::new();
+    let kinds = template_kinds("`{a + 1}`", &interner);
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::TemplateHead(interner.intern("")),
+            TokenKind::Ident(interner.intern("a")),
+            TokenKind::Plus,
+            TokenKind::Int(1),
+            TokenKind::TemplateTail(interner.intern("")),
+        ]
+    );
+}
+
+#[test]
+fn template_escaped_braces_are_literal() {
+    let interner = StringInterner::new();
+    assert_eq!(
+        template_kinds("`{{x}}`", &interner),
+        vec![TokenKind::TemplateFull(interner.intern("{x}"))]
+    );
+    assert_eq!(
+        template_kinds("`{{{x}}}`", &interner),
+        vec![
+            TokenKind::TemplateHead(interner.intern("{")),
+            TokenKind::Ident(interner.intern("x")),
+            TokenKind::TemplateTail(interner.intern("}")),
+        ]
+    );
+}
+
+#[test]
+fn regular_string_braces_are_literal() {
+    let interner = StringInterner::new();
+    assert_eq!(
+        template_kinds("\"x={x}\"", &interner),
+        vec![TokenKind::String(interner.intern("x={x}"))]
+    );
+}
+
+// === Block comment tests ===
+
+#[test]
+fn block_comment_single_line() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("let /* note */ x = 1", &interner);
+    assert!(!output.has_errors());
+    // let, x, =, 1, EOF: the comment is trivia
+    assert_eq!(output.tokens.len(), 5);
+    assert_eq!(output.comments.len(), 1);
+    let comment = &output.comments[0];
+    assert_eq!(comment.kind, CommentKind::Block);
+    assert_eq!(comment.span, Span::new(4, 14));
+    assert_eq!(interner.lookup(comment.content), " note ");
+    assert!(output.tokens.flags()[1].has_trivia_before());
+}
+
+#[test]
+fn block_comment_multiline() {
+    let interner = StringInterner::new();
+    let source = "/* line one\n   line two */\nlet x = 1";
+    let output = lex_with_comments(source, &interner);
+    assert!(!output.has_errors());
+    assert_eq!(output.comments.len(), 1);
+    assert_eq!(output.comments[0].span, Span::new(0, 26));
+    assert_eq!(output.comments[0].kind, CommentKind::Block);
+    assert_eq!(
+        interner.lookup(output.comments[0].content),
+        " line one\n   line two "
+    );
+    // Only the newline after the comment is a token
+    assert_eq!(output.tokens[0].kind, TokenKind::Newline);
+    assert_eq!(output.tokens[1].kind, TokenKind::Let);
+}
+
+#[test]
+fn block_comment_nested() {
+    let interner = StringInterner::new();
+    let source = "/* a /* b */ c */ x";
+    let output = lex_with_comments(source, &interner);
+    assert!(!output.has_errors());
+    assert_eq!(output.comments.len(), 1);
+    assert_eq!(output.comments[0].span, Span::new(0, 17));
+    assert_eq!(interner.lookup(output.comments[0].content), " a /* b */ c ");
+    // x, EOF
+    assert_eq!(output.tokens.len(), 2);
+    assert!(matches!(output.tokens[0].kind, TokenKind::Ident(_)));
+}
+
+#[test]
+fn block_comment_unterminated() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("x /* a /* b */\nc", &interner);
+    assert_eq!(output.errors.len(), 1);
+    let err = &output.errors[0];
+    assert_eq!(err.kind, lex_error::LexErrorKind::UnterminatedBlockComment);
+    assert_eq!(err.span, Span::new(2, 4));
+    assert!(output.comments.is_empty());
+    // x, error, EOF
+    assert_eq!(output.tokens.len(), 3);
+    assert_eq!(output.tokens[1].kind, TokenKind::Error);
+}
+
+// === Type-suffixed numbers ===
+
+#[test]
+fn suffixed_numbers_in_expression() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("255u8 + 3.0f32", &interner);
+    assert!(!output.has_errors(), "{:?}", output.errors);
+    assert_eq!(
+        output.tokens[0].kind,
+        TokenKind::SuffixedNumber(255, ori_ir::NumericSuffix::U8)
+    );
+    assert_eq!(output.tokens[0].span, Span::new(0, 5));
+    assert_eq!(
+        output.tokens[2].kind,
+        TokenKind::SuffixedNumber(3.0f64.to_bits(), ori_ir::NumericSuffix::F32)
+    );
+}
+
+#[test]
+fn suffixed_number_out_of_range_flagged() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("256u8", &interner);
+    assert_eq!(output.errors.len(), 1);
+    assert_eq!(output.tokens[0].kind, TokenKind::Error);
+    assert!(output.tokens.flags()[0].has_error());
+}
+
+// === Unterminated literals ===
+
+#[test]
+fn unterminated_string_single_error_token() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("let s = \"abc\nnext", &interner);
+    assert_eq!(output.errors.len(), 1);
+    assert_eq!(
+        output.errors[0].kind,
+        lex_error::LexErrorKind::UnterminatedString
+    );
+    // From the opening quote to the end of the line
+    assert_eq!(output.errors[0].span, Span::new(8, 12));
+    let error_tokens: Vec<_> = output
+        .tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Error)
+        .collect();
+    assert_eq!(error_tokens.len(), 1);
+    assert_eq!(error_tokens[0].span, Span::new(8, 12));
+}
+
+#[test]
+fn unterminated_char_single_error_token() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("let c = 'abc\nnext", &interner);
+    assert_eq!(output.errors.len(), 1);
+    assert_eq!(
+        output.errors[0].kind,
+        lex_error::LexErrorKind::UnterminatedChar
+    );
+    assert_eq!(output.errors[0].span, Span::new(8, 12));
+    let error_tokens: Vec<_> = output
+        .tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Error)
+        .collect();
+    assert_eq!(error_tokens.len(), 1);
+    assert_eq!(error_tokens[0].span, Span::new(8, 12));
+    // Lexing resumes on the next line
+    assert_eq!(output.tokens[4].kind, TokenKind::Newline);
+}
+
+#[test]
+fn hex_escape_followed_by_text_is_reported() {
+    // The escape ends after two digits, so the literal holds more than one
+    // character rather than silently dropping `zz`.
+    let interner = StringInterner::new();
+    for source in [r"'\x41zz'", r"b'\x41zz'"] {
+        let output = lex_with_comments(source, &interner);
+        assert_eq!(output.errors.len(), 1, "{source}");
+        assert_eq!(
+            output.errors[0].kind,
+            lex_error::LexErrorKind::SingleQuoteString,
+            "{source}"
+        );
+        assert_eq!(output.tokens[0].kind, TokenKind::Error, "{source}");
+    }
+}
+
+#[test]
+fn single_quoted_string_reported_as_such() {
+    let interner = StringInterner::new();
+    let output = lex_with_comments("'hello'", &interner);
+    assert_eq!(output.errors.len(), 1);
+    assert_eq!(
+        output.errors[0].kind,
+        lex_error::LexErrorKind::SingleQuoteString
+    );
+    assert_eq!(output.errors[0].span, Span::new(0, 7));
+}
+
+// === Line index ===
+
+#[test]
+fn line_index_maps_token_spans() {
+    let interner = StringInterner::new();
+    // The block comment and multiline string hide newlines from the token
+    // stream; the index still counts them.
+    let source = "let a = 1\r\n/* x\n */ let s = \"\"\"\nhi\"\"\"\nb";
+    let output = lex_with_comments(source, &interner);
+    assert!(!output.has_errors(), "{:?}", output.errors);
+    let index = &output.line_index;
+    assert_eq!(index.line_count(), 5);
+    // `a` on line 1
+    assert_eq!(output.tokens[1].span.line_col(index), (1, 5));
+    // `let` after the block comment on line 3
+    assert_eq!(output.tokens[5].span.line_col(index), (3, 5));
+    // `b` on the last line, no trailing newline
+    let b = &output.tokens[output.tokens.len() - 2];
+    assert_eq!(b.span.line_col(index), (5, 1));
+}
+
+#[test]
+fn line_index_matches_full_scan() {
+    let interner = StringInterner::new();
+    let sources = [
+        "",
+        "a\n\nb\n",
+        "let t = `x\n{y}\nz`\n/* a\r\nb */ c",
+        "let s = \"\"\"\nunterminated",
+        "/* open\ncomment",
+    ];
+    for source in sources {
+        let output = lex_with_comments(source, &interner);
+        assert_eq!(output.line_index, LineIndex::new(source), "{source:?}");
+    }
+}
+
+// === Lex options ===
+
+#[test]
+fn indentation_check_is_off_by_default() {
+    let interner = StringInterner::new();
+    let source = "@f () -> int = {\n \t1\n}\n";
+    let plain = lex_with_options(source, &interner, LexOptions::default());
+    assert!(plain.mixed_indentation.is_empty());
+    assert_eq!(plain, lex_with_comments(source, &interner));
+}
+
+#[test]
+fn indentation_check_reports_mixed_lines() {
+    let interner = StringInterner::new();
+    let source = "@f () -> int = {\n\tlet a = 1;\n    let b = 2;\n \ta + b\n}\n";
+    let options = LexOptions {
+        check_indentation: true,
+    };
+    let output = lex_with_options(source, &interner, options);
+    assert_eq!(output.mixed_indentation, vec![Span::new(44, 46)]);
+    assert!(!output.has_errors());
+}
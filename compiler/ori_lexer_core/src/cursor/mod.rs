@@ -198,6 +198,17 @@ impl<'a> Cursor<'a> {
         self.buf[self.pos as usize + 2]
     }
 
+    /// Returns the byte `n` positions ahead of current.
+    ///
+    /// Reads past the end of the buffer return `0x00`, matching the padding.
+    #[inline]
+    pub fn peek_n(&self, n: u32) -> u8 {
+        self.buf
+            .get(self.pos as usize + n as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Advance the cursor by one byte.
     #[inline]
     pub fn advance(&mut self) {
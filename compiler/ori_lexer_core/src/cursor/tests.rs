@@ -646,7 +646,7 @@ fn swar_matches_scalar_basic_cases() {
     for case in cases {
         let scalar = scalar_count_whitespace(case);
         let swar = swar_count_whitespace(case);
-        assert_eq!(scalar, swar, "scalar={scalar} != swar={swar} for {case:?}",);
+        assert_eq!(scalar, swar, "scalar={scalar} != swar={swar} for {case:?}");
     }
 }
 
@@ -68,6 +68,8 @@ impl<'a> RawScanner<'a> {
             b' ' | b'\t' => self.whitespace(start),
             b'\r' => self.carriage_return(start),
             b'\n' => self.newline(start),
+            b'r' => self.ident_or_raw_string(start),
+            b'b' => self.ident_or_byte_literal(start),
             b'a'..=b'z' | b'A'..=b'Z' => self.identifier(start),
             b'_' => self.underscore_or_ident(start),
             b'0'..=b'9' => self.number(start),
@@ -177,6 +179,9 @@ impl<'a> RawScanner<'a> {
                 tag: RawTag::LineComment,
                 len: self.cursor.pos() - start,
             }
+        } else if self.cursor.current() == b'*' {
+            self.cursor.advance(); // consume '*'
+            self.block_comment(start)
         } else {
             RawToken {
                 tag: RawTag::Slash,
@@ -185,6 +190,40 @@ impl<'a> RawScanner<'a> {
         }
     }
 
+    /// Scan a block comment body after the opening `/*`.
+    ///
+    /// Block comments nest: each inner `/*` needs its own `*/`, so
+    /// `/* a /* b */ c */` is a single comment. Byte-wise scanning is safe
+    /// for UTF-8 content because continuation bytes never equal `/` or `*`.
+    fn block_comment(&mut self, start: u32) -> RawToken {
+        let mut depth = 1u32;
+        loop {
+            match (self.cursor.current(), self.cursor.peek()) {
+                (b'/', b'*') => {
+                    self.cursor.advance_n(2);
+                    depth += 1;
+                }
+                (b'*', b'/') => {
+                    self.cursor.advance_n(2);
+                    depth -= 1;
+                    if depth == 0 {
+                        return RawToken {
+                            tag: RawTag::BlockComment,
+                            len: self.cursor.pos() - start,
+                        };
+                    }
+                }
+                (0, _) if self.cursor.is_eof() => {
+                    return RawToken {
+                        tag: RawTag::UnterminatedBlockComment,
+                        len: self.cursor.pos() - start,
+                    };
+                }
+                _ => self.cursor.advance(),
+            }
+        }
+    }
+
     // ─── Identifiers ───────────────────────────────────────────────
 
     #[inline]
@@ -197,6 +236,41 @@ impl<'a> RawScanner<'a> {
         }
     }
 
+    /// `r` starts a raw string only when followed by `#`s and then `"`;
+    /// otherwise it is an ordinary identifier (`r`, `result`).
+    fn ident_or_raw_string(&mut self, start: u32) -> RawToken {
+        let mut hashes = 0;
+        while self.cursor.peek_n(1 + hashes) == b'#' {
+            hashes += 1;
+        }
+        if self.cursor.peek_n(1 + hashes) == b'"' {
+            self.cursor.advance_n(2 + hashes); // consume `r`, hashes, opening '"'
+            self.raw_string(start, hashes)
+        } else {
+            self.identifier(start)
+        }
+    }
+
+    /// `b'` starts a byte literal and `b"` a byte string; the body is
+    /// scanned like a char or string literal and validated by the cooker.
+    fn ident_or_byte_literal(&mut self, start: u32) -> RawToken {
+        let tag = match self.cursor.peek() {
+            b'\'' => RawTag::Byte,
+            b'"' => RawTag::ByteString,
+            _ => return self.identifier(start),
+        };
+        self.cursor.advance(); // consume `b`
+        let token = if tag == RawTag::Byte {
+            self.char_literal(start)
+        } else {
+            self.quoted_string(start)
+        };
+        match token.tag {
+            RawTag::Char | RawTag::String => RawToken { tag, ..token },
+            _ => token,
+        }
+    }
+
     fn underscore_or_ident(&mut self, start: u32) -> RawToken {
         self.cursor.advance(); // consume '_'
         if is_ident_continue(self.cursor.current()) {
@@ -595,12 +669,46 @@ impl<'a> RawScanner<'a> {
         self.cursor.advance(); // consume 'x' or 'X'
         self.cursor
             .eat_while(|b| b.is_ascii_hexdigit() || b == b'_');
+        if let Some(exp_at) = self.hex_float_exponent_at() {
+            self.cursor.advance_n(exp_at + 1); // fraction and `p`
+            if matches!(self.cursor.current(), b'+' | b'-') {
+                self.cursor.advance();
+            }
+            self.eat_decimal_digits();
+            return RawToken {
+                tag: RawTag::HexFloat,
+                len: self.cursor.pos() - start,
+            };
+        }
         RawToken {
             tag: RawTag::HexInt,
             len: self.cursor.pos() - start,
         }
     }
 
+    /// After the integer digits of a hex literal, find the `p` of a hex
+    /// float exponent (`0x1.8p3`, `0x1p-2`) as an offset from the cursor.
+    ///
+    /// The exponent is required, so `0xFF.abs()` stays a method call on a
+    /// hex integer.
+    fn hex_float_exponent_at(&self) -> Option<u32> {
+        let mut n = 0;
+        if self.cursor.current() == b'.' && self.cursor.peek().is_ascii_hexdigit() {
+            n = 1;
+            while self.cursor.peek_n(n).is_ascii_hexdigit() || self.cursor.peek_n(n) == b'_' {
+                n += 1;
+            }
+        }
+        if !matches!(self.cursor.peek_n(n), b'p' | b'P') {
+            return None;
+        }
+        let exp = match self.cursor.peek_n(n + 1) {
+            b'+' | b'-' => self.cursor.peek_n(n + 2),
+            b => b,
+        };
+        exp.is_ascii_digit().then_some(n)
+    }
+
     fn bin_number(&mut self, start: u32) -> RawToken {
         self.cursor.advance(); // consume 'b' or 'B'
         self.cursor
@@ -625,12 +733,22 @@ impl<'a> RawScanner<'a> {
         }
     }
 
-    /// Check for duration/size suffix after a numeric literal.
+    /// Check for duration/size/type suffix after a numeric literal.
     /// `is_float` indicates whether a decimal point was consumed.
     fn check_suffix(&mut self, start: u32, is_float: bool) -> RawToken {
         let default_tag = if is_float { RawTag::Float } else { RawTag::Int };
 
         match self.cursor.current() {
+            // i32, u8, f64 — type suffixes. The whole identifier tail is taken
+            // so the cooker can reject unknown widths like `42i7` as one token.
+            b'i' | b'u' | b'f' if self.cursor.peek().is_ascii_digit() => {
+                self.cursor.advance();
+                self.cursor.eat_while(is_ident_continue);
+                RawToken {
+                    tag: RawTag::SuffixedNumber,
+                    len: self.cursor.pos() - start,
+                }
+            }
             // ns, us — 2-char duration suffixes
             b'n' | b'u'
                 if self.cursor.peek() == b's' && !is_ident_continue(self.cursor.peek2()) =>
@@ -641,6 +759,19 @@ impl<'a> RawScanner<'a> {
                     len: self.cursor.pos() - start,
                 }
             }
+            // kib, mib, gib, tib — binary size suffixes. Checked before the
+            // `m` arm so `5mib` is not split into `5m` + `ib`.
+            b'k' | b'm' | b'g' | b't'
+                if self.cursor.peek() == b'i'
+                    && self.cursor.peek2() == b'b'
+                    && !is_ident_continue(self.cursor.peek_n(3)) =>
+            {
+                self.cursor.advance_n(3);
+                RawToken {
+                    tag: RawTag::Size,
+                    len: self.cursor.pos() - start,
+                }
+            }
             // m, ms, mb — minutes / milliseconds / megabytes
             b'm' => match self.cursor.peek() {
                 b's' if !is_ident_continue(self.cursor.peek2()) => {
@@ -705,6 +836,14 @@ impl<'a> RawScanner<'a> {
     // ─── String & Char Literals ────────────────────────────────────
 
     fn string(&mut self, start: u32) -> RawToken {
+        if self.cursor.peek() == b'"' && self.cursor.peek2() == b'"' {
+            return self.multiline_string(start);
+        }
+        self.quoted_string(start)
+    }
+
+    /// Scan a single-line `"..."` body; the cursor is on the opening `"`.
+    fn quoted_string(&mut self, start: u32) -> RawToken {
         self.cursor.advance(); // consume opening '"'
         loop {
             // SIMD-accelerated skip past ordinary string content
@@ -744,6 +883,84 @@ impl<'a> RawScanner<'a> {
         }
     }
 
+    /// Scan a triple-quoted string. Newlines and lone `"` are content; the
+    /// literal closes at the first unescaped `"""`. An unterminated literal
+    /// runs to EOF as a single `UnterminatedString` token.
+    fn multiline_string(&mut self, start: u32) -> RawToken {
+        self.cursor.advance_n(3); // consume opening '"""'
+        loop {
+            match self.cursor.skip_to_string_delim() {
+                b'"' => {
+                    if self.cursor.peek() == b'"' && self.cursor.peek2() == b'"' {
+                        self.cursor.advance_n(3); // consume closing '"""'
+                        return RawToken {
+                            tag: RawTag::MultilineString,
+                            len: self.cursor.pos() - start,
+                        };
+                    }
+                    self.cursor.advance();
+                }
+                b'\\' => {
+                    self.cursor.advance(); // consume '\'
+                    if self.cursor.current() != 0 || !self.cursor.is_eof() {
+                        self.cursor.advance(); // skip escaped char
+                    }
+                }
+                b'\n' | b'\r' => self.cursor.advance(),
+                0 => {
+                    if self.cursor.is_eof() {
+                        return RawToken {
+                            tag: RawTag::UnterminatedString,
+                            len: self.cursor.pos() - start,
+                        };
+                    }
+                    self.cursor.advance(); // interior null
+                }
+                _ => unreachable!("skip_to_string_delim returned unexpected byte"),
+            }
+        }
+    }
+
+    /// Scan raw string content after the opening delimiter.
+    ///
+    /// Backslashes are ordinary content. A `"` closes the literal only when
+    /// followed by exactly as many `#`s as the opening delimiter had, so
+    /// `r#"say "hi""#` keeps its inner quotes. Like ordinary strings, raw
+    /// strings may not span lines.
+    fn raw_string(&mut self, start: u32, hashes: u32) -> RawToken {
+        loop {
+            match self.cursor.skip_to_string_delim() {
+                b'"' => {
+                    self.cursor.advance(); // consume '"'
+                    if (0..hashes).all(|i| self.cursor.peek_n(i) == b'#') {
+                        self.cursor.advance_n(hashes);
+                        return RawToken {
+                            tag: RawTag::RawString,
+                            len: self.cursor.pos() - start,
+                        };
+                    }
+                }
+                b'\\' => self.cursor.advance(),
+                b'\n' | b'\r' => {
+                    return RawToken {
+                        tag: RawTag::UnterminatedString,
+                        len: self.cursor.pos() - start,
+                    };
+                }
+                0 => {
+                    if self.cursor.is_eof() {
+                        return RawToken {
+                            tag: RawTag::UnterminatedString,
+                            len: self.cursor.pos() - start,
+                        };
+                    }
+                    self.cursor.advance();
+                }
+                _ => unreachable!("skip_to_string_delim returned unexpected byte"),
+            }
+        }
+    }
+
     fn char_literal(&mut self, start: u32) -> RawToken {
         self.cursor.advance(); // consume opening '\''
 
@@ -752,7 +969,32 @@ impl<'a> RawScanner<'a> {
         match self.cursor.current() {
             b'\\' => {
                 self.cursor.advance(); // consume '\'
-                if self.cursor.current() != 0 || !self.cursor.is_eof() {
+                if self.cursor.current() == b'u' && self.cursor.peek() == b'{' {
+                    // `\u{...}`: consume through `}`; the cooker validates the body
+                    self.cursor.advance();
+                    self.cursor.advance();
+                    while self.cursor.current().is_ascii_alphanumeric() {
+                        self.cursor.advance();
+                    }
+                    if self.cursor.current() == b'}' {
+                        self.cursor.advance();
+                    }
+                } else if self.cursor.current() == b'x' {
+                    // `\xNN`: a complete escape ends after two hex digits, so
+                    // `'\x41zz'` is unterminated. A malformed one (`'\xZZ'`)
+                    // is consumed whole for the cooker to report.
+                    self.cursor.advance();
+                    let mut digits = 0;
+                    while digits < 2 && self.cursor.current().is_ascii_hexdigit() {
+                        self.cursor.advance();
+                        digits += 1;
+                    }
+                    if digits < 2 {
+                        while self.cursor.current().is_ascii_alphanumeric() {
+                            self.cursor.advance();
+                        }
+                    }
+                } else if self.cursor.current() != 0 || !self.cursor.is_eof() {
                     self.cursor.advance(); // skip escaped char (always ASCII)
                 }
             }
@@ -783,11 +1025,32 @@ impl<'a> RawScanner<'a> {
                 len: self.cursor.pos() - start,
             }
         } else {
-            RawToken {
-                tag: RawTag::UnterminatedChar,
-                len: self.cursor.pos() - start,
+            self.unterminated_char_rest(start)
+        }
+    }
+
+    /// Recover from a char literal that did not close after one character.
+    ///
+    /// Consumes the rest of the line as a single `UnterminatedChar` token so
+    /// `'abc` does not decompose into an error plus an identifier. A `'`
+    /// later on the line (`'abc'`) is included in the token.
+    #[cold]
+    fn unterminated_char_rest(&mut self, start: u32) -> RawToken {
+        loop {
+            match self.cursor.current() {
+                b'\'' => {
+                    self.cursor.advance();
+                    break;
+                }
+                b'\n' | b'\r' => break,
+                0 if self.cursor.is_eof() => break,
+                _ => self.cursor.advance_char(),
             }
         }
+        RawToken {
+            tag: RawTag::UnterminatedChar,
+            len: self.cursor.pos() - start,
+        }
     }
 
     // ─── Template Literals ─────────────────────────────────────────
@@ -400,6 +400,27 @@ fn hex_literals() {
     assert_eq!(scan_tags("0X1A"), vec![RawTag::HexInt]);
 }
 
+#[test]
+fn hex_float_literals() {
+    assert_eq!(scan_tags("0x1.8p3"), vec![RawTag::HexFloat]);
+    assert_eq!(scan_tags("0x1p-2"), vec![RawTag::HexFloat]);
+    assert_eq!(scan_tags("0xA_B.C_DP+10"), vec![RawTag::HexFloat]);
+    // No binary exponent: stays an integer followed by a method call
+    assert_eq!(
+        scan_tags("0xFF.abs"),
+        vec![RawTag::HexInt, RawTag::Dot, RawTag::Ident]
+    );
+    // Exponent marker without digits is not a float
+    assert_eq!(scan_tags("0x1p")[0], RawTag::HexInt);
+}
+
+#[test]
+fn exponent_without_fraction() {
+    assert_eq!(scan_tags("1e10"), vec![RawTag::Float]);
+    assert_eq!(scan_tags("1E-3"), vec![RawTag::Float]);
+    assert_eq!(scan_tags("2e+2"), vec![RawTag::Float]);
+}
+
 #[test]
 fn binary_literals() {
     assert_eq!(scan_tags("0b1010"), vec![RawTag::BinInt]);
@@ -486,6 +507,19 @@ fn size_integer() {
     assert_eq!(scan_tags("5mb"), vec![RawTag::Size]);
     assert_eq!(scan_tags("2gb"), vec![RawTag::Size]);
     assert_eq!(scan_tags("1tb"), vec![RawTag::Size]);
+    assert_eq!(scan_tags("5tb"), vec![RawTag::Size]);
+}
+
+#[test]
+fn size_binary_units() {
+    assert_eq!(scan_tags("1kib"), vec![RawTag::Size]);
+    assert_eq!(scan("1kib")[0].len, 4);
+    assert_eq!(scan_tags("2mib"), vec![RawTag::Size]);
+    assert_eq!(scan_tags("3gib"), vec![RawTag::Size]);
+    assert_eq!(scan_tags("4tib"), vec![RawTag::Size]);
+    assert_eq!(scan("1.5mib")[0].len, 6);
+    // `kibi` is not a unit
+    assert_eq!(scan_tags("1kibi"), vec![RawTag::Int, RawTag::Ident]);
 }
 
 #[test]
@@ -506,6 +540,74 @@ fn size_suffix_not_consumed_if_followed_by_ident() {
     assert_eq!(tags, vec![RawTag::Int, RawTag::Ident]);
 }
 
+// ─── Type Suffixes ─────────────────────────────────────────────
+
+#[test]
+fn type_suffixed_numbers() {
+    assert_eq!(scan_tags("42i32"), vec![RawTag::SuffixedNumber]);
+    assert_eq!(scan_tags("255u8"), vec![RawTag::SuffixedNumber]);
+    assert_eq!(scan_tags("3.0f32"), vec![RawTag::SuffixedNumber]);
+    assert_eq!(scan("3.0f32")[0].len, 6);
+    assert_eq!(scan_tags("1e5f64"), vec![RawTag::SuffixedNumber]);
+}
+
+#[test]
+fn type_suffix_takes_whole_tail() {
+    // Unknown widths stay one token so the cooker can reject them
+    assert_eq!(scan_tags("42i7"), vec![RawTag::SuffixedNumber]);
+    assert_eq!(scan("42i32x")[0].len, 6);
+}
+
+#[test]
+fn type_suffix_needs_digit() {
+    // `us` is still a duration; `5u` and `5if` are Int + Ident
+    assert_eq!(scan_tags("5us"), vec![RawTag::Duration]);
+    assert_eq!(scan_tags("5u"), vec![RawTag::Int, RawTag::Ident]);
+    assert_eq!(scan_tags("5if"), vec![RawTag::Int, RawTag::Ident]);
+}
+
+// ─── Block Comments ────────────────────────────────────────────
+
+#[test]
+fn block_comment_single_line() {
+    assert_eq!(
+        scan_tags("/* note */x"),
+        vec![RawTag::BlockComment, RawTag::Ident]
+    );
+    assert_eq!(scan("/* note */x")[0].len, 10);
+}
+
+#[test]
+fn block_comment_multiline() {
+    let src = "/* one\ntwo */";
+    assert_eq!(scan_tags(src), vec![RawTag::BlockComment]);
+    assert_eq!(scan(src)[0].len, 13);
+}
+
+#[test]
+fn block_comment_nested() {
+    let src = "/* a /* b */ c */ d";
+    let tokens = scan(src);
+    assert_eq!(tokens[0].tag, RawTag::BlockComment);
+    assert_eq!(tokens[0].len, 17);
+    assert_eq!(scan_tags(src)[1..], [RawTag::Whitespace, RawTag::Ident]);
+}
+
+#[test]
+fn block_comment_unterminated() {
+    assert_eq!(
+        scan_tags("/* a /* b */ c"),
+        vec![RawTag::UnterminatedBlockComment]
+    );
+    assert_eq!(scan_tags("/*"), vec![RawTag::UnterminatedBlockComment]);
+}
+
+#[test]
+fn slash_star_adjacent_operators() {
+    // `*/` outside a comment is still two operators
+    assert_eq!(scan_tags("*/"), vec![RawTag::Star, RawTag::Slash]);
+}
+
 // ─── String Literals ───────────────────────────────────────────
 
 #[test]
@@ -547,6 +649,124 @@ fn adjacent_strings() {
     );
 }
 
+// ─── Multiline String Literals ─────────────────────────────────
+
+#[test]
+fn multiline_string_two_lines() {
+    let src = "\"\"\"line one\nline two\"\"\"";
+    assert_eq!(scan_tags(src), vec![RawTag::MultilineString]);
+    assert_eq!(scan(src)[0].len, 23);
+}
+
+#[test]
+fn multiline_string_embedded_quotes() {
+    // Lone and doubled quotes are content; an escaped quote cannot start the close
+    let src = "\"\"\"a \"b\" \"\"c\\\"\"\"\"";
+    assert_eq!(scan_tags(src), vec![RawTag::MultilineString]);
+}
+
+#[test]
+fn multiline_string_unterminated() {
+    let src = "\"\"\"abc\ndef\n";
+    let tokens = scan(src);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].tag, RawTag::UnterminatedString);
+    assert_eq!(tokens[0].len, 11);
+}
+
+#[test]
+fn empty_string_is_not_triple_quote() {
+    assert_eq!(
+        scan_tags("\"\" + x"),
+        vec![
+            RawTag::String,
+            RawTag::Whitespace,
+            RawTag::Plus,
+            RawTag::Whitespace,
+            RawTag::Ident
+        ]
+    );
+}
+
+// ─── Byte Literals ─────────────────────────────────────────────
+
+#[test]
+fn byte_literal() {
+    assert_eq!(scan_tags("b'A'"), vec![RawTag::Byte]);
+    assert_eq!(scan("b'A'")[0].len, 4);
+    assert_eq!(scan_tags("b'\\n'"), vec![RawTag::Byte]);
+    assert_eq!(scan_tags("b'\\xFF'"), vec![RawTag::Byte]);
+    // Non-ASCII is scanned as one literal; the cooker rejects it
+    assert_eq!(scan_tags("b'λ'"), vec![RawTag::Byte]);
+}
+
+#[test]
+fn byte_string_literal() {
+    assert_eq!(scan_tags("b\"abc\""), vec![RawTag::ByteString]);
+    assert_eq!(scan("b\"abc\"")[0].len, 6);
+    assert_eq!(scan_tags("b\"\\xFF\\\"\""), vec![RawTag::ByteString]);
+}
+
+#[test]
+fn byte_literal_unterminated() {
+    assert_eq!(scan_tags("b'A"), vec![RawTag::UnterminatedChar]);
+    assert_eq!(scan_tags("b\"abc"), vec![RawTag::UnterminatedString]);
+}
+
+#[test]
+fn b_identifier_not_byte_literal() {
+    assert_eq!(scan_tags("b"), vec![RawTag::Ident]);
+    assert_eq!(scan_tags("bytes"), vec![RawTag::Ident]);
+    assert_eq!(
+        scan_tags("b \"x\""),
+        vec![RawTag::Ident, RawTag::Whitespace, RawTag::String]
+    );
+}
+
+// ─── Raw String Literals ───────────────────────────────────────
+
+#[test]
+fn raw_string_backslash_is_content() {
+    assert_eq!(scan_tags(r#"r"a\nb""#), vec![RawTag::RawString]);
+    assert_eq!(scan(r#"r"a\nb""#)[0].len, 7);
+    // A trailing backslash does not escape the closing quote
+    assert_eq!(scan_tags(r#"r"a\""#), vec![RawTag::RawString]);
+}
+
+#[test]
+fn raw_string_hashes_allow_embedded_quotes() {
+    let src = "r#\"he said \"hi\"\"#";
+    assert_eq!(scan_tags(src), vec![RawTag::RawString]);
+    assert_eq!(scan(src)[0].len, 17);
+}
+
+#[test]
+fn raw_string_requires_matching_hash_count() {
+    // `"#` does not close a `##` literal
+    let src = "r##\"a\"#b\"##";
+    assert_eq!(scan_tags(src), vec![RawTag::RawString]);
+    assert_eq!(scan(src)[0].len, 11);
+}
+
+#[test]
+fn raw_string_unterminated() {
+    assert_eq!(scan_tags("r#\"abc\""), vec![RawTag::UnterminatedString]);
+    assert_eq!(
+        scan_tags("r\"abc\n"),
+        vec![RawTag::UnterminatedString, RawTag::Newline]
+    );
+}
+
+#[test]
+fn r_identifier_not_raw_string() {
+    assert_eq!(scan_tags("r"), vec![RawTag::Ident]);
+    assert_eq!(scan_tags("result"), vec![RawTag::Ident]);
+    assert_eq!(
+        scan_tags("r#x"),
+        vec![RawTag::Ident, RawTag::Hash, RawTag::Ident]
+    );
+}
+
 // ─── Character Literals ────────────────────────────────────────
 
 #[test]
@@ -562,11 +782,53 @@ fn char_with_escape() {
     assert_eq!(scan_tags("'\\''"), vec![RawTag::Char]);
 }
 
+#[test]
+fn char_with_unicode_escape() {
+    assert_eq!(scan_tags("'\\u{1F600}'"), vec![RawTag::Char]);
+    assert_eq!(scan("'\\u{1F600}'")[0].len, 11);
+    // Malformed body still scans as one literal; the cooker reports it
+    assert_eq!(scan_tags("'\\u{XYZ}'"), vec![RawTag::Char]);
+}
+
+#[test]
+fn char_with_hex_escape() {
+    assert_eq!(scan_tags("'\\x41'"), vec![RawTag::Char]);
+    assert_eq!(scan("'\\x41'")[0].len, 6);
+    assert_eq!(scan_tags("'\\x4'"), vec![RawTag::Char]);
+    assert_eq!(scan_tags("'\\xZZ'"), vec![RawTag::Char]);
+}
+
+#[test]
+fn hex_escape_ends_after_two_digits() {
+    assert_eq!(scan_tags("'\\x41zz'"), vec![RawTag::UnterminatedChar]);
+    assert_eq!(scan("'\\x41zz'")[0].len, 8);
+    assert_eq!(scan_tags("b'\\x41zz'"), vec![RawTag::UnterminatedChar]);
+    assert_eq!(scan_tags("\"\\x41zz\""), vec![RawTag::String]);
+}
+
 #[test]
 fn unterminated_char_eof() {
     assert_eq!(scan_tags("'x"), vec![RawTag::UnterminatedChar]);
 }
 
+#[test]
+fn unterminated_char_runs_to_line_end() {
+    assert_eq!(
+        scan_tags("'abc def\nx"),
+        vec![RawTag::UnterminatedChar, RawTag::Newline, RawTag::Ident]
+    );
+    assert_eq!(scan("'abc def\nx")[0].len, 8);
+}
+
+#[test]
+fn multi_char_single_quoted_is_one_token() {
+    assert_eq!(
+        scan_tags("'abc' x"),
+        vec![RawTag::UnterminatedChar, RawTag::Whitespace, RawTag::Ident]
+    );
+    assert_eq!(scan("'abc' x")[0].len, 5);
+}
+
 #[test]
 fn empty_char_literal() {
     // '' — opening ' consumed, then immediate ' is "empty char" -> UnterminatedChar(1)
@@ -14,8 +14,8 @@
 //! | 16-20   | Template Literals     |
 //! | 32-61   | Operators             |
 //! | 80-95   | Delimiters            |
-//! | 112-114 | Trivia                |
-//! | 240-245 | Errors                |
+//! | 112-115 | Trivia                |
+//! | 240-246 | Errors                |
 //! | 255     | EOF                   |
 
 /// Raw token kind produced by the low-level tokenizer.
@@ -57,6 +57,18 @@ pub enum RawTag {
     Size = 7,
     /// Binary integer literal (`0b...`).
     BinInt = 8,
+    /// Raw string literal (`r"..."` or `r#"..."#`), content taken verbatim.
+    RawString = 9,
+    /// Triple-quoted string literal (`"""..."""`), may span lines.
+    MultilineString = 10,
+    /// Byte literal (`b'A'`), ASCII or escape only.
+    Byte = 11,
+    /// Byte string literal (`b"..."`), ASCII or escapes only.
+    ByteString = 12,
+    /// Numeric literal with a type suffix (e.g., `42i32`, `3.0f32`).
+    SuffixedNumber = 13,
+    /// Hex float literal with a binary exponent (e.g., `0x1.8p3`).
+    HexFloat = 14,
 
     // === Template Literals (16-19) ===
     /// Template head: `` `text{ `` (opening backtick to first unescaped `{`).
@@ -170,15 +182,17 @@ pub enum RawTag {
     /// `#!` (file attribute prefix).
     HashBang = 95,
 
-    // === Trivia (112-114) ===
+    // === Trivia (112-115) ===
     /// Horizontal whitespace (spaces, tabs).
     Whitespace = 112,
     /// Line feed (`\n`) or CRLF (`\r\n`).
     Newline = 113,
     /// Line comment (`//` to end of line).
     LineComment = 114,
+    /// Block comment (`/* ... */`), possibly nested and spanning lines.
+    BlockComment = 115,
 
-    // === Errors (240-245) ===
+    // === Errors (240-246) ===
     /// Invalid byte (non-ASCII, control character).
     InvalidByte = 240,
     /// Unterminated string literal (missing closing `"`).
@@ -201,6 +215,8 @@ pub enum RawTag {
     /// skips these tokens because `SourceBuffer` already detected interior nulls
     /// via `encoding_issues()` and reported them with more specific diagnostics.
     InteriorNull = 245,
+    /// Unterminated block comment (missing closing `*/`).
+    UnterminatedBlockComment = 246,
 
     // === Control (255) ===
     /// End of file (sentinel reached).
@@ -276,6 +292,12 @@ impl RawTag {
             Self::HexInt => "hex integer literal",
             Self::BinInt => "binary integer literal",
             Self::String => "string literal",
+            Self::RawString => "raw string literal",
+            Self::MultilineString => "multiline string literal",
+            Self::Byte => "byte literal",
+            Self::ByteString => "byte string literal",
+            Self::SuffixedNumber => "suffixed numeric literal",
+            Self::HexFloat => "hex float literal",
             Self::Char => "character literal",
             Self::Duration => "duration literal",
             Self::Size => "size literal",
@@ -331,12 +353,14 @@ impl RawTag {
             Self::Whitespace => "whitespace",
             Self::Newline => "newline",
             Self::LineComment => "line comment",
+            Self::BlockComment => "block comment",
             Self::InvalidByte => "invalid byte",
             Self::UnterminatedString => "unterminated string",
             Self::UnterminatedChar => "unterminated character literal",
             Self::InvalidEscape => "invalid escape",
             Self::UnterminatedTemplate => "unterminated template",
             Self::InteriorNull => "interior null byte",
+            Self::UnterminatedBlockComment => "unterminated block comment",
             Self::Eof => "end of file",
         }
     }
@@ -347,7 +371,31 @@ impl RawTag {
     /// separators in Ori.
     #[must_use]
     pub fn is_trivia(self) -> bool {
-        matches!(self, Self::Whitespace | Self::LineComment)
+        matches!(
+            self,
+            Self::Whitespace | Self::LineComment | Self::BlockComment
+        )
+    }
+
+    /// Returns `true` if a token with this tag may contain newline bytes.
+    ///
+    /// Line breaks inside these tokens are not reported as separate
+    /// [`RawTag::Newline`] tokens, so anything tracking line starts must
+    /// look inside them.
+    #[must_use]
+    pub fn may_span_lines(self) -> bool {
+        matches!(
+            self,
+            Self::MultilineString
+                | Self::UnterminatedString
+                | Self::BlockComment
+                | Self::UnterminatedBlockComment
+                | Self::TemplateHead
+                | Self::TemplateMiddle
+                | Self::TemplateTail
+                | Self::TemplateComplete
+                | Self::UnterminatedTemplate
+        )
     }
 }
 
@@ -13,6 +13,12 @@ fn repr_u8_identifiers_and_literals() {
     assert_eq!(RawTag::Duration as u8, 6);
     assert_eq!(RawTag::Size as u8, 7);
     assert_eq!(RawTag::BinInt as u8, 8);
+    assert_eq!(RawTag::RawString as u8, 9);
+    assert_eq!(RawTag::MultilineString as u8, 10);
+    assert_eq!(RawTag::Byte as u8, 11);
+    assert_eq!(RawTag::ByteString as u8, 12);
+    assert_eq!(RawTag::SuffixedNumber as u8, 13);
+    assert_eq!(RawTag::HexFloat as u8, 14);
 }
 
 #[test]
@@ -38,6 +44,7 @@ fn repr_u8_operators_delimiters_trivia() {
     assert_eq!(RawTag::Whitespace as u8, 112);
     assert_eq!(RawTag::Newline as u8, 113);
     assert_eq!(RawTag::LineComment as u8, 114);
+    assert_eq!(RawTag::BlockComment as u8, 115);
 }
 
 #[test]
@@ -45,6 +52,7 @@ fn repr_u8_errors_and_control() {
     assert_eq!(RawTag::InvalidByte as u8, 240);
     assert_eq!(RawTag::UnterminatedTemplate as u8, 244);
     assert_eq!(RawTag::InteriorNull as u8, 245);
+    assert_eq!(RawTag::UnterminatedBlockComment as u8, 246);
     assert_eq!(RawTag::Eof as u8, 255);
 }
 
@@ -161,6 +169,7 @@ fn name_returns_readable_description() {
 fn trivia_classification() {
     assert!(RawTag::Whitespace.is_trivia());
     assert!(RawTag::LineComment.is_trivia());
+    assert!(RawTag::BlockComment.is_trivia());
 
     // Newlines are NOT trivia in Ori (they're significant)
     assert!(!RawTag::Newline.is_trivia());
@@ -168,6 +177,19 @@ fn trivia_classification() {
     assert!(!RawTag::Eof.is_trivia());
 }
 
+#[test]
+fn may_span_lines() {
+    assert!(RawTag::MultilineString.may_span_lines());
+    assert!(RawTag::BlockComment.may_span_lines());
+    assert!(RawTag::TemplateComplete.may_span_lines());
+    assert!(RawTag::UnterminatedTemplate.may_span_lines());
+
+    // Newlines are their own tokens; single-line tokens never contain one
+    assert!(!RawTag::Newline.may_span_lines());
+    assert!(!RawTag::String.may_span_lines());
+    assert!(!RawTag::LineComment.may_span_lines());
+}
+
 // === RawToken ===
 
 #[test]
@@ -9,6 +9,7 @@ use inkwell::debug_info::{
 };
 use inkwell::module::Module;
 use inkwell::values::{BasicValueEnum, InstructionValue, PointerValue};
+use ori_ir::LineIndex;
 use ori_types::{Idx, Pool};
 
 use super::builder::DebugInfoBuilder;
@@ -47,9 +48,30 @@ impl<'ctx> DebugContext<'ctx> {
         config: DebugInfoConfig,
         source_path: &Path,
         source_text: &str,
+    ) -> Option<Self> {
+        Self::with_line_index(
+            module,
+            context,
+            config,
+            source_path,
+            LineIndex::new(source_text),
+        )
+    }
+
+    /// Create a new debug context from an already-built line index.
+    ///
+    /// Same as [`DebugContext::new`], but reuses the line starts the lexer
+    /// collected instead of rescanning the source text.
+    #[must_use]
+    pub fn with_line_index(
+        module: &Module<'ctx>,
+        context: &'ctx Context,
+        config: DebugInfoConfig,
+        source_path: &Path,
+        line_index: LineIndex,
     ) -> Option<Self> {
         let builder = DebugInfoBuilder::from_path(module, context, config, source_path)?;
-        let line_map = LineMap::new(source_text);
+        let line_map = LineMap::from_index(line_index);
         Some(Self { builder, line_map })
     }
 
@@ -247,6 +247,17 @@ fn line_map_offset_to_line_col() {
     assert_eq!(map.line_count(), 4); // 3 newlines + initial line
 }
 
+#[test]
+fn line_map_from_lexer_index_matches_source_scan() {
+    let source = "let x = 42\r\nlet y = 99\n";
+    let map = LineMap::from_index(ori_ir::LineIndex::new(source));
+    assert_eq!(
+        map.offset_to_line_col(16),
+        LineMap::new(source).offset_to_line_col(16)
+    );
+    assert_eq!(map.offset_to_line_col(16), (2, 5));
+}
+
 #[test]
 fn debug_none_level_returns_none_builder() {
     let ctx = Context::create();
@@ -219,6 +219,31 @@ impl ObjectEmitter {
         Self::new(&config)
     }
 
+    /// Create a new object emitter for an explicit target triple.
+    ///
+    /// Used for cross-compilation: only the LLVM backend for `triple` is
+    /// initialized, and the host CPU and features are never consulted.
+    /// `cpu` defaults to `"generic"` and `features` to none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the supported targets if `triple` is unknown,
+    /// or if the target machine cannot be created.
+    pub fn for_triple(
+        triple: &str,
+        cpu: Option<&str>,
+        features: Option<&str>,
+    ) -> Result<Self, EmitError> {
+        let mut config = TargetConfig::from_triple(triple)?;
+        if let Some(cpu) = cpu {
+            config = config.with_cpu(cpu);
+        }
+        if let Some(features) = features {
+            config = config.with_features(features);
+        }
+        Self::new(&config)
+    }
+
     /// Get the target configuration for this emitter.
     #[must_use]
     pub fn config(&self) -> &TargetConfig {
@@ -373,7 +398,9 @@ impl ObjectEmitter {
     /// Pipeline:
     /// 1. Verify module IR (unconditional — catches codegen bugs early)
     /// 2. Run optimization passes (per `OptimizationConfig`)
-    /// 3. Emit to the requested output format
+    /// 3. Re-verify the optimized IR (a pass producing invalid IR is an LLVM
+    ///    or pipeline-configuration bug, and must not reach emission)
+    /// 4. Emit to the requested output format
     ///
     /// # Arguments
     ///
@@ -401,7 +428,14 @@ impl ObjectEmitter {
         super::passes::run_optimization_passes(module, &self.machine, opt_config)
             .map_err(ModulePipelineError::Optimization)?;
 
-        // Step 3: Emit
+        // Step 3: Re-verify after optimization
+        if let Err(msg) = module.verify() {
+            return Err(ModulePipelineError::Verification(format!(
+                "after optimization: {msg}"
+            )));
+        }
+
+        // Step 4: Emit
         self.emit(module, path, format)
             .map_err(ModulePipelineError::Emission)?;
 
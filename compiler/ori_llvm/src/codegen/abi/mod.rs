@@ -104,6 +104,11 @@ pub struct FunctionAbi {
     pub return_abi: ReturnAbi,
     /// Calling convention.
     pub call_conv: CallConv,
+    /// Capabilities from the signature's `uses` clause, in declaration order.
+    ///
+    /// Each is passed as a trailing pointer to the caller's provider, after
+    /// the regular parameters.
+    pub capabilities: Vec<Name>,
 }
 
 // ---------------------------------------------------------------------------
@@ -256,6 +261,7 @@ pub fn compute_function_abi(sig: &FunctionSig, store: &TypeInfoStore<'_>) -> Fun
         params,
         return_abi,
         call_conv,
+        capabilities: sig.capabilities.clone(),
     }
 }
 
@@ -335,6 +341,7 @@ pub fn compute_function_abi_with_ownership(
         params,
         return_abi,
         call_conv,
+        capabilities: sig.capabilities.clone(),
     }
 }
 
@@ -23,7 +23,11 @@ use ori_types::{Idx, Pool};
 use rustc_hash::FxHashMap;
 
 use super::abi::{FunctionAbi, ReturnPassing};
+use super::checked_arith::{emit_checked_arith, emit_divisor_checks, emit_int_pow};
+use super::const_fold::fold_const_binary;
 use super::ir_builder::IrBuilder;
+use super::lower_collections::compute_elem_byte_size;
+use super::tail_calls::{abi_supports_tail_call, tail_invoke_blocks};
 use super::type_info::{TypeInfoStore, TypeLayoutResolver};
 use super::value_id::{BlockId, FunctionId, LLVMTypeId, ValueId};
 
@@ -62,6 +66,11 @@ pub struct ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
     /// Deferred phi incoming values: `block_index` → `[(param_index, value, source_block)]`.
     /// Collected during terminator emission, applied after all blocks are emitted.
     phi_incoming: Vec<(usize, usize, ValueId, BlockId)>,
+    /// Per-block flag: the block's `Invoke` terminator is in tail position
+    /// (see `tail_calls::tail_invoke_blocks`).
+    tail_invokes: Vec<bool>,
+    /// Panic on `int` overflow and zero divisors (see `checked_arith`).
+    overflow_checks: bool,
 }
 
 impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
@@ -94,9 +103,16 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
             var_map: Vec::new(),
             block_map: Vec::new(),
             phi_incoming: Vec::new(),
+            tail_invokes: Vec::new(),
+            overflow_checks: false,
         }
     }
 
+    /// Lower `int` arithmetic with overflow and division-by-zero checks.
+    pub fn set_overflow_checks(&mut self, enabled: bool) {
+        self.overflow_checks = enabled;
+    }
+
     /// Resolve an `Idx` to an `LLVMTypeId`.
     fn resolve_type(&mut self, idx: Idx) -> LLVMTypeId {
         let llvm_ty = self.type_resolver.resolve(idx);
@@ -154,6 +170,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
         // Resize var_map to hold all variables
         self.var_map.resize(func.var_types.len(), None);
 
+        self.tail_invokes = tail_invoke_blocks(func);
+
         // Bind function parameters
         let sret_offset = u32::from(matches!(abi.return_abi.passing, ReturnPassing::Sret { .. }));
         for (i, param) in func.params.iter().enumerate() {
@@ -208,7 +226,7 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
     fn emit_terminator(
         &mut self,
         term: &ArcTerminator,
-        _current_block: ori_arc::ir::ArcBlockId,
+        current_block: ori_arc::ir::ArcBlockId,
         _phi_nodes: &[Vec<(ArcVarId, ValueId)>],
         abi: &FunctionAbi,
     ) {
@@ -282,7 +300,12 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
                 args,
                 normal,
                 unwind,
-            } => self.emit_invoke(*dst, *func, args, *normal, *unwind),
+            } => {
+                let in_tail_position = self.tail_invokes[current_block.index()];
+                if !(in_tail_position && self.emit_self_tail_call(*dst, *func, args)) {
+                    self.emit_invoke(*dst, *func, args, *normal, *unwind);
+                }
+            }
 
             ArcTerminator::Resume => {
                 // Re-raise the caught exception.
@@ -348,13 +371,49 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
         }
     }
 
+    /// Emit a self-recursive `Invoke` in tail position as `tail call` + `ret`.
+    ///
+    /// Returns `false` without emitting anything unless the callee is the
+    /// current function and its ABI allows a tail call; the caller then
+    /// emits a regular invoke. The normal and unwind blocks become
+    /// unreachable but are still emitted, so `dst` is bound as usual.
+    fn emit_self_tail_call(&mut self, dst: ArcVarId, func: Name, args: &[ArcVarId]) -> bool {
+        let Some(&(func_id, ref func_abi)) = self.functions.get(&func) else {
+            return false;
+        };
+        if func_id != self.current_function || !abi_supports_tail_call(func_abi) {
+            return false;
+        }
+
+        let arg_vals: Vec<ValueId> = args.iter().map(|a| self.var(*a)).collect();
+        match self.builder.call_tail(func_id, &arg_vals, "tailcall") {
+            Some(val) => {
+                self.def_var(dst, val);
+                if matches!(func_abi.return_abi.passing, ReturnPassing::Direct) {
+                    self.builder.ret(val);
+                } else {
+                    self.builder.ret_void();
+                }
+            }
+            None => self.builder.ret_void(),
+        }
+        true
+    }
+
     /// Emit an `Apply` instruction (ABI-aware direct call).
     fn emit_apply(&mut self, dst: ArcVarId, callee: Name, args: &[ArcVarId]) {
         let arg_vals: Vec<ValueId> = args.iter().map(|a| self.var(*a)).collect();
         let callee_name_str = self.interner.lookup(callee);
 
         let result = if let Some(&(func_id, ref abi)) = self.functions.get(&callee) {
-            let passed_args = self.apply_param_passing(&arg_vals, &abi.params);
+            let mut passed_args = self.apply_param_passing(&arg_vals, &abi.params);
+            // Tier 2 doesn't lower `with` providers yet: `uses` capabilities
+            // get a null provider.
+            passed_args.extend(
+                abi.capabilities
+                    .iter()
+                    .map(|_| self.builder.const_null_ptr()),
+            );
             match &abi.return_abi.passing {
                 ReturnPassing::Sret { .. } => {
                     let ret_ty = self.resolve_type(abi.return_abi.ty);
@@ -627,7 +686,7 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
             LitValue::Unit => self.builder.const_i64(0),
             LitValue::String(name) => {
                 let s = self.interner.lookup(*name);
-                let global = self.builder.build_global_string_ptr(s, "str");
+                let global = self.builder.string_literal_ptr(s);
                 let len = self.builder.const_i64(s.len() as i64);
                 // Ori string: { i64 len, ptr data }
                 let str_ty = self.builder.register_type(
@@ -692,10 +751,24 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
             }
         }
 
+        if let Some(folded) = fold_const_binary(self.builder, op, lhs, rhs, lhs_ty) {
+            return folded;
+        }
+
+        if self.overflow_checks && lhs_ty == Idx::INT {
+            let func = self.current_function;
+            emit_divisor_checks(self.builder, func, op, lhs, rhs);
+            if let Some(result) = emit_checked_arith(self.builder, func, op, lhs, rhs) {
+                return result;
+            }
+        }
+
         let is_float = matches!(
             self.type_info.get(lhs_ty),
             super::type_info::TypeInfo::Float
         );
+        // `char` and `byte` order as unsigned, matching `lower_binary_op`
+        let is_unsigned = matches!(lhs_ty, Idx::CHAR | Idx::BYTE);
 
         match op {
             BinaryOp::Add if is_float => self.builder.fadd(lhs, rhs, "add"),
@@ -713,12 +786,16 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
             BinaryOp::NotEq if is_float => self.builder.fcmp_one(lhs, rhs, "ne"),
             BinaryOp::NotEq => self.builder.icmp_ne(lhs, rhs, "ne"),
             BinaryOp::Lt if is_float => self.builder.fcmp_olt(lhs, rhs, "lt"),
+            BinaryOp::Lt if is_unsigned => self.builder.icmp_ult(lhs, rhs, "lt"),
             BinaryOp::Lt => self.builder.icmp_slt(lhs, rhs, "lt"),
             BinaryOp::Gt if is_float => self.builder.fcmp_ogt(lhs, rhs, "gt"),
+            BinaryOp::Gt if is_unsigned => self.builder.icmp_ugt(lhs, rhs, "gt"),
             BinaryOp::Gt => self.builder.icmp_sgt(lhs, rhs, "gt"),
             BinaryOp::LtEq if is_float => self.builder.fcmp_ole(lhs, rhs, "le"),
+            BinaryOp::LtEq if is_unsigned => self.builder.icmp_ule(lhs, rhs, "le"),
             BinaryOp::LtEq => self.builder.icmp_sle(lhs, rhs, "le"),
             BinaryOp::GtEq if is_float => self.builder.fcmp_oge(lhs, rhs, "ge"),
+            BinaryOp::GtEq if is_unsigned => self.builder.icmp_uge(lhs, rhs, "ge"),
             BinaryOp::GtEq => self.builder.icmp_sge(lhs, rhs, "ge"),
             BinaryOp::And => self.builder.and(lhs, rhs, "and"),
             BinaryOp::Or => self.builder.or(lhs, rhs, "or"),
@@ -728,6 +805,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
             BinaryOp::Shl => self.builder.shl(lhs, rhs, "shl"),
             BinaryOp::Shr => self.builder.ashr(lhs, rhs, "shr"),
             BinaryOp::FloorDiv => self.builder.sdiv(lhs, rhs, "floordiv"),
+            BinaryOp::Pow if is_float => self.builder.fpow(lhs, rhs, "pow"),
+            BinaryOp::Pow => emit_int_pow(self.builder, lhs, rhs),
             BinaryOp::Range | BinaryOp::RangeInclusive | BinaryOp::Coalesce => {
                 // Range/coalesce ops are desugared before reaching ARC IR
                 tracing::warn!(?op, "ArcIrEmitter: desugared op in binary expression");
@@ -887,10 +966,9 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ArcIrEmitter<'a, 'scx, 'ctx, 'tcx> {
                     _ => ori_types::Idx::INT,
                 };
                 let elem_llvm_ty = self.resolve_type(elem_idx);
-                let elem_size = self.type_info.get(elem_idx).size().unwrap_or(8);
+                let esize_val = compute_elem_byte_size(self.builder, self.type_resolver, elem_idx);
 
                 let cap_val = self.builder.const_i64(count as i64);
-                let esize_val = self.builder.const_i64(elem_size as i64);
 
                 let data_ptr = if let Some(alloc_fn) =
                     self.builder.scx().llmod.get_function("ori_list_alloc_data")
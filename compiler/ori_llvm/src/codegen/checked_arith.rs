@@ -0,0 +1,132 @@
+//! Overflow- and zero-checked integer arithmetic (opt-in).
+//!
+//! By default `int` arithmetic lowers to plain `add`/`sub`/`mul`/`sdiv`/
+//! `srem`, which wrap on overflow. With overflow checks enabled
+//! (`FunctionCompiler::set_overflow_checks`), both codegen tiers route
+//! `int` operands through these helpers instead, which panic with the same
+//! messages as the interpreter:
+//!
+//! - `+`, `-`, `*` use `llvm.s{add,sub,mul}.with.overflow` and panic with
+//!   "integer overflow in addition" (etc.) when the overflow bit is set.
+//! - `/`, `%`, `div` panic with "division by zero" / "modulo by zero" on a
+//!   zero divisor, and with "integer overflow in division" for `MIN / -1`,
+//!   which LLVM leaves undefined.
+//!
+//! `int ** int` has no LLVM instruction and always goes through the runtime's
+//! `ori_ipow` ([`emit_int_pow`]), which panics on overflow and on a negative
+//! exponent whether or not overflow checks are enabled.
+
+use ori_ir::BinaryOp;
+
+use super::ir_builder::IrBuilder;
+use super::value_id::{FunctionId, ValueId};
+
+/// Emit `lhs op rhs` for `+`, `-`, `*` with an overflow check.
+///
+/// Returns `None` for any other operator, leaving it to the caller.
+pub(crate) fn emit_checked_arith(
+    builder: &mut IrBuilder<'_, '_>,
+    func: FunctionId,
+    op: BinaryOp,
+    lhs: ValueId,
+    rhs: ValueId,
+) -> Option<ValueId> {
+    let (value, overflowed, what) = match op {
+        BinaryOp::Add => {
+            let (v, o) = builder.sadd_with_overflow(lhs, rhs, "add");
+            (v, o, "addition")
+        }
+        BinaryOp::Sub => {
+            let (v, o) = builder.ssub_with_overflow(lhs, rhs, "sub");
+            (v, o, "subtraction")
+        }
+        BinaryOp::Mul => {
+            let (v, o) = builder.smul_with_overflow(lhs, rhs, "mul");
+            (v, o, "multiplication")
+        }
+        _ => return None,
+    };
+    emit_panic_if(
+        builder,
+        func,
+        overflowed,
+        &format!("integer overflow in {what}"),
+        "ovf",
+    );
+    Some(value)
+}
+
+/// Guard the divisor of `/`, `%`, and `div` before the division is emitted.
+///
+/// No-op for any other operator.
+pub(crate) fn emit_divisor_checks(
+    builder: &mut IrBuilder<'_, '_>,
+    func: FunctionId,
+    op: BinaryOp,
+    lhs: ValueId,
+    rhs: ValueId,
+) {
+    let (zero_msg, what) = match op {
+        BinaryOp::Div | BinaryOp::FloorDiv => ("division by zero", "division"),
+        BinaryOp::Mod => ("modulo by zero", "remainder"),
+        _ => return,
+    };
+
+    let zero = builder.const_i64(0);
+    let is_zero = builder.icmp_eq(rhs, zero, "div.is_zero");
+    emit_panic_if(builder, func, is_zero, zero_msg, "divzero");
+
+    let min = builder.const_i64(i64::MIN);
+    let minus_one = builder.const_i64(-1);
+    let lhs_is_min = builder.icmp_eq(lhs, min, "div.lhs_min");
+    let rhs_is_minus_one = builder.icmp_eq(rhs, minus_one, "div.rhs_neg1");
+    let overflows = builder.and(lhs_is_min, rhs_is_minus_one, "div.overflows");
+    emit_panic_if(
+        builder,
+        func,
+        overflows,
+        &format!("integer overflow in {what}"),
+        "ovf",
+    );
+}
+
+/// Emit `lhs ** rhs` for `int` operands as a call to `ori_ipow`.
+pub(crate) fn emit_int_pow(builder: &mut IrBuilder<'_, '_>, lhs: ValueId, rhs: ValueId) -> ValueId {
+    let result = builder
+        .scx()
+        .llmod
+        .get_function("ori_ipow")
+        .and_then(|ipow_fn| {
+            let ipow_id = builder.intern_function(ipow_fn);
+            builder.call(ipow_id, &[lhs, rhs], "ipow")
+        });
+    result.unwrap_or_else(|| {
+        tracing::error!("runtime function ori_ipow not declared");
+        builder.record_codegen_error();
+        builder.const_i64(0)
+    })
+}
+
+/// Branch to a block that panics with `msg` when `cond` is true, and
+/// continue emitting in the fall-through block.
+fn emit_panic_if(
+    builder: &mut IrBuilder<'_, '_>,
+    func: FunctionId,
+    cond: ValueId,
+    msg: &str,
+    label: &str,
+) {
+    let panic_bb = builder.append_block(func, &format!("{label}.panic"));
+    let ok_bb = builder.append_block(func, &format!("{label}.ok"));
+    builder.cond_br(cond, panic_bb, ok_bb);
+
+    builder.position_at_end(panic_bb);
+    let msg_ptr = builder.build_global_string_ptr(msg, &format!("{label}.msg"));
+    if let Some(panic_fn) = builder.scx().llmod.get_function("ori_panic_cstr") {
+        let panic_id = builder.intern_function(panic_fn);
+        builder.call(panic_id, &[msg_ptr], "");
+    }
+    builder.unreachable();
+
+    builder.position_at_end(ok_bb);
+}
@@ -0,0 +1,123 @@
+//! Compile-time folding of binary operators on constant operands.
+//!
+//! LLVM's IR builder already folds a plain `add` or `mul` of two constants,
+//! but not the overflow-checked intrinsics, the floor-division correction,
+//! or the `ori_ipow` runtime call. Folding them here removes the emitted
+//! code at every optimization level, including `OptimizationLevel::None`.
+//!
+//! Integer folds use checked arithmetic. Anything that would overflow,
+//! divide by zero, or shift out of range is not folded, so the emitted
+//! code keeps its runtime behavior (wrapping or panicking).
+
+use ori_ir::BinaryOp;
+use ori_types::Idx;
+
+use super::ir_builder::IrBuilder;
+use super::value_id::ValueId;
+
+/// Result of folding a binary operator.
+#[derive(Debug, PartialEq)]
+enum Folded {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Fold `lhs op rhs` when both operands are `int` or `float` constants.
+///
+/// Returns `None` when the operands are not constants or the operation must
+/// be left to runtime.
+pub(crate) fn fold_const_binary(
+    builder: &mut IrBuilder<'_, '_>,
+    op: BinaryOp,
+    lhs: ValueId,
+    rhs: ValueId,
+    operand_type: Idx,
+) -> Option<ValueId> {
+    let folded = match operand_type {
+        Idx::INT => fold_int(
+            op,
+            builder.const_int_value(lhs)?,
+            builder.const_int_value(rhs)?,
+        )?,
+        Idx::FLOAT => fold_float(
+            op,
+            builder.const_float_value(lhs)?,
+            builder.const_float_value(rhs)?,
+        )?,
+        _ => return None,
+    };
+    Some(match folded {
+        Folded::Int(v) => builder.const_i64(v),
+        Folded::Float(v) => builder.const_f64(v),
+        Folded::Bool(v) => builder.const_bool(v),
+    })
+}
+
+/// Fold an `int` operator, mirroring the instruction `lower_binary_op` emits.
+fn fold_int(op: BinaryOp, a: i64, b: i64) -> Option<Folded> {
+    let value = match op {
+        BinaryOp::Add => a.checked_add(b)?,
+        BinaryOp::Sub => a.checked_sub(b)?,
+        BinaryOp::Mul => a.checked_mul(b)?,
+        // `sdiv`/`srem` truncate toward zero, like Rust's `/` and `%`.
+        BinaryOp::Div => a.checked_div(b)?,
+        BinaryOp::Mod => a.checked_rem(b)?,
+        BinaryOp::FloorDiv => {
+            let quotient = a.checked_div(b)?;
+            let remainder = a.checked_rem(b)?;
+            if remainder != 0 && (a < 0) != (b < 0) {
+                quotient.checked_sub(1)?
+            } else {
+                quotient
+            }
+        }
+        BinaryOp::Pow => a.checked_pow(u32::try_from(b).ok()?)?,
+        BinaryOp::BitAnd => a & b,
+        BinaryOp::BitOr => a | b,
+        BinaryOp::BitXor => a ^ b,
+        BinaryOp::Shl => a.checked_shl(u32::try_from(b).ok()?)?,
+        BinaryOp::Shr => a.checked_shr(u32::try_from(b).ok()?)?,
+        BinaryOp::Eq => return Some(Folded::Bool(a == b)),
+        BinaryOp::NotEq => return Some(Folded::Bool(a != b)),
+        BinaryOp::Lt => return Some(Folded::Bool(a < b)),
+        BinaryOp::LtEq => return Some(Folded::Bool(a <= b)),
+        BinaryOp::Gt => return Some(Folded::Bool(a > b)),
+        BinaryOp::GtEq => return Some(Folded::Bool(a >= b)),
+        BinaryOp::Range
+        | BinaryOp::RangeInclusive
+        | BinaryOp::And
+        | BinaryOp::Or
+        | BinaryOp::Coalesce => return None,
+    };
+    Some(Folded::Int(value))
+}
+
+/// Fold a `float` operator with IEEE semantics.
+///
+/// Rust's comparisons match the emitted `fcmp` predicates: ordered for
+/// `==`, `<`, `<=`, `>`, `>=` and unordered for `!=`. `**` is left to
+/// `llvm.pow`.
+#[allow(
+    clippy::float_cmp,
+    reason = "exact comparison is the semantics being folded"
+)]
+fn fold_float(op: BinaryOp, a: f64, b: f64) -> Option<Folded> {
+    Some(match op {
+        BinaryOp::Add => Folded::Float(a + b),
+        BinaryOp::Sub => Folded::Float(a - b),
+        BinaryOp::Mul => Folded::Float(a * b),
+        BinaryOp::Div => Folded::Float(a / b),
+        BinaryOp::Mod => Folded::Float(a % b),
+        BinaryOp::Eq => Folded::Bool(a == b),
+        BinaryOp::NotEq => Folded::Bool(a != b),
+        BinaryOp::Lt => Folded::Bool(a < b),
+        BinaryOp::LtEq => Folded::Bool(a <= b),
+        BinaryOp::Gt => Folded::Bool(a > b),
+        BinaryOp::GtEq => Folded::Bool(a >= b),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+fn folds_int_arithmetic() {
+    assert_eq!(fold_int(BinaryOp::Add, 2, 3), Some(Folded::Int(5)));
+    assert_eq!(fold_int(BinaryOp::Sub, 2, 3), Some(Folded::Int(-1)));
+    assert_eq!(fold_int(BinaryOp::Mul, -4, 3), Some(Folded::Int(-12)));
+    assert_eq!(fold_int(BinaryOp::Pow, 2, 10), Some(Folded::Int(1024)));
+    assert_eq!(
+        fold_int(BinaryOp::BitXor, 0b1100, 0b1010),
+        Some(Folded::Int(0b0110))
+    );
+    assert_eq!(fold_int(BinaryOp::Shl, 1, 4), Some(Folded::Int(16)));
+    assert_eq!(fold_int(BinaryOp::Shr, -16, 2), Some(Folded::Int(-4)));
+}
+
+#[test]
+fn folds_int_division_like_sdiv_and_srem() {
+    assert_eq!(fold_int(BinaryOp::Div, -7, 2), Some(Folded::Int(-3)));
+    assert_eq!(fold_int(BinaryOp::Mod, -7, 2), Some(Folded::Int(-1)));
+    assert_eq!(fold_int(BinaryOp::FloorDiv, -7, 2), Some(Folded::Int(-4)));
+    assert_eq!(fold_int(BinaryOp::FloorDiv, 7, 2), Some(Folded::Int(3)));
+    assert_eq!(fold_int(BinaryOp::FloorDiv, -8, 2), Some(Folded::Int(-4)));
+}
+
+#[test]
+fn folds_int_comparisons_to_bool() {
+    assert_eq!(fold_int(BinaryOp::Lt, 2, 3), Some(Folded::Bool(true)));
+    assert_eq!(fold_int(BinaryOp::GtEq, 2, 3), Some(Folded::Bool(false)));
+    assert_eq!(fold_int(BinaryOp::Eq, 5, 5), Some(Folded::Bool(true)));
+}
+
+#[test]
+fn leaves_trapping_int_ops_to_runtime() {
+    assert_eq!(fold_int(BinaryOp::Div, 1, 0), None);
+    assert_eq!(fold_int(BinaryOp::Mod, 1, 0), None);
+    assert_eq!(fold_int(BinaryOp::FloorDiv, 1, 0), None);
+    assert_eq!(fold_int(BinaryOp::Div, i64::MIN, -1), None);
+    assert_eq!(fold_int(BinaryOp::Add, i64::MAX, 1), None);
+    assert_eq!(fold_int(BinaryOp::Mul, i64::MIN, 2), None);
+    assert_eq!(fold_int(BinaryOp::Pow, 2, -1), None);
+    assert_eq!(fold_int(BinaryOp::Pow, 2, 64), None);
+    assert_eq!(fold_int(BinaryOp::Shl, 1, 64), None);
+    assert_eq!(fold_int(BinaryOp::Shr, 1, -1), None);
+}
+
+#[test]
+fn does_not_fold_non_arithmetic_ops() {
+    assert_eq!(fold_int(BinaryOp::Range, 0, 3), None);
+    assert_eq!(fold_int(BinaryOp::Coalesce, 0, 3), None);
+}
+
+#[test]
+fn folds_float_ops_with_ieee_semantics() {
+    assert_eq!(
+        fold_float(BinaryOp::Add, 1.5, 2.0),
+        Some(Folded::Float(3.5))
+    );
+    assert_eq!(
+        fold_float(BinaryOp::Mod, -7.5, 2.0),
+        Some(Folded::Float(-1.5))
+    );
+    assert_eq!(
+        fold_float(BinaryOp::Div, 1.0, 0.0),
+        Some(Folded::Float(f64::INFINITY))
+    );
+    assert_eq!(
+        fold_float(BinaryOp::Eq, f64::NAN, f64::NAN),
+        Some(Folded::Bool(false))
+    );
+    assert_eq!(
+        fold_float(BinaryOp::NotEq, f64::NAN, f64::NAN),
+        Some(Folded::Bool(true))
+    );
+    assert_eq!(
+        fold_float(BinaryOp::Lt, f64::NAN, 1.0),
+        Some(Folded::Bool(false))
+    );
+    assert_eq!(fold_float(BinaryOp::Pow, 2.0, 3.0), None);
+}
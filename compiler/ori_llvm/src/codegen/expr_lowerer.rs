@@ -11,6 +11,8 @@
 //!   ├── lower_literals.rs     — Int, Float, Bool, String, Ident, …
 //!   ├── lower_operators.rs    — Binary, Unary, Cast
 //!   ├── lower_control_flow.rs — If, Loop, For, Block, Break, Continue, …
+//!   ├── lower_match.rs        — Match (decision trees)
+//!   ├── lower_bindings.rs     — Let, destructuring patterns
 //!   ├── lower_error_handling.rs — Ok, Err, Some, None, Try
 //!   ├── lower_collections.rs  — List, Map, Tuple, Struct, Range, Field, Index
 //!   ├── lower_calls.rs        — Call, MethodCall, Lambda
@@ -20,13 +22,13 @@
 use std::cell::Cell;
 
 use ori_ir::canon::{CanExpr, CanId, CanonResult};
-use ori_ir::{Name, Span, StringInterner};
+use ori_ir::{LineIndex, Name, Span, StringInterner};
 use ori_types::{Idx, Pool};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::aot::debug::DebugContext;
 
-use super::abi::FunctionAbi;
+use super::abi::{FunctionAbi, ReturnAbi, ReturnPassing};
 use super::ir_builder::IrBuilder;
 use super::scope::Scope;
 use super::type_info::{TypeInfoStore, TypeLayoutResolver};
@@ -52,6 +54,9 @@ pub(crate) struct LoopContext {
     pub continue_block: BlockId,
     /// Accumulated `(value, source_block)` pairs from `break expr`.
     pub break_values: Vec<(ValueId, BlockId)>,
+    /// Ori type of the loop expression, used to materialize the default
+    /// value for a bare `break` in a value-producing loop.
+    pub result_type: Idx,
 }
 
 // ---------------------------------------------------------------------------
@@ -70,6 +75,20 @@ pub(crate) struct PropNames {
     pub(crate) message: Name,
     pub(crate) value: Name,
     pub(crate) expr: Name,
+    pub(crate) condition: Name,
+    pub(crate) base: Name,
+    pub(crate) step: Name,
+    pub(crate) tasks: Name,
+    pub(crate) max_concurrent: Name,
+    pub(crate) timeout: Name,
+    pub(crate) operation: Name,
+    pub(crate) after: Name,
+    pub(crate) memo: Name,
+    pub(crate) op: Name,
+    pub(crate) key: Name,
+    pub(crate) ttl: Name,
+    // `self(...)` inside `recurse`
+    pub(crate) recurse_self: Name,
     // Trait method names (used by inner dispatch in lower_builtin_methods/)
     pub(crate) eq: Name,
     pub(crate) compare: Name,
@@ -84,6 +103,19 @@ impl PropNames {
             message: interner.intern("message"),
             value: interner.intern("value"),
             expr: interner.intern("expr"),
+            condition: interner.intern("condition"),
+            base: interner.intern("base"),
+            step: interner.intern("step"),
+            tasks: interner.intern("tasks"),
+            max_concurrent: interner.intern("max_concurrent"),
+            timeout: interner.intern("timeout"),
+            operation: interner.intern("operation"),
+            after: interner.intern("after"),
+            memo: interner.intern("memo"),
+            op: interner.intern("op"),
+            key: interner.intern("key"),
+            ttl: interner.intern("ttl"),
+            recurse_self: interner.intern("self"),
             eq: interner.intern("eq"),
             compare: interner.intern("compare"),
             hash: interner.intern("hash"),
@@ -122,6 +154,11 @@ pub struct ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
     pub(crate) pool: &'a Pool,
     /// The LLVM function currently being compiled.
     pub(crate) current_function: FunctionId,
+    /// Source name of `current_function` when it is a top-level function.
+    ///
+    /// `self(...)` inside `recurse` calls this function. Set by
+    /// `FunctionCompiler`; `None` for methods and lambdas.
+    pub(crate) current_function_name: Option<Name>,
     /// Declared functions: `Name` → (`FunctionId`, ABI). Used by call lowering to
     /// determine sret vs direct return and calling convention.
     pub(crate) functions: &'a FxHashMap<Name, (FunctionId, FunctionAbi)>,
@@ -134,12 +171,50 @@ pub struct ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
     pub(crate) type_idx_to_name: &'a FxHashMap<Idx, Name>,
     /// Active loop context for break/continue (None outside loops).
     pub(crate) loop_ctx: Option<LoopContext>,
+    /// Return convention of the function (or lambda) being lowered.
+    ///
+    /// Early returns (`?`) build a value of `return_abi.ty` and honor its
+    /// passing mode (direct, sret, void). Defaults to void; set by
+    /// `FunctionCompiler` after construction and swapped by `lower_lambda`.
+    pub(crate) return_abi: ReturnAbi,
     /// Resolved `#` (hash length) value for the current index expression.
     ///
     /// Set by `lower_index` before lowering the index sub-expression,
     /// so that `CanExpr::HashLength` resolves to the collection's length
     /// instead of zero. Mirrors the interpreter's `eval_can_with_hash_length`.
     pub(crate) hash_length: Option<ValueId>,
+    /// Capability providers in effect, keyed by capability name.
+    ///
+    /// `with Cap = provider in body` registers `provider` while `body` is
+    /// lowered and restores the previous entry afterward. Identifiers fall
+    /// back to this table after local bindings, mirroring the interpreter,
+    /// which binds the capability name in the body's environment.
+    pub(crate) capabilities: FxHashMap<Name, ValueId>,
+    /// Pointers to the caller's providers for the function's `uses`
+    /// capabilities (trailing hidden parameters), keyed by capability name.
+    ///
+    /// Consulted after `capabilities`, so a `with` inside the function
+    /// shadows the caller's provider. Set by `FunctionCompiler`.
+    pub(crate) capability_params: FxHashMap<Name, ValueId>,
+    /// Ownership flags for mutable list bindings: slot → `i1` alloca.
+    ///
+    /// The flag is set once an index assignment has given the binding a
+    /// buffer of its own, and cleared whenever the whole list is read out
+    /// of the slot (it may now be shared) or the binding is reassigned.
+    /// While it is set, `xs[i] = v` writes in place instead of copying.
+    pub(crate) owned_lists: FxHashMap<ValueId, ValueId>,
+    /// `Call` expressions in tail position of the function body.
+    ///
+    /// A call in this set that targets `current_function` is emitted as a
+    /// `tail call` followed by `ret` (see `tail_calls`). Empty by default;
+    /// set by `FunctionCompiler` for top-level function bodies.
+    pub(crate) tail_calls: FxHashSet<CanId>,
+    /// Panic on `int` overflow and zero divisors instead of wrapping
+    /// (see `checked_arith`). Set by `FunctionCompiler`.
+    pub(crate) overflow_checks: bool,
+    /// Line starts of the source file, for runtime panic locations.
+    /// Set by `FunctionCompiler`; `None` only when no source is known.
+    pub(crate) line_index: Option<&'a LineIndex>,
     /// Module-wide lambda counter for unique lambda function names.
     ///
     /// Shared via `&Cell<u32>` so that nested lambdas (which create new
@@ -186,11 +261,22 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
             interner,
             pool,
             current_function,
+            current_function_name: None,
             functions,
             method_functions,
             type_idx_to_name,
             loop_ctx: None,
+            return_abi: ReturnAbi {
+                ty: Idx::UNIT,
+                passing: ReturnPassing::Void,
+            },
             hash_length: None,
+            capabilities: FxHashMap::default(),
+            capability_params: FxHashMap::default(),
+            owned_lists: FxHashMap::default(),
+            tail_calls: FxHashSet::default(),
+            overflow_checks: false,
+            line_index: None,
             lambda_counter,
             module_path,
             debug_context,
@@ -229,12 +315,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
     /// Returns `None` for expressions that produce no value (e.g., `Unit`,
     /// `Error`, void-returning calls, terminated control flow).
     ///
-    /// Every `CanExpr` variant is listed explicitly — no catch-all — so
-    /// adding a new variant to the canonical IR causes a compile error here.
-    #[allow(
-        clippy::too_many_lines,
-        reason = "exhaustive match over all CanExpr variants; splitting would obscure dispatch"
-    )]
+    /// Dispatches on the expression kind via `lower_kind`; with debug info
+    /// enabled, also sets the source location for emitted instructions.
     pub fn lower(&mut self, id: CanId) -> Option<ValueId> {
         if !id.is_valid() {
             return None;
@@ -249,20 +331,52 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
             return None;
         }
 
-        let kind = *self.canon.arena.kind(id);
-        let span = self.canon.arena.span(id);
+        let Some(dc) = self.debug_context else {
+            return self.lower_kind(id);
+        };
 
-        // Set debug location for this expression so all emitted
-        // instructions are tagged with the correct source position.
-        if let Some(dc) = self.debug_context {
-            if span != Span::DUMMY {
-                dc.set_location_from_offset_in_current_scope(
-                    self.builder.inkwell_builder(),
-                    span.start,
-                );
-            }
+        // Tag emitted instructions with this expression's source position,
+        // then restore the caller's position so instructions emitted after a
+        // nested expression (e.g. a call after its arguments) keep theirs.
+        let saved = self.builder.inkwell_builder().get_current_debug_location();
+        self.set_debug_location(dc, id);
+        let result = self.lower_kind(id);
+        let ir = self.builder.inkwell_builder();
+        match saved {
+            Some(loc) => ir.set_current_debug_location(loc),
+            None => ir.unset_current_debug_location(),
         }
+        result
+    }
 
+    /// Point the debug location at `id`'s span.
+    ///
+    /// Lambdas and trampolines have no subprogram, so locations inside them
+    /// are cleared: a location scoped to the enclosing function would not
+    /// verify.
+    fn set_debug_location(&self, dc: &DebugContext<'ctx>, id: CanId) {
+        let ir = self.builder.inkwell_builder();
+        let func = self.builder.get_function_value(self.current_function);
+        if func.get_subprogram().is_none() {
+            ir.unset_current_debug_location();
+            return;
+        }
+        let span = self.canon.arena.span(id);
+        if span != Span::DUMMY {
+            dc.set_location_from_offset_in_current_scope(ir, span.start);
+        }
+    }
+
+    /// Dispatch on the expression kind. Called by [`lower`](Self::lower).
+    ///
+    /// Every `CanExpr` variant is listed explicitly — no catch-all — so
+    /// adding a new variant to the canonical IR causes a compile error here.
+    #[allow(
+        clippy::too_many_lines,
+        reason = "exhaustive match over all CanExpr variants; splitting would obscure dispatch"
+    )]
+    fn lower_kind(&mut self, id: CanId) -> Option<ValueId> {
+        let kind = *self.canon.arena.kind(id);
         match kind {
             // --- Literals & identifiers (lower_literals.rs) ---
             CanExpr::Int(n) => Some(self.lower_int_typed(n, id)),
@@ -320,6 +434,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
             CanExpr::Break { value, .. } => self.lower_break(value),
             CanExpr::Continue { value, .. } => self.lower_continue(value),
             CanExpr::Assign { target, value } => self.lower_assign(target, value),
+
+            // --- Match (lower_match.rs) ---
             CanExpr::Match {
                 scrutinee,
                 decision_tree,
@@ -343,12 +459,12 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> ExprLowerer<'a, 'scx, 'ctx, 'tcx> {
                 inclusive,
             } => self.lower_range(start, end, step, inclusive),
             CanExpr::Field { receiver, field } => self.lower_field(receiver, field),
-            CanExpr::Index { receiver, index } => self.lower_index(receiver, index),
+            CanExpr::Index { receiver, index } => self.lower_index(receiver, index, id),
             CanExpr::List(range) => self.lower_list(range, id),
             CanExpr::Map(entries) => self.lower_map(entries, id),
 
             // --- Calls (lower_calls.rs) ---
-            CanExpr::Call { func, args } => self.lower_call(func, args),
+            CanExpr::Call { func, args } => self.lower_call(func, args, id),
             CanExpr::MethodCall {
                 receiver,
                 method,
@@ -17,7 +17,7 @@ use std::cell::Cell;
 
 use ori_arc::{lower_function_can, AnnotatedSig, ArcClassifier};
 use ori_ir::canon::{CanId, CanonResult};
-use ori_ir::{Function, Name, Span, StringInterner, TestDef, TraitDef, TraitItem};
+use ori_ir::{Function, LineIndex, Name, Span, StringInterner, TestDef, TraitDef, TraitItem};
 use ori_types::{FunctionSig, Idx, Pool};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
@@ -33,9 +33,14 @@ use super::arc_emitter::ArcIrEmitter;
 use super::expr_lowerer::ExprLowerer;
 use super::ir_builder::IrBuilder;
 use super::scope::Scope;
+use super::tail_calls::tail_position_calls;
 use super::type_info::{TypeInfoStore, TypeLayoutResolver};
 use super::value_id::{FunctionId, LLVMTypeId, ValueId};
 
+mod out_thunks;
+
+pub(crate) use out_thunks::out_thunk_symbol;
+
 // ---------------------------------------------------------------------------
 // FunctionCompiler
 // ---------------------------------------------------------------------------
@@ -82,6 +87,12 @@ pub struct FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
     /// When `true`, use Tier 2 ARC codegen path (ARC IR → LLVM IR with RC).
     /// When `false` (default), use Tier 1 (`ExprLowerer` → LLVM IR, no RC).
     use_arc_codegen: bool,
+    /// When `true`, `int` arithmetic panics on overflow and zero divisors
+    /// instead of wrapping. Off by default.
+    overflow_checks: bool,
+    /// Line starts of the source being compiled, so panics can report
+    /// where they happened. Independent of `debug_context`.
+    line_index: Option<&'a LineIndex>,
 }
 
 impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
@@ -122,6 +133,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
             arc_classifier,
             debug_context,
             use_arc_codegen: false,
+            overflow_checks: false,
+            line_index: None,
         }
     }
 
@@ -135,6 +148,28 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         self.use_arc_codegen = enabled;
     }
 
+    /// Enable checked `int` arithmetic for all functions compiled through
+    /// this instance.
+    ///
+    /// When enabled, `+`, `-`, `*` lower to `llvm.s*.with.overflow` and
+    /// `/`, `%`, `div` guard their divisor; a failed check calls
+    /// `ori_panic_cstr` with the interpreter's message (e.g. "integer
+    /// overflow in addition", "division by zero"). When disabled (the
+    /// default), arithmetic wraps.
+    pub fn set_overflow_checks(&mut self, enabled: bool) {
+        self.overflow_checks = enabled;
+    }
+
+    /// Set the line index of the source whose bodies are compiled next.
+    ///
+    /// `panic`, failed index checks and other runtime panics report the
+    /// `line:col` of their expression through it, with or without debug
+    /// info. Pass `None` before compiling bodies from a source without an
+    /// index (spans from another file would map to the wrong lines).
+    pub fn set_line_index(&mut self, line_index: Option<&'a LineIndex>) {
+        self.line_index = line_index;
+    }
+
     // -----------------------------------------------------------------------
     // Phase 1: Declare
     // -----------------------------------------------------------------------
@@ -196,6 +231,10 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
                 ParamPassing::Void => {}
             }
         }
+        // `uses` capabilities: pointers to the caller's providers
+        for _ in &abi.capabilities {
+            llvm_param_types.push(self.builder.ptr_type());
+        }
 
         let func_id = match &abi.return_abi.passing {
             ReturnPassing::Direct => {
@@ -338,7 +377,7 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         let name_str = self.interner.lookup(name);
         debug!(name = name_str, tier = 1, "defining function body");
 
-        self.enter_debug_scope(func_id);
+        self.enter_debug_scope(func_id, canon.arena.span(body).start);
 
         // Create entry block
         let entry_block = self.builder.append_block(func_id, "entry");
@@ -347,6 +386,14 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
 
         // Bind parameters to scope
         let scope = self.bind_parameters(func_id, abi);
+        let capability_params = self.capability_params(func_id, abi);
+        // `self(...)` resolves through `functions`; a method that shares a
+        // top-level function's name must not resolve to that function.
+        let current_name = self
+            .functions
+            .get(&name)
+            .filter(|(id, _)| *id == func_id)
+            .map(|_| name);
 
         // Lower the body expression
         let mut lowerer = ExprLowerer::new(
@@ -365,20 +412,26 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
             self.module_path,
             self.debug_context,
         );
+        lowerer.current_function_name = current_name;
+        lowerer.return_abi = abi.return_abi.clone();
+        lowerer.tail_calls = tail_position_calls(canon, body);
+        lowerer.overflow_checks = self.overflow_checks;
+        lowerer.line_index = self.line_index;
+        lowerer.capability_params = capability_params;
 
         let result = lowerer.lower(body);
 
         // Check if the block is already terminated (e.g., by panic, break, unreachable)
         if let Some(block) = self.builder.current_block() {
             if self.builder.block_has_terminator(block) {
-                self.exit_debug_scope();
+                self.exit_debug_scope(func_id);
                 return;
             }
         }
 
         // Emit return instruction based on ABI
         self.emit_return(func_id, abi, result, name_str);
-        self.exit_debug_scope();
+        self.exit_debug_scope(func_id);
     }
 
     /// Tier 2: ARC IR → LLVM IR codegen (with RC lifecycle).
@@ -398,7 +451,7 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         let name_str = self.interner.lookup(name);
         debug!(name = name_str, tier = 2, "defining function body (ARC)");
 
-        self.enter_debug_scope(func_id);
+        self.enter_debug_scope(func_id, canon.arena.span(body).start);
         self.builder.set_current_function(func_id);
 
         // Build parameter list for ARC IR lowering: (Name, Idx) pairs
@@ -457,25 +510,39 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
             &self.method_functions,
             &self.type_idx_to_name,
         );
+        emitter.set_overflow_checks(self.overflow_checks);
         emitter.emit_function(&arc_func, abi);
 
-        self.exit_debug_scope();
+        self.exit_debug_scope(func_id);
     }
 
     /// Enter debug scope for the function being compiled.
-    fn enter_debug_scope(&self, func_id: FunctionId) {
+    ///
+    /// Instructions emitted before the first expression (parameter setup,
+    /// and all of a Tier 2 body, which carries no per-expression spans) are
+    /// located at the start of the body. A function without a subprogram
+    /// gets no location, so none leaks in from the previous function.
+    fn enter_debug_scope(&self, func_id: FunctionId, body_start: u32) {
         if let Some(dc) = self.debug_context {
             let func_val = self.builder.get_function_value(func_id);
+            let ir = self.builder.inkwell_builder();
             if let Some(subprogram) = func_val.get_subprogram() {
                 dc.enter_function(subprogram);
+                dc.set_location_from_offset_in_current_scope(ir, body_start);
+            } else {
+                dc.di().clear_location(ir);
             }
         }
     }
 
     /// Exit debug scope after function compilation.
-    fn exit_debug_scope(&self) {
+    fn exit_debug_scope(&self, func_id: FunctionId) {
         if let Some(dc) = self.debug_context {
-            dc.exit_function();
+            let func_val = self.builder.get_function_value(func_id);
+            if func_val.get_subprogram().is_some() {
+                dc.exit_function();
+            }
+            dc.di().clear_location(self.builder.inkwell_builder());
         }
     }
 
@@ -549,6 +616,30 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         values
     }
 
+    /// The trailing parameters carrying the function's `uses` capabilities,
+    /// keyed by capability name. Each is a pointer to the caller's provider.
+    fn capability_params(
+        &mut self,
+        func_id: FunctionId,
+        abi: &FunctionAbi,
+    ) -> FxHashMap<Name, ValueId> {
+        let has_sret = matches!(abi.return_abi.passing, ReturnPassing::Sret { .. });
+        let first = abi
+            .params
+            .iter()
+            .filter(|p| p.passing != ParamPassing::Void)
+            .fold(u32::from(has_sret), |idx, _| idx + 1);
+        abi.capabilities
+            .iter()
+            .zip(first..)
+            .map(|(&cap, idx)| {
+                let ptr = self.builder.get_param(func_id, idx);
+                self.builder.set_value_name(ptr, self.interner.lookup(cap));
+                (cap, ptr)
+            })
+            .collect()
+    }
+
     /// Bind function parameters to a `Scope`, accounting for sret offset.
     ///
     /// Uses [`Self::load_param_values`] to load raw values, then names them
@@ -649,6 +740,8 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
                 self.module_path,
                 self.debug_context,
             );
+            lowerer.overflow_checks = self.overflow_checks;
+            lowerer.line_index = self.line_index;
 
             lowerer.lower(body);
 
@@ -885,7 +978,7 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         }
 
         // Build args for calling the Ori @main function
-        let call_args = if has_args {
+        let mut call_args = if has_args {
             // Call ori_args_from_argv(arg_count, arg_values) → Ori [str]
             let arg_count = self.builder.get_param(c_main_id, 0);
             let arg_values = self.builder.get_param(c_main_id, 1);
@@ -915,6 +1008,13 @@ impl<'a, 'scx: 'ctx, 'ctx, 'tcx> FunctionCompiler<'a, 'scx, 'ctx, 'tcx> {
         } else {
             vec![]
         };
+        // No provider is in effect above `@main`: runtime-handled
+        // capabilities (e.g. `uses Print`) are never read through these.
+        call_args.extend(
+            abi.capabilities
+                .iter()
+                .map(|_| self.builder.const_null_ptr()),
+        );
 
         // Call the Ori @main function
         match &abi.return_abi.passing {
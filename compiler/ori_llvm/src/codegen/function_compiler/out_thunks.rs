@@ -0,0 +1,76 @@
+//! Out-pointer entry points for JIT callers.
+//!
+//! A function's return value may come back in registers (`Direct`) or through
+//! a hidden `sret` pointer, depending on its size, and Ori functions default
+//! to `fastcc`. Neither is something a Rust caller can reproduce for an
+//! arbitrary struct. For every zero-parameter function that returns a value,
+//! [`FunctionCompiler::compile_out_thunks`] emits a C-ABI thunk
+//! `void <symbol>$out(ptr %out)` that calls the function and writes its
+//! result to `%out`, so the JIT can read any return type as raw bytes.
+
+use ori_ir::Function;
+use ori_types::FunctionSig;
+use tracing::debug;
+
+use super::{FunctionCompiler, ReturnPassing};
+
+/// Symbol of the out-pointer thunk for the function named `symbol`.
+pub(crate) fn out_thunk_symbol(symbol: &str) -> String {
+    format!("{symbol}$out")
+}
+
+impl FunctionCompiler<'_, '_, '_, '_> {
+    /// Emit an out-pointer thunk for each declared zero-parameter function
+    /// that returns a value.
+    ///
+    /// Must be called after `declare_all()`. `sret` functions receive the
+    /// out pointer as their hidden first argument; `Direct` results are
+    /// stored through it.
+    pub fn compile_out_thunks(
+        &mut self,
+        module_functions: &[Function],
+        function_sigs: &[FunctionSig],
+    ) {
+        for (func, sig) in module_functions.iter().zip(function_sigs.iter()) {
+            if sig.is_generic() || !sig.param_types.is_empty() {
+                continue;
+            }
+            let Some((func_id, abi)) = self.functions.get(&func.name).cloned() else {
+                continue;
+            };
+            if matches!(abi.return_abi.passing, ReturnPassing::Void) {
+                continue;
+            }
+
+            let symbol = self
+                .builder
+                .get_function_value(func_id)
+                .get_name()
+                .to_string_lossy()
+                .into_owned();
+            let thunk_symbol = out_thunk_symbol(&symbol);
+            debug!(symbol, thunk = %thunk_symbol, "compiling out-pointer thunk");
+
+            let ptr_ty = self.builder.ptr_type();
+            let thunk_id = self.builder.declare_void_function(&thunk_symbol, &[ptr_ty]);
+            self.builder.set_ccc(thunk_id);
+
+            let entry = self.builder.append_block(thunk_id, "entry");
+            self.builder.position_at_end(entry);
+            self.builder.set_current_function(thunk_id);
+
+            let out = self.builder.get_param(thunk_id, 0);
+            match abi.return_abi.passing {
+                ReturnPassing::Sret { .. } => {
+                    self.builder.call(func_id, &[out], "");
+                }
+                ReturnPassing::Direct | ReturnPassing::Void => {
+                    if let Some(result) = self.builder.call(func_id, &[], "result") {
+                        self.builder.store(result, out);
+                    }
+                }
+            }
+            self.builder.ret_void();
+        }
+    }
+}
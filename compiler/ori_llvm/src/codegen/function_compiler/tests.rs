@@ -512,3 +512,156 @@ fn module_path_appears_in_mangled_name() {
     // Unmangled name should NOT exist
     assert!(scx.llmod.get_function("add").is_none());
 }
+
+#[test]
+fn debug_info_emits_compile_unit_and_locations() {
+    use crate::aot::debug::DebugInfoConfig;
+    use ori_ir::canon::{CanArena, CanExpr, CanNode};
+    use ori_ir::TypeId;
+
+    let pool = Pool::new();
+    let ctx = Context::create();
+    let interner = StringInterner::new();
+    let store = TypeInfoStore::new(&pool);
+    let scx = ManuallyDrop::new(SimpleCx::new(&ctx, "test_debug_info"));
+    let resolver = TypeLayoutResolver::new(&store, &scx);
+    let mut builder = IrBuilder::new(&scx);
+
+    // @answer () -> int = 40 + 2
+    let source = "@answer () -> int = 40 + 2\n";
+    let dc = DebugContext::new(
+        &scx.llmod,
+        &ctx,
+        DebugInfoConfig::new(DebugLevel::LineTablesOnly),
+        std::path::Path::new("answer.ori"),
+        source,
+    )
+    .expect("debug info is enabled");
+
+    let int_ty = TypeId::from_raw(Idx::INT.raw());
+    let mut arena = CanArena::with_capacity(8);
+    let lit40 = arena.push(CanNode::new(CanExpr::Int(40), Span::new(20, 22), int_ty));
+    let lit2 = arena.push(CanNode::new(CanExpr::Int(2), Span::new(25, 26), int_ty));
+    let body = arena.push(CanNode::new(
+        CanExpr::Binary {
+            op: ori_ir::BinaryOp::Add,
+            left: lit40,
+            right: lit2,
+        },
+        Span::new(20, 26),
+        int_ty,
+    ));
+    let canon = CanonResult {
+        arena,
+        constants: ori_ir::canon::ConstantPool::new(),
+        decision_trees: ori_ir::canon::DecisionTreePool::default(),
+        root: body,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let func_name = interner.intern("answer");
+    let sig = make_sig(func_name, vec![], vec![], Idx::INT, false);
+    let mut fc = FunctionCompiler::new(
+        &mut builder,
+        &store,
+        &resolver,
+        &interner,
+        &pool,
+        "",
+        None,
+        None,
+        Some(&dc),
+    );
+    fc.declare_function(func_name, &sig, Span::new(0, 26));
+    let (func_id, abi) = fc
+        .get_function(func_name)
+        .map(|(id, abi)| (*id, abi.clone()))
+        .unwrap();
+    fc.define_function_body(func_name, func_id, &abi, body, &canon);
+
+    drop(fc);
+    drop(builder);
+    drop(resolver);
+    dc.finalize();
+
+    let ir = scx.llmod.print_to_string().to_string();
+    assert!(ir.contains("!llvm.dbg.cu"), "missing compile unit:\n{ir}");
+    assert!(ir.contains("!DILocation("), "missing line locations:\n{ir}");
+    assert!(scx.llmod.verify().is_ok(), "debug info must verify:\n{ir}");
+}
+
+#[test]
+fn constant_int_add_folds_without_instructions() {
+    use ori_ir::canon::{CanArena, CanExpr, CanNode};
+    use ori_ir::TypeId;
+
+    let pool = Pool::new();
+    let ctx = Context::create();
+    let interner = StringInterner::new();
+    let store = TypeInfoStore::new(&pool);
+    let scx = ManuallyDrop::new(SimpleCx::new(&ctx, "test_const_fold"));
+    let resolver = TypeLayoutResolver::new(&store, &scx);
+    let mut builder = IrBuilder::new(&scx);
+
+    // @five () -> int = 2 + 3
+    let int_ty = TypeId::from_raw(Idx::INT.raw());
+    let mut arena = CanArena::with_capacity(4);
+    let lit2 = arena.push(CanNode::new(CanExpr::Int(2), Span::new(18, 19), int_ty));
+    let lit3 = arena.push(CanNode::new(CanExpr::Int(3), Span::new(22, 23), int_ty));
+    let body = arena.push(CanNode::new(
+        CanExpr::Binary {
+            op: ori_ir::BinaryOp::Add,
+            left: lit2,
+            right: lit3,
+        },
+        Span::new(18, 23),
+        int_ty,
+    ));
+    let canon = CanonResult {
+        arena,
+        constants: ori_ir::canon::ConstantPool::new(),
+        decision_trees: ori_ir::canon::DecisionTreePool::default(),
+        root: body,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let func_name = interner.intern("five");
+    let sig = make_sig(func_name, vec![], vec![], Idx::INT, false);
+    let mut fc = FunctionCompiler::new(
+        &mut builder,
+        &store,
+        &resolver,
+        &interner,
+        &pool,
+        "",
+        None,
+        None,
+        None,
+    );
+    // Checked arithmetic would otherwise emit `llvm.sadd.with.overflow`,
+    // which LLVM's builder does not fold.
+    fc.set_overflow_checks(true);
+    fc.declare_function(func_name, &sig, Span::new(0, 23));
+    let (func_id, abi) = fc
+        .get_function(func_name)
+        .map(|(id, abi)| (*id, abi.clone()))
+        .unwrap();
+    fc.define_function_body(func_name, func_id, &abi, body, &canon);
+
+    drop(fc);
+    drop(builder);
+    drop(resolver);
+
+    let ir = scx.llmod.print_to_string().to_string();
+    assert!(ir.contains("ret i64 5"), "expected folded constant:\n{ir}");
+    assert!(
+        !ir.contains("with.overflow"),
+        "unexpected checked add:\n{ir}"
+    );
+    assert!(!ir.contains(" add "), "unexpected add instruction:\n{ir}");
+    assert!(scx.llmod.verify().is_ok(), "module must verify:\n{ir}");
+}
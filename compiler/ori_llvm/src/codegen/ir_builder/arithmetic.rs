@@ -1,5 +1,7 @@
 //! Signed, unsigned, float, and bitwise arithmetic for `IrBuilder`.
 
+use inkwell::intrinsics::Intrinsic;
+
 use super::IrBuilder;
 use crate::codegen::value_id::ValueId;
 
@@ -101,6 +103,84 @@ impl IrBuilder<'_, '_> {
         self.arena.push_value(result.into())
     }
 
+    // -- Overflow-checked arithmetic --
+
+    /// Build `llvm.sadd.with.overflow`: returns `(sum, overflowed)`.
+    pub fn sadd_with_overflow(
+        &mut self,
+        lhs: ValueId,
+        rhs: ValueId,
+        name: &str,
+    ) -> (ValueId, ValueId) {
+        self.int_with_overflow("llvm.sadd.with.overflow", lhs, rhs, name)
+    }
+
+    /// Build `llvm.ssub.with.overflow`: returns `(difference, overflowed)`.
+    pub fn ssub_with_overflow(
+        &mut self,
+        lhs: ValueId,
+        rhs: ValueId,
+        name: &str,
+    ) -> (ValueId, ValueId) {
+        self.int_with_overflow("llvm.ssub.with.overflow", lhs, rhs, name)
+    }
+
+    /// Build `llvm.smul.with.overflow`: returns `(product, overflowed)`.
+    pub fn smul_with_overflow(
+        &mut self,
+        lhs: ValueId,
+        rhs: ValueId,
+        name: &str,
+    ) -> (ValueId, ValueId) {
+        self.int_with_overflow("llvm.smul.with.overflow", lhs, rhs, name)
+    }
+
+    /// Call an `llvm.*.with.overflow` intrinsic overloaded on the operand
+    /// type and split its `{ iN, i1 }` result into `(value, overflowed)`.
+    fn int_with_overflow(
+        &mut self,
+        intrinsic: &str,
+        lhs: ValueId,
+        rhs: ValueId,
+        name: &str,
+    ) -> (ValueId, ValueId) {
+        let l = self.arena.get_value(lhs);
+        let r = self.arena.get_value(rhs);
+        if !l.is_int_value() || !r.is_int_value() {
+            tracing::error!(lhs_type = ?l.get_type(), rhs_type = ?r.get_type(), intrinsic, "overflow intrinsic on non-int operands");
+            self.record_codegen_error();
+            return (self.const_i64(0), self.const_bool(false));
+        }
+        let (l, r) = (l.into_int_value(), r.into_int_value());
+        let Some(decl) = Intrinsic::find(intrinsic)
+            .and_then(|i| i.get_declaration(&self.scx.llmod, &[l.get_type().into()]))
+        else {
+            tracing::error!(intrinsic, "LLVM intrinsic not found");
+            self.record_codegen_error();
+            return (self.const_i64(0), self.const_bool(false));
+        };
+        let pair = self
+            .builder
+            .build_call(decl, &[l.into(), r.into()], name)
+            .expect("with.overflow")
+            .try_as_basic_value()
+            .basic()
+            .expect("with.overflow returns a struct")
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(pair, 0, name)
+            .expect("with.overflow value");
+        let overflowed = self
+            .builder
+            .build_extract_value(pair, 1, "overflowed")
+            .expect("with.overflow flag");
+        (
+            self.arena.push_value(value),
+            self.arena.push_value(overflowed),
+        )
+    }
+
     // -- Unsigned arithmetic --
 
     /// Build unsigned integer division.
@@ -233,6 +313,33 @@ impl IrBuilder<'_, '_> {
         self.arena.push_value(v.into())
     }
 
+    /// Build floating-point power via the `llvm.pow` intrinsic.
+    pub fn fpow(&mut self, lhs: ValueId, rhs: ValueId, name: &str) -> ValueId {
+        let l = self.arena.get_value(lhs);
+        let r = self.arena.get_value(rhs);
+        if !l.is_float_value() || !r.is_float_value() {
+            tracing::error!(lhs_type = ?l.get_type(), rhs_type = ?r.get_type(), "fpow on non-float operands");
+            self.record_codegen_error();
+            return self.const_f64(0.0);
+        }
+        let (l, r) = (l.into_float_value(), r.into_float_value());
+        let Some(decl) = Intrinsic::find("llvm.pow")
+            .and_then(|i| i.get_declaration(&self.scx.llmod, &[l.get_type().into()]))
+        else {
+            tracing::error!("LLVM intrinsic llvm.pow not found");
+            self.record_codegen_error();
+            return self.const_f64(0.0);
+        };
+        let v = self
+            .builder
+            .build_call(decl, &[l.into(), r.into()], name)
+            .expect("fpow")
+            .try_as_basic_value()
+            .basic()
+            .expect("llvm.pow returns a float");
+        self.arena.push_value(v)
+    }
+
     /// Build floating-point negation.
     pub fn fneg(&mut self, val: ValueId, name: &str) -> ValueId {
         let v = self.arena.get_value(val);
@@ -42,6 +42,24 @@ impl<'ctx> IrBuilder<'_, 'ctx> {
         self.arena.push_value(v.into())
     }
 
+    /// Read `id` as a constant `i64`, or `None` if it is not a constant integer.
+    pub fn const_int_value(&self, id: ValueId) -> Option<i64> {
+        match self.arena.get_value(id) {
+            BasicValueEnum::IntValue(v) if v.get_type().get_bit_width() == 64 => {
+                v.get_sign_extended_constant()
+            }
+            _ => None,
+        }
+    }
+
+    /// Read `id` as a constant `f64`, or `None` if it is not a constant float.
+    pub fn const_float_value(&self, id: ValueId) -> Option<f64> {
+        match self.arena.get_value(id) {
+            BasicValueEnum::FloatValue(v) => v.get_constant().map(|(value, _lossy)| value),
+            _ => None,
+        }
+    }
+
     /// Create a null pointer constant.
     #[inline]
     pub fn const_null_ptr(&mut self) -> ValueId {
@@ -85,4 +103,29 @@ impl<'ctx> IrBuilder<'_, 'ctx> {
             .as_pointer_value();
         self.arena.push_value(v.into())
     }
+
+    /// Create a private, zero-initialized `i8` global and return a pointer to it.
+    ///
+    /// The global is mutable, so LLVM never merges it with another one: its
+    /// address identifies a single program point (e.g. a `cache` call site).
+    pub fn build_site_marker(&mut self, name: &str) -> ValueId {
+        let i8_ty = self.scx.type_i8();
+        let global = self.scx.llmod.add_global(i8_ty, None, name);
+        global.set_linkage(inkwell::module::Linkage::Private);
+        global.set_initializer(&i8_ty.const_zero());
+        self.arena.push_value(global.as_pointer_value().into())
+    }
+
+    /// Pointer to a global string shared by every literal with this content.
+    ///
+    /// The cache is keyed by the full content, so two different strings
+    /// never share a global even if their hashes collide.
+    pub fn string_literal_ptr(&mut self, value: &str) -> ValueId {
+        if let Some(&id) = self.string_globals.get(value) {
+            return id;
+        }
+        let id = self.build_global_string_ptr(value, "str.data");
+        self.string_globals.insert(value.to_owned(), id);
+        id
+    }
 }
@@ -144,6 +144,30 @@ impl<'ctx> IrBuilder<'_, 'ctx> {
             .expect("store");
     }
 
+    /// Build a `memcpy` of `size` bytes from `src` to `dst`.
+    ///
+    /// Both pointers are treated as byte-aligned, so the copy is valid for
+    /// any element type. The regions must not overlap.
+    pub fn memcpy(&mut self, dst: ValueId, src: ValueId, size: ValueId) {
+        let d = self.arena.get_value(dst);
+        let s = self.arena.get_value(src);
+        let n = self.arena.get_value(size);
+        if !d.is_pointer_value() || !s.is_pointer_value() || !n.is_int_value() {
+            tracing::error!(dst = ?d.get_type(), src = ?s.get_type(), "memcpy on non-pointer — skipping");
+            self.record_codegen_error();
+            return;
+        }
+        self.builder
+            .build_memcpy(
+                d.into_pointer_value(),
+                1,
+                s.into_pointer_value(),
+                1,
+                n.into_int_value(),
+            )
+            .expect("memcpy");
+    }
+
     /// Build a GEP (get element pointer) with arbitrary indices.
     ///
     /// # Safety
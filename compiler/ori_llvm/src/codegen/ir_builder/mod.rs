@@ -42,6 +42,7 @@ use inkwell::basic_block::BasicBlock;
 use inkwell::builder::Builder as InkwellBuilder;
 use inkwell::types::BasicTypeEnum;
 use inkwell::values::{BasicValueEnum, FunctionValue};
+use rustc_hash::FxHashMap;
 
 use crate::context::SimpleCx;
 
@@ -77,6 +78,8 @@ pub struct IrBuilder<'scx, 'ctx> {
     /// NOT be passed to LLVM's JIT — doing so causes heap corruption (SIGABRT).
     /// The evaluator checks this after compilation to bail out early.
     pub(super) codegen_errors: Cell<u32>,
+    /// Global string constants emitted for literals, by content.
+    pub(super) string_globals: FxHashMap<String, ValueId>,
 }
 
 impl<'scx, 'ctx> IrBuilder<'scx, 'ctx> {
@@ -90,6 +93,7 @@ impl<'scx, 'ctx> IrBuilder<'scx, 'ctx> {
             current_function: None,
             current_block: None,
             codegen_errors: Cell::new(0),
+            string_globals: FxHashMap::default(),
         }
     }
 
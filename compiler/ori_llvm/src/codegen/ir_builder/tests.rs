@@ -65,6 +65,39 @@ fn const_bool_roundtrip() {
     drop(irb);
 }
 
+#[test]
+fn string_literal_ptr_shares_globals_by_content() {
+    let ctx = Context::create();
+    let scx = test_scx(&ctx);
+    let mut irb = IrBuilder::new(&scx);
+    setup_builder(&mut irb);
+
+    let hello = irb.string_literal_ptr("hello");
+    let world = irb.string_literal_ptr("world!");
+    let hello_again = irb.string_literal_ptr("hello");
+
+    // Same content reuses the global; different content never does, since
+    // the cache compares full keys rather than trusting the hash
+    assert_eq!(hello, hello_again);
+    assert_ne!(irb.raw_value(hello), irb.raw_value(world));
+
+    // Each global holds its own bytes, null terminator included
+    let globals: Vec<_> = scx.llmod.get_globals().collect();
+    assert_eq!(globals.len(), 2);
+    let sizes: Vec<_> = globals
+        .iter()
+        .map(|g| {
+            g.get_initializer()
+                .unwrap()
+                .into_array_value()
+                .get_type()
+                .len()
+        })
+        .collect();
+    assert_eq!(sizes, [6, 7]);
+    drop(irb);
+}
+
 // -- Arithmetic --
 
 #[test]
@@ -118,6 +151,56 @@ fn float_arithmetic() {
     drop(irb);
 }
 
+#[test]
+fn overflow_checked_arithmetic() {
+    let ctx = Context::create();
+    let scx = test_scx(&ctx);
+    let mut irb = IrBuilder::new(&scx);
+    setup_builder(&mut irb);
+
+    let a = irb.const_i64(10);
+    let b = irb.const_i64(3);
+
+    let (sum, sum_ovf) = irb.sadd_with_overflow(a, b, "sum");
+    let (diff, _) = irb.ssub_with_overflow(a, b, "diff");
+    let (prod, prod_ovf) = irb.smul_with_overflow(a, b, "prod");
+
+    assert!(irb.raw_value(sum).is_int_value());
+    assert!(irb.raw_value(diff).is_int_value());
+    assert!(irb.raw_value(prod).is_int_value());
+    for flag in [sum_ovf, prod_ovf] {
+        let width = irb
+            .raw_value(flag)
+            .into_int_value()
+            .get_type()
+            .get_bit_width();
+        assert_eq!(width, 1, "overflow flag must be i1");
+    }
+
+    let ir = scx.llmod.print_to_string().to_string();
+    assert!(ir.contains("llvm.sadd.with.overflow.i64"), "got:\n{ir}");
+    assert!(ir.contains("llvm.ssub.with.overflow.i64"), "got:\n{ir}");
+    assert!(ir.contains("llvm.smul.with.overflow.i64"), "got:\n{ir}");
+    drop(irb);
+}
+
+#[test]
+fn float_power_intrinsic() {
+    let ctx = Context::create();
+    let scx = test_scx(&ctx);
+    let mut irb = IrBuilder::new(&scx);
+    setup_builder(&mut irb);
+
+    let a = irb.const_f64(2.0);
+    let b = irb.const_f64(0.5);
+    let pow = irb.fpow(a, b, "pow");
+    assert!(irb.raw_value(pow).is_float_value());
+
+    let ir = scx.llmod.print_to_string().to_string();
+    assert!(ir.contains("llvm.pow.f64"), "got:\n{ir}");
+    drop(irb);
+}
+
 // -- Memory --
 
 #[test]
@@ -138,6 +221,25 @@ fn alloca_load_store_roundtrip() {
     drop(irb);
 }
 
+#[test]
+fn memcpy_between_allocas() {
+    let ctx = Context::create();
+    let scx = test_scx(&ctx);
+    let mut irb = IrBuilder::new(&scx);
+    setup_builder(&mut irb);
+
+    let i64_ty = irb.i64_type();
+    let src = irb.alloca(i64_ty, "src");
+    let dst = irb.alloca(i64_ty, "dst");
+    let val = irb.const_i64(7);
+    irb.store(val, src);
+    let size = irb.const_i64(8);
+    irb.memcpy(dst, src, size);
+
+    assert!(!irb.has_codegen_errors());
+    drop(irb);
+}
+
 #[test]
 fn create_entry_alloca_inserts_at_entry() {
     let ctx = Context::create();
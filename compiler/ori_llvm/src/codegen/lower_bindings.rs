@@ -0,0 +1,295 @@
+//! Let-binding lowering for V2 codegen.
+//!
+//! Handles `let` bindings and irrefutable destructuring patterns (tuple,
+//! struct, list), including per-binding mutability and debug info.
+
+use ori_ir::canon::{CanBindingPattern, CanBindingPatternId, CanId};
+use ori_ir::{Mutability, Name, Span};
+use ori_types::Idx;
+
+use crate::aot::debug::DebugLevel;
+
+use super::expr_lowerer::ExprLowerer;
+use super::type_info::TypeInfo;
+use super::value_id::ValueId;
+
+impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    // -----------------------------------------------------------------------
+    // Let binding
+    // -----------------------------------------------------------------------
+
+    /// Lower `CanExpr::Let { pattern, init, mutable }`.
+    ///
+    /// Per-binding mutability is carried on each `CanBindingPattern::Name`,
+    /// so the statement-level `mutable` flag is not consulted here.
+    pub(crate) fn lower_let(
+        &mut self,
+        pattern: CanBindingPatternId,
+        init: CanId,
+        _mutable: Mutability,
+    ) -> Option<ValueId> {
+        let init_val = self.lower(init)?;
+        let init_type = self.expr_type(init);
+        let binding_pattern = self.canon.arena.get_binding_pattern(pattern);
+        self.bind_pattern(binding_pattern, init_val, init_type, init);
+        // Let bindings produce unit
+        Some(self.builder.const_i64(0))
+    }
+
+    /// Emit debug info for a mutable binding (alloca-backed).
+    fn emit_debug_mutable(&self, name_str: &str, ptr: ValueId, ty: Idx, init_id: CanId) {
+        if let Some(dc) = self.debug_context {
+            if dc.level() == DebugLevel::Full {
+                let init_span = self.canon.arena.span(init_id);
+                if init_span != Span::DUMMY {
+                    if let Some(di_ty) = dc.resolve_debug_type(ty, self.pool) {
+                        let raw = self.builder.raw_value(ptr);
+                        if let (true, Some(cur_bb)) =
+                            (raw.is_pointer_value(), self.builder.current_block())
+                        {
+                            let block = self.builder.raw_block(cur_bb);
+                            dc.emit_declare_for_alloca(
+                                raw.into_pointer_value(),
+                                name_str,
+                                di_ty,
+                                init_span.start,
+                                block,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Emit debug info for an immutable binding (SSA value).
+    fn emit_debug_immutable(&self, name: Name, val: ValueId, ty: Idx, init_id: CanId) {
+        if let Some(dc) = self.debug_context {
+            if dc.level() == DebugLevel::Full {
+                let init_span = self.canon.arena.span(init_id);
+                if init_span != Span::DUMMY {
+                    if let Some(di_ty) = dc.resolve_debug_type(ty, self.pool) {
+                        let raw_val = self.builder.raw_value(val);
+                        let block = self
+                            .builder
+                            .raw_block(self.builder.current_block().unwrap());
+                        let name_str = self.resolve_name(name);
+                        dc.emit_value_for_binding_at_end(
+                            raw_val,
+                            name_str,
+                            di_ty,
+                            init_span.start,
+                            block,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Destructuring
+    // -----------------------------------------------------------------------
+
+    /// Bind a canonical binding pattern to `val` (of Ori type `ty`).
+    ///
+    /// `ty` is threaded through the recursion so nested sub-patterns resolve
+    /// their own element/field types instead of the whole initializer's.
+    /// `init_id` only supplies the source location for debug info.
+    fn bind_pattern(&mut self, pattern: &CanBindingPattern, val: ValueId, ty: Idx, init_id: CanId) {
+        match pattern {
+            CanBindingPattern::Name { name, mutable } => {
+                self.bind_name(*name, *mutable, val, ty, init_id);
+            }
+            CanBindingPattern::Wildcard => {
+                // Discard — don't bind anything
+            }
+            CanBindingPattern::Tuple(elements) => {
+                let elem_types = match self.type_info.get(ty) {
+                    TypeInfo::Tuple { elements } => elements,
+                    _ => {
+                        tracing::warn!(?ty, "tuple pattern on non-tuple type");
+                        self.builder.record_codegen_error();
+                        return;
+                    }
+                };
+                let elem_ids: Vec<_> = self
+                    .canon
+                    .arena
+                    .get_binding_pattern_list(*elements)
+                    .to_vec();
+                for (i, (sub_pat_id, &elem_ty)) in elem_ids.iter().zip(&elem_types).enumerate() {
+                    let sub_pattern = self.canon.arena.get_binding_pattern(*sub_pat_id);
+                    if matches!(sub_pattern, CanBindingPattern::Wildcard) {
+                        continue;
+                    }
+                    if let Some(elem_val) =
+                        self.builder
+                            .extract_value(val, i as u32, &format!("tup.{i}"))
+                    {
+                        self.bind_pattern(sub_pattern, elem_val, elem_ty, init_id);
+                    }
+                }
+            }
+            CanBindingPattern::Struct { fields } => {
+                self.bind_struct_pattern(*fields, val, ty, init_id);
+            }
+            CanBindingPattern::List { elements, rest } => {
+                self.bind_list_pattern(*elements, val, ty, init_id);
+                if let Some((_rest_name, _rest_mutable)) = rest {
+                    tracing::warn!(
+                        "list rest pattern (`...name`) not yet implemented in V2 codegen"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Bind a single name, as an SSA value or an alloca-backed mutable slot.
+    fn bind_name(
+        &mut self,
+        name: Name,
+        mutable: Mutability,
+        val: ValueId,
+        ty: Idx,
+        init_id: CanId,
+    ) {
+        // Per-binding mutability: use the flag from the pattern itself
+        // to support `let ($x, y) = ...` with mixed mutability.
+        if mutable.is_mutable() {
+            let llvm_ty = self.resolve_type(ty);
+            let name_str = self.resolve_name(name).to_owned();
+            let ptr = self
+                .builder
+                .create_entry_alloca(self.current_function, &name_str, llvm_ty);
+            self.builder.store(val, ptr);
+            self.scope.bind_mutable(name, ptr, llvm_ty);
+            if matches!(self.type_info.get(ty), TypeInfo::List { .. }) {
+                // The initial value may share its buffer with other holders.
+                let bool_ty = self.builder.bool_type();
+                let flag = self.builder.create_entry_alloca(
+                    self.current_function,
+                    &format!("{name_str}.owned"),
+                    bool_ty,
+                );
+                let not_owned = self.builder.const_bool(false);
+                self.builder.store(not_owned, flag);
+                self.owned_lists.insert(ptr, flag);
+            }
+            self.emit_debug_mutable(&name_str, ptr, ty, init_id);
+        } else {
+            self.scope.bind_immutable(name, val);
+            self.emit_debug_immutable(name, val, ty, init_id);
+        }
+    }
+
+    /// Bind `let { a, b: pat } = val`.
+    ///
+    /// Field bindings appear in source order; each is resolved to its
+    /// declaration-order index (the LLVM struct layout) by name.
+    fn bind_struct_pattern(
+        &mut self,
+        fields: ori_ir::canon::CanFieldBindingRange,
+        val: ValueId,
+        ty: Idx,
+        init_id: CanId,
+    ) {
+        let TypeInfo::Struct {
+            fields: declared_fields,
+        } = self.type_info.get(ty)
+        else {
+            tracing::warn!(?ty, "struct pattern on non-struct type");
+            self.builder.record_codegen_error();
+            return;
+        };
+
+        let field_bindings: Vec<_> = self.canon.arena.get_field_bindings(fields).to_vec();
+        for fb in &field_bindings {
+            let Some(idx) = declared_fields
+                .iter()
+                .position(|(name, _)| *name == fb.name)
+            else {
+                let field_name = self.resolve_name(fb.name);
+                tracing::warn!(field = field_name, "unknown struct field in pattern");
+                self.builder.record_codegen_error();
+                continue;
+            };
+            let field_ty = declared_fields[idx].1;
+            if let Some(field_val) = self.builder.extract_value(
+                val,
+                idx as u32,
+                &format!("field.{}", self.resolve_name(fb.name)),
+            ) {
+                let sub_pattern = self.canon.arena.get_binding_pattern(fb.pattern);
+                self.bind_pattern(sub_pattern, field_val, field_ty, init_id);
+            }
+        }
+    }
+
+    /// Bind `let [a, b] = list` by loading elements from the list's buffer.
+    ///
+    /// Lists are `{len, cap, data}` structs; elements live behind `data`.
+    /// A list shorter than the pattern panics, matching the interpreter.
+    fn bind_list_pattern(
+        &mut self,
+        elements: ori_ir::canon::CanBindingPatternRange,
+        val: ValueId,
+        ty: Idx,
+        init_id: CanId,
+    ) {
+        let TypeInfo::List { element } = self.type_info.get(ty) else {
+            tracing::warn!(?ty, "list pattern on non-list type");
+            self.builder.record_codegen_error();
+            return;
+        };
+        let elem_ids: Vec<_> = self.canon.arena.get_binding_pattern_list(elements).to_vec();
+        if elem_ids.is_empty() {
+            return;
+        }
+
+        let (Some(len), Some(data_ptr)) = (
+            self.builder.extract_value(val, 0, "list.len"),
+            self.builder.extract_value(val, 2, "list.data"),
+        ) else {
+            return;
+        };
+
+        // Length guard: the pattern needs at least `elem_ids.len()` elements.
+        let needed = self.builder.const_i64(elem_ids.len() as i64);
+        let too_short = self.builder.icmp_ult(len, needed, "destr.short");
+        let panic_bb = self
+            .builder
+            .append_block(self.current_function, "destr.panic");
+        let bind_bb = self
+            .builder
+            .append_block(self.current_function, "destr.bind");
+        self.builder.cond_br(too_short, panic_bb, bind_bb);
+
+        self.builder.position_at_end(panic_bb);
+        let msg = self
+            .builder
+            .build_global_string_ptr("list pattern too long for value", "panic.destr_msg");
+        if let Some(panic_fn) = self.builder.scx().llmod.get_function("ori_panic_cstr") {
+            let panic_id = self.builder.intern_function(panic_fn);
+            self.builder.call(panic_id, &[msg], "");
+        }
+        self.builder.unreachable();
+
+        self.builder.position_at_end(bind_bb);
+        let elem_llvm_ty = self.resolve_type(element);
+        for (i, sub_pat_id) in elem_ids.iter().enumerate() {
+            let sub_pattern = self.canon.arena.get_binding_pattern(*sub_pat_id);
+            if matches!(sub_pattern, CanBindingPattern::Wildcard) {
+                continue;
+            }
+            let idx = self.builder.const_i64(i as i64);
+            let elem_ptr =
+                self.builder
+                    .gep(elem_llvm_ty, data_ptr, &[idx], &format!("list.{i}.ptr"));
+            let elem_val = self
+                .builder
+                .load(elem_llvm_ty, elem_ptr, &format!("list.{i}"));
+            self.bind_pattern(sub_pattern, elem_val, element, init_id);
+        }
+    }
+}
@@ -54,6 +54,13 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         args: CanRange,
     ) -> Option<ValueId> {
         match method {
+            // Map layout {i64 len, i64 cap, ptr keys, ptr vals}: len is field 0
+            "len" | "length" => self.builder.extract_value(recv, 0, "map.len"),
+            "is_empty" => {
+                let len = self.builder.extract_value(recv, 0, "map.len")?;
+                let zero = self.builder.const_i64(0);
+                Some(self.builder.icmp_eq(len, zero, "map.is_empty"))
+            }
             "clone" => Some(recv),
             "equals" => {
                 let arg_ids = self.canon.arena.get_expr_list(args);
@@ -75,6 +82,12 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         args: CanRange,
     ) -> Option<ValueId> {
         match method {
+            "len" | "length" => self.builder.extract_value(recv, 0, "set.len"),
+            "is_empty" => {
+                let len = self.builder.extract_value(recv, 0, "set.len")?;
+                let zero = self.builder.const_i64(0);
+                Some(self.builder.icmp_eq(len, zero, "set.is_empty"))
+            }
             "equals" => {
                 let arg_ids = self.canon.arena.get_expr_list(args);
                 let other = self.lower(*arg_ids.first()?)?;
@@ -208,15 +208,4 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         self.builder
             .call(count_fn, &[iter, elem_size], "iter.count")
     }
-
-    // -- Helpers --
-
-    /// Compute element byte size as an i64 constant.
-    ///
-    /// Uses `TypeInfo::size()` for known types. Falls back to 8 for types
-    /// where size is not statically known (Tuple, Struct, Enum).
-    fn compute_elem_byte_size(&mut self, elem_type: Idx) -> ValueId {
-        let size = self.type_info.get(elem_type).size().unwrap_or(8);
-        self.builder.const_i64(size as i64)
-    }
 }
@@ -13,8 +13,8 @@
 //! - **Result**: `is_ok`, `is_err`, `unwrap`, `compare`, `equals`, `hash`, `clone`
 //! - **Tuple**: `len`, `compare`, `equals`, `hash`, `clone`
 //! - **List**: `len`, `is_empty`, `clone`, `compare`, `equals`, `hash`
-//! - **Map**: `clone`, `equals`, `hash`
-//! - **Set**: `clone`, `equals`, `hash`
+//! - **Map**: `len`, `is_empty`, `clone`, `equals`, `hash`
+//! - **Set**: `len`, `is_empty`, `clone`, `equals`, `hash`, `into`, `to_list`
 //! - **Iterator**: `map`, `filter`, `take`, `skip`, `enumerate`, `collect`, `count`
 //! - **Range**: `iter`
 
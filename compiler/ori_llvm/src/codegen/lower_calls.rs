@@ -15,6 +15,7 @@ use ori_types::Idx;
 use super::abi::{ParamPassing, ReturnPassing};
 use super::expr_lowerer::ExprLowerer;
 use super::scope::ScopeBinding;
+use super::tail_calls::abi_supports_tail_call;
 use super::type_info::TypeInfo;
 use super::value_id::{FunctionId, LLVMTypeId, ValueId};
 
@@ -28,8 +29,15 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// Handles:
     /// 1. Built-in type conversions (`str()`, `int()`, `float()`, `byte()`)
     /// 2. Closure calls (if callee is a local binding)
-    /// 3. Direct function calls via module lookup
-    pub(crate) fn lower_call(&mut self, func: CanId, args: CanRange) -> Option<ValueId> {
+    /// 3. `self(...)` recursion to the current function
+    /// 4. Direct function calls via module lookup (self-recursive calls in
+    ///    tail position become `tail call` + `ret`)
+    pub(crate) fn lower_call(
+        &mut self,
+        func: CanId,
+        args: CanRange,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
         let func_kind = *self.canon.arena.kind(func);
 
         // Check if callee is a named function
@@ -53,8 +61,25 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
                 return self.lower_closure_call(binding, args, callee_type);
             }
 
+            // `self(...)` inside `recurse` — call the enclosing function
+            let func_name = match self.current_function_name {
+                Some(current) if func_name == self.prop_names.recurse_self => current,
+                _ => func_name,
+            };
+
             // Look up in declared function map (has ABI info for sret)
             if let Some((func_id, abi)) = self.functions.get(&func_name) {
+                // A provider spilled to this frame can't outlive a tail call.
+                if *func_id == self.current_function
+                    && self.tail_calls.contains(&expr_id)
+                    && abi_supports_tail_call(abi)
+                    && !abi
+                        .capabilities
+                        .iter()
+                        .any(|c| self.capabilities.contains_key(c))
+                {
+                    return self.lower_self_tail_call(*func_id, abi, args);
+                }
                 return self.lower_abi_call(*func_id, abi, args);
             }
 
@@ -144,7 +169,8 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         }
 
         // Build final argument list, respecting passing modes
-        let arg_vals = self.apply_param_passing(&raw_arg_vals, &abi.params);
+        let mut arg_vals = self.apply_param_passing(&raw_arg_vals, &abi.params);
+        arg_vals.extend(self.capability_args(&abi.capabilities));
 
         match &abi.return_abi.passing {
             ReturnPassing::Sret { .. } => {
@@ -348,7 +374,8 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         }
 
         // Apply param passing modes (Reference → alloca + store + pass ptr)
-        let all_args = self.apply_param_passing(&raw_args, &abi.params);
+        let mut all_args = self.apply_param_passing(&raw_args, &abi.params);
+        all_args.extend(self.capability_args(&abi.capabilities));
 
         match &abi.return_abi.passing {
             ReturnPassing::Sret { .. } => {
@@ -381,7 +408,8 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         }
 
         // Apply param passing modes (Reference → alloca + store + pass ptr)
-        let all_args = self.apply_param_passing(&raw_args, &abi.params);
+        let mut all_args = self.apply_param_passing(&raw_args, &abi.params);
+        all_args.extend(self.capability_args(&abi.capabilities));
 
         match &abi.return_abi.passing {
             ReturnPassing::Sret { .. } => {
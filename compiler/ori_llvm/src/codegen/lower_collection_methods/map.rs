@@ -1,11 +1,203 @@
-//! Map equality and hashing via runtime loops.
+//! Map lookup, equality, and hashing via runtime loops.
 
 use ori_types::Idx;
 
 use crate::codegen::expr_lowerer::ExprLowerer;
 use crate::codegen::value_id::ValueId;
 
+/// Whether maps keyed by `key_type` carry a runtime hash index, and if so
+/// whether the keys are strings.
+///
+/// `str` keys and scalars compared by their bytes (`int`, `char`, `bool`,
+/// `byte`) are indexed by `ori_map_new`/`ori_map_insert`; other key types
+/// are stored in literal order and found by scanning.
+pub(crate) fn hashed_map_keys(key_type: Idx) -> Option<bool> {
+    match key_type {
+        Idx::STR => Some(true),
+        Idx::INT | Idx::CHAR | Idx::BOOL | Idx::BYTE => Some(false),
+        _ => None,
+    }
+}
+
 impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    // -----------------------------------------------------------------------
+    // Map lookup — hash index, or O(n) key scan
+    // -----------------------------------------------------------------------
+
+    /// Emit `map[key]` → `Option<V>`.
+    ///
+    /// Keys with a runtime hash index (see [`hashed_map_keys`]) are found by
+    /// `ori_map_find`; other keys by scanning the key array. Returns
+    /// `Some(vals[i])`, or `None` if no key matches. `opt_type` is the
+    /// `Option<V>` type of the index expression.
+    ///
+    /// Layout: Map = `{i64 len, i64 cap, ptr keys, ptr vals}`.
+    pub(crate) fn emit_map_lookup(
+        &mut self,
+        map: ValueId,
+        key: ValueId,
+        key_type: Idx,
+        val_type: Idx,
+        opt_type: Idx,
+    ) -> Option<ValueId> {
+        if let Some(str_keys) = hashed_map_keys(key_type) {
+            return self.emit_map_hash_lookup(map, key, key_type, val_type, opt_type, str_keys);
+        }
+
+        let len = self.builder.extract_value(map, 0, "mget.len")?;
+        let keys = self.builder.extract_value(map, 2, "mget.keys")?;
+        let vals = self.builder.extract_value(map, 3, "mget.vals")?;
+
+        let key_llvm_ty = self.resolve_type(key_type);
+        let val_llvm_ty = self.resolve_type(val_type);
+        let opt_llvm_ty = self.resolve_type(opt_type);
+
+        let entry_bb = self.builder.current_block()?;
+        let hdr = self.builder.append_block(self.current_function, "mget.hdr");
+        let body = self
+            .builder
+            .append_block(self.current_function, "mget.body");
+        let latch = self
+            .builder
+            .append_block(self.current_function, "mget.latch");
+        let found_bb = self
+            .builder
+            .append_block(self.current_function, "mget.found");
+        let missing_bb = self
+            .builder
+            .append_block(self.current_function, "mget.missing");
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "mget.merge");
+
+        self.builder.br(hdr);
+
+        // Header: i < len
+        self.builder.position_at_end(hdr);
+        let i64_ty = self.builder.i64_type();
+        let zero = self.builder.const_i64(0);
+        let i = self.builder.phi(i64_ty, "mget.i");
+        self.builder.add_phi_incoming(i, &[(zero, entry_bb)]);
+        let i_ok = self.builder.icmp_slt(i, len, "mget.i.ok");
+        self.builder.cond_br(i_ok, body, missing_bb);
+
+        // Body: compare keys[i] with the probe key
+        self.builder.position_at_end(body);
+        let k_ptr = self.builder.gep(key_llvm_ty, keys, &[i], "mget.k.ptr");
+        let k = self.builder.load(key_llvm_ty, k_ptr, "mget.k");
+        let key_eq = self.emit_inner_eq(k, key, key_type, "mget.keq");
+        self.builder.cond_br(key_eq, found_bb, latch);
+
+        // Latch: i++
+        self.builder.position_at_end(latch);
+        let one = self.builder.const_i64(1);
+        let next_i = self.builder.add(i, one, "mget.i.next");
+        self.builder.add_phi_incoming(i, &[(next_i, latch)]);
+        self.builder.br(hdr);
+
+        // Found: Some(vals[i])
+        self.builder.position_at_end(found_bb);
+        let v_ptr = self.builder.gep(val_llvm_ty, vals, &[i], "mget.v.ptr");
+        let v = self.builder.load(val_llvm_ty, v_ptr, "mget.v");
+        let some_tag = self.builder.const_i8(1);
+        let some = self
+            .builder
+            .build_struct(opt_llvm_ty, &[some_tag, v], "mget.some");
+        self.builder.br(merge_bb);
+
+        // Missing: None
+        self.builder.position_at_end(missing_bb);
+        let none_tag = self.builder.const_i8(0);
+        let zero_payload = self.zero_value_for_option_payload(opt_type);
+        let none = self
+            .builder
+            .build_struct(opt_llvm_ty, &[none_tag, zero_payload], "mget.none");
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(merge_bb);
+        self.builder.phi_from_incoming(
+            opt_llvm_ty,
+            &[(some, found_bb), (none, missing_bb)],
+            "mget.result",
+        )
+    }
+
+    /// Emit `map[key]` through the runtime hash index: `ori_map_find`
+    /// returns the key's dense index, or -1 when it is absent.
+    fn emit_map_hash_lookup(
+        &mut self,
+        map: ValueId,
+        key: ValueId,
+        key_type: Idx,
+        val_type: Idx,
+        opt_type: Idx,
+        str_keys: bool,
+    ) -> Option<ValueId> {
+        let cap = self.builder.extract_value(map, 1, "mget.cap")?;
+        let keys = self.builder.extract_value(map, 2, "mget.keys")?;
+        let vals = self.builder.extract_value(map, 3, "mget.vals")?;
+
+        let val_llvm_ty = self.resolve_type(val_type);
+        let opt_llvm_ty = self.resolve_type(opt_type);
+
+        let key_ptr = self.alloca_and_store(key, "mget.key");
+        let key_size = self.compute_elem_byte_size(key_type);
+        let str_keys = self.builder.const_bool(str_keys);
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let bool_ty = self.builder.bool_type();
+        let find_fn = self.builder.get_or_declare_function(
+            "ori_map_find",
+            &[ptr_ty, i64_ty, ptr_ty, i64_ty, bool_ty],
+            i64_ty,
+        );
+        let index = self.builder.call(
+            find_fn,
+            &[keys, cap, key_ptr, key_size, str_keys],
+            "mget.index",
+        )?;
+
+        let found_bb = self
+            .builder
+            .append_block(self.current_function, "mget.found");
+        let missing_bb = self
+            .builder
+            .append_block(self.current_function, "mget.missing");
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "mget.merge");
+
+        let zero = self.builder.const_i64(0);
+        let found = self.builder.icmp_sge(index, zero, "mget.hit");
+        self.builder.cond_br(found, found_bb, missing_bb);
+
+        // Found: Some(vals[index])
+        self.builder.position_at_end(found_bb);
+        let v_ptr = self.builder.gep(val_llvm_ty, vals, &[index], "mget.v.ptr");
+        let v = self.builder.load(val_llvm_ty, v_ptr, "mget.v");
+        let some_tag = self.builder.const_i8(1);
+        let some = self
+            .builder
+            .build_struct(opt_llvm_ty, &[some_tag, v], "mget.some");
+        self.builder.br(merge_bb);
+
+        // Missing: None
+        self.builder.position_at_end(missing_bb);
+        let none_tag = self.builder.const_i8(0);
+        let zero_payload = self.zero_value_for_option_payload(opt_type);
+        let none = self
+            .builder
+            .build_struct(opt_llvm_ty, &[none_tag, zero_payload], "mget.none");
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(merge_bb);
+        self.builder.phi_from_incoming(
+            opt_llvm_ty,
+            &[(some, found_bb), (none, missing_bb)],
+            "mget.result",
+        )
+    }
+
     // -----------------------------------------------------------------------
     // Map equals — O(n²) key-value containment check
     // -----------------------------------------------------------------------
@@ -9,8 +9,10 @@
 //!
 //! - **List**: `compare`, `hash`, `equals` (in `list.rs`)
 //! - **Set**: `equals`, `hash` (in `set.rs`)
-//! - **Map**: `equals`, `hash` (in `map.rs`)
+//! - **Map**: lookup, `equals`, `hash` (in `map.rs`)
 
 mod list;
 mod map;
 mod set;
+
+pub(crate) use map::hashed_map_keys;
@@ -3,13 +3,33 @@
 //! Handles construction and access for tuples, structs, ranges, lists,
 //! maps, sets, and their field/index operations.
 
+use inkwell::types::BasicType;
 use ori_ir::canon::{CanFieldRange, CanId, CanMapEntryRange, CanRange};
 use ori_ir::Name;
 use ori_types::Idx;
 
 use super::expr_lowerer::ExprLowerer;
-use super::type_info::TypeInfo;
-use super::value_id::ValueId;
+use super::ir_builder::IrBuilder;
+use super::lower_collection_methods::hashed_map_keys;
+use super::type_info::{TypeInfo, TypeLayoutResolver};
+use super::value_id::{LLVMTypeId, ValueId};
+
+/// Compute the byte size of one `elem_type` slot as an i64 constant.
+///
+/// Taken from the resolved LLVM type so it matches the stride that
+/// `gep` uses, including padding inside tuples, structs, and nested
+/// Option/Result payloads (where `TypeInfo::size()` is absent or
+/// too small).
+pub(crate) fn compute_elem_byte_size<'ll: 'ctx, 'ctx>(
+    builder: &mut IrBuilder<'_, 'ctx>,
+    type_resolver: &TypeLayoutResolver<'_, 'll, '_>,
+    elem_type: Idx,
+) -> ValueId {
+    match type_resolver.resolve(elem_type).size_of() {
+        Some(size) => builder.intern_value(size.into()),
+        None => builder.const_i64(8),
+    }
+}
 
 impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     // -----------------------------------------------------------------------
@@ -224,10 +244,17 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
     /// Lower `CanExpr::Index { receiver, index }` — `expr[index]`.
     ///
-    /// For lists: bounds-check + element pointer access.
+    /// For lists: bounds-check + element pointer access, or a copied
+    /// sub-list for a range index (see `lower_slice`).
+    /// For maps: key lookup producing `Option<V>`.
     /// For tuples: static index extraction.
-    pub(crate) fn lower_index(&mut self, receiver: CanId, index: CanId) -> Option<ValueId> {
-        let recv_val = self.lower(receiver)?;
+    pub(crate) fn lower_index(
+        &mut self,
+        receiver: CanId,
+        index: CanId,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let recv_val = self.lower_inspected(receiver)?;
         let recv_type = self.expr_type(receiver);
         let type_info = self.type_info.get(recv_type);
 
@@ -249,52 +276,21 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         match &type_info {
             TypeInfo::List { element } => {
                 let elem_idx = *element;
+                let index_type = self.expr_type(index);
+                if matches!(self.type_info.get(index_type), TypeInfo::Range) {
+                    return self.lower_list_slice(recv_val, idx_val, elem_idx, expr_id);
+                }
                 let elem_llvm_ty = self.resolve_type(elem_idx);
 
                 // Extract data pointer from list struct: field 2
                 let data_ptr = self.builder.extract_value(recv_val, 2, "list.data")?;
-
-                // Bounds check — use unsigned comparison so negative indices
-                // (e.g., from a buggy `# - N` expression) are caught as OOB
-                // instead of passing a signed `idx < len` check.
                 let len = self.builder.extract_value(recv_val, 0, "list.len")?;
-                let in_bounds = self.builder.icmp_ult(idx_val, len, "idx.inbounds");
-
-                let access_bb = self
-                    .builder
-                    .append_block(self.current_function, "idx.access");
-                let panic_bb = self
-                    .builder
-                    .append_block(self.current_function, "idx.panic");
-                let merge_bb = self
-                    .builder
-                    .append_block(self.current_function, "idx.merge");
-
-                self.builder.cond_br(in_bounds, access_bb, panic_bb);
+                self.emit_index_bounds_check(idx_val, len, expr_id);
 
-                // Panic on out-of-bounds
-                self.builder.position_at_end(panic_bb);
-                self.emit_index_panic();
-                self.builder.unreachable();
-
-                // Access element
-                self.builder.position_at_end(access_bb);
                 let elem_ptr = self
                     .builder
                     .gep(elem_llvm_ty, data_ptr, &[idx_val], "idx.elem_ptr");
-                let elem_val = self.builder.load(elem_llvm_ty, elem_ptr, "idx.elem");
-
-                if !self.builder.current_block_terminated() {
-                    self.builder.br(merge_bb);
-                }
-                let access_exit = self.builder.current_block()?;
-
-                self.builder.position_at_end(merge_bb);
-                self.builder.phi_from_incoming(
-                    elem_llvm_ty,
-                    &[(elem_val, access_exit)],
-                    "idx.result",
-                )
+                Some(self.builder.load(elem_llvm_ty, elem_ptr, "idx.elem"))
             }
             TypeInfo::Str => {
                 // String indexing: access byte at index
@@ -330,6 +326,10 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
                     None
                 }
             }
+            TypeInfo::Map { key, value } => {
+                let opt_type = self.expr_type(expr_id);
+                self.emit_map_lookup(recv_val, idx_val, *key, *value, opt_type)
+            }
             _ => {
                 tracing::warn!(?recv_type, "index access on unsupported type");
                 self.builder.record_codegen_error();
@@ -338,16 +338,46 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         }
     }
 
-    /// Emit a panic for index out-of-bounds.
-    fn emit_index_panic(&mut self) {
-        let msg = self
+    /// Compute the byte size of one `elem_type` slot as an i64 constant.
+    ///
+    /// See the free [`compute_elem_byte_size`].
+    pub(crate) fn compute_elem_byte_size(&mut self, elem_type: Idx) -> ValueId {
+        compute_elem_byte_size(self.builder, self.type_resolver, elem_type)
+    }
+
+    /// Guard a list access: panic unless `0 <= idx < len`.
+    ///
+    /// Uses an unsigned comparison so negative indices (e.g., from a buggy
+    /// `# - N` expression) are caught as out of bounds instead of passing a
+    /// signed `idx < len` check. Leaves the builder in the in-bounds block.
+    pub(crate) fn emit_index_bounds_check(&mut self, idx: ValueId, len: ValueId, expr_id: CanId) {
+        let in_bounds = self.builder.icmp_ult(idx, len, "idx.inbounds");
+        let access_bb = self
             .builder
-            .build_global_string_ptr("index out of bounds", "panic.idx_msg");
+            .append_block(self.current_function, "idx.access");
+        let panic_bb = self
+            .builder
+            .append_block(self.current_function, "idx.panic");
+        self.builder.cond_br(in_bounds, access_bb, panic_bb);
+
+        self.builder.position_at_end(panic_bb);
+        self.emit_index_panic(idx, expr_id);
+        self.builder.unreachable();
 
-        // Try to call ori_panic_cstr
-        if let Some(panic_fn) = self.builder.scx().llmod.get_function("ori_panic_cstr") {
+        self.builder.position_at_end(access_bb);
+    }
+
+    /// Emit a panic for index out-of-bounds via `ori_index_panic`.
+    ///
+    /// Reports the offending index and the source location of `expr_id`,
+    /// matching the interpreter's `index N out of bounds` error.
+    pub(crate) fn emit_index_panic(&mut self, index: ValueId, expr_id: CanId) {
+        let (line, col) = self.panic_location(expr_id);
+        if let Some(panic_fn) = self.builder.scx().llmod.get_function("ori_index_panic") {
             let panic_id = self.builder.intern_function(panic_fn);
-            self.builder.call(panic_id, &[msg], "");
+            let line_val = self.builder.const_i32(line);
+            let col_val = self.builder.const_i32(col);
+            self.builder.call(panic_id, &[index, line_val, col_val], "");
         }
     }
 
@@ -361,7 +391,6 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// and builds a `{len, cap, data}` struct.
     pub(crate) fn lower_list(&mut self, range: CanRange, expr_id: CanId) -> Option<ValueId> {
         let expr_ids = self.canon.arena.get_expr_list(range);
-        let count = expr_ids.len();
 
         let result_type = self.expr_type(expr_id);
         let type_info = self.type_info.get(result_type);
@@ -369,12 +398,32 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             TypeInfo::List { element } => *element,
             _ => Idx::INT,
         };
+
+        let mut compiled_values = Vec::with_capacity(expr_ids.len());
+        for &eid in expr_ids {
+            let val = self.lower(eid)?;
+            compiled_values.push(val);
+        }
+
+        self.build_list(&compiled_values, elem_idx, result_type)
+    }
+
+    /// Build a list of type `list_type` holding `values`, in order.
+    ///
+    /// Allocates an exactly-sized data buffer via `ori_list_alloc_data` and
+    /// stores each element into it.
+    pub(crate) fn build_list(
+        &mut self,
+        values: &[ValueId],
+        elem_idx: Idx,
+        list_type: Idx,
+    ) -> Option<ValueId> {
+        let count = values.len();
         let elem_llvm_ty = self.resolve_type(elem_idx);
-        let elem_size = self.type_info.get(elem_idx).size().unwrap_or(8);
 
         // Allocate raw data buffer: ori_list_alloc_data(capacity, elem_size) -> *mut u8
         let cap = self.builder.const_i64(count as i64);
-        let esize = self.builder.const_i64(elem_size as i64);
+        let esize = self.compute_elem_byte_size(elem_idx);
         let i64_ty = self.builder.i64_type();
         let i64_ty2 = self.builder.i64_type();
         let ptr_ty = self.builder.ptr_type();
@@ -384,13 +433,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         let data_ptr = self.builder.call(alloc_data, &[cap, esize], "list.data")?;
 
         // Store each element
-        let mut compiled_values = Vec::with_capacity(count);
-        for &eid in expr_ids {
-            let val = self.lower(eid)?;
-            compiled_values.push(val);
-        }
-
-        for (i, val) in compiled_values.iter().enumerate() {
+        for (i, val) in values.iter().enumerate() {
             let idx = self.builder.const_i64(i as i64);
             let elem_ptr = self
                 .builder
@@ -400,7 +443,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
         // Build list struct: {i64 len, i64 cap, ptr data}
         let len = self.builder.const_i64(count as i64);
-        let list_ty = self.resolve_type(result_type);
+        let list_ty = self.resolve_type(list_type);
         Some(
             self.builder
                 .build_struct(list_ty, &[len, cap, data_ptr], "list"),
@@ -413,7 +456,10 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
     /// Lower `CanExpr::Map(entries)` — `{k: v, ...}`.
     ///
-    /// Allocates key and value arrays, stores entries, builds map struct.
+    /// Allocates key and value arrays and stores the entries. Keys with a
+    /// runtime hash index (see [`hashed_map_keys`]) go through
+    /// `ori_map_insert`, which also drops duplicate keys (the last value
+    /// wins); other keys are stored in literal order.
     pub(crate) fn lower_map(
         &mut self,
         entries: CanMapEntryRange,
@@ -430,8 +476,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         };
         let key_llvm_ty = self.resolve_type(key_idx);
         let val_llvm_ty = self.resolve_type(val_idx);
-        let key_size = self.type_info.get(key_idx).size().unwrap_or(8);
-        let val_size = self.type_info.get(val_idx).size().unwrap_or(8);
+        let hashed = hashed_map_keys(key_idx);
 
         // Allocate key and value data buffers
         let cap = self.builder.const_i64(count as i64);
@@ -441,45 +486,111 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             self.builder
                 .get_or_declare_function("ori_list_alloc_data", &[i64_ty, i64_ty], ptr_ty);
 
-        let key_elem_sz = self.builder.const_i64(key_size as i64);
-        let keys_buf = self
-            .builder
-            .call(alloc_data, &[cap, key_elem_sz], "map.keys")?;
+        let key_elem_sz = self.compute_elem_byte_size(key_idx);
+        let keys_buf = if hashed.is_some() {
+            let map_new =
+                self.builder
+                    .get_or_declare_function("ori_map_new", &[i64_ty, i64_ty], ptr_ty);
+            self.builder
+                .call(map_new, &[cap, key_elem_sz], "map.keys")?
+        } else {
+            self.builder
+                .call(alloc_data, &[cap, key_elem_sz], "map.keys")?
+        };
 
-        let val_elem_sz = self.builder.const_i64(val_size as i64);
+        let val_elem_sz = self.compute_elem_byte_size(val_idx);
         let vals_buf = self
             .builder
             .call(alloc_data, &[cap, val_elem_sz], "map.vals")?;
 
         // Store each entry
-        let mut compiled_keys = Vec::with_capacity(count);
-        let mut compiled_vals = Vec::with_capacity(count);
+        let mut compiled = Vec::with_capacity(count);
         for entry in map_entries {
             let key = self.lower(entry.key)?;
             let val = self.lower(entry.value)?;
-            compiled_keys.push(key);
-            compiled_vals.push(val);
+            compiled.push((key, val));
         }
 
-        for (i, (key, val)) in compiled_keys.iter().zip(compiled_vals.iter()).enumerate() {
-            let idx = self.builder.const_i64(i as i64);
-            let kp = self
-                .builder
-                .gep(key_llvm_ty, keys_buf, &[idx], "map.key_ptr");
-            self.builder.store(*key, kp);
+        let len = if let Some(str_keys) = hashed {
+            self.store_hashed_map_entries(
+                keys_buf,
+                vals_buf,
+                cap,
+                (key_llvm_ty, key_elem_sz, str_keys),
+                val_llvm_ty,
+                &compiled,
+            )?
+        } else {
+            for (i, (key, val)) in compiled.iter().enumerate() {
+                let idx = self.builder.const_i64(i as i64);
+                let kp = self
+                    .builder
+                    .gep(key_llvm_ty, keys_buf, &[idx], "map.key_ptr");
+                self.builder.store(*key, kp);
 
-            let vp = self
-                .builder
-                .gep(val_llvm_ty, vals_buf, &[idx], "map.val_ptr");
-            self.builder.store(*val, vp);
-        }
+                let vp = self
+                    .builder
+                    .gep(val_llvm_ty, vals_buf, &[idx], "map.val_ptr");
+                self.builder.store(*val, vp);
+            }
+            self.builder.const_i64(count as i64)
+        };
 
         // Build map struct: {i64 len, i64 cap, ptr keys, ptr vals}
-        let len = self.builder.const_i64(count as i64);
         let map_ty = self.resolve_type(result_type);
         Some(
             self.builder
                 .build_struct(map_ty, &[len, cap, keys_buf, vals_buf], "map"),
         )
     }
+
+    /// Insert each key into the hash-indexed `keys_buf` with
+    /// `ori_map_insert` and store its value at the returned index.
+    ///
+    /// `key` is the key's LLVM type, byte size, and whether keys are
+    /// strings. Returns the map's length after duplicates are merged.
+    fn store_hashed_map_entries(
+        &mut self,
+        keys_buf: ValueId,
+        vals_buf: ValueId,
+        cap: ValueId,
+        key: (LLVMTypeId, ValueId, bool),
+        val_llvm_ty: LLVMTypeId,
+        entries: &[(ValueId, ValueId)],
+    ) -> Option<ValueId> {
+        let (key_llvm_ty, key_size, str_keys) = key;
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let bool_ty = self.builder.bool_type();
+        let insert_fn = self.builder.get_or_declare_function(
+            "ori_map_insert",
+            &[ptr_ty, i64_ty, ptr_ty, ptr_ty, i64_ty, bool_ty],
+            i64_ty,
+        );
+
+        let len_slot = self
+            .builder
+            .create_entry_alloca(self.current_function, "map.len", i64_ty);
+        let zero = self.builder.const_i64(0);
+        self.builder.store(zero, len_slot);
+        let key_slot =
+            self.builder
+                .create_entry_alloca(self.current_function, "map.key", key_llvm_ty);
+        let str_keys = self.builder.const_bool(str_keys);
+
+        for &(key, val) in entries {
+            self.builder.store(key, key_slot);
+            let idx = self.builder.call(
+                insert_fn,
+                &[keys_buf, cap, len_slot, key_slot, key_size, str_keys],
+                "map.index",
+            )?;
+            let vp = self
+                .builder
+                .gep(val_llvm_ty, vals_buf, &[idx], "map.val_ptr");
+            self.builder.store(val, vp);
+        }
+
+        Some(self.builder.load(i64_ty, len_slot, "map.len"))
+    }
 }
@@ -0,0 +1,541 @@
+//! Concurrency pattern lowering for V2 codegen.
+//!
+//! - `parallel(tasks: [...], max_concurrent:, timeout:)` hands the task
+//!   list's closures to `ori_parallel`, which runs them on worker threads and
+//!   reports each task's value or failure. The results are collected, in
+//!   task order, into a `[Result<T, str>]`: a task that panics or misses the
+//!   timeout becomes `Err(message)`.
+//! - `timeout(operation: expr, after: duration)` compiles the operation as a
+//!   `() -> T` closure and runs it through `ori_run_with_timeout`, yielding
+//!   `Result<T, str>`.
+//! - `catch(expr: expr)` compiles the expression the same way and runs it on
+//!   the current thread through `ori_catch`, which turns a panic into
+//!   `Err(message)`.
+//!
+//! All three share one task trampoline per result type, with the ABI
+//! `(ptr %wrapper, ptr %out) -> void`.
+
+use ori_ir::canon::{CanId, CanNamedExprRange, CanParamRange};
+use ori_types::Idx;
+
+use super::expr_lowerer::ExprLowerer;
+use super::type_info::TypeInfo;
+use super::value_id::{FunctionId, LLVMTypeId, ValueId};
+
+/// Byte size of the runtime's `OriTaskStatus`: `{ i8 failed, str message }`.
+const TASK_STATUS_SIZE: i64 = 24;
+/// Byte offset of `OriTaskStatus::message`.
+const TASK_STATUS_MESSAGE_OFFSET: i64 = 8;
+
+impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    /// Lower `parallel(tasks: [() -> T], max_concurrent:, timeout:)`.
+    ///
+    /// `ori_parallel` writes each task's value into a heap result buffer and
+    /// its outcome into a status buffer. A loop then wraps every slot in
+    /// `Ok(value)` or, for a failed task, `Err(message)`. `max_concurrent`
+    /// and `timeout` may be given as `int`/`Duration` or as their `Option`s.
+    pub(crate) fn lower_exp_parallel(
+        &mut self,
+        props: CanNamedExprRange,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let named_exprs = self.canon.arena.get_named_exprs(props);
+        let find = |name| {
+            named_exprs
+                .iter()
+                .find(|ne| ne.name == name)
+                .map(|ne| ne.value)
+        };
+        let Some(tasks) = find(self.prop_names.tasks) else {
+            tracing::warn!("parallel expression missing tasks property");
+            self.builder.record_codegen_error();
+            return None;
+        };
+        let max_concurrent = find(self.prop_names.max_concurrent);
+        let timeout = find(self.prop_names.timeout);
+
+        let tasks_type = self.expr_type(tasks);
+        let is_closure_list = match self.type_info.get(tasks_type) {
+            TypeInfo::List { element } => {
+                matches!(self.type_info.get(element), TypeInfo::Function { .. })
+            }
+            _ => false,
+        };
+        if !is_closure_list {
+            tracing::warn!(?tasks_type, "parallel tasks must be a list of closures");
+            self.builder.record_codegen_error();
+            return None;
+        }
+
+        let list_type = self.expr_type(expr_id);
+        let (result_idx, ok_idx) = match self.type_info.get(list_type) {
+            TypeInfo::List { element } => match self.type_info.get(element) {
+                TypeInfo::Result { ok, .. } => (element, ok),
+                other => {
+                    tracing::warn!(?other, "parallel result element is not a Result");
+                    self.builder.record_codegen_error();
+                    return None;
+                }
+            },
+            other => {
+                tracing::warn!(?other, "parallel result is not a list");
+                self.builder.record_codegen_error();
+                return None;
+            }
+        };
+
+        // {i64 len, i64 cap, ptr data}; the data buffer holds the closures
+        // `{ ptr fn_ptr, ptr env_ptr }`, the trampoline wrapper layout.
+        let tasks_val = self.lower(tasks)?;
+        let count = self.builder.extract_value(tasks_val, 0, "parallel.count")?;
+        let task_data = self.builder.extract_value(tasks_val, 2, "parallel.tasks")?;
+
+        // Runtime sentinels: 0 = no concurrency limit, -1 = no timeout.
+        let max_concurrent = match max_concurrent {
+            Some(id) => self.lower_parallel_limit(id, 0)?,
+            None => self.builder.const_i64(0),
+        };
+        let timeout_ms = match timeout {
+            Some(id) => {
+                let nanos = self.lower_parallel_limit(id, -1)?;
+                let millis = self.nanos_to_millis(nanos);
+                let zero = self.builder.const_i64(0);
+                let no_timeout = self.builder.const_i64(-1);
+                let absent = self.builder.icmp_slt(nanos, zero, "parallel.no_timeout");
+                self.builder
+                    .select(absent, no_timeout, millis, "parallel.timeout_ms")
+            }
+            None => self.builder.const_i64(-1),
+        };
+
+        let ptr_ty = self.builder.ptr_type();
+        let i64_ty = self.builder.i64_type();
+        let alloc_data =
+            self.builder
+                .get_or_declare_function("ori_list_alloc_data", &[i64_ty, i64_ty], ptr_ty);
+        let out_size = self.compute_elem_byte_size(ok_idx);
+        let out = self
+            .builder
+            .call(alloc_data, &[count, out_size], "parallel.out")?;
+        let status_size = self.builder.const_i64(TASK_STATUS_SIZE);
+        let status = self
+            .builder
+            .call(alloc_data, &[count, status_size], "parallel.status")?;
+
+        let trampoline = self.get_or_create_task_trampoline(ok_idx);
+        let trampoline_ptr = self.builder.get_function_ptr(trampoline);
+        let parallel_fn = self.builder.get_or_declare_void_function(
+            "ori_parallel",
+            &[
+                ptr_ty, ptr_ty, i64_ty, ptr_ty, i64_ty, ptr_ty, i64_ty, i64_ty,
+            ],
+        );
+        self.builder.call(
+            parallel_fn,
+            &[
+                trampoline_ptr,
+                task_data,
+                count,
+                out,
+                out_size,
+                status,
+                max_concurrent,
+                timeout_ms,
+            ],
+            "",
+        );
+
+        let result_size = self.compute_elem_byte_size(result_idx);
+        let results = self
+            .builder
+            .call(alloc_data, &[count, result_size], "parallel.results")?;
+        self.emit_parallel_results(count, out, status, results, ok_idx, result_idx)?;
+
+        let free_data = self
+            .builder
+            .get_or_declare_void_function("ori_list_free_data", &[ptr_ty, i64_ty, i64_ty]);
+        self.builder.call(free_data, &[out, count, out_size], "");
+        self.builder
+            .call(free_data, &[status, count, status_size], "");
+
+        let list_ty = self.resolve_type(list_type);
+        Some(
+            self.builder
+                .build_struct(list_ty, &[count, count, results], "parallel.list"),
+        )
+    }
+
+    /// Wrap each of `count` task outcomes in a `Result` and store it in
+    /// `results`: `Ok(out[i])` for a completed task, `Err(message)` for a
+    /// failed one.
+    fn emit_parallel_results(
+        &mut self,
+        count: ValueId,
+        out: ValueId,
+        status: ValueId,
+        results: ValueId,
+        ok_idx: Idx,
+        result_idx: Idx,
+    ) -> Option<()> {
+        let ok_ty = self.resolve_type(ok_idx);
+        let str_ty = self.resolve_type(Idx::STR);
+        let result_ty = self.resolve_type(result_idx);
+        let i8_ty = self.builder.i8_type();
+        let i64_ty = self.builder.i64_type();
+
+        let entry_bb = self.builder.current_block()?;
+        let header_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.header");
+        let body_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.body");
+        let ok_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.ok");
+        let err_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.err");
+        let latch_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.latch");
+        let exit_bb = self
+            .builder
+            .append_block(self.current_function, "parallel.exit");
+
+        let zero = self.builder.const_i64(0);
+        self.builder.br(header_bb);
+
+        // Header: index phi + bounds check
+        self.builder.position_at_end(header_bb);
+        let idx = self.builder.phi(i64_ty, "parallel.idx");
+        self.builder.add_phi_incoming(idx, &[(zero, entry_bb)]);
+        let in_bounds = self.builder.icmp_slt(idx, count, "parallel.inbounds");
+        self.builder.cond_br(in_bounds, body_bb, exit_bb);
+
+        // Body: branch on the task's `failed` flag
+        self.builder.position_at_end(body_bb);
+        let status_stride = self.builder.const_i64(TASK_STATUS_SIZE);
+        let status_offset = self
+            .builder
+            .mul(idx, status_stride, "parallel.status_offset");
+        let status_ptr = self
+            .builder
+            .gep(i8_ty, status, &[status_offset], "parallel.status_ptr");
+        let failed = self.builder.load(i8_ty, status_ptr, "parallel.failed");
+        let no = self.builder.const_i8(0);
+        let is_err = self.builder.icmp_ne(failed, no, "parallel.is_err");
+        let result_ptr = self
+            .builder
+            .gep(result_ty, results, &[idx], "parallel.result_ptr");
+        self.builder.cond_br(is_err, err_bb, ok_bb);
+
+        // Ok: the task's value
+        self.builder.position_at_end(ok_bb);
+        let value_ptr = self.builder.gep(ok_ty, out, &[idx], "parallel.value_ptr");
+        let value = self.builder.load(ok_ty, value_ptr, "parallel.value");
+        let ok_tag = self.builder.const_i8(0); // Ok = 0
+        let ok_payload = self.coerce_for_result_payload(value, result_idx, true);
+        let ok = self
+            .builder
+            .build_struct(result_ty, &[ok_tag, ok_payload], "parallel.ok");
+        self.builder.store(ok, result_ptr);
+        self.builder.br(latch_bb);
+
+        // Err: the task's panic or timeout message
+        self.builder.position_at_end(err_bb);
+        let message_offset = self.builder.const_i64(TASK_STATUS_MESSAGE_OFFSET);
+        let message_ptr =
+            self.builder
+                .gep(i8_ty, status_ptr, &[message_offset], "parallel.message_ptr");
+        let message = self.builder.load(str_ty, message_ptr, "parallel.message");
+        let err_tag = self.builder.const_i8(1); // Err = 1
+        let err_payload = self.coerce_for_result_payload(message, result_idx, false);
+        let err = self
+            .builder
+            .build_struct(result_ty, &[err_tag, err_payload], "parallel.err");
+        self.builder.store(err, result_ptr);
+        self.builder.br(latch_bb);
+
+        // Latch
+        self.builder.position_at_end(latch_bb);
+        let one = self.builder.const_i64(1);
+        let next = self.builder.add(idx, one, "parallel.next");
+        self.builder.add_phi_incoming(idx, &[(next, latch_bb)]);
+        self.builder.br(header_bb);
+
+        self.builder.position_at_end(exit_bb);
+        Some(())
+    }
+
+    /// Lower a `parallel` limit given as `int`/`Duration` or as its `Option`
+    /// to an `i64`, with `absent` standing in for `None`.
+    fn lower_parallel_limit(&mut self, id: CanId, absent: i64) -> Option<ValueId> {
+        let val = self.lower(id)?;
+        if !matches!(
+            self.type_info.get(self.expr_type(id)),
+            TypeInfo::Option { .. }
+        ) {
+            return Some(val);
+        }
+        let tag = self.builder.extract_value(val, 0, "limit.tag")?;
+        let payload = self.builder.extract_value(val, 1, "limit.value")?;
+        let some = self.builder.const_i8(1); // Some = 1
+        let is_some = self.builder.icmp_eq(tag, some, "limit.is_some");
+        let absent = self.builder.const_i64(absent);
+        Some(self.builder.select(is_some, payload, absent, "limit"))
+    }
+
+    /// Round a `Duration` in nanoseconds up to whole milliseconds.
+    fn nanos_to_millis(&mut self, nanos: ValueId) -> ValueId {
+        let round_up = self.builder.const_i64(999_999);
+        let nanos_per_milli = self.builder.const_i64(1_000_000);
+        let rounded = self.builder.add(nanos, round_up, "duration.ns");
+        self.builder.sdiv(rounded, nanos_per_milli, "duration.ms")
+    }
+
+    /// Lower `timeout(op: expr, after: duration)` → `Result<T, str>`.
+    ///
+    /// The operation runs on its own thread; `after` (nanoseconds) is
+    /// rounded up to whole milliseconds for the runtime. Yields `Ok(value)`
+    /// if the operation finishes in time and `Err("timed out")` otherwise,
+    /// the message `parallel` reports for a late task.
+    pub(crate) fn lower_exp_timeout(
+        &mut self,
+        props: CanNamedExprRange,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let named_exprs = self.canon.arena.get_named_exprs(props);
+        let find = |name| {
+            named_exprs
+                .iter()
+                .find(|ne| ne.name == name)
+                .map(|ne| ne.value)
+        };
+        // `op` per the spec; `operation` is the older spelling
+        let operation = find(self.prop_names.op).or_else(|| find(self.prop_names.operation));
+        let (Some(operation), Some(after)) = (operation, find(self.prop_names.after)) else {
+            tracing::warn!("timeout expression missing op or after property");
+            self.builder.record_codegen_error();
+            return None;
+        };
+
+        let result_idx = self.expr_type(expr_id);
+        let inner_idx = self.expr_type(operation);
+
+        let nanos = self.lower(after)?;
+        let closure = self.compile_closure(CanParamRange::EMPTY, operation, &[], inner_idx)?;
+        let fn_ptr = self
+            .builder
+            .extract_value(closure, 0, "timeout.closure.fn")?;
+        let env_ptr = self
+            .builder
+            .extract_value(closure, 1, "timeout.closure.env")?;
+        // Heap wrapper: an expired task may still read it after this frame
+        // returns. `ori_run_with_timeout` takes ownership and frees it.
+        let wrapper = self.alloc_trampoline_wrapper(fn_ptr, env_ptr)?;
+        let trampoline = self.get_or_create_task_trampoline(inner_idx);
+        let trampoline_ptr = self.builder.get_function_ptr(trampoline);
+
+        // Zeroed result slot; only written if the task finishes in time.
+        let inner_llvm_ty = self.type_resolver.resolve(inner_idx);
+        let inner_ty = self.builder.register_type(inner_llvm_ty);
+        let out = self
+            .builder
+            .create_entry_alloca(self.current_function, "timeout.out", inner_ty);
+        let zero = self.builder.const_zero(inner_llvm_ty);
+        self.builder.store(zero, out);
+        let out_size = self.compute_elem_byte_size(inner_idx);
+
+        let millis = self.nanos_to_millis(nanos);
+
+        let ptr_ty = self.builder.ptr_type();
+        let i64_ty = self.builder.i64_type();
+        let bool_ty = self.builder.bool_type();
+        let run_fn = self.builder.get_or_declare_function(
+            "ori_run_with_timeout",
+            &[ptr_ty, ptr_ty, ptr_ty, i64_ty, i64_ty],
+            bool_ty,
+        );
+        let done = self.builder.call(
+            run_fn,
+            &[trampoline_ptr, wrapper, out, out_size, millis],
+            "timeout.done",
+        )?;
+
+        self.build_task_result(done, out, inner_ty, result_idx, |this| {
+            this.lower_str_constant("timed out")
+        })
+    }
+
+    /// Lower `catch(expr: expr)` — panic capture producing `Result<T, str>`.
+    ///
+    /// The expression becomes a `() -> T` closure that `ori_catch` runs on
+    /// the current thread. A panic inside it unwinds only as far as
+    /// `ori_catch`, which reports the panic message for the `Err` arm.
+    pub(crate) fn lower_exp_catch(
+        &mut self,
+        props: CanNamedExprRange,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let named_exprs = self.canon.arena.get_named_exprs(props);
+        let expr_name = self.prop_names.expr;
+        let value_name = self.prop_names.value;
+        let Some(inner) = named_exprs
+            .iter()
+            .find(|ne| ne.name == expr_name || ne.name == value_name)
+            .map(|ne| ne.value)
+        else {
+            tracing::warn!("catch expression missing expr property");
+            self.builder.record_codegen_error();
+            return None;
+        };
+
+        let result_idx = self.expr_type(expr_id);
+        if !matches!(self.type_info.get(result_idx), TypeInfo::Result { .. }) {
+            tracing::warn!(?result_idx, "catch expression without a Result type");
+            self.builder.record_codegen_error();
+            return None;
+        }
+        let inner_idx = self.expr_type(inner);
+
+        // The closure value is already the `{ fn_ptr, env_ptr }` wrapper
+        // layout, and `ori_catch` returns before this frame does.
+        let closure = self.compile_closure(CanParamRange::EMPTY, inner, &[], inner_idx)?;
+        let wrapper = self.alloca_and_store(closure, "catch.closure");
+        let trampoline = self.get_or_create_task_trampoline(inner_idx);
+        let trampoline_ptr = self.builder.get_function_ptr(trampoline);
+
+        let inner_llvm_ty = self.type_resolver.resolve(inner_idx);
+        let inner_ty = self.builder.register_type(inner_llvm_ty);
+        let out = self
+            .builder
+            .create_entry_alloca(self.current_function, "catch.out", inner_ty);
+        let str_ty = self.resolve_type(Idx::STR);
+        let message =
+            self.builder
+                .create_entry_alloca(self.current_function, "catch.message", str_ty);
+
+        let ptr_ty = self.builder.ptr_type();
+        let bool_ty = self.builder.bool_type();
+        let catch_fn = self.builder.get_or_declare_function(
+            "ori_catch",
+            &[ptr_ty, ptr_ty, ptr_ty, ptr_ty],
+            bool_ty,
+        );
+        let done = self.builder.call(
+            catch_fn,
+            &[trampoline_ptr, wrapper, out, message],
+            "catch.done",
+        )?;
+
+        self.build_task_result(done, out, inner_ty, result_idx, |this| {
+            this.builder.load(str_ty, message, "catch.message")
+        })
+    }
+
+    /// Build a `Result<T, str>` from a runtime `done` flag: `Ok` with the
+    /// value in `out`, or `Err` with the message built by `err_message` in
+    /// the failure block.
+    fn build_task_result(
+        &mut self,
+        done: ValueId,
+        out: ValueId,
+        inner_ty: LLVMTypeId,
+        result_idx: Idx,
+        err_message: impl FnOnce(&mut Self) -> ValueId,
+    ) -> Option<ValueId> {
+        let result_ty = self.resolve_type(result_idx);
+        let ok_bb = self.builder.append_block(self.current_function, "task.ok");
+        let err_bb = self.builder.append_block(self.current_function, "task.err");
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "task.merge");
+        self.builder.cond_br(done, ok_bb, err_bb);
+
+        self.builder.position_at_end(ok_bb);
+        let value = self.builder.load(inner_ty, out, "task.value");
+        let ok_tag = self.builder.const_i8(0); // Ok = 0
+        let ok_payload = self.coerce_for_result_payload(value, result_idx, true);
+        let ok = self
+            .builder
+            .build_struct(result_ty, &[ok_tag, ok_payload], "task.ok");
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(err_bb);
+        let message = err_message(self);
+        let err_tag = self.builder.const_i8(1); // Err = 1
+        let err_payload = self.coerce_for_result_payload(message, result_idx, false);
+        let err = self
+            .builder
+            .build_struct(result_ty, &[err_tag, err_payload], "task.err");
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(merge_bb);
+        self.builder
+            .phi_from_incoming(result_ty, &[(ok, ok_bb), (err, err_bb)], "task.result")
+    }
+
+    /// Get or create the task trampoline for `() -> T` closures.
+    ///
+    /// Signature: `ccc void @_ori_tramp_task_<T>(ptr %wrapper, ptr %out)`
+    ///
+    /// Unpacks the closure from the wrapper, calls it with `fastcc`, and
+    /// stores the result to `out`. One trampoline is emitted per result
+    /// type and shared by every `parallel`, `timeout`, and `catch` in the
+    /// module.
+    fn get_or_create_task_trampoline(&mut self, result_type: Idx) -> FunctionId {
+        let name = format!("_ori_tramp_task_{}", result_type.raw());
+        let ptr_ty = self.builder.ptr_type();
+        if self.builder.scx().llmod.get_function(&name).is_some() {
+            return self
+                .builder
+                .get_or_declare_void_function(&name, &[ptr_ty, ptr_ty]);
+        }
+
+        // declare ccc void @tramp(ptr %wrapper, ptr %out_ptr)
+        let tramp = self.builder.declare_void_function(&name, &[ptr_ty, ptr_ty]);
+        // C calling convention (called by Rust runtime)
+        self.builder.set_ccc(tramp);
+
+        let entry = self.builder.append_block(tramp, "entry");
+        let saved_pos = self.builder.save_position();
+        let saved_func = self.current_function;
+
+        self.builder.set_current_function(tramp);
+        self.builder.position_at_end(entry);
+
+        // Load fn_ptr and env_ptr from wrapper struct
+        let wrapper_param = self.builder.get_param(tramp, 0);
+        let out_param = self.builder.get_param(tramp, 1);
+
+        let fn_ptr = self.builder.load(ptr_ty, wrapper_param, "tramp.fn_ptr");
+        let i8_ty = self.builder.i8_type();
+        let eight = self.builder.const_i64(8);
+        let env_slot = self
+            .builder
+            .gep(i8_ty, wrapper_param, &[eight], "tramp.env_slot");
+        let env_ptr = self.builder.load(ptr_ty, env_slot, "tramp.env_ptr");
+
+        // Call closure: fastcc T @fn_ptr(ptr %env)
+        let result_ty_id = self.resolve_type(result_type);
+        let call_result =
+            self.builder
+                .call_indirect(result_ty_id, &[ptr_ty], fn_ptr, &[env_ptr], "tramp.result");
+
+        // Store result to out_ptr
+        if let Some(result) = call_result {
+            self.builder.store(result, out_param);
+        }
+
+        // Return void
+        self.builder.ret_void();
+
+        // Restore
+        self.current_function = saved_func;
+        self.builder.set_current_function(saved_func);
+        self.builder.restore_position(saved_pos);
+
+        tramp
+    }
+}
@@ -7,10 +7,11 @@
 //! - `Await`: async (stub)
 //! - `WithCapability`: capability provision
 
-use ori_ir::canon::{CanId, CanNamedExprRange};
+use ori_ir::canon::{CanExpr, CanId, CanNamedExprRange, ConstValue};
 use ori_ir::{FunctionExpKind, Name};
 use ori_types::Idx;
 
+use super::abi::{ParamPassing, ReturnPassing};
 use super::expr_lowerer::ExprLowerer;
 use super::value_id::ValueId;
 
@@ -31,27 +32,19 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     ) -> Option<ValueId> {
         match kind {
             FunctionExpKind::Print => self.lower_exp_print(props),
-            FunctionExpKind::Panic => self.lower_exp_panic(props),
+            FunctionExpKind::Panic => self.lower_exp_panic(props, expr_id),
             FunctionExpKind::Todo => self.lower_exp_todo(),
             FunctionExpKind::Unreachable => self.lower_exp_unreachable(),
             FunctionExpKind::Recurse => self.lower_exp_recurse(props, expr_id),
             FunctionExpKind::Cache => self.lower_exp_cache(props, expr_id),
             FunctionExpKind::Catch => self.lower_exp_catch(props, expr_id),
-            FunctionExpKind::Parallel => {
-                tracing::warn!("parallel expression not yet implemented");
-                self.builder.record_codegen_error();
-                None
-            }
+            FunctionExpKind::Parallel => self.lower_exp_parallel(props, expr_id),
             FunctionExpKind::Spawn => {
                 tracing::warn!("spawn expression not yet implemented");
                 self.builder.record_codegen_error();
                 None
             }
-            FunctionExpKind::Timeout => {
-                tracing::warn!("timeout expression not yet implemented");
-                self.builder.record_codegen_error();
-                None
-            }
+            FunctionExpKind::Timeout => self.lower_exp_timeout(props, expr_id),
             FunctionExpKind::With => {
                 tracing::warn!("with expression not yet implemented");
                 self.builder.record_codegen_error();
@@ -104,6 +97,20 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
                         .get_or_declare_function("ori_print_bool", &[bool_ty], bool_ty);
                 self.builder.call(func, &[val], "");
             }
+            Idx::CHAR => {
+                let i32_ty = self.builder.i32_type();
+                let func =
+                    self.builder
+                        .get_or_declare_function("ori_print_char", &[i32_ty], i32_ty);
+                self.builder.call(func, &[val], "");
+            }
+            Idx::BYTE => {
+                let i8_ty = self.builder.i8_type();
+                let func = self
+                    .builder
+                    .get_or_declare_function("ori_print_byte", &[i8_ty], i8_ty);
+                self.builder.call(func, &[val], "");
+            }
             Idx::STR => {
                 // String: pass pointer to {len, data} struct
                 let ptr = self.alloca_and_store(val, "print.str");
@@ -130,16 +137,18 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         Some(self.builder.const_i64(0))
     }
 
-    /// Lower `panic(message: expr)`.
+    /// Lower `panic(msg: expr)` (also accepts `message:` and `value:`).
     ///
-    /// Calls `ori_panic` with the message string, then emits `unreachable`.
-    fn lower_exp_panic(&mut self, props: CanNamedExprRange) -> Option<ValueId> {
+    /// Calls `ori_panic_at` with the message string and the 1-based line and
+    /// column of the panic site, then emits `unreachable`.
+    fn lower_exp_panic(&mut self, props: CanNamedExprRange, expr_id: CanId) -> Option<ValueId> {
         let named_exprs = self.canon.arena.get_named_exprs(props);
+        let msg_name = self.prop_names.msg;
         let message_name = self.prop_names.message;
         let value_name = self.prop_names.value;
         let msg_expr = named_exprs
             .iter()
-            .find(|ne| ne.name == message_name || ne.name == value_name);
+            .find(|ne| ne.name == msg_name || ne.name == message_name || ne.name == value_name);
 
         if let Some(ne) = msg_expr {
             let val = self.lower(ne.value)?;
@@ -147,9 +156,12 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
             if val_type == Idx::STR {
                 let ptr = self.alloca_and_store(val, "panic.msg");
-                if let Some(panic_fn) = self.builder.scx().llmod.get_function("ori_panic") {
+                let (line, col) = self.panic_location(expr_id);
+                if let Some(panic_fn) = self.builder.scx().llmod.get_function("ori_panic_at") {
                     let func_id = self.builder.intern_function(panic_fn);
-                    self.builder.call(func_id, &[ptr], "");
+                    let line = self.builder.const_i32(line);
+                    let col = self.builder.const_i32(col);
+                    self.builder.call(func_id, &[ptr, line, col], "");
                 }
             } else {
                 // Non-string panic — use a default message
@@ -176,6 +188,23 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         None // panic never returns
     }
 
+    /// Resolve the 1-based `(line, column)` of an expression for runtime
+    /// panic messages from the source's line index.
+    ///
+    /// Only a compiler built without a source (unit tests) has no index;
+    /// it passes `(0, 0)` and the runtime reports the message alone.
+    pub(crate) fn panic_location(&self, expr_id: CanId) -> (i32, i32) {
+        let Some(index) = self.line_index else {
+            return (0, 0);
+        };
+        let span = self.canon.arena.span(expr_id);
+        let (line, col) = span.line_col(index);
+        (
+            i32::try_from(line).unwrap_or(0),
+            i32::try_from(col).unwrap_or(0),
+        )
+    }
+
     /// Lower `todo` — panics with "not yet implemented".
     fn lower_exp_todo(&mut self) -> Option<ValueId> {
         let msg = self
@@ -202,54 +231,300 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         None
     }
 
-    /// Lower `recurse(args...)` — tail-recursive call to current function.
+    /// Lower `recurse(condition:, base:, step:, memo:)` — recursive function
+    /// body.
     ///
-    /// Compiles the arguments, then emits a tail call to the current
-    /// function. The `tail` attribute combined with `fastcc` enables LLVM
-    /// to perform tail call optimization (reusing the caller's stack frame),
-    /// preventing stack overflow on deep recursion.
-    fn lower_exp_recurse(&mut self, props: CanNamedExprRange, _expr_id: CanId) -> Option<ValueId> {
+    /// Equivalent to `if condition then base else step`: only the selected
+    /// branch is evaluated, so `step` may recurse without running away.
+    /// Recursive calls inside `step` are written `self(args...)` and take
+    /// the enclosing function's parameters positionally, in declaration
+    /// order; `lower_call` routes them to the current function with its
+    /// ABI. `memo: true` caches results per top-level call (see
+    /// `lower_memo_recurse`).
+    fn lower_exp_recurse(&mut self, props: CanNamedExprRange, expr_id: CanId) -> Option<ValueId> {
         let named_exprs = self.canon.arena.get_named_exprs(props);
-        let mut arg_vals = Vec::with_capacity(named_exprs.len());
-        for ne in named_exprs {
-            let val = self.lower(ne.value)?;
-            arg_vals.push(val);
+        let find = |name: Name| {
+            named_exprs
+                .iter()
+                .find(|ne| ne.name == name)
+                .map(|ne| ne.value)
+        };
+        let (Some(condition), Some(base), Some(step)) = (
+            find(self.prop_names.condition),
+            find(self.prop_names.base),
+            find(self.prop_names.step),
+        ) else {
+            tracing::warn!("recurse expression missing condition, base, or step property");
+            self.builder.record_codegen_error();
+            return None;
+        };
+
+        let memo = match find(self.prop_names.memo) {
+            None => false,
+            Some(memo) => {
+                let Some(memo) = self.literal_bool(memo) else {
+                    tracing::warn!("recurse memo must be a literal bool");
+                    self.builder.record_codegen_error();
+                    return None;
+                };
+                memo
+            }
+        };
+        if memo {
+            return self.lower_memo_recurse(condition, base, step, expr_id);
+        }
+        self.lower_if(condition, base, step, expr_id)
+    }
+
+    /// The value of a `true`/`false` literal, possibly folded to a constant.
+    fn literal_bool(&self, id: CanId) -> Option<bool> {
+        match *self.canon.arena.kind(id) {
+            CanExpr::Bool(b) => Some(b),
+            CanExpr::Constant(const_id) => match self.canon.constants.get(const_id) {
+                ConstValue::Bool(b) => Some(*b),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Lower a `memo: true` recurse through the runtime memo table.
+    ///
+    /// The function's arguments are the cache key: a hit yields the cached
+    /// result, a miss evaluates the branch and records it. The table lives
+    /// for the top-level call (`ori_memo_enter`/`ori_memo_exit`). Self-calls
+    /// are never emitted as tail calls here, since `ori_memo_exit` must run
+    /// after the branch. Only functions whose parameters and result are all
+    /// `int` are supported.
+    fn lower_memo_recurse(
+        &mut self,
+        condition: CanId,
+        base: CanId,
+        step: CanId,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let functions = self.functions;
+        let current = self
+            .current_function_name
+            .and_then(|name| functions.get(&name))
+            .filter(|(func_id, _)| *func_id == self.current_function);
+        let Some((func_id, abi)) = current else {
+            tracing::warn!("recurse memo outside a top-level function body");
+            self.builder.record_codegen_error();
+            return None;
+        };
+        let int_signature = abi.return_abi.ty == Idx::INT
+            && matches!(abi.return_abi.passing, ReturnPassing::Direct)
+            && abi
+                .params
+                .iter()
+                .all(|p| p.ty == Idx::INT && matches!(p.passing, ParamPassing::Direct));
+        if !int_signature {
+            tracing::warn!("recurse memo requires int parameters and an int result");
+            self.builder.record_codegen_error();
+            return None;
         }
+        let func_id = *func_id;
+        let nargs = abi.params.len();
+
+        // Cache key: the argument words, in parameter order.
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let bool_ty = self.builder.bool_type();
+        let keys_llvm = self.builder.scx().type_i64().array_type(nargs as u32);
+        let keys_ty = self.builder.register_type(keys_llvm.into());
+        let keys = self
+            .builder
+            .create_entry_alloca(self.current_function, "memo.keys", keys_ty);
+        for i in 0..nargs {
+            let arg = self.builder.get_param(func_id, i as u32);
+            let index = self.builder.const_i64(i as i64);
+            let slot = self.builder.gep(i64_ty, keys, &[index], "memo.key");
+            self.builder.store(arg, slot);
+        }
+        let nkeys = self.builder.const_i64(nargs as i64);
+        let out = self
+            .builder
+            .create_entry_alloca(self.current_function, "memo.out", i64_ty);
+        let func_ptr = self.builder.get_function_ptr(func_id);
+
+        let enter_fn = self
+            .builder
+            .get_or_declare_void_function("ori_memo_enter", &[ptr_ty]);
+        self.builder.call(enter_fn, &[func_ptr], "");
+        let lookup_fn = self.builder.get_or_declare_function(
+            "ori_memo_lookup",
+            &[ptr_ty, ptr_ty, i64_ty, ptr_ty],
+            bool_ty,
+        );
+        let found = self
+            .builder
+            .call(lookup_fn, &[func_ptr, keys, nkeys, out], "memo.found")?;
+
+        let hit_bb = self.builder.append_block(self.current_function, "memo.hit");
+        let miss_bb = self
+            .builder
+            .append_block(self.current_function, "memo.miss");
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "memo.merge");
+        self.builder.cond_br(found, hit_bb, miss_bb);
+
+        self.builder.position_at_end(hit_bb);
+        let cached = self.builder.load(i64_ty, out, "memo.cached");
+        self.builder.br(merge_bb);
 
+        self.builder.position_at_end(miss_bb);
+        let tail_calls = std::mem::take(&mut self.tail_calls);
+        let computed = self.lower_if(condition, base, step, expr_id);
+        self.tail_calls = tail_calls;
+        let computed = computed?;
+        let store_fn = self
+            .builder
+            .get_or_declare_void_function("ori_memo_store", &[ptr_ty, ptr_ty, i64_ty, i64_ty]);
         self.builder
-            .call_tail(self.current_function, &arg_vals, "recurse")
+            .call(store_fn, &[func_ptr, keys, nkeys, computed], "");
+        let miss_end = self.builder.current_block()?;
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(merge_bb);
+        let result = self.builder.phi_from_incoming(
+            i64_ty,
+            &[(cached, hit_bb), (computed, miss_end)],
+            "memo.result",
+        );
+        let exit_fn = self
+            .builder
+            .get_or_declare_void_function("ori_memo_exit", &[ptr_ty]);
+        self.builder.call(exit_fn, &[func_ptr], "");
+        result
     }
 
-    /// Lower `cache(key: ..., value: ...)` — memoization.
-    fn lower_exp_cache(&mut self, props: CanNamedExprRange, _expr_id: CanId) -> Option<ValueId> {
-        // Simplified: just evaluate the value expression
+    /// Lower `cache(key: ..., op: ..., ttl: ...)` through the runtime store.
+    ///
+    /// Each `cache` expression owns its entries, identified by the address
+    /// of a private marker global. A hit copies the stored bytes back and
+    /// skips `op`; a miss evaluates `op` and stores its value for `ttl`
+    /// (forever without one). A zero or negative `ttl` always recomputes,
+    /// and without a `key` `op` is simply evaluated.
+    ///
+    /// Keys may be `str` or a scalar (`int`, `char`, `bool`, `byte`). Only
+    /// results without reference counting can be stored, since the store
+    /// hands out bitwise copies. A function-valued `op` is not supported.
+    fn lower_exp_cache(&mut self, props: CanNamedExprRange, expr_id: CanId) -> Option<ValueId> {
         let named_exprs = self.canon.arena.get_named_exprs(props);
-        let value_name = self.prop_names.value;
-        let expr_name = self.prop_names.expr;
-        for ne in named_exprs {
-            if ne.name == value_name || ne.name == expr_name {
-                return self.lower(ne.value);
-            }
+        let pn = &self.prop_names;
+        let find = |name: Name| {
+            named_exprs
+                .iter()
+                .find(|ne| ne.name == name)
+                .map(|ne| ne.value)
+        };
+        // `op` per the spec; `operation` is the older spelling
+        let (op, key, ttl) = (
+            find(pn.op).or_else(|| find(pn.operation)),
+            find(pn.key),
+            find(pn.ttl),
+        );
+        let Some(op) = op else {
+            tracing::warn!("cache expression missing op property");
+            self.builder.record_codegen_error();
+            return None;
+        };
+        let Some(key) = key else {
+            return self.lower(op);
+        };
+
+        let result_type = self.expr_type(expr_id);
+        if self.expr_type(op) != result_type {
+            tracing::warn!("cache with a function-valued op is not supported");
+            self.builder.record_codegen_error();
+            return None;
         }
-        tracing::warn!("cache expression missing value property");
-        self.builder.record_codegen_error();
-        None
+        if !self.type_info.is_trivial(result_type) {
+            tracing::warn!(?result_type, "cache result type needs reference counting");
+            self.builder.record_codegen_error();
+            return None;
+        }
+
+        let (key_ptr, key_len) = self.lower_cache_key(key)?;
+        let ttl_nanos = match ttl {
+            Some(ttl) => self.lower(ttl)?,
+            None => self.builder.const_i64(i64::MAX),
+        };
+        let site = self.builder.build_site_marker("cache.site");
+        let value_ty = self.resolve_type(result_type);
+        let slot = self
+            .builder
+            .create_entry_alloca(self.current_function, "cache.value", value_ty);
+        let size = self.compute_elem_byte_size(result_type);
+
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let bool_ty = self.builder.bool_type();
+        let params = [ptr_ty, ptr_ty, i64_ty, i64_ty, ptr_ty, i64_ty];
+        let get_fn = self
+            .builder
+            .get_or_declare_function("ori_cache_get", &params, bool_ty);
+        let args = [site, key_ptr, key_len, ttl_nanos, slot, size];
+        let found = self.builder.call(get_fn, &args, "cache.found")?;
+
+        let hit_bb = self
+            .builder
+            .append_block(self.current_function, "cache.hit");
+        let miss_bb = self
+            .builder
+            .append_block(self.current_function, "cache.miss");
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "cache.merge");
+        self.builder.cond_br(found, hit_bb, miss_bb);
+
+        self.builder.position_at_end(hit_bb);
+        let cached = self.builder.load(value_ty, slot, "cache.cached");
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(miss_bb);
+        let computed = self.lower(op)?;
+        self.builder.store(computed, slot);
+        let put_fn = self
+            .builder
+            .get_or_declare_void_function("ori_cache_put", &params);
+        self.builder.call(put_fn, &args, "");
+        let miss_end = self.builder.current_block()?;
+        self.builder.br(merge_bb);
+
+        self.builder.position_at_end(merge_bb);
+        self.builder.phi_from_incoming(
+            value_ty,
+            &[(cached, hit_bb), (computed, miss_end)],
+            "cache.result",
+        )
     }
 
-    /// Lower `catch(expr: ..., handler: ...)` — error catching.
-    fn lower_exp_catch(&mut self, props: CanNamedExprRange, _expr_id: CanId) -> Option<ValueId> {
-        // Simplified: just evaluate the expr property
-        let named_exprs = self.canon.arena.get_named_exprs(props);
-        let expr_name = self.prop_names.expr;
-        let value_name = self.prop_names.value;
-        for ne in named_exprs {
-            if ne.name == expr_name || ne.name == value_name {
-                return self.lower(ne.value);
+    /// Lower a `cache` key to the pointer and length of its bytes.
+    ///
+    /// A `str` key is its UTF-8 data; a scalar key is spilled to the stack.
+    fn lower_cache_key(&mut self, key: CanId) -> Option<(ValueId, ValueId)> {
+        let key_type = self.expr_type(key);
+        let value = self.lower(key)?;
+        match key_type {
+            Idx::STR => {
+                let len = self.builder.extract_value(value, 0, "cache.key.len")?;
+                let data = self.builder.extract_value(value, 1, "cache.key.data")?;
+                Some((data, len))
+            }
+            Idx::INT | Idx::CHAR | Idx::BOOL | Idx::BYTE => {
+                let ptr = self.alloca_and_store(value, "cache.key");
+                let len = self.compute_elem_byte_size(key_type);
+                Some((ptr, len))
+            }
+            _ => {
+                tracing::warn!(?key_type, "cache key must be a str or scalar");
+                self.builder.record_codegen_error();
+                None
             }
         }
-        tracing::warn!("catch expression missing expr property");
-        self.builder.record_codegen_error();
-        None
     }
 
     // -----------------------------------------------------------------------
@@ -395,14 +670,64 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
     /// Lower `CanExpr::WithCapability { capability, provider, body }`.
     ///
-    /// Capability system not yet implemented. For now, just evaluates
-    /// the body expression.
+    /// Registers the provider in the capability table for the duration of
+    /// the body, so references to `capability` inside it resolve to the
+    /// provider. A nested provision of the same capability shadows this one;
+    /// the previous provider (if any) is restored on exit.
     pub(crate) fn lower_with_capability(
         &mut self,
-        _capability: Name,
-        _provider: CanId,
+        capability: Name,
+        provider: CanId,
         body: CanId,
     ) -> Option<ValueId> {
-        self.lower(body)
+        let provider_val = self.lower(provider)?;
+        let shadowed = self.capabilities.insert(capability, provider_val);
+        let result = self.lower(body);
+        match shadowed {
+            Some(outer) => self.capabilities.insert(capability, outer),
+            None => self.capabilities.remove(&capability),
+        };
+        result
+    }
+
+    /// The provider of `capability` as a value of the type of `expr_id`:
+    /// from an enclosing `with`, else loaded through the caller's pointer
+    /// when the function is declared `uses capability`.
+    pub(crate) fn lower_capability(&mut self, capability: Name, expr_id: CanId) -> Option<ValueId> {
+        if let Some(&provider) = self.capabilities.get(&capability) {
+            return Some(provider);
+        }
+        let &ptr = self.capability_params.get(&capability)?;
+        let ty = self.resolve_type(self.expr_type(expr_id));
+        Some(self.builder.load(ty, ptr, self.interner.lookup(capability)))
+    }
+
+    /// Trailing arguments for a call to a function declared `uses
+    /// capabilities`: a pointer to each provider in effect here.
+    ///
+    /// A provider from an enclosing `with` is spilled to a stack slot; one
+    /// received from this function's caller is passed on as is. The type
+    /// checker rejects calls whose capabilities aren't available, so a
+    /// missing provider (a runtime-handled capability such as `Print`) is
+    /// passed as null and never read.
+    pub(crate) fn capability_args(&mut self, capabilities: &[Name]) -> Vec<ValueId> {
+        capabilities
+            .iter()
+            .map(|cap| {
+                if let Some(&provider) = self.capabilities.get(cap) {
+                    let raw_ty = self.builder.raw_value(provider).get_type();
+                    let ty = self.builder.register_type(raw_ty);
+                    let slot =
+                        self.builder
+                            .create_entry_alloca(self.current_function, "cap_arg", ty);
+                    self.builder.store(provider, slot);
+                    slot
+                } else if let Some(&ptr) = self.capability_params.get(cap) {
+                    ptr
+                } else {
+                    self.builder.const_null_ptr()
+                }
+            })
+            .collect()
     }
 }
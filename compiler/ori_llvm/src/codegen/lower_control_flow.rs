@@ -1,22 +1,20 @@
 //! Control flow lowering for V2 codegen.
 //!
-//! Handles if/else, blocks, let bindings, loops, break/continue,
-//! assignment, and match expressions.
+//! Handles if/else, blocks, loops, break/continue, and assignment.
 //!
-//! For-loop lowering lives in `lower_for_loop.rs`.
+//! For-loop lowering lives in `lower_for_loop.rs`; let bindings and
+//! destructuring live in `lower_bindings.rs`; match expressions live in
+//! `lower_match.rs`.
 
 use std::mem;
 
-use ori_ir::canon::{
-    CanBindingPattern, CanBindingPatternId, CanExpr, CanId, CanRange, DecisionTreeId,
-};
-use ori_ir::{Mutability, Name, Span};
+use ori_ir::canon::{CanExpr, CanId, CanRange};
+use ori_ir::Name;
 use ori_types::Idx;
 
-use crate::aot::debug::DebugLevel;
-
 use super::expr_lowerer::{ExprLowerer, LoopContext};
 use super::scope::ScopeBinding;
+use super::type_info::TypeInfo;
 use super::value_id::ValueId;
 
 impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
@@ -44,7 +42,8 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         self.builder.position_at_end(then_bb);
         let then_val = self.lower(then_branch);
         let then_exit = self.builder.current_block();
-        if !self.builder.current_block_terminated() {
+        let then_open = !self.builder.current_block_terminated();
+        if then_open {
             self.builder.br(merge_bb);
         }
 
@@ -57,21 +56,34 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             Some(self.builder.const_i64(0))
         };
         let else_exit = self.builder.current_block();
-        if !self.builder.current_block_terminated() {
+        let else_open = !self.builder.current_block_terminated();
+        if else_open {
             self.builder.br(merge_bb);
         }
 
-        // Merge
+        // Merge — only branches that fall through contribute a value. A
+        // terminated branch (panic, break, tail call) is not a predecessor.
         self.builder.position_at_end(merge_bb);
 
-        match (then_val, else_val, then_exit, else_exit) {
-            (Some(tv), Some(ev), Some(tb), Some(eb)) => {
-                let result_type = self.expr_type(expr_id);
-                let result_llvm_ty = self.resolve_type(result_type);
-                self.builder
-                    .phi_from_incoming(result_llvm_ty, &[(tv, tb), (ev, eb)], "if.result")
+        match (then_open, else_open) {
+            (true, true) => match (then_val, else_val, then_exit, else_exit) {
+                (Some(tv), Some(ev), Some(tb), Some(eb)) => {
+                    let result_type = self.expr_type(expr_id);
+                    let result_llvm_ty = self.resolve_type(result_type);
+                    self.builder.phi_from_incoming(
+                        result_llvm_ty,
+                        &[(tv, tb), (ev, eb)],
+                        "if.result",
+                    )
+                }
+                _ => None,
+            },
+            (true, false) => then_val,
+            (false, true) => else_val,
+            (false, false) => {
+                self.builder.unreachable();
+                None
             }
-            _ => None,
         }
     }
 
@@ -111,166 +123,6 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         result_val
     }
 
-    // -----------------------------------------------------------------------
-    // Let binding
-    // -----------------------------------------------------------------------
-
-    /// Lower `CanExpr::Let { pattern, init, mutable }`.
-    pub(crate) fn lower_let(
-        &mut self,
-        pattern: CanBindingPatternId,
-        init: CanId,
-        mutable: Mutability,
-    ) -> Option<ValueId> {
-        let init_val = self.lower(init)?;
-        let binding_pattern = self.canon.arena.get_binding_pattern(pattern);
-        self.bind_pattern(binding_pattern, init_val, mutable, init);
-        // Let bindings produce unit
-        Some(self.builder.const_i64(0))
-    }
-
-    /// Emit debug info for a mutable binding (alloca-backed).
-    fn emit_debug_mutable(&self, name_str: &str, ptr: ValueId, init_type: Idx, init_id: CanId) {
-        if let Some(dc) = self.debug_context {
-            if dc.level() == DebugLevel::Full {
-                let init_span = self.canon.arena.span(init_id);
-                if init_span != Span::DUMMY {
-                    if let Some(di_ty) = dc.resolve_debug_type(init_type, self.pool) {
-                        let raw = self.builder.raw_value(ptr);
-                        if let (true, Some(cur_bb)) =
-                            (raw.is_pointer_value(), self.builder.current_block())
-                        {
-                            let block = self.builder.raw_block(cur_bb);
-                            dc.emit_declare_for_alloca(
-                                raw.into_pointer_value(),
-                                name_str,
-                                di_ty,
-                                init_span.start,
-                                block,
-                            );
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    /// Emit debug info for an immutable binding (SSA value).
-    fn emit_debug_immutable(&self, name: Name, val: ValueId, init_id: CanId) {
-        if let Some(dc) = self.debug_context {
-            if dc.level() == DebugLevel::Full {
-                let init_type = self.expr_type(init_id);
-                let init_span = self.canon.arena.span(init_id);
-                if init_span != Span::DUMMY {
-                    if let Some(di_ty) = dc.resolve_debug_type(init_type, self.pool) {
-                        let raw_val = self.builder.raw_value(val);
-                        let block = self
-                            .builder
-                            .raw_block(self.builder.current_block().unwrap());
-                        let name_str = self.resolve_name(name);
-                        dc.emit_value_for_binding_at_end(
-                            raw_val,
-                            name_str,
-                            di_ty,
-                            init_span.start,
-                            block,
-                        );
-                    }
-                }
-            }
-        }
-    }
-
-    /// Bind a canonical binding pattern to a value, adding entries to scope.
-    #[expect(
-        clippy::only_used_in_recursion,
-        reason = "mutable is forwarded to sub-patterns; will be consumed directly once list rest patterns are implemented"
-    )]
-    fn bind_pattern(
-        &mut self,
-        pattern: &CanBindingPattern,
-        val: ValueId,
-        mutable: Mutability,
-        init_id: CanId,
-    ) {
-        match pattern {
-            CanBindingPattern::Name {
-                name,
-                mutable: pat_mutable,
-            } => {
-                // Per-binding mutability: use the flag from the pattern itself
-                // to support `let ($x, y) = ...` with mixed mutability.
-                if pat_mutable.is_mutable() {
-                    let init_type = self.expr_type(init_id);
-                    let llvm_ty = self.resolve_type(init_type);
-                    let name_str = self.resolve_name(*name).to_owned();
-                    let ptr =
-                        self.builder
-                            .create_entry_alloca(self.current_function, &name_str, llvm_ty);
-                    self.builder.store(val, ptr);
-                    self.scope.bind_mutable(*name, ptr, llvm_ty);
-                    self.emit_debug_mutable(&name_str, ptr, init_type, init_id);
-                } else {
-                    self.scope.bind_immutable(*name, val);
-                    self.emit_debug_immutable(*name, val, init_id);
-                }
-            }
-            CanBindingPattern::Wildcard => {
-                // Discard — don't bind anything
-            }
-            CanBindingPattern::Tuple(elements) => {
-                let elem_ids: Vec<_> = self
-                    .canon
-                    .arena
-                    .get_binding_pattern_list(*elements)
-                    .to_vec();
-                for (i, sub_pat_id) in elem_ids.iter().enumerate() {
-                    let sub_pattern = self.canon.arena.get_binding_pattern(*sub_pat_id);
-                    if let Some(elem_val) =
-                        self.builder
-                            .extract_value(val, i as u32, &format!("tup.{i}"))
-                    {
-                        self.bind_pattern(sub_pattern, elem_val, mutable, init_id);
-                    }
-                }
-            }
-            CanBindingPattern::Struct { fields } => {
-                let field_bindings: Vec<_> = self.canon.arena.get_field_bindings(*fields).to_vec();
-                for (i, fb) in field_bindings.iter().enumerate() {
-                    if let Some(field_val) = self.builder.extract_value(
-                        val,
-                        i as u32,
-                        &format!("field.{}", self.resolve_name(fb.name)),
-                    ) {
-                        let sub_pattern = self.canon.arena.get_binding_pattern(fb.pattern);
-                        self.bind_pattern(sub_pattern, field_val, mutable, init_id);
-                    }
-                }
-            }
-            CanBindingPattern::List { elements, rest } => {
-                let elem_ids: Vec<_> = self
-                    .canon
-                    .arena
-                    .get_binding_pattern_list(*elements)
-                    .to_vec();
-                for (i, sub_pat_id) in elem_ids.iter().enumerate() {
-                    let sub_pattern = self.canon.arena.get_binding_pattern(*sub_pat_id);
-                    if let Some(elem_val) =
-                        self.builder
-                            .extract_value(val, i as u32, &format!("list.{i}"))
-                    {
-                        self.bind_pattern(sub_pattern, elem_val, mutable, init_id);
-                    }
-                }
-                if let Some((_rest_name, _rest_mutable)) = rest {
-                    tracing::warn!(
-                        "list rest pattern (`...name`) not yet implemented in V2 codegen"
-                    );
-                }
-            }
-        }
-    }
-
     // -----------------------------------------------------------------------
     // Loop
     // -----------------------------------------------------------------------
@@ -290,11 +142,13 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         }
 
         // Save and set loop context
+        let result_type = self.expr_type(expr_id);
         let prev_loop = self.loop_ctx.take();
         self.loop_ctx = Some(LoopContext {
             exit_block: exit_bb,
             continue_block: header_bb,
             break_values: Vec::new(),
+            result_type,
         });
 
         // Compile loop body
@@ -316,7 +170,6 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             // No break with value — loop result is unit
             Some(self.builder.const_i64(0))
         } else {
-            let result_type = self.expr_type(expr_id);
             let result_llvm_ty = self.resolve_type(result_type);
             self.builder
                 .phi_from_incoming(result_llvm_ty, &loop_ctx.break_values, "loop.result")
@@ -328,12 +181,21 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     // -----------------------------------------------------------------------
 
     /// Lower `CanExpr::Break(value)`.
+    ///
+    /// Each break contributes a `(value, block)` incoming edge to the loop's
+    /// exit phi. A bare `break` contributes the zero value of the loop's
+    /// result type so every incoming edge agrees with the phi type.
     pub(crate) fn lower_break(&mut self, value: CanId) -> Option<ValueId> {
         let break_val = if value.is_valid() {
-            self.lower(value)
-                .unwrap_or_else(|| self.builder.const_i64(0))
+            let val = self.lower(value);
+            if self.builder.current_block_terminated() {
+                // The break value diverged (e.g. `break panic(...)`); there
+                // is no edge to the exit block from here.
+                return None;
+            }
+            val.unwrap_or_else(|| self.loop_default_value())
         } else {
-            self.builder.const_i64(0)
+            self.loop_default_value()
         };
 
         if let Some(ref mut ctx) = self.loop_ctx {
@@ -352,6 +214,17 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         None // Break terminates the current block
     }
 
+    /// Zero value of the innermost loop's result type (unit outside loops).
+    fn loop_default_value(&mut self) -> ValueId {
+        match self.loop_ctx.as_ref().map(|ctx| ctx.result_type) {
+            Some(ty) if ty != Idx::UNIT && ty != Idx::NEVER => {
+                let llvm_ty = self.type_resolver.resolve(ty);
+                self.builder.const_zero(llvm_ty)
+            }
+            _ => self.builder.const_i64(0),
+        }
+    }
+
     /// Lower `CanExpr::Continue(value)`.
     pub(crate) fn lower_continue(&mut self, _value: CanId) -> Option<ValueId> {
         if let Some(ref ctx) = self.loop_ctx {
@@ -369,158 +242,236 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     // -----------------------------------------------------------------------
 
     /// Lower `CanExpr::Assign { target, value }`.
+    ///
+    /// A target is a mutable binding followed by any chain of field and
+    /// index projections (`p.x`, `xs[i]`, `state.items[i].name`). Values are
+    /// immutable, so a projected assignment rebuilds every aggregate on the
+    /// path and stores the new root back into the binding's slot: struct
+    /// fields via `insertvalue`, list elements by writing into a fresh copy
+    /// of the buffer so other holders of the old list don't observe it.
+    ///
+    /// The copy is skipped for `xs[i] = v` when the binding already owns its
+    /// buffer (see `owned_lists`): the element is written in place. An old
+    /// buffer that is copied from may still be shared, so it isn't freed.
     pub(crate) fn lower_assign(&mut self, target: CanId, value: CanId) -> Option<ValueId> {
         let rhs = self.lower(value)?;
 
-        let target_kind = *self.canon.arena.kind(target);
-        match target_kind {
-            CanExpr::Ident(name) => {
-                if let Some(ScopeBinding::Mutable { ptr, .. }) = self.scope.lookup(name) {
-                    self.builder.store(rhs, ptr);
-                } else {
-                    tracing::warn!(
-                        name = self.resolve_name(name),
-                        "assignment to non-mutable binding"
-                    );
+        // Walk the target down to its root binding, collecting projections.
+        let mut steps = Vec::new();
+        let mut root = target;
+        loop {
+            match *self.canon.arena.kind(root) {
+                CanExpr::Field { receiver, field } => {
+                    steps.push((root, AssignStep::Field(field)));
+                    root = receiver;
                 }
+                CanExpr::Index { receiver, index } => {
+                    steps.push((root, AssignStep::Index(index)));
+                    root = receiver;
+                }
+                _ => break,
             }
-            CanExpr::Field { receiver, field } => {
-                tracing::debug!("field assignment lowering");
-                let receiver_val = self.lower(receiver);
-                let _ = (receiver_val, field);
-                // Full field assignment requires knowing the struct layout
-            }
-            CanExpr::Index { receiver, index } => {
-                tracing::debug!("index assignment lowering");
-                let _ = (receiver, index);
-                // Full index assignment requires bounds checking + element store
-            }
-            _ => {
-                tracing::warn!("unsupported assignment target");
-            }
+        }
+        steps.reverse();
+
+        let CanExpr::Ident(name) = *self.canon.arena.kind(root) else {
+            tracing::warn!("unsupported assignment target");
+            self.builder.record_codegen_error();
+            return Some(self.builder.const_i64(0));
+        };
+        let Some(ScopeBinding::Mutable { ptr, ty }) = self.scope.lookup(name) else {
+            tracing::warn!(
+                name = self.resolve_name(name),
+                "assignment to non-mutable binding"
+            );
+            return Some(self.builder.const_i64(0));
+        };
+
+        let owned = self.owned_lists.get(&ptr).copied();
+        if steps.is_empty() {
+            self.builder.store(rhs, ptr);
+            self.release_list_ownership(ptr);
+        } else {
+            let root_val = self.builder.load(ty, ptr, "assign.root");
+            let root_ty = self.expr_type(root);
+            let new_root = self.rebuild_assign_path(root_val, root_ty, &steps, rhs, owned)?;
+            self.builder.store(new_root, ptr);
         }
 
         // Assignment produces unit
         Some(self.builder.const_i64(0))
     }
 
-    // -----------------------------------------------------------------------
-    // Match (sequential if-else chain — stub for pre-decision-tree code)
-    // -----------------------------------------------------------------------
-
-    /// Lower `CanExpr::Match { scrutinee, decision_tree, arms }`.
+    /// Rebuild `agg` (of type `ty`) with the place named by `steps` set to
+    /// `rhs`, returning the updated aggregate.
     ///
-    /// Currently uses a sequential if-else chain over the arm bodies.
-    /// The decision tree is available for future upgrade to proper
-    /// switch-based emission.
-    pub(crate) fn lower_match(
+    /// `owned` is the ownership flag of `agg`'s list buffer, if it has one;
+    /// only a binding's own list does, so nested steps pass `None`.
+    fn rebuild_assign_path(
         &mut self,
-        scrutinee: CanId,
-        _decision_tree: DecisionTreeId,
-        arms: CanRange,
-        expr_id: CanId,
+        agg: ValueId,
+        ty: Idx,
+        steps: &[(CanId, AssignStep)],
+        rhs: ValueId,
+        owned: Option<ValueId>,
     ) -> Option<ValueId> {
-        let scrut_val = self.lower(scrutinee)?;
-        let scrut_type = self.expr_type(scrutinee);
-        let result_type = self.expr_type(expr_id);
-        let result_llvm_ty = self.resolve_type(result_type);
-
-        let arm_ids = self.canon.arena.get_expr_list(arms);
-        if arm_ids.is_empty() {
-            return None;
+        let Some(&(step_expr, step)) = steps.first() else {
+            return Some(rhs);
+        };
+        match (step, self.type_info.get(ty)) {
+            (AssignStep::Field(field), TypeInfo::Struct { fields }) => {
+                let Some(idx) = fields.iter().position(|(n, _)| *n == field) else {
+                    tracing::warn!(
+                        field = self.resolve_name(field),
+                        "unknown struct field in assignment"
+                    );
+                    self.builder.record_codegen_error();
+                    return None;
+                };
+                let field_ty = fields[idx].1;
+                let old = self
+                    .builder
+                    .extract_value(agg, idx as u32, "assign.field")?;
+                let new = self.rebuild_assign_path(old, field_ty, &steps[1..], rhs, None)?;
+                Some(
+                    self.builder
+                        .insert_value(agg, new, idx as u32, "assign.struct"),
+                )
+            }
+            (AssignStep::Index(index), TypeInfo::List { element }) => {
+                let len = self.builder.extract_value(agg, 0, "assign.len")?;
+                let data = self.builder.extract_value(agg, 2, "assign.data")?;
+
+                // `#` inside the index refers to the list's length.
+                let old_hash = self.hash_length.replace(len);
+                let idx_result = self.lower(index);
+                self.hash_length = old_hash;
+                let idx = idx_result?;
+                self.emit_index_bounds_check(idx, len, step_expr);
+
+                let elem_llvm_ty = self.resolve_type(element);
+                let new = if steps.len() > 1 {
+                    let old_ptr = self
+                        .builder
+                        .gep(elem_llvm_ty, data, &[idx], "assign.elem_ptr");
+                    let old = self.builder.load(elem_llvm_ty, old_ptr, "assign.elem");
+                    self.rebuild_assign_path(old, element, &steps[1..], rhs, None)?
+                } else {
+                    rhs
+                };
+
+                let (cap, data) = match owned {
+                    Some(flag) => self.writable_list_data(agg, len, data, element, flag)?,
+                    None => (len, self.copy_list_data(len, data, element)?),
+                };
+                let slot = self.builder.gep(elem_llvm_ty, data, &[idx], "assign.slot");
+                self.builder.store(new, slot);
+
+                let list_ty = self.resolve_type(ty);
+                Some(
+                    self.builder
+                        .build_struct(list_ty, &[len, cap, data], "assign.list"),
+                )
+            }
+            (step, info) => {
+                tracing::warn!(?step, ?info, "unsupported assignment target projection");
+                self.builder.record_codegen_error();
+                None
+            }
         }
+    }
 
-        let merge_bb = self
+    /// The `(cap, data)` of a buffer `list` may be written through: its own
+    /// `data` when the ownership `flag` is set, else a fresh copy, after
+    /// which the flag is set.
+    fn writable_list_data(
+        &mut self,
+        list: ValueId,
+        len: ValueId,
+        data: ValueId,
+        element: Idx,
+        flag: ValueId,
+    ) -> Option<(ValueId, ValueId)> {
+        let cap = self.builder.extract_value(list, 1, "assign.cap")?;
+
+        let bool_ty = self.builder.bool_type();
+        let is_owned = self.builder.load(bool_ty, flag, "assign.owned");
+        let entry_bb = self.builder.current_block()?;
+        let copy_bb = self
             .builder
-            .append_block(self.current_function, "match.merge");
-        let mut incoming: Vec<(ValueId, super::value_id::BlockId)> = Vec::new();
-
-        // For now, treat each arm as a body expression. The first arm that
-        // matches wins. Without decision tree emission, we do literal/wildcard
-        // matching on the arm body's pattern context.
-        // TODO: Use decision_tree for proper switch-based emission.
-        let last_idx = arm_ids.len() - 1;
-        for (i, &arm_body) in arm_ids.iter().enumerate() {
-            let is_last = i == last_idx;
-
-            if is_last {
-                // Last arm is the catch-all — just lower the body
-                let body_val = self.lower(arm_body);
-                let body_bb = self.builder.current_block();
-                if let (Some(bv), Some(bb)) = (body_val, body_bb) {
-                    if !self.builder.current_block_terminated() {
-                        incoming.push((bv, bb));
-                        self.builder.br(merge_bb);
-                    }
-                }
-                break;
-            }
-
-            // Non-last arms need pattern tests. Since canonical IR doesn't
-            // embed patterns in arm bodies, we use the decision tree for
-            // proper dispatch. For now, simple literal matching via the
-            // arm body (which contains the full expression including any
-            // guard). This is a temporary stub.
-            let next_bb = self
-                .builder
-                .append_block(self.current_function, &format!("match.arm{}", i + 1));
-
-            // Check if arm body is a literal we can compare against
-            let arm_kind = *self.canon.arena.kind(arm_body);
-            let matches = match arm_kind {
-                CanExpr::Int(n) => {
-                    let pat_val = self.builder.const_i64(n);
-                    let is_float = scrut_type == Idx::FLOAT;
-                    if is_float {
-                        Some(self.builder.fcmp_oeq(scrut_val, pat_val, "pat.eq"))
-                    } else {
-                        Some(self.builder.icmp_eq(scrut_val, pat_val, "pat.eq"))
-                    }
-                }
-                CanExpr::Bool(b) => {
-                    let pat_val = self.builder.const_bool(b);
-                    Some(self.builder.icmp_eq(scrut_val, pat_val, "pat.eq"))
-                }
-                _ => None,
-            };
+            .append_block(self.current_function, "assign.copy");
+        let write_bb = self
+            .builder
+            .append_block(self.current_function, "assign.write");
+        self.builder.cond_br(is_owned, write_bb, copy_bb);
+
+        self.builder.position_at_end(copy_bb);
+        let new_data = self.copy_list_data(len, data, element)?;
+        let copy_end_bb = self.builder.current_block()?;
+        self.builder.br(write_bb);
+
+        self.builder.position_at_end(write_bb);
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let cap = self.builder.phi_from_incoming(
+            i64_ty,
+            &[(cap, entry_bb), (len, copy_end_bb)],
+            "assign.cap",
+        )?;
+        let data = self.builder.phi_from_incoming(
+            ptr_ty,
+            &[(data, entry_bb), (new_data, copy_end_bb)],
+            "assign.data",
+        )?;
+        let owned = self.builder.const_bool(true);
+        self.builder.store(owned, flag);
+        Some((cap, data))
+    }
 
-            if let Some(test_val) = matches {
-                let arm_bb = self
-                    .builder
-                    .append_block(self.current_function, &format!("match.body{i}"));
-                self.builder.cond_br(test_val, arm_bb, next_bb);
-
-                self.builder.position_at_end(arm_bb);
-                let body_val = self.lower(arm_body);
-                let body_exit = self.builder.current_block();
-                if let (Some(bv), Some(bb)) = (body_val, body_exit) {
-                    if !self.builder.current_block_terminated() {
-                        incoming.push((bv, bb));
-                        self.builder.br(merge_bb);
-                    }
-                }
+    /// Copy the `len` elements at `data` into a fresh buffer.
+    fn copy_list_data(&mut self, len: ValueId, data: ValueId, element: Idx) -> Option<ValueId> {
+        let esize = self.compute_elem_byte_size(element);
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let alloc_data =
+            self.builder
+                .get_or_declare_function("ori_list_alloc_data", &[i64_ty, i64_ty], ptr_ty);
+        let new_data = self
+            .builder
+            .call(alloc_data, &[len, esize], "assign.new_data")?;
+        let bytes = self.builder.mul(len, esize, "assign.bytes");
+        self.builder.memcpy(new_data, data, bytes);
+        Some(new_data)
+    }
 
-                self.builder.position_at_end(next_bb);
-            } else {
-                // Cannot compile pattern test — treat as wildcard
-                let body_val = self.lower(arm_body);
-                let body_bb = self.builder.current_block();
-                if let (Some(bv), Some(bb)) = (body_val, body_bb) {
-                    if !self.builder.current_block_terminated() {
-                        incoming.push((bv, bb));
-                        self.builder.br(merge_bb);
-                    }
-                }
-                break;
-            }
+    /// Mark the list in the mutable slot `ptr` as possibly shared, so the
+    /// next index assignment copies it. No-op for non-list bindings.
+    pub(crate) fn release_list_ownership(&mut self, ptr: ValueId) {
+        if let Some(&flag) = self.owned_lists.get(&ptr) {
+            let not_owned = self.builder.const_bool(false);
+            self.builder.store(not_owned, flag);
         }
+    }
 
-        self.builder.position_at_end(merge_bb);
-        if incoming.is_empty() {
-            Some(self.builder.const_i64(0))
-        } else {
-            self.builder
-                .phi_from_incoming(result_llvm_ty, &incoming, "match.result")
+    /// Lower `id` for a read that only inspects the value (the receiver of
+    /// `xs[i]`): a mutable list binding keeps ownership of its buffer,
+    /// since the loaded list doesn't outlive the expression.
+    pub(crate) fn lower_inspected(&mut self, id: CanId) -> Option<ValueId> {
+        if let CanExpr::Ident(name) = *self.canon.arena.kind(id) {
+            if let Some(ScopeBinding::Mutable { ptr, ty }) = self.scope.lookup(name) {
+                let name_str = self.resolve_name(name).to_owned();
+                return Some(self.builder.load(ty, ptr, &name_str));
+            }
         }
+        self.lower(id)
     }
 }
+
+/// One projection in an assignment target, outermost first.
+#[derive(Clone, Copy, Debug)]
+enum AssignStep {
+    /// `.field` on a struct.
+    Field(Name),
+    /// `[index]` on a list; holds the index expression.
+    Index(CanId),
+}
@@ -17,6 +17,7 @@
 use ori_ir::canon::CanId;
 use ori_types::Idx;
 
+use super::abi::ReturnPassing;
 use super::expr_lowerer::ExprLowerer;
 use super::type_info::TypeInfo;
 use super::value_id::ValueId;
@@ -46,11 +47,15 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     }
 
     /// Lower `ExprKind::None` → `{i8 tag=0, <zero> payload}`.
+    ///
+    /// The payload slot must match the Option's real inner type, or a
+    /// sibling `Some` of the same type would be read back through the wrong
+    /// layout — see `none_option_type`.
     pub(crate) fn lower_none(&mut self, expr_id: CanId) -> Option<ValueId> {
-        let result_type = self.expr_type(expr_id);
-        let opt_ty = self.resolve_type(result_type);
+        let option_type = self.none_option_type(expr_id)?;
+        let opt_ty = self.resolve_type(option_type);
         let tag = self.builder.const_i8(0); // None = 0
-        let zero_payload = self.zero_value_for_option_payload(result_type);
+        let zero_payload = self.zero_value_for_option_payload(option_type);
 
         Some(
             self.builder
@@ -58,6 +63,30 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         )
     }
 
+    /// The Option type a `None` expression constructs.
+    ///
+    /// Normally the expression's own type. If inference left its payload
+    /// unresolved (a bare `None` pinned down only by the enclosing function's
+    /// return type), fall back to that return type rather than guessing an
+    /// `i64` payload.
+    fn none_option_type(&mut self, expr_id: CanId) -> Option<Idx> {
+        let expr_ty = self.expr_type(expr_id);
+        if let TypeInfo::Option { inner } = self.type_info.get(expr_ty) {
+            if !matches!(self.type_info.get(inner), TypeInfo::Error) {
+                return Some(expr_ty);
+            }
+        }
+
+        let ret_ty = self.return_abi.ty;
+        if matches!(self.type_info.get(ret_ty), TypeInfo::Option { .. }) {
+            return Some(ret_ty);
+        }
+
+        tracing::warn!(?expr_ty, "`None` without a resolvable Option type");
+        self.builder.record_codegen_error();
+        None
+    }
+
     // -----------------------------------------------------------------------
     // Result constructors
     // -----------------------------------------------------------------------
@@ -134,6 +163,11 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         let type_info = self.type_info.get(inner_type);
 
         let is_option = matches!(type_info, TypeInfo::Option { .. });
+        if !is_option && !matches!(type_info, TypeInfo::Result { .. }) {
+            tracing::warn!(?inner_type, "`?` applied to a non-Option/Result value");
+            self.builder.record_codegen_error();
+            return None;
+        }
 
         // Extract tag
         let tag = self.builder.extract_value(inner_val, 0, "try.tag")?;
@@ -155,33 +189,46 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
         self.builder.cond_br(has_value, unwrap_bb, propagate_bb);
 
-        // Propagate: early return with the error/None value
+        // Propagate: early return with None / Err(e) of the *function's* type
         self.builder.position_at_end(propagate_bb);
-        if is_option {
-            // Return None — build {tag=0, zero_payload}
-            let none_tag = self.builder.const_i8(0);
-
-            // Get the enclosing function's return type to build the right struct
-            let fn_ret_type = self.resolve_function_return_type();
-            // Produce a zero payload matching the function's option type
-            let zero_payload = self.zero_value_for_fn_return_payload(fn_ret_type);
-            let ret_val =
-                self.builder
-                    .build_struct(fn_ret_type, &[none_tag, zero_payload], "try.none");
-            self.builder.ret(ret_val);
-        } else {
-            // Return Err(err_payload) — preserve the error
-            let err_payload = self
-                .builder
-                .extract_value(inner_val, 1, "try.err_payload")
-                .unwrap_or_else(|| self.builder.const_i64(0));
-            let err_tag = self.builder.const_i8(1);
-
-            let fn_ret_type = self.resolve_function_return_type();
-            let ret_val =
-                self.builder
-                    .build_struct(fn_ret_type, &[err_tag, err_payload], "try.err");
-            self.builder.ret(ret_val);
+        match (type_info, self.type_info.get(self.return_abi.ty)) {
+            (TypeInfo::Option { .. }, TypeInfo::Option { .. }) => {
+                let ret_ty = self.return_abi.ty;
+                let ret_llvm_ty = self.resolve_type(ret_ty);
+                let none_tag = self.builder.const_i8(0);
+                let zero_payload = self.zero_value_for_option_payload(ret_ty);
+                let ret_val =
+                    self.builder
+                        .build_struct(ret_llvm_ty, &[none_tag, zero_payload], "try.none");
+                self.emit_early_return(ret_val);
+            }
+            (TypeInfo::Result { err, .. }, TypeInfo::Result { .. }) => {
+                // Reinterpret the inner payload as the error value, then
+                // re-pack it into the function's own payload layout (the
+                // function's ok type may differ from the inner ok type).
+                let ret_ty = self.return_abi.ty;
+                let ret_llvm_ty = self.resolve_type(ret_ty);
+                let inner_payload = self
+                    .builder
+                    .extract_value(inner_val, 1, "try.err_payload")
+                    .unwrap_or_else(|| self.builder.const_i64(0));
+                let err_val = self.coerce_payload(inner_payload, err);
+                let err_payload = self.coerce_for_result_payload(err_val, ret_ty, false);
+                let err_tag = self.builder.const_i8(1);
+                let ret_val =
+                    self.builder
+                        .build_struct(ret_llvm_ty, &[err_tag, err_payload], "try.err");
+                self.emit_early_return(ret_val);
+            }
+            (inner_info, ret_info) => {
+                tracing::warn!(
+                    ?inner_info,
+                    ?ret_info,
+                    "`?` operand does not match the enclosing function's return type"
+                );
+                self.builder.record_codegen_error();
+                self.builder.unreachable();
+            }
         }
 
         // Unwrap: extract the success payload
@@ -198,23 +245,19 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     // Helpers
     // -----------------------------------------------------------------------
 
-    /// Resolve the LLVM type of the enclosing function's return value.
+    /// Return `val` from the enclosing function, honoring its return ABI.
     ///
-    /// Used by Try to construct the early-return value with the correct type.
-    fn resolve_function_return_type(&mut self) -> super::value_id::LLVMTypeId {
-        let func_val = self.builder.get_function_value(self.current_function);
-        let ret_ty = func_val.get_type().get_return_type();
-        if let Some(ty) = ret_ty {
-            self.builder.register_type(ty)
-        } else {
-            // Void return — shouldn't happen with Try, but fall back
-            let i8_tag = self.builder.scx().type_i8().into();
-            let i64_payload = self.builder.scx().type_i64().into();
-            let struct_ty = self
-                .builder
-                .scx()
-                .type_struct(&[i8_tag, i64_payload], false);
-            self.builder.register_type(struct_ty.into())
+    /// Sret functions write through the hidden first parameter and return
+    /// void; direct functions return the value.
+    fn emit_early_return(&mut self, val: ValueId) {
+        match self.return_abi.passing {
+            ReturnPassing::Sret { .. } => {
+                let sret_ptr = self.builder.get_param(self.current_function, 0);
+                self.builder.store(val, sret_ptr);
+                self.builder.ret_void();
+            }
+            ReturnPassing::Direct => self.builder.ret(val),
+            ReturnPassing::Void => self.builder.ret_void(),
         }
     }
 
@@ -222,7 +265,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     ///
     /// For `option[int]` → `i64 0`, for `option[bool]` → `i1 0`,
     /// for `option[str]` → `{i64 0, ptr null}`, etc.
-    fn zero_value_for_option_payload(&mut self, option_idx: Idx) -> ValueId {
+    pub(crate) fn zero_value_for_option_payload(&mut self, option_idx: Idx) -> ValueId {
         let type_info = self.type_info.get(option_idx);
         match type_info {
             TypeInfo::Option { inner } => {
@@ -235,8 +278,6 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     }
 
     /// Produce a zero payload for a Result's payload slot.
-    ///
-    /// The payload type is `max(resolve(ok), resolve(err))`.
     fn zero_value_for_result_payload(&mut self, result_idx: Idx) -> ValueId {
         let payload_ty = self.resolve_result_payload_type(result_idx);
         self.builder.const_zero(payload_ty)
@@ -244,20 +285,15 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
     /// Get the LLVM type for a Result's payload slot.
     ///
-    /// Returns `max(resolve(ok), resolve(err))` — the larger of the two types.
+    /// Sized and aligned to hold either arm — see
+    /// `TypeLayoutResolver::result_payload_type`.
     fn resolve_result_payload_type(&self, result_idx: Idx) -> inkwell::types::BasicTypeEnum<'ctx> {
         let type_info = self.type_info.get(result_idx);
         match type_info {
             TypeInfo::Result { ok, err } => {
                 let ok_ty = self.type_resolver.resolve(ok);
                 let err_ty = self.type_resolver.resolve(err);
-                let ok_size = super::type_info::TypeLayoutResolver::type_store_size(ok_ty);
-                let err_size = super::type_info::TypeLayoutResolver::type_store_size(err_ty);
-                if ok_size >= err_size {
-                    ok_ty
-                } else {
-                    err_ty
-                }
+                self.type_resolver.result_payload_type(ok_ty, err_ty)
             }
             // Not a Result — fall back to i64
             _ => self.builder.scx().type_i64().into(),
@@ -269,7 +305,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// If the value's type matches the payload type, use it directly.
     /// Otherwise, store the value through a payload-sized alloca and load
     /// back as the payload type (reinterpretation cast).
-    fn coerce_for_result_payload(
+    pub(crate) fn coerce_for_result_payload(
         &mut self,
         val: ValueId,
         result_idx: Idx,
@@ -299,22 +335,4 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         self.builder.store(val, ptr);
         self.builder.load(payload_ty_id, ptr, "result.payload")
     }
-
-    /// Produce a zero payload for the function's return type (used by Try propagation).
-    ///
-    /// Extracts the payload type from the struct type (field 1) and produces
-    /// a zero value of that type.
-    fn zero_value_for_fn_return_payload(
-        &mut self,
-        fn_ret_type: super::value_id::LLVMTypeId,
-    ) -> ValueId {
-        let raw_ty = self.builder.raw_type(fn_ret_type);
-        if let inkwell::types::BasicTypeEnum::StructType(st) = raw_ty {
-            if let Some(field_ty) = st.get_field_type_at_index(1) {
-                return self.builder.const_zero(field_ty);
-            }
-        }
-        // Fallback: i64 zero
-        self.builder.const_i64(0)
-    }
 }
@@ -6,6 +6,8 @@
 //!
 //! Extracted from `lower_control_flow.rs` to keep files under the 500-line limit.
 
+use std::mem;
+
 use ori_ir::canon::CanId;
 use ori_ir::Name;
 use ori_types::Idx;
@@ -15,15 +17,21 @@ use super::type_info::TypeInfo;
 use super::value_id::{LLVMTypeId, ValueId};
 
 /// Temporary state for for-yield list construction.
+///
+/// The buffer is pre-sized from the source length where it is known, but
+/// the data pointer and capacity live in allocas so `emit_yield_store` can
+/// grow the buffer when the estimate is too small (e.g. iterators).
 pub(crate) struct YieldContext {
-    /// Pointer to the allocated data buffer.
-    data_ptr: ValueId,
+    /// Alloca holding the pointer to the data buffer.
+    data_slot: ValueId,
     /// Alloca holding the current write index (mutable counter).
     write_idx: ValueId,
-    /// Allocated capacity of the buffer.
-    cap: ValueId,
+    /// Alloca holding the allocated capacity of the buffer.
+    cap_slot: ValueId,
     /// LLVM type of each element (for GEP sizing).
     elem_llvm_ty: LLVMTypeId,
+    /// Size of each element in bytes (for growth).
+    elem_size: ValueId,
 }
 
 impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
@@ -45,6 +53,34 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         let iter_type = self.expr_type(iter);
         let type_info = self.type_info.get(iter_type);
 
+        // The loop binding lives in a child scope: its value is defined in
+        // the loop body, which does not dominate the exit block, so it must
+        // not leak into (or shadow a name in) the enclosing scope.
+        let child = self.scope.child();
+        let parent = mem::replace(&mut self.scope, child);
+        let result = self.lower_for_over(
+            binding, iter_val, iter_type, type_info, guard, body, is_yield, expr_id,
+        );
+        self.scope = parent;
+        result
+    }
+
+    /// Dispatch a for-loop on the iterable's type.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "for-loop lowering needs all loop components + type info"
+    )]
+    fn lower_for_over(
+        &mut self,
+        binding: Name,
+        iter_val: ValueId,
+        iter_type: Idx,
+        type_info: TypeInfo,
+        guard: CanId,
+        body: CanId,
+        is_yield: bool,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
         match type_info {
             TypeInfo::Range => {
                 self.lower_for_range(binding, iter_val, guard, body, is_yield, expr_id)
@@ -147,6 +183,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: latch_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         // Body
@@ -292,6 +329,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: latch_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         let body_val = self.lower(body);
@@ -435,6 +473,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: latch_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         let body_val = self.lower(body);
@@ -547,6 +586,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: exit_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         let body_val = self.lower(body);
@@ -686,6 +726,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: latch_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         let body_val = self.lower(body);
@@ -743,13 +784,12 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         expr_id: CanId,
     ) -> Option<ValueId> {
         let elem_llvm_ty = self.resolve_type(element);
-        let elem_size = self.type_info.get(element).size().unwrap_or(8);
-        let elem_size_val = self.builder.const_i64(elem_size as i64);
+        let elem_size_val = self.compute_elem_byte_size(element);
 
         // Allocate scratch space for element on the stack
         let scratch = self.builder.alloca(elem_llvm_ty, "foriter.scratch");
 
-        // Yield setup: unknown capacity, start with 0
+        // Yield setup: unknown capacity, start with 0 and grow on demand
         let yield_ctx = if is_yield {
             let zero = self.builder.const_i64(0);
             Some(self.setup_yield_context_with_capacity(zero, expr_id)?)
@@ -815,6 +855,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             exit_block: exit_bb,
             continue_block: latch_bb,
             break_values: Vec::new(),
+            result_type: Idx::UNIT,
         });
 
         let body_val = self.lower(body);
@@ -888,10 +929,9 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             _ => ori_types::Idx::INT,
         };
         let elem_llvm_ty = self.resolve_type(elem_idx);
-        let elem_size = self.type_info.get(elem_idx).size().unwrap_or(8);
 
         // Allocate raw data buffer: ori_list_alloc_data(capacity, elem_size)
-        let esize = self.builder.const_i64(elem_size as i64);
+        let esize = self.compute_elem_byte_size(elem_idx);
         let i64_ty = self.builder.i64_type();
         let i64_ty2 = self.builder.i64_type();
         let ptr_ty = self.builder.ptr_type();
@@ -900,30 +940,80 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
                 .get_or_declare_function("ori_list_alloc_data", &[i64_ty, i64_ty2], ptr_ty);
         let data_ptr = self.builder.call(alloc_data, &[cap, esize], "yield.data")?;
 
-        // Write index alloca at function entry
+        // Write index, capacity and data pointer allocas at function entry
         let i64_llvm = self.builder.i64_type();
         let write_idx =
             self.builder
                 .create_entry_alloca(self.current_function, "yield.widx", i64_llvm);
         let zero = self.builder.const_i64(0);
         self.builder.store(zero, write_idx);
+        let cap_slot =
+            self.builder
+                .create_entry_alloca(self.current_function, "yield.cap", i64_llvm);
+        self.builder.store(cap, cap_slot);
+        let data_slot =
+            self.builder
+                .create_entry_alloca(self.current_function, "yield.data", ptr_ty);
+        self.builder.store(data_ptr, data_slot);
 
         Some(YieldContext {
-            data_ptr,
+            data_slot,
             write_idx,
-            cap,
+            cap_slot,
             elem_llvm_ty,
+            elem_size: esize,
         })
     }
 
     /// Store a body value into the yield output list and increment write index.
+    ///
+    /// When the buffer is full, capacity doubles (minimum 4) via
+    /// `ori_list_grow_data` before the store.
     fn emit_yield_store(&mut self, yc: &YieldContext, body_val: ValueId) {
         let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
         let widx = self.builder.load(i64_ty, yc.write_idx, "yield.widx_cur");
+        let cap = self.builder.load(i64_ty, yc.cap_slot, "yield.cap_cur");
 
+        let grow_bb = self
+            .builder
+            .append_block(self.current_function, "yield.grow");
+        let store_bb = self
+            .builder
+            .append_block(self.current_function, "yield.store");
+        let full = self.builder.icmp_sge(widx, cap, "yield.full");
+        self.builder.cond_br(full, grow_bb, store_bb);
+
+        // Grow: new_cap = max(cap * 2, 4)
+        self.builder.position_at_end(grow_bb);
+        let two = self.builder.const_i64(2);
+        let four = self.builder.const_i64(4);
+        let doubled = self.builder.mul(cap, two, "yield.doubled");
+        let too_small = self.builder.icmp_slt(doubled, four, "yield.small");
+        let new_cap = self
+            .builder
+            .select(too_small, four, doubled, "yield.new_cap");
+        let old_data = self.builder.load(ptr_ty, yc.data_slot, "yield.old_data");
+        let grow_fn = self.builder.get_or_declare_function(
+            "ori_list_grow_data",
+            &[ptr_ty, i64_ty, i64_ty, i64_ty],
+            ptr_ty,
+        );
+        if let Some(new_data) = self.builder.call(
+            grow_fn,
+            &[old_data, cap, new_cap, yc.elem_size],
+            "yield.new_data",
+        ) {
+            self.builder.store(new_data, yc.data_slot);
+            self.builder.store(new_cap, yc.cap_slot);
+        }
+        self.builder.br(store_bb);
+
+        self.builder.position_at_end(store_bb);
+        let data_ptr = self.builder.load(ptr_ty, yc.data_slot, "yield.data_cur");
         let elem_ptr = self
             .builder
-            .gep(yc.elem_llvm_ty, yc.data_ptr, &[widx], "yield.elem_ptr");
+            .gep(yc.elem_llvm_ty, data_ptr, &[widx], "yield.elem_ptr");
         self.builder.store(body_val, elem_ptr);
 
         let one = self.builder.const_i64(1);
@@ -934,12 +1024,15 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// Build the final list struct from yield context after the loop completes.
     fn finish_yield_list(&mut self, yc: &YieldContext, expr_id: CanId) -> Option<ValueId> {
         let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
         let final_len = self.builder.load(i64_ty, yc.write_idx, "yield.final_len");
+        let final_cap = self.builder.load(i64_ty, yc.cap_slot, "yield.final_cap");
+        let data_ptr = self.builder.load(ptr_ty, yc.data_slot, "yield.final_data");
         let result_type = self.expr_type(expr_id);
         let list_ty = self.resolve_type(result_type);
         Some(
             self.builder
-                .build_struct(list_ty, &[final_len, yc.cap, yc.data_ptr], "yield.list"),
+                .build_struct(list_ty, &[final_len, final_cap, data_ptr], "yield.list"),
         )
     }
 
@@ -76,7 +76,11 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     }
 
     /// Allocate and populate a trampoline wrapper struct `{ ptr fn_ptr, ptr env_ptr }`.
-    fn alloc_trampoline_wrapper(&mut self, fn_ptr: ValueId, env_ptr: ValueId) -> Option<ValueId> {
+    pub(crate) fn alloc_trampoline_wrapper(
+        &mut self,
+        fn_ptr: ValueId,
+        env_ptr: ValueId,
+    ) -> Option<ValueId> {
         // Wrapper = { ptr, ptr } = 16 bytes, 8-byte aligned
         let i64_ty = self.builder.i64_type();
         let ptr_ty = self.builder.ptr_type();
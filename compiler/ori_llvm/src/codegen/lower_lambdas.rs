@@ -7,12 +7,13 @@
 //! 4. Compile body, emit return at native type (no i64 coercion)
 //! 5. Build fat pointer: `{ fn_ptr, env_ptr }` (`env_ptr` = null if no captures)
 
-use ori_ir::canon::{CanExpr, CanId, CanParamRange};
-use ori_ir::Name;
+use ori_ir::canon::{CanId, CanParamRange};
+use ori_ir::{canon_free_vars, Name};
 use ori_types::Idx;
 
 use crate::aot::mangle::Mangler;
 
+use super::abi::{ReturnAbi, ReturnPassing};
 use super::expr_lowerer::ExprLowerer;
 use super::scope::ScopeBinding;
 use super::type_info::TypeInfo;
@@ -33,12 +34,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         body: CanId,
         lambda_id: CanId,
     ) -> Option<ValueId> {
-        let param_list = self.canon.arena.get_params(params);
-
-        // Step 1: Capture analysis
-        let captures = self.find_captures(body, params);
-
-        // Step 2: Get lambda type info for actual param/return types
+        // Get lambda type info for actual param/return types
         let lambda_type_idx = self.expr_type(lambda_id);
         let type_info = self.type_info.get(lambda_type_idx);
         let (fn_param_types, fn_ret_type) = if let TypeInfo::Function { params, ret } = &type_info {
@@ -46,24 +42,42 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         } else {
             tracing::warn!(?type_info, "lambda has non-Function type info");
             // Fallback: use i64 for everything
-            let param_types = vec![Idx::INT; param_list.len()];
-            (param_types, Idx::INT)
+            (vec![Idx::INT; params.len()], Idx::INT)
         };
 
-        // Step 3: Generate unique mangled lambda name via module-wide counter
+        self.compile_closure(params, body, &fn_param_types, fn_ret_type)
+    }
+
+    /// Compile `body` as a closure with the given parameter and return types.
+    ///
+    /// Shared by lambdas and patterns that run an expression out of line
+    /// (e.g. the body of `timeout`, compiled as a `() -> T` closure).
+    pub(crate) fn compile_closure(
+        &mut self,
+        params: CanParamRange,
+        body: CanId,
+        fn_param_types: &[Idx],
+        fn_ret_type: Idx,
+    ) -> Option<ValueId> {
+        let param_list = self.canon.arena.get_params(params);
+
+        // Step 1: Capture analysis
+        let captures = self.find_captures(body, params);
+
+        // Step 2: Generate unique mangled lambda name via module-wide counter
         let counter = self.lambda_counter.get();
         self.lambda_counter.set(counter + 1);
         let mangler = Mangler::new();
         let lambda_name = mangler.mangle_function(self.module_path, &format!("__lambda_{counter}"));
 
-        // Step 4: Build LLVM function signature
+        // Step 3: Build LLVM function signature
         // First param: hidden ptr %env (for captures)
         // Remaining params: actual types from type info
         let ptr_ty = self.builder.ptr_type();
         let mut llvm_param_types = Vec::with_capacity(1 + fn_param_types.len());
         llvm_param_types.push(ptr_ty); // hidden env_ptr
 
-        for &param_idx in &fn_param_types {
+        for &param_idx in fn_param_types {
             let llvm_ty = self.type_resolver.resolve(param_idx);
             llvm_param_types.push(self.builder.register_type(llvm_ty));
         }
@@ -87,8 +101,17 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         self.builder.set_current_function(lambda_func);
         self.builder.position_at_end(entry_bb);
 
-        // Create lambda scope (swap out parent)
+        // Create lambda scope (swap out parent). Provided capabilities the
+        // body uses were captured above; the rest are not visible inside.
         let parent_scope = std::mem::take(&mut self.scope);
+        let parent_capabilities = std::mem::take(&mut self.capabilities);
+        let parent_return = std::mem::replace(
+            &mut self.return_abi,
+            ReturnAbi {
+                ty: fn_ret_type,
+                passing: ReturnPassing::Direct,
+            },
+        );
 
         // Bind user parameters (LLVM params start at index 1, after hidden env_ptr)
         for (i, param) in param_list.iter().enumerate() {
@@ -124,7 +147,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             }
         }
 
-        // Step 5: Compile body
+        // Step 4: Compile body
         let body_val = self.lower(body);
 
         // Emit return (native type, no coercion)
@@ -140,11 +163,13 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
         // Restore context
         self.scope = parent_scope;
+        self.capabilities = parent_capabilities;
+        self.return_abi = parent_return;
         self.current_function = saved_func;
         self.builder.set_current_function(saved_func);
         self.builder.restore_position(saved_pos);
 
-        // Step 6: Build fat-pointer closure { fn_ptr, env_ptr }
+        // Step 5: Build fat-pointer closure { fn_ptr, env_ptr }
         let fn_val = self.builder.get_function_value(lambda_func);
         let fn_ptr = fn_val.as_global_value().as_pointer_value();
         let fn_ptr_id = self.builder.intern_value(fn_ptr.into());
@@ -163,180 +188,37 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// Returns `(Name, ValueId, Idx)` triples — name, current value, and
     /// type index — for each captured variable. The type is needed to build
     /// the environment struct with native-typed fields.
+    ///
+    /// Free names come from [`ori_ir::canon_free_vars`]; only those bound
+    /// in the enclosing scope (locals and provided capabilities) are
+    /// captured. Mutable locals are captured by their current value.
     fn find_captures(&mut self, body: CanId, params: CanParamRange) -> Vec<(Name, ValueId, Idx)> {
-        let param_list = self.canon.arena.get_params(params);
-        let param_names: Vec<Name> = param_list.iter().map(|p| p.name).collect();
+        let param_names: Vec<Name> = self
+            .canon
+            .arena
+            .get_params(params)
+            .iter()
+            .map(|p| p.name)
+            .collect();
 
         let mut captures = Vec::new();
-        let mut seen = std::collections::HashSet::new();
-        self.collect_free_vars(body, &param_names, &mut captures, &mut seen);
-        captures
-    }
-
-    /// Recursively collect free variables from a canonical expression.
-    #[expect(
-        clippy::too_many_lines,
-        reason = "dispatch table — each arm is 1-3 lines"
-    )]
-    fn collect_free_vars(
-        &mut self,
-        expr_id: CanId,
-        params: &[Name],
-        captures: &mut Vec<(Name, ValueId, Idx)>,
-        seen: &mut std::collections::HashSet<Name>,
-    ) {
-        if !expr_id.is_valid() {
-            return;
-        }
-
-        let kind = *self.canon.arena.kind(expr_id);
-        match kind {
-            CanExpr::Ident(name) => {
-                // Capture if: in outer scope, not a parameter, not already captured
-                if !params.contains(&name) && !seen.contains(&name) {
-                    if let Some(binding) = self.scope.lookup(name) {
-                        seen.insert(name);
-                        let val = match binding {
-                            ScopeBinding::Immutable(v) => v,
-                            ScopeBinding::Mutable { ptr, ty } => {
-                                // Capture current value (by-value semantics)
-                                self.builder.load(ty, ptr, "capture")
-                            }
-                        };
-                        let capture_type = self.expr_type(expr_id);
-                        captures.push((name, val, capture_type));
-                    }
+        for (name, first_ref) in canon_free_vars(body, self.canon, &param_names) {
+            let val = match self.scope.lookup(name) {
+                Some(ScopeBinding::Immutable(v)) => Some(v),
+                // Capture current value (by-value semantics)
+                Some(ScopeBinding::Mutable { ptr, ty }) => {
+                    let val = self.builder.load(ty, ptr, "capture");
+                    self.release_list_ownership(ptr);
+                    Some(val)
                 }
+                // A provided capability is captured like a local.
+                None => self.lower_capability(name, first_ref),
+            };
+            if let Some(val) = val {
+                captures.push((name, val, self.expr_type(first_ref)));
             }
-            CanExpr::Binary { left, right, .. } => {
-                self.collect_free_vars(left, params, captures, seen);
-                self.collect_free_vars(right, params, captures, seen);
-            }
-            CanExpr::Unary { operand, .. } => {
-                self.collect_free_vars(operand, params, captures, seen);
-            }
-            CanExpr::Call { func, args } => {
-                self.collect_free_vars(func, params, captures, seen);
-                for &arg in self.canon.arena.get_expr_list(args) {
-                    self.collect_free_vars(arg, params, captures, seen);
-                }
-            }
-            CanExpr::If {
-                cond,
-                then_branch,
-                else_branch,
-            } => {
-                self.collect_free_vars(cond, params, captures, seen);
-                self.collect_free_vars(then_branch, params, captures, seen);
-                self.collect_free_vars(else_branch, params, captures, seen);
-            }
-            CanExpr::Block { stmts, result } => {
-                for &stmt in self.canon.arena.get_expr_list(stmts) {
-                    self.collect_free_vars(stmt, params, captures, seen);
-                }
-                self.collect_free_vars(result, params, captures, seen);
-            }
-            CanExpr::Lambda { body, .. } | CanExpr::Loop { body, .. } => {
-                self.collect_free_vars(body, params, captures, seen);
-            }
-            CanExpr::Field { receiver, .. } => {
-                self.collect_free_vars(receiver, params, captures, seen);
-            }
-            CanExpr::Index { receiver, index } => {
-                self.collect_free_vars(receiver, params, captures, seen);
-                self.collect_free_vars(index, params, captures, seen);
-            }
-            CanExpr::For {
-                iter, body, guard, ..
-            } => {
-                self.collect_free_vars(iter, params, captures, seen);
-                self.collect_free_vars(guard, params, captures, seen);
-                self.collect_free_vars(body, params, captures, seen);
-            }
-            CanExpr::Match {
-                scrutinee, arms, ..
-            } => {
-                self.collect_free_vars(scrutinee, params, captures, seen);
-                for &arm_body in self.canon.arena.get_expr_list(arms) {
-                    self.collect_free_vars(arm_body, params, captures, seen);
-                }
-            }
-            CanExpr::Ok(e)
-            | CanExpr::Err(e)
-            | CanExpr::Some(e)
-            | CanExpr::Try(e)
-            | CanExpr::Await(e)
-            | CanExpr::Unsafe(e)
-            | CanExpr::Break { value: e, .. }
-            | CanExpr::Continue { value: e, .. } => {
-                self.collect_free_vars(e, params, captures, seen);
-            }
-            CanExpr::Assign { target, value } => {
-                self.collect_free_vars(target, params, captures, seen);
-                self.collect_free_vars(value, params, captures, seen);
-            }
-            CanExpr::Cast { expr, .. } | CanExpr::FormatWith { expr, .. } => {
-                self.collect_free_vars(expr, params, captures, seen);
-            }
-            CanExpr::Tuple(range) | CanExpr::List(range) => {
-                for &e in self.canon.arena.get_expr_list(range) {
-                    self.collect_free_vars(e, params, captures, seen);
-                }
-            }
-            CanExpr::MethodCall { receiver, args, .. } => {
-                self.collect_free_vars(receiver, params, captures, seen);
-                for &arg in self.canon.arena.get_expr_list(args) {
-                    self.collect_free_vars(arg, params, captures, seen);
-                }
-            }
-            CanExpr::WithCapability { body, provider, .. } => {
-                self.collect_free_vars(provider, params, captures, seen);
-                self.collect_free_vars(body, params, captures, seen);
-            }
-            CanExpr::Let { init, .. } => {
-                self.collect_free_vars(init, params, captures, seen);
-            }
-            CanExpr::Range {
-                start, end, step, ..
-            } => {
-                self.collect_free_vars(start, params, captures, seen);
-                self.collect_free_vars(end, params, captures, seen);
-                self.collect_free_vars(step, params, captures, seen);
-            }
-            CanExpr::Struct { fields, .. } => {
-                for fi in self.canon.arena.get_fields(fields) {
-                    self.collect_free_vars(fi.value, params, captures, seen);
-                }
-            }
-            CanExpr::Map(entries) => {
-                for entry in self.canon.arena.get_map_entries(entries) {
-                    self.collect_free_vars(entry.key, params, captures, seen);
-                    self.collect_free_vars(entry.value, params, captures, seen);
-                }
-            }
-            CanExpr::FunctionExp { props, .. } => {
-                for ne in self.canon.arena.get_named_exprs(props) {
-                    self.collect_free_vars(ne.value, params, captures, seen);
-                }
-            }
-            // Leaf expressions — no free variables
-            CanExpr::Constant(_)
-            | CanExpr::Int(_)
-            | CanExpr::Float(_)
-            | CanExpr::Bool(_)
-            | CanExpr::Char(_)
-            | CanExpr::Str(_)
-            | CanExpr::Unit
-            | CanExpr::None
-            | CanExpr::Error
-            | CanExpr::SelfRef
-            | CanExpr::FunctionRef(_)
-            | CanExpr::TypeRef(_)
-            | CanExpr::Const(_)
-            | CanExpr::HashLength
-            | CanExpr::Duration { .. }
-            | CanExpr::Size { .. } => {}
         }
+        captures
     }
 
     /// Build a heap-allocated environment struct from captured values.
@@ -89,16 +89,18 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// but the length field does not include the null terminator.
     pub(crate) fn lower_string(&mut self, name: Name) -> Option<ValueId> {
         let s = self.resolve_name(name).to_owned();
-        let len = s.len();
-        let len_val = self.builder.const_i64(len as i64);
-        let ptr_val = self.builder.build_global_string_ptr(&s, "str.data");
+        Some(self.lower_str_constant(&s))
+    }
+
+    /// Build a `str` value for the constant text `s`.
+    pub(crate) fn lower_str_constant(&mut self, s: &str) -> ValueId {
+        let len_val = self.builder.const_i64(s.len() as i64);
+        let ptr_val = self.builder.string_literal_ptr(s);
 
         // Build {i64 len, ptr data} struct
         let str_ty = self.resolve_type(ori_types::Idx::STR);
-        let result = self
-            .builder
-            .build_struct(str_ty, &[len_val, ptr_val], "str");
-        Some(result)
+        self.builder
+            .build_struct(str_ty, &[len_val, ptr_val], "str")
     }
 
     // -----------------------------------------------------------------------
@@ -129,12 +131,27 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             Some(ScopeBinding::Mutable { ptr, ty }) => {
                 let name_str = self.resolve_name(name).to_owned();
                 let val = self.builder.load(ty, ptr, &name_str);
+                self.release_list_ownership(ptr);
                 Some(val)
             }
             None => {
+                // A capability provided by an enclosing `with ... in`, or
+                // by the caller for a function declared `uses`
+                if let Some(provider) = self.lower_capability(name, expr_id) {
+                    return Some(provider);
+                }
+
                 // Check declared functions map first (V2 mangled names)
-                if let Some(&(func_id, _)) = self.functions.get(&name) {
-                    return self.wrap_function_as_value(func_id, expr_id);
+                if let Some((func_id, abi)) = self.functions.get(&name) {
+                    if !abi.capabilities.is_empty() {
+                        tracing::warn!(
+                            name = self.resolve_name(name),
+                            "function declared `uses` taken as a value"
+                        );
+                        self.builder.record_codegen_error();
+                        return None;
+                    }
+                    return self.wrap_function_as_value(*func_id, expr_id);
                 }
 
                 // Fall back to LLVM module lookup (runtime functions, etc.)
@@ -184,8 +201,9 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
     /// Lower `CanExpr::Const(name)` — compile-time constant reference.
     ///
-    /// Constants are bound in the scope like immutable variables.
-    /// Falls back to identifier lookup.
+    /// Constants defined in the compiled module never reach codegen:
+    /// canonicalization inlines and folds their initializers. Any remaining
+    /// reference is resolved like an immutable binding.
     pub(crate) fn lower_const(&mut self, name: Name, expr_id: CanId) -> Option<ValueId> {
         self.lower_ident(name, expr_id)
     }
@@ -0,0 +1,399 @@
+//! Match lowering for V2 codegen.
+//!
+//! `CanExpr::Match` carries a decision tree compiled during canonicalization
+//! (see `ori_ir::canon::tree`). This module walks that tree and emits one
+//! conditional branch per `Switch` edge, falling through to the next edge
+//! (and finally the default subtree) on a miss. LLVM's `simplifycfg` folds
+//! the integer chains back into `switch` instructions.
+//!
+//! Sub-values are reached through `ScrutineePath`s, projected from the
+//! scrutinee on demand:
+//!
+//! - `TupleIndex` / `StructField` → `extractvalue` (struct fields are
+//!   numbered in Name order by the tree; mapped back to declaration order)
+//! - `TagPayload` → the payload field of an `Option`/`Result`
+//! - `ListElement` / `ListRest` → loads / slices over the list's buffer
+//!
+//! Leaves bind their names in a child scope, lower the arm body, and feed
+//! the `match.result` phi. `Fail` nodes are unreachable: exhaustiveness is
+//! checked before codegen.
+
+use std::mem;
+
+use ori_ir::canon::{
+    CanId, CanRange, DecisionTree, DecisionTreeId, PathInstruction, ScrutineePath, TestKind,
+    TestValue,
+};
+use ori_ir::Name;
+use ori_types::Idx;
+
+use super::expr_lowerer::ExprLowerer;
+use super::scope::Scope;
+use super::type_info::TypeInfo;
+use super::value_id::{BlockId, ValueId};
+
+/// Per-match state threaded through decision tree emission.
+struct MatchState {
+    scrutinee: ValueId,
+    scrut_type: Idx,
+    arm_ids: Vec<CanId>,
+    merge_bb: BlockId,
+    incoming: Vec<(ValueId, BlockId)>,
+    /// Set when an arm falls through to the merge block without a value.
+    missing_value: bool,
+    /// Variants confirmed by the enclosing tag tests, keyed by tested path.
+    /// `Result` payloads need this to know whether they hold `ok` or `err`.
+    known_variants: Vec<(ScrutineePath, u32)>,
+}
+
+impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    /// Lower `CanExpr::Match { scrutinee, decision_tree, arms }`.
+    pub(crate) fn lower_match(
+        &mut self,
+        scrutinee: CanId,
+        decision_tree: DecisionTreeId,
+        arms: CanRange,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let scrut_val = self.lower(scrutinee)?;
+        let scrut_type = self.expr_type(scrutinee);
+        let result_type = self.expr_type(expr_id);
+        let result_llvm_ty = self.resolve_type(result_type);
+
+        let canon = self.canon;
+        let arm_ids = canon.arena.get_expr_list(arms).to_vec();
+        if arm_ids.is_empty() {
+            return None;
+        }
+        let tree = canon.decision_trees.get(decision_tree);
+
+        let merge_bb = self
+            .builder
+            .append_block(self.current_function, "match.merge");
+        let mut state = MatchState {
+            scrutinee: scrut_val,
+            scrut_type,
+            arm_ids,
+            merge_bb,
+            incoming: Vec::new(),
+            missing_value: false,
+            known_variants: Vec::new(),
+        };
+
+        self.emit_decision_tree(tree, &mut state);
+
+        self.builder.position_at_end(merge_bb);
+        if state.missing_value {
+            return None;
+        }
+        if state.incoming.is_empty() {
+            Some(self.builder.const_i64(0))
+        } else {
+            self.builder
+                .phi_from_incoming(result_llvm_ty, &state.incoming, "match.result")
+        }
+    }
+
+    /// Emit one decision tree node at the current insertion point.
+    fn emit_decision_tree(&mut self, tree: &DecisionTree, state: &mut MatchState) {
+        match tree {
+            DecisionTree::Switch {
+                path,
+                test_kind,
+                edges,
+                default,
+            } => self.emit_match_switch(path, *test_kind, edges, default.as_deref(), state),
+            DecisionTree::Leaf {
+                arm_index,
+                bindings,
+            } => {
+                let parent = self.bind_match_names(bindings, state);
+                self.emit_match_arm(*arm_index, state);
+                self.scope = parent;
+            }
+            DecisionTree::Guard {
+                arm_index,
+                bindings,
+                guard,
+                on_fail,
+            } => {
+                let parent = self.bind_match_names(bindings, state);
+                let Some(cond) = self.lower(*guard) else {
+                    self.scope = parent;
+                    return;
+                };
+                let body_bb = self
+                    .builder
+                    .append_block(self.current_function, "match.guard.ok");
+                let fail_bb = self
+                    .builder
+                    .append_block(self.current_function, "match.guard.fail");
+                self.builder.cond_br(cond, body_bb, fail_bb);
+
+                self.builder.position_at_end(body_bb);
+                self.emit_match_arm(*arm_index, state);
+                self.scope = parent;
+
+                self.builder.position_at_end(fail_bb);
+                self.emit_decision_tree(on_fail, state);
+            }
+            DecisionTree::Fail => self.builder.unreachable(),
+        }
+    }
+
+    /// Emit a `Switch` node as a chain of tests, one per edge.
+    fn emit_match_switch(
+        &mut self,
+        path: &ScrutineePath,
+        test_kind: TestKind,
+        edges: &[(TestValue, DecisionTree)],
+        default: Option<&DecisionTree>,
+        state: &mut MatchState,
+    ) {
+        let Some((value, ty)) = self.match_path_value(path, state) else {
+            self.builder.unreachable();
+            return;
+        };
+        // Tag and length tests inspect the header field, not the value.
+        let subject = match test_kind {
+            TestKind::EnumTag => self.builder.extract_value(value, 0, "match.tag"),
+            TestKind::ListLen => self.builder.extract_value(value, 0, "match.len"),
+            _ => Some(value),
+        };
+        let Some(subject) = subject else {
+            self.builder.unreachable();
+            return;
+        };
+
+        for (i, (test_value, subtree)) in edges.iter().enumerate() {
+            let Some(hit) = self.emit_match_test(subject, ty, test_value) else {
+                self.builder.unreachable();
+                return;
+            };
+            let case_bb = self
+                .builder
+                .append_block(self.current_function, &format!("match.case{i}"));
+            let next_bb = self
+                .builder
+                .append_block(self.current_function, &format!("match.next{i}"));
+            self.builder.cond_br(hit, case_bb, next_bb);
+
+            self.builder.position_at_end(case_bb);
+            if let TestValue::Tag { variant_index, .. } = test_value {
+                state.known_variants.push((path.clone(), *variant_index));
+                self.emit_decision_tree(subtree, state);
+                state.known_variants.pop();
+            } else {
+                self.emit_decision_tree(subtree, state);
+            }
+
+            self.builder.position_at_end(next_bb);
+        }
+
+        match default {
+            Some(subtree) => self.emit_decision_tree(subtree, state),
+            None => self.builder.unreachable(),
+        }
+    }
+
+    /// Emit the `i1` test for a single switch edge.
+    ///
+    /// `subject` is the tag (i8) for `EnumTag`, the length (i64) for
+    /// `ListLen`, and the projected value itself otherwise.
+    fn emit_match_test(&mut self, subject: ValueId, ty: Idx, test: &TestValue) -> Option<ValueId> {
+        let hit = match *test {
+            TestValue::Tag { variant_index, .. } => {
+                let tag = self.builder.const_i8(variant_index as i8);
+                self.builder.icmp_eq(subject, tag, "match.is_variant")
+            }
+            TestValue::Int(n) => {
+                let expected = match ty {
+                    Idx::BYTE => self.builder.const_i8(n as i8),
+                    _ => self.builder.const_i64(n),
+                };
+                self.builder.icmp_eq(subject, expected, "match.int_eq")
+            }
+            TestValue::Bool(b) => {
+                let expected = self.builder.const_bool(b);
+                self.builder.icmp_eq(subject, expected, "match.bool_eq")
+            }
+            TestValue::Char(c) => {
+                let expected = self.builder.const_i32(c as i32);
+                self.builder.icmp_eq(subject, expected, "match.char_eq")
+            }
+            TestValue::Float(bits) => {
+                let expected = self.builder.const_f64(f64::from_bits(bits));
+                self.builder.fcmp_oeq(subject, expected, "match.float_eq")
+            }
+            TestValue::Str(name) => {
+                let expected = self.lower_string(name)?;
+                self.lower_str_eq(subject, expected)?
+            }
+            TestValue::IntRange { lo, hi, inclusive } => {
+                // Char (and byte) ranges compare code points as i64.
+                let wide = if matches!(ty, Idx::CHAR | Idx::BYTE) {
+                    let i64_ty = self.builder.i64_type();
+                    self.builder.zext(subject, i64_ty, "match.widen")
+                } else {
+                    subject
+                };
+                let lo_val = self.builder.const_i64(lo);
+                let hi_val = self.builder.const_i64(hi);
+                let above = self.builder.icmp_sge(wide, lo_val, "match.range_lo");
+                let below = if inclusive {
+                    self.builder.icmp_sle(wide, hi_val, "match.range_hi")
+                } else {
+                    self.builder.icmp_slt(wide, hi_val, "match.range_hi")
+                };
+                self.builder.and(above, below, "match.in_range")
+            }
+            TestValue::ListLen { len, is_exact } => {
+                let expected = self.builder.const_i64(i64::from(len));
+                if is_exact {
+                    self.builder.icmp_eq(subject, expected, "match.len_eq")
+                } else {
+                    self.builder.icmp_sge(subject, expected, "match.len_ge")
+                }
+            }
+        };
+        Some(hit)
+    }
+
+    /// Bind a leaf's names in a fresh child scope, returning the parent
+    /// scope for the caller to restore once the arm is lowered.
+    fn bind_match_names(
+        &mut self,
+        bindings: &[(Name, ScrutineePath)],
+        state: &MatchState,
+    ) -> Scope {
+        let child = self.scope.child();
+        let parent = mem::replace(&mut self.scope, child);
+        for (name, path) in bindings {
+            if let Some((value, _)) = self.match_path_value(path, state) {
+                self.scope.bind_immutable(*name, value);
+            }
+        }
+        parent
+    }
+
+    /// Lower the body of arm `arm_index` and branch to the merge block.
+    fn emit_match_arm(&mut self, arm_index: usize, state: &mut MatchState) {
+        let body = state.arm_ids[arm_index];
+        let value = self.lower(body);
+        if self.builder.current_block_terminated() {
+            return;
+        }
+        match (value, self.builder.current_block()) {
+            (Some(v), Some(bb)) => state.incoming.push((v, bb)),
+            _ => state.missing_value = true,
+        }
+        self.builder.br(state.merge_bb);
+    }
+
+    /// Project the sub-value at `path` out of the scrutinee.
+    ///
+    /// Returns the value with its type, or `None` (after recording a
+    /// codegen error) when the path cannot be followed on this type.
+    fn match_path_value(
+        &mut self,
+        path: &ScrutineePath,
+        state: &MatchState,
+    ) -> Option<(ValueId, Idx)> {
+        let mut value = state.scrutinee;
+        let mut ty = state.scrut_type;
+
+        for (depth, step) in path.iter().enumerate() {
+            let info = self.type_info.get(ty);
+            let projected = match (*step, info) {
+                (PathInstruction::TupleIndex(i), TypeInfo::Tuple { elements }) => elements
+                    .get(i as usize)
+                    .copied()
+                    .zip(self.builder.extract_value(value, i, "match.tup")),
+                (PathInstruction::StructField(i), TypeInfo::Struct { fields }) => {
+                    // The tree numbers fields in Name order; LLVM uses
+                    // declaration order.
+                    let mut by_name: Vec<Name> = fields.iter().map(|(n, _)| *n).collect();
+                    by_name.sort();
+                    by_name
+                        .get(i as usize)
+                        .and_then(|name| fields.iter().position(|(n, _)| n == name))
+                        .and_then(|decl| {
+                            let field_ty = fields[decl].1;
+                            self.builder
+                                .extract_value(value, decl as u32, "match.field")
+                                .map(|v| (field_ty, v))
+                        })
+                }
+                (PathInstruction::TagPayload(0), TypeInfo::Option { inner }) => self
+                    .builder
+                    .extract_value(value, 1, "match.payload")
+                    .map(|v| (inner, v)),
+                (PathInstruction::TagPayload(0), TypeInfo::Result { ok, err }) => {
+                    let prefix = &path[..depth];
+                    let variant = state
+                        .known_variants
+                        .iter()
+                        .rev()
+                        .find(|(p, _)| p.as_slice() == prefix)
+                        .map(|(_, v)| *v);
+                    let payload_ty = if variant == Some(1) { err } else { ok };
+                    self.builder
+                        .extract_value(value, 1, "match.payload")
+                        .map(|v| (payload_ty, self.coerce_payload(v, payload_ty)))
+                }
+                (PathInstruction::ListElement(i), TypeInfo::List { element }) => {
+                    let elem_llvm_ty = self.resolve_type(element);
+                    self.builder
+                        .extract_value(value, 2, "match.data")
+                        .map(|data| {
+                            let idx = self.builder.const_i64(i64::from(i));
+                            let ptr =
+                                self.builder
+                                    .gep(elem_llvm_ty, data, &[idx], "match.elem.ptr");
+                            (element, self.builder.load(elem_llvm_ty, ptr, "match.elem"))
+                        })
+                }
+                (PathInstruction::ListRest(start), TypeInfo::List { element }) => {
+                    let elem_llvm_ty = self.resolve_type(element);
+                    let list_llvm_ty = self.resolve_type(ty);
+                    match (
+                        self.builder.extract_value(value, 0, "match.len"),
+                        self.builder.extract_value(value, 2, "match.data"),
+                    ) {
+                        (Some(len), Some(data)) => {
+                            let start_val = self.builder.const_i64(i64::from(start));
+                            let rest_len = self.builder.sub(len, start_val, "match.rest.len");
+                            let rest_data = self.builder.gep(
+                                elem_llvm_ty,
+                                data,
+                                &[start_val],
+                                "match.rest.data",
+                            );
+                            let rest = self.builder.build_struct(
+                                list_llvm_ty,
+                                &[rest_len, rest_len, rest_data],
+                                "match.rest",
+                            );
+                            Some((ty, rest))
+                        }
+                        _ => None,
+                    }
+                }
+                (step, info) => {
+                    tracing::warn!(
+                        ?step,
+                        ?info,
+                        "unsupported match path projection in V2 codegen"
+                    );
+                    self.builder.record_codegen_error();
+                    None
+                }
+            };
+            let (next_ty, next_value) = projected?;
+            ty = next_ty;
+            value = next_value;
+        }
+
+        Some((value, ty))
+    }
+}
@@ -10,6 +10,8 @@ use ori_ir::{BinaryOp, UnaryOp};
 use ori_types::Idx;
 
 use super::abi::ReturnPassing;
+use super::checked_arith::{emit_checked_arith, emit_divisor_checks, emit_int_pow};
+use super::const_fold::fold_const_binary;
 use super::expr_lowerer::ExprLowerer;
 use super::value_id::ValueId;
 
@@ -61,8 +63,23 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             }
         }
 
+        if let Some(folded) = fold_const_binary(self.builder, op, lhs, rhs, left_type) {
+            return Some(folded);
+        }
+
+        if self.overflow_checks && left_type == Idx::INT {
+            let func = self.current_function;
+            emit_divisor_checks(self.builder, func, op, lhs, rhs);
+            if let Some(result) = emit_checked_arith(self.builder, func, op, lhs, rhs) {
+                return Some(result);
+            }
+        }
+
         let is_float = left_type == Idx::FLOAT;
         let is_str = left_type == Idx::STR;
+        // `char` (i32 scalar value) and `byte` (i8) order as unsigned, like
+        // their `compare` methods; signed predicates misorder values >= 0x80.
+        let is_unsigned = matches!(left_type, Idx::CHAR | Idx::BYTE);
 
         match op {
             // Arithmetic
@@ -84,6 +101,9 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
 
             BinaryOp::FloorDiv => Some(self.lower_floor_div(lhs, rhs)),
 
+            BinaryOp::Pow if is_float => Some(self.builder.fpow(lhs, rhs, "fpow")),
+            BinaryOp::Pow => Some(emit_int_pow(self.builder, lhs, rhs)),
+
             // Comparisons
             BinaryOp::Eq if is_float => Some(self.builder.fcmp_oeq(lhs, rhs, "feq")),
             BinaryOp::Eq if is_str => self.lower_str_eq(lhs, rhs),
@@ -93,16 +113,24 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
             BinaryOp::NotEq if is_str => self.lower_str_ne(lhs, rhs),
             BinaryOp::NotEq => Some(self.builder.icmp_ne(lhs, rhs, "ne")),
 
+            BinaryOp::Lt | BinaryOp::LtEq | BinaryOp::Gt | BinaryOp::GtEq if is_str => {
+                self.lower_str_ordering(op, lhs, rhs)
+            }
+
             BinaryOp::Lt if is_float => Some(self.builder.fcmp_olt(lhs, rhs, "flt")),
+            BinaryOp::Lt if is_unsigned => Some(self.builder.icmp_ult(lhs, rhs, "ult")),
             BinaryOp::Lt => Some(self.builder.icmp_slt(lhs, rhs, "slt")),
 
             BinaryOp::LtEq if is_float => Some(self.builder.fcmp_ole(lhs, rhs, "fle")),
+            BinaryOp::LtEq if is_unsigned => Some(self.builder.icmp_ule(lhs, rhs, "ule")),
             BinaryOp::LtEq => Some(self.builder.icmp_sle(lhs, rhs, "sle")),
 
             BinaryOp::Gt if is_float => Some(self.builder.fcmp_ogt(lhs, rhs, "fgt")),
+            BinaryOp::Gt if is_unsigned => Some(self.builder.icmp_ugt(lhs, rhs, "ugt")),
             BinaryOp::Gt => Some(self.builder.icmp_sgt(lhs, rhs, "sgt")),
 
             BinaryOp::GtEq if is_float => Some(self.builder.fcmp_oge(lhs, rhs, "fge")),
+            BinaryOp::GtEq if is_unsigned => Some(self.builder.icmp_uge(lhs, rhs, "uge")),
             BinaryOp::GtEq => Some(self.builder.icmp_sge(lhs, rhs, "sge")),
 
             // Bitwise
@@ -363,7 +391,7 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     }
 
     /// Lower `str == str` → `ori_str_eq(a, b)`.
-    fn lower_str_eq(&mut self, lhs: ValueId, rhs: ValueId) -> Option<ValueId> {
+    pub(crate) fn lower_str_eq(&mut self, lhs: ValueId, rhs: ValueId) -> Option<ValueId> {
         let ptr_ty = self.builder.ptr_type();
         let bool_ty = self.builder.bool_type();
         let func = self
@@ -386,6 +414,33 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
         self.builder.call(func, &[lhs_ptr, rhs_ptr], "str_ne")
     }
 
+    /// Lower `str < str` (and `<=`, `>`, `>=`) via `ori_str_compare(a, b)`.
+    ///
+    /// The runtime returns an Ordering tag — 0 (Less), 1 (Equal),
+    /// 2 (Greater) — comparing by bytes, so a proper prefix orders first
+    /// and `""` is the least string. The tag is then tested against the
+    /// operator.
+    fn lower_str_ordering(&mut self, op: BinaryOp, lhs: ValueId, rhs: ValueId) -> Option<ValueId> {
+        let ptr_ty = self.builder.ptr_type();
+        let i8_ty = self.builder.i8_type();
+        let func =
+            self.builder
+                .get_or_declare_function("ori_str_compare", &[ptr_ty, ptr_ty], i8_ty);
+        let lhs_ptr = self.alloca_and_store(lhs, "str_cmp.lhs");
+        let rhs_ptr = self.alloca_and_store(rhs, "str_cmp.rhs");
+        let tag = self.builder.call(func, &[lhs_ptr, rhs_ptr], "str_cmp")?;
+
+        let less = self.builder.const_i8(0);
+        let greater = self.builder.const_i8(2);
+        Some(match op {
+            BinaryOp::Lt => self.builder.icmp_eq(tag, less, "str_lt"),
+            BinaryOp::LtEq => self.builder.icmp_ne(tag, greater, "str_le"),
+            BinaryOp::Gt => self.builder.icmp_eq(tag, greater, "str_gt"),
+            BinaryOp::GtEq => self.builder.icmp_ne(tag, less, "str_ge"),
+            _ => unreachable!("lower_str_ordering called with non-ordering operator"),
+        })
+    }
+
     // -----------------------------------------------------------------------
     // Unary operators
     // -----------------------------------------------------------------------
@@ -556,6 +611,11 @@ impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
     /// Maps the operator to its trait method name (e.g., `+` → `"add"`),
     /// looks up the compiled method function in `method_functions`, and
     /// emits a method call via `invoke_user_function`.
+    ///
+    /// Method names come from `BinaryOp::trait_method_name` (`+` → `add`,
+    /// `-` → `subtract`, `*` → `multiply`, ...), the methods of the `Add`,
+    /// `Sub`, `Mul`, ... traits the type checker requires, so an `impl Add`
+    /// is all a struct needs for `a + b` to compile natively.
     // SYNC: also update ArcIrEmitter::emit_binary_op_via_trait in arc_emitter.rs
     fn lower_binary_op_via_trait(
         &mut self,
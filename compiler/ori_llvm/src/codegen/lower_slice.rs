@@ -0,0 +1,83 @@
+//! List slicing for V2 codegen.
+//!
+//! Lowers `xs[start..end]` (a `Range<int>` index on a list) to a fresh list.
+//! Slices copy: the selected elements are `memcpy`'d into a newly allocated
+//! buffer, so the result never aliases the source list's storage. This keeps
+//! slicing consistent with the interpreter and with list value semantics,
+//! where mutating one binding is never visible through another.
+
+use ori_ir::canon::CanId;
+use ori_types::Idx;
+
+use super::expr_lowerer::ExprLowerer;
+use super::value_id::ValueId;
+
+impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    /// Lower `list[range]` — copy `list[start..end]` into a new list.
+    ///
+    /// `range_val` is the `{i64 start, i64 end, i1 inclusive}` range struct.
+    /// Panics via `ori_index_panic` unless `0 <= start <= end <= len`,
+    /// reporting the offending bound like the interpreter does.
+    pub(crate) fn lower_list_slice(
+        &mut self,
+        list_val: ValueId,
+        range_val: ValueId,
+        elem_idx: Idx,
+        expr_id: CanId,
+    ) -> Option<ValueId> {
+        let start = self.builder.extract_value(range_val, 0, "slice.start")?;
+        let end = self.builder.extract_value(range_val, 1, "slice.end")?;
+        let inclusive = self.builder.extract_value(range_val, 2, "slice.incl")?;
+        let len = self.builder.extract_value(list_val, 0, "slice.src_len")?;
+        let data = self.builder.extract_value(list_val, 2, "slice.src_data")?;
+
+        // Exclusive end: `a..=b` covers `a..b+1`
+        let one = self.builder.const_i64(1);
+        let end_plus_one = self.builder.add(end, one, "slice.end_incl");
+        let end_excl = self
+            .builder
+            .select(inclusive, end_plus_one, end, "slice.end_excl");
+
+        // Unsigned compares also reject a negative start or end
+        let start_ok = self.builder.icmp_ule(start, end_excl, "slice.start_ok");
+        let end_ok = self.builder.icmp_ule(end_excl, len, "slice.end_ok");
+        let in_bounds = self.builder.and(start_ok, end_ok, "slice.inbounds");
+
+        let copy_bb = self
+            .builder
+            .append_block(self.current_function, "slice.copy");
+        let panic_bb = self
+            .builder
+            .append_block(self.current_function, "slice.panic");
+        self.builder.cond_br(in_bounds, copy_bb, panic_bb);
+
+        self.builder.position_at_end(panic_bb);
+        let bad_bound = self.builder.select(start_ok, end, start, "slice.bad");
+        self.emit_index_panic(bad_bound, expr_id);
+        self.builder.unreachable();
+
+        self.builder.position_at_end(copy_bb);
+        let count = self.builder.sub(end_excl, start, "slice.len");
+        let esize = self.compute_elem_byte_size(elem_idx);
+        let i64_ty = self.builder.i64_type();
+        let ptr_ty = self.builder.ptr_type();
+        let alloc_data =
+            self.builder
+                .get_or_declare_function("ori_list_alloc_data", &[i64_ty, i64_ty], ptr_ty);
+        let new_data = self
+            .builder
+            .call(alloc_data, &[count, esize], "slice.data")?;
+
+        let elem_llvm_ty = self.resolve_type(elem_idx);
+        let src = self.builder.gep(elem_llvm_ty, data, &[start], "slice.src");
+        let bytes = self.builder.mul(count, esize, "slice.bytes");
+        self.builder.memcpy(new_data, src, bytes);
+
+        let list_type = self.expr_type(expr_id);
+        let list_ty = self.resolve_type(list_type);
+        Some(
+            self.builder
+                .build_struct(list_ty, &[count, count, new_data], "slice"),
+        )
+    }
+}
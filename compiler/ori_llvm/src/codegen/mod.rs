@@ -27,7 +27,8 @@
 //! ├── expr_lowerer.rs     — ExprLowerer struct + dispatch (Section 03)
 //! ├── lower_literals.rs   — Literals, identifiers, constants
 //! ├── lower_operators.rs  — Binary/unary ops, cast, short-circuit
-//! ├── lower_control_flow.rs — If, loop, block, break, continue, match, assign
+//! ├── lower_control_flow.rs — If, loop, block, break, continue, assign
+//! ├── lower_match.rs      — Match via decision trees
 //! ├── lower_for_loop.rs    — For-loops (range, list, str, option, set, map)
 //! ├── lower_error_handling.rs — Ok, Err, Some, None, Try
 //! ├── lower_collections.rs — List, map, tuple, struct, range, field, index
@@ -35,6 +36,10 @@
 //! ├── lower_lambdas.rs    — Lambda compilation + capture analysis
 //! ├── lower_conversion_builtins.rs — str(), int(), float(), byte(), assert_eq()
 //! ├── lower_constructs.rs — FunctionSeq, FunctionExp, SelfRef, Await
+//! ├── lower_concurrency.rs — parallel, timeout via the task runtime
+//! ├── checked_arith.rs    — Opt-in overflow/zero-divisor checks (both tiers)
+//! ├── const_fold/         — Constant folding of binary operators (both tiers)
+//! ├── tail_calls/         — Self-recursive tail call detection (both tiers)
 //! ├── lower_builtin_methods/ — Built-in method dispatch (Section 04.1)
 //! │   ├── primitives.rs   — int, float, bool, byte, char, ordering, str
 //! │   ├── option.rs       — Option compare/equals/hash
@@ -70,12 +75,19 @@ pub mod type_registration;
 // -- ARC IR emission (Tier 2 — Section 07.2) --
 pub mod arc_emitter;
 
+// -- Shared by both tiers --
+mod checked_arith;
+mod const_fold;
+mod tail_calls;
+
 // -- Expression lowering (Section 03) --
 pub mod expr_lowerer;
+mod lower_bindings;
 mod lower_builtin_methods;
 mod lower_calls;
 mod lower_collection_methods;
 mod lower_collections;
+mod lower_concurrency;
 mod lower_constructs;
 mod lower_control_flow;
 mod lower_conversion_builtins;
@@ -84,7 +96,9 @@ mod lower_for_loop;
 mod lower_iterator_trampolines;
 mod lower_lambdas;
 mod lower_literals;
+mod lower_match;
 mod lower_operators;
+mod lower_slice;
 
 // -- Public re-exports --
 pub use expr_lowerer::ExprLowerer;
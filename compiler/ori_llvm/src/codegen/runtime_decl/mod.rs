@@ -16,6 +16,7 @@ pub fn declare_runtime(builder: &mut IrBuilder<'_, '_>) {
     let void = None;
     let i64_ty = builder.i64_type();
     let i32_ty = builder.i32_type();
+    let i8_ty = builder.i8_type();
     let f64_ty = builder.f64_type();
     let bool_ty = builder.bool_type();
     let ptr_ty = builder.ptr_type();
@@ -39,15 +40,23 @@ pub fn declare_runtime(builder: &mut IrBuilder<'_, '_>) {
     builder.declare_extern_function("ori_print_int", &[i64_ty], void);
     builder.declare_extern_function("ori_print_float", &[f64_ty], void);
     builder.declare_extern_function("ori_print_bool", &[bool_ty], void);
+    builder.declare_extern_function("ori_print_char", &[i32_ty], void);
+    builder.declare_extern_function("ori_print_byte", &[i8_ty], void);
 
     // -- Panic functions --
     // cold: panic paths are rarely taken; moves code out of hot layout
-    // NOT nounwind: ori_panic unwinds via Rust panic infrastructure
-    // so LLVM invoke/landingpad can run RC cleanup handlers
+    // NOT nounwind: these are `extern "C-unwind"` in ori_rt and unwind via
+    // Rust panic infrastructure, so LLVM invoke/landingpad can run RC cleanup
+    // handlers (and `ori_parallel` can catch a panicking task)
     let panic_fn = builder.declare_extern_function("ori_panic", &[ptr_ty], void);
     builder.add_cold_attribute(panic_fn);
+    let panic_at = builder.declare_extern_function("ori_panic_at", &[ptr_ty, i32_ty, i32_ty], void);
+    builder.add_cold_attribute(panic_at);
     let panic_cstr = builder.declare_extern_function("ori_panic_cstr", &[ptr_ty], void);
     builder.add_cold_attribute(panic_cstr);
+    let index_panic =
+        builder.declare_extern_function("ori_index_panic", &[i64_ty, i32_ty, i32_ty], void);
+    builder.add_cold_attribute(index_panic);
 
     // -- Entry point wrapper --
     // ori_run_main wraps @main with catch_unwind for clean panic handling.
@@ -65,6 +74,11 @@ pub fn declare_runtime(builder: &mut IrBuilder<'_, '_>) {
     // -- List functions --
     builder.declare_extern_function("ori_list_alloc_data", &[i64_ty, i64_ty], Some(ptr_ty));
     builder.declare_extern_function("ori_list_free_data", &[ptr_ty, i64_ty, i64_ty], void);
+    builder.declare_extern_function(
+        "ori_list_grow_data",
+        &[ptr_ty, i64_ty, i64_ty, i64_ty],
+        Some(ptr_ty),
+    );
     builder.declare_extern_function("ori_list_new", &[i64_ty, i64_ty], Some(ptr_ty));
     builder.declare_extern_function("ori_list_free", &[ptr_ty, i64_ty], void);
     builder.declare_extern_function("ori_list_len", &[ptr_ty], Some(i64_ty));
@@ -74,8 +88,10 @@ pub fn declare_runtime(builder: &mut IrBuilder<'_, '_>) {
     builder.declare_extern_function("ori_min_int", &[i64_ty, i64_ty], Some(i64_ty));
     builder.declare_extern_function("ori_max_int", &[i64_ty, i64_ty], Some(i64_ty));
 
+    // -- Arithmetic functions --
+    builder.declare_extern_function("ori_ipow", &[i64_ty, i64_ty], Some(i64_ty));
+
     // -- String functions --
-    let i8_ty = builder.i8_type();
     builder.declare_extern_function("ori_str_concat", &[ptr_ty, ptr_ty], Some(str_ty));
     builder.declare_extern_function("ori_str_eq", &[ptr_ty, ptr_ty], Some(bool_ty));
     builder.declare_extern_function("ori_str_ne", &[ptr_ty, ptr_ty], Some(bool_ty));
@@ -208,6 +224,71 @@ pub fn declare_runtime(builder: &mut IrBuilder<'_, '_>) {
     // Cleanup
     builder.declare_extern_function("ori_iter_drop", &[ptr_ty], void);
 
+    // -- Task functions (parallel, timeout, catch patterns) --
+    // ori_parallel(task_fn, tasks, count, out, out_size, status, max_concurrent, timeout_ms) -> void
+    builder.declare_extern_function(
+        "ori_parallel",
+        &[
+            ptr_ty, ptr_ty, i64_ty, ptr_ty, i64_ty, ptr_ty, i64_ty, i64_ty,
+        ],
+        void,
+    );
+    // ori_run_with_timeout(task_fn, wrapper, out, out_size, millis) -> bool
+    builder.declare_extern_function(
+        "ori_run_with_timeout",
+        &[ptr_ty, ptr_ty, ptr_ty, i64_ty, i64_ty],
+        Some(bool_ty),
+    );
+    // ori_catch(task_fn, wrapper, out, message) -> bool
+    builder.declare_extern_function(
+        "ori_catch",
+        &[ptr_ty, ptr_ty, ptr_ty, ptr_ty],
+        Some(bool_ty),
+    );
+
+    // -- Memo tables (recurse with memo: true) --
+    // ori_memo_enter(func) / ori_memo_exit(func) -> void
+    builder.declare_extern_function("ori_memo_enter", &[ptr_ty], void);
+    builder.declare_extern_function("ori_memo_exit", &[ptr_ty], void);
+    // ori_memo_lookup(func, args, nargs, out) -> bool
+    builder.declare_extern_function(
+        "ori_memo_lookup",
+        &[ptr_ty, ptr_ty, i64_ty, ptr_ty],
+        Some(bool_ty),
+    );
+    // ori_memo_store(func, args, nargs, value) -> void
+    builder.declare_extern_function("ori_memo_store", &[ptr_ty, ptr_ty, i64_ty, i64_ty], void);
+
+    // -- Cache store (cache pattern) --
+    // ori_cache_get(site, key, key_len, ttl_nanos, out, size) -> bool
+    builder.declare_extern_function(
+        "ori_cache_get",
+        &[ptr_ty, ptr_ty, i64_ty, i64_ty, ptr_ty, i64_ty],
+        Some(bool_ty),
+    );
+    // ori_cache_put(site, key, key_len, ttl_nanos, value, size) -> void
+    builder.declare_extern_function(
+        "ori_cache_put",
+        &[ptr_ty, ptr_ty, i64_ty, i64_ty, ptr_ty, i64_ty],
+        void,
+    );
+
+    // -- Map hash index (map literals and lookups) --
+    // ori_map_new(capacity, key_size) -> ptr keys
+    builder.declare_extern_function("ori_map_new", &[i64_ty, i64_ty], Some(ptr_ty));
+    // ori_map_insert(keys, capacity, len_ptr, key, key_size, str_keys) -> i64 index
+    builder.declare_extern_function(
+        "ori_map_insert",
+        &[ptr_ty, i64_ty, ptr_ty, ptr_ty, i64_ty, bool_ty],
+        Some(i64_ty),
+    );
+    // ori_map_find(keys, capacity, key, key_size, str_keys) -> i64 index (-1 if absent)
+    builder.declare_extern_function(
+        "ori_map_find",
+        &[ptr_ty, i64_ty, ptr_ty, i64_ty, bool_ty],
+        Some(i64_ty),
+    );
+
     // -- Panic handler registration --
     builder.declare_extern_function("ori_register_panic_handler", &[ptr_ty], void);
 
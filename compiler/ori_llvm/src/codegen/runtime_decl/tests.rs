@@ -20,9 +20,13 @@ fn runtime_functions_declared() {
         "ori_print_int",
         "ori_print_float",
         "ori_print_bool",
+        "ori_print_char",
+        "ori_print_byte",
         // Panic
         "ori_panic",
+        "ori_panic_at",
         "ori_panic_cstr",
+        "ori_index_panic",
         // Entry point wrapper (AOT-only, not in JIT mappings)
         "ori_run_main",
         // Assertions
@@ -34,6 +38,7 @@ fn runtime_functions_declared() {
         // Lists
         "ori_list_alloc_data",
         "ori_list_free_data",
+        "ori_list_grow_data",
         "ori_list_new",
         "ori_list_free",
         "ori_list_len",
@@ -41,6 +46,8 @@ fn runtime_functions_declared() {
         "ori_compare_int",
         "ori_min_int",
         "ori_max_int",
+        // Arithmetic
+        "ori_ipow",
         // Strings
         "ori_str_concat",
         "ori_str_eq",
@@ -66,6 +73,22 @@ fn runtime_functions_declared() {
         // Args
         "ori_args_from_argv",
         "ori_register_panic_handler",
+        // Tasks
+        "ori_parallel",
+        "ori_run_with_timeout",
+        "ori_catch",
+        // Memo
+        "ori_memo_enter",
+        "ori_memo_exit",
+        "ori_memo_lookup",
+        "ori_memo_store",
+        // Cache
+        "ori_cache_get",
+        "ori_cache_put",
+        // Map hash index
+        "ori_map_new",
+        "ori_map_insert",
+        "ori_map_find",
         // EH personality
         "rust_eh_personality",
     ];
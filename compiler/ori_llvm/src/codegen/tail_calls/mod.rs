@@ -0,0 +1,174 @@
+//! Tail-position analysis for self-recursive calls.
+//!
+//! A call whose result is returned unchanged can reuse the caller's stack
+//! frame. Both codegen tiers consult this module before emitting a call to
+//! the function being compiled:
+//!
+//! - Tier 1 (`ExprLowerer`) asks [`tail_position_calls`] which `Call`
+//!   expressions sit in tail position of the function body.
+//! - Tier 2 (`ArcIrEmitter`) asks [`tail_invoke_blocks`] which `Invoke`
+//!   terminators flow straight into a `Return` of their result.
+//!
+//! The emitters then check that the callee is the current function and that
+//! [`abi_supports_tail_call`] holds, and emit `tail call` + `ret` in place
+//! of the regular call (or invoke). Combined with `fastcc`, LLVM turns the
+//! self-recursion into a jump, so deep recursion runs in constant stack.
+
+use ori_arc::ir::{ArcBlockId, ArcFunction, ArcTerminator, ArcVarId};
+use ori_ir::canon::{CanExpr, CanId, CanRange, CanonResult};
+use rustc_hash::FxHashSet;
+
+use super::abi::{FunctionAbi, ParamPassing, ReturnPassing};
+use super::expr_lowerer::ExprLowerer;
+use super::value_id::{FunctionId, ValueId};
+
+// ---------------------------------------------------------------------------
+// Analysis
+// ---------------------------------------------------------------------------
+
+/// Collect the `Call` expressions in tail position of `body`.
+///
+/// Tail positions are the body itself, a block's result, both branches of
+/// an `if`, every `match` arm, and the inside of `unsafe`. Lambda bodies are
+/// not tail positions of the enclosing function and are never visited.
+pub(crate) fn tail_position_calls(canon: &CanonResult, body: CanId) -> FxHashSet<CanId> {
+    let mut calls = FxHashSet::default();
+    let mut worklist = vec![body];
+
+    while let Some(id) = worklist.pop() {
+        if !id.is_valid() {
+            continue;
+        }
+        match *canon.arena.kind(id) {
+            CanExpr::Call { .. } => {
+                calls.insert(id);
+            }
+            CanExpr::Block { result, .. } | CanExpr::Unsafe(result) => worklist.push(result),
+            CanExpr::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                worklist.push(then_branch);
+                worklist.push(else_branch);
+            }
+            CanExpr::Match { arms, .. } => {
+                worklist.extend_from_slice(canon.arena.get_expr_list(arms));
+            }
+            _ => {}
+        }
+    }
+
+    calls
+}
+
+/// Mark the blocks of `func` whose `Invoke` terminator is a tail call.
+///
+/// An invoke qualifies when its unwind block only resumes and its normal
+/// block returns the result unchanged, either directly or by forwarding it
+/// through empty join blocks (the shape `if`/`match` lowering produces).
+/// Any instruction on the way — e.g. an RC decrement inserted by the ARC
+/// pipeline — means work remains after the call, so the invoke is kept.
+///
+/// Returns one flag per block, indexed by `ArcBlockId::index()`.
+pub(crate) fn tail_invoke_blocks(func: &ArcFunction) -> Vec<bool> {
+    func.blocks
+        .iter()
+        .map(|block| match block.terminator {
+            ArcTerminator::Invoke {
+                dst,
+                normal,
+                unwind,
+                ..
+            } => only_resumes(func, unwind) && returns_unchanged(func, normal, dst),
+            _ => false,
+        })
+        .collect()
+}
+
+/// Whether `block` has no instructions and terminates with `Resume`.
+fn only_resumes(func: &ArcFunction, block: ArcBlockId) -> bool {
+    let block = &func.blocks[block.index()];
+    block.body.is_empty() && matches!(block.terminator, ArcTerminator::Resume)
+}
+
+/// Whether control entering `start` returns `var` without further work.
+fn returns_unchanged(func: &ArcFunction, start: ArcBlockId, var: ArcVarId) -> bool {
+    let mut block_id = start;
+    let mut var = var;
+
+    // Bounded by the block count so a jump cycle cannot loop forever.
+    for _ in 0..func.blocks.len() {
+        let block = &func.blocks[block_id.index()];
+        if !block.body.is_empty() {
+            return false;
+        }
+        match &block.terminator {
+            ArcTerminator::Return { value } => return *value == var,
+            ArcTerminator::Jump { target, args } if *args == [var] => {
+                let target_block = &func.blocks[target.index()];
+                let [(param, _)] = target_block.params.as_slice() else {
+                    return false;
+                };
+                var = *param;
+                block_id = *target;
+            }
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+/// Whether a call with this ABI can be emitted as a `tail` call.
+///
+/// `tail` promises the callee does not access the caller's stack. Sret
+/// returns and by-pointer parameters (`Indirect`, `Reference`) are backed
+/// by allocas in the caller's frame, so only direct passing qualifies.
+pub(crate) fn abi_supports_tail_call(abi: &FunctionAbi) -> bool {
+    matches!(
+        abi.return_abi.passing,
+        ReturnPassing::Direct | ReturnPassing::Void
+    ) && abi
+        .params
+        .iter()
+        .all(|p| matches!(p.passing, ParamPassing::Direct | ParamPassing::Void))
+}
+
+// ---------------------------------------------------------------------------
+// Tier 1 lowering
+// ---------------------------------------------------------------------------
+
+impl<'scx: 'ctx, 'ctx> ExprLowerer<'_, 'scx, 'ctx, '_> {
+    /// Lower a self-recursive call in tail position as `tail call` + `ret`.
+    ///
+    /// The caller has already checked that the call is in
+    /// [`ExprLowerer::tail_calls`], targets `current_function`, and that
+    /// [`abi_supports_tail_call`] holds. Returns `None`: the current block
+    /// is terminated by the `ret`.
+    pub(crate) fn lower_self_tail_call(
+        &mut self,
+        func_id: FunctionId,
+        abi: &FunctionAbi,
+        args: CanRange,
+    ) -> Option<ValueId> {
+        let arg_ids = self.canon.arena.get_expr_list(args);
+        let mut raw_arg_vals = Vec::with_capacity(arg_ids.len());
+        for &arg_id in arg_ids {
+            raw_arg_vals.push(self.lower(arg_id)?);
+        }
+        let mut arg_vals = self.apply_param_passing(&raw_arg_vals, &abi.params);
+        arg_vals.extend(self.capability_args(&abi.capabilities));
+
+        match self.builder.call_tail(func_id, &arg_vals, "tailcall") {
+            Some(val) if matches!(abi.return_abi.passing, ReturnPassing::Direct) => {
+                self.builder.ret(val);
+            }
+            _ => self.builder.ret_void(),
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,253 @@
+use super::*;
+use crate::codegen::abi::{CallConv, ParamAbi, ReturnAbi};
+use ori_arc::ir::{ArcBlock, ArcInstr, ArcParam, ArcValue, LitValue};
+use ori_arc::Ownership;
+use ori_ir::canon::{CanArena, CanNode, ConstantPool, DecisionTreePool};
+use ori_ir::{Name, Span, TypeId};
+use ori_types::Idx;
+
+fn node(arena: &mut CanArena, kind: CanExpr) -> CanId {
+    arena.push(CanNode::new(
+        kind,
+        Span::DUMMY,
+        TypeId::from_raw(Idx::INT.raw()),
+    ))
+}
+
+fn canon(arena: CanArena, root: CanId) -> CanonResult {
+    CanonResult {
+        arena,
+        constants: ConstantPool::new(),
+        decision_trees: DecisionTreePool::default(),
+        root,
+        roots: vec![],
+        method_roots: vec![],
+        problems: vec![],
+    }
+}
+
+fn call(arena: &mut CanArena) -> CanId {
+    let func = node(arena, CanExpr::Ident(Name::from_raw(1)));
+    let args = arena.push_expr_list(&[]);
+    node(arena, CanExpr::Call { func, args })
+}
+
+// -- tail_position_calls --
+
+#[test]
+fn call_as_body_is_tail() {
+    let mut arena = CanArena::new();
+    let body = call(&mut arena);
+    let canon = canon(arena, body);
+
+    let calls = tail_position_calls(&canon, body);
+    assert!(calls.contains(&body));
+}
+
+#[test]
+fn if_branches_and_block_result_are_tail() {
+    // if c then { f() } else f()
+    let mut arena = CanArena::new();
+    let cond = node(&mut arena, CanExpr::Bool(true));
+    let then_call = call(&mut arena);
+    let stmts = arena.push_expr_list(&[]);
+    let then_branch = node(
+        &mut arena,
+        CanExpr::Block {
+            stmts,
+            result: then_call,
+        },
+    );
+    let else_call = call(&mut arena);
+    let body = node(
+        &mut arena,
+        CanExpr::If {
+            cond,
+            then_branch,
+            else_branch: else_call,
+        },
+    );
+    let canon = canon(arena, body);
+
+    let calls = tail_position_calls(&canon, body);
+    assert_eq!(calls.len(), 2);
+    assert!(calls.contains(&then_call));
+    assert!(calls.contains(&else_call));
+}
+
+#[test]
+fn operand_and_statement_calls_are_not_tail() {
+    // { f(); f() + 1 }
+    let mut arena = CanArena::new();
+    let stmt_call = call(&mut arena);
+    let operand_call = call(&mut arena);
+    let one = node(&mut arena, CanExpr::Int(1));
+    let sum = node(
+        &mut arena,
+        CanExpr::Binary {
+            op: ori_ir::BinaryOp::Add,
+            left: operand_call,
+            right: one,
+        },
+    );
+    let stmts = arena.push_expr_list(&[stmt_call]);
+    let body = node(&mut arena, CanExpr::Block { stmts, result: sum });
+    let canon = canon(arena, body);
+
+    assert!(tail_position_calls(&canon, body).is_empty());
+}
+
+// -- tail_invoke_blocks --
+
+/// Build `f(x) = invoke f(x)` with the normal continuation in `normal`.
+///
+/// Block 0 invokes into block 1 (normal) and block 2 (unwind).
+fn invoke_function(normal: ArcBlock, unwind_body: Vec<ArcInstr>) -> ArcFunction {
+    let v = ArcVarId::new;
+    let b = ArcBlockId::new;
+    ArcFunction {
+        name: Name::from_raw(1),
+        params: vec![ArcParam {
+            var: v(0),
+            ty: Idx::INT,
+            ownership: Ownership::Owned,
+        }],
+        return_type: Idx::INT,
+        blocks: vec![
+            ArcBlock {
+                id: b(0),
+                params: vec![],
+                body: vec![],
+                terminator: ArcTerminator::Invoke {
+                    dst: v(1),
+                    ty: Idx::INT,
+                    func: Name::from_raw(1),
+                    args: vec![v(0)],
+                    normal: b(1),
+                    unwind: b(2),
+                },
+            },
+            normal,
+            ArcBlock {
+                id: b(2),
+                params: vec![],
+                body: unwind_body,
+                terminator: ArcTerminator::Resume,
+            },
+            ArcBlock {
+                id: b(3),
+                params: vec![(v(2), Idx::INT)],
+                body: vec![],
+                terminator: ArcTerminator::Return { value: v(2) },
+            },
+        ],
+        entry: b(0),
+        var_types: vec![Idx::INT; 3],
+        spans: vec![],
+    }
+}
+
+fn empty_block(terminator: ArcTerminator) -> ArcBlock {
+    ArcBlock {
+        id: ArcBlockId::new(1),
+        params: vec![],
+        body: vec![],
+        terminator,
+    }
+}
+
+#[test]
+fn invoke_returning_result_is_tail() {
+    let func = invoke_function(
+        empty_block(ArcTerminator::Return {
+            value: ArcVarId::new(1),
+        }),
+        vec![],
+    );
+    assert_eq!(tail_invoke_blocks(&func), vec![true, false, false, false]);
+}
+
+#[test]
+fn invoke_forwarding_result_through_join_is_tail() {
+    let func = invoke_function(
+        empty_block(ArcTerminator::Jump {
+            target: ArcBlockId::new(3),
+            args: vec![ArcVarId::new(1)],
+        }),
+        vec![],
+    );
+    assert!(tail_invoke_blocks(&func)[0]);
+}
+
+#[test]
+fn invoke_with_work_after_call_is_not_tail() {
+    let mut normal = empty_block(ArcTerminator::Return {
+        value: ArcVarId::new(2),
+    });
+    normal.body.push(ArcInstr::Let {
+        dst: ArcVarId::new(2),
+        ty: Idx::INT,
+        value: ArcValue::Literal(LitValue::Int(0)),
+    });
+    let func = invoke_function(normal, vec![]);
+    assert!(!tail_invoke_blocks(&func)[0]);
+}
+
+#[test]
+fn invoke_with_unwind_cleanup_is_not_tail() {
+    let func = invoke_function(
+        empty_block(ArcTerminator::Return {
+            value: ArcVarId::new(1),
+        }),
+        vec![ArcInstr::RcDec {
+            var: ArcVarId::new(0),
+        }],
+    );
+    assert!(!tail_invoke_blocks(&func)[0]);
+}
+
+// -- abi_supports_tail_call --
+
+fn abi(param: ParamPassing, ret: ReturnPassing) -> FunctionAbi {
+    FunctionAbi {
+        params: vec![ParamAbi {
+            name: Name::from_raw(2),
+            ty: Idx::INT,
+            passing: param,
+        }],
+        return_abi: ReturnAbi {
+            ty: Idx::INT,
+            passing: ret,
+        },
+        call_conv: CallConv::Fast,
+        capabilities: Vec::new(),
+    }
+}
+
+#[test]
+fn direct_abi_supports_tail_call() {
+    assert!(abi_supports_tail_call(&abi(
+        ParamPassing::Direct,
+        ReturnPassing::Direct
+    )));
+    assert!(abi_supports_tail_call(&abi(
+        ParamPassing::Void,
+        ReturnPassing::Void
+    )));
+}
+
+#[test]
+fn caller_stack_abi_rejects_tail_call() {
+    assert!(!abi_supports_tail_call(&abi(
+        ParamPassing::Reference,
+        ReturnPassing::Direct
+    )));
+    assert!(!abi_supports_tail_call(&abi(
+        ParamPassing::Indirect { alignment: 8 },
+        ReturnPassing::Direct
+    )));
+    assert!(!abi_supports_tail_call(&abi(
+        ParamPassing::Direct,
+        ReturnPassing::Sret { alignment: 8 }
+    )));
+}
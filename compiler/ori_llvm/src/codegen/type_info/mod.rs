@@ -167,9 +167,9 @@ impl TypeInfo {
                 .into(),
 
             // Tagged unions: {i8 tag, payload}
-            // Option uses the inner type directly as payload.
-            // Result uses the larger of ok/err — for now, uses ok type.
-            // TODO: Result should use max(ok, err) size for correct layout.
+            // Without the store, payloads can't be resolved here; the real
+            // layouts (Option: inner type, Result: sized to the larger arm)
+            // come from `TypeLayoutResolver`.
             Self::Option { inner } => {
                 // Payload type depends on inner type. Since we don't have
                 // the store here, use i64 as a uniform payload representation.
@@ -834,10 +834,7 @@ impl<'a, 'll, 'tcx> TypeLayoutResolver<'a, 'll, 'tcx> {
                 let ok_ty = self.resolve(*ok);
                 let err_ty = self.resolve(*err);
                 self.resolving.borrow_mut().remove(&idx);
-                // Use the larger of the two as the payload type.
-                let ok_size = Self::type_store_size(ok_ty);
-                let err_size = Self::type_store_size(err_ty);
-                let payload = if ok_size >= err_size { ok_ty } else { err_ty };
+                let payload = self.result_payload_type(ok_ty, err_ty);
                 self.scx
                     .type_struct(&[self.scx.type_i8().into(), payload], false)
                     .into()
@@ -974,46 +971,76 @@ impl<'a, 'll, 'tcx> TypeLayoutResolver<'a, 'll, 'tcx> {
         }
     }
 
-    /// Approximate store size of an LLVM type in bytes.
+    /// Payload slot type for a `Result` with the given resolved arm types.
     ///
-    /// Uses LLVM's type system to determine sizes. For types where we
-    /// can't easily determine the size, falls back to 8 bytes (i64-sized).
+    /// The slot must hold either arm: its size is `max(ok, err)` and its
+    /// alignment is at least that of both arms. When one arm satisfies both
+    /// bounds it is used directly (the common case, e.g. `Result<int, int>`
+    /// or `Result<bool, str>`), so extraction of that arm needs no coercion.
+    /// Otherwise the slot is an `[N x i64]` array covering the larger arm,
+    /// and both arms are reinterpreted through memory on construction and
+    /// extraction (see `coerce_payload`).
+    pub(crate) fn result_payload_type(
+        &self,
+        ok_ty: BasicTypeEnum<'ll>,
+        err_ty: BasicTypeEnum<'ll>,
+    ) -> BasicTypeEnum<'ll> {
+        let (ok_size, ok_align) = Self::type_layout(ok_ty, 0);
+        let (err_size, err_align) = Self::type_layout(err_ty, 0);
+        if ok_size >= err_size && ok_align >= err_align {
+            ok_ty
+        } else if err_size >= ok_size && err_align >= ok_align {
+            err_ty
+        } else {
+            let words = ok_size.max(err_size).div_ceil(8);
+            self.scx.type_i64().array_type(words as u32).into()
+        }
+    }
+
+    /// Store size of an LLVM type in bytes, including struct padding.
+    ///
+    /// Mirrors the natural layout of the x86-64/AArch64 data layouts the
+    /// backend targets: integers are padded to a power-of-two byte width
+    /// and aligned to it (capped at 8), floats and pointers are 8 bytes,
+    /// and struct fields are placed at aligned offsets with the total
+    /// rounded up to the struct's alignment.
     pub(crate) fn type_store_size(ty: BasicTypeEnum<'ll>) -> u64 {
-        Self::type_store_size_inner(ty, 0)
+        Self::type_layout(ty, 0).0
     }
 
-    /// Inner implementation with depth tracking for recursive struct types.
-    fn type_store_size_inner(ty: BasicTypeEnum<'ll>, depth: u32) -> u64 {
+    /// `(size, alignment)` in bytes, with depth tracking for recursive
+    /// struct types.
+    fn type_layout(ty: BasicTypeEnum<'ll>, depth: u32) -> (u64, u64) {
         if depth > 16 {
-            return 8; // Fall back to pointer size
+            return (8, 8); // Fall back to pointer size
         }
         match ty {
             BasicTypeEnum::IntType(t) => {
-                let bits = t.get_bit_width();
-                u64::from(bits).div_ceil(8)
+                let bytes = u64::from(t.get_bit_width()).div_ceil(8).next_power_of_two();
+                (bytes, bytes.min(8))
             }
             BasicTypeEnum::StructType(st) => {
                 // Opaque structs have no body yet (two-phase creation).
                 if st.is_opaque() {
-                    return 8; // Pointer-sized fallback
+                    return (8, 8); // Pointer-sized fallback
                 }
-                // Sum of field sizes (approximation — ignores padding).
-                // For our purposes this is sufficient: we only use this to
-                // compare variant payload sizes and pick the max.
-                let mut total = 0u64;
+                let mut offset = 0u64;
+                let mut align = 1u64;
                 for i in 0..st.count_fields() {
                     if let Some(field) = st.get_field_type_at_index(i) {
-                        total += Self::type_store_size_inner(field, depth + 1);
+                        let (field_size, field_align) = Self::type_layout(field, depth + 1);
+                        offset = offset.next_multiple_of(field_align) + field_size;
+                        align = align.max(field_align);
                     }
                 }
-                total
+                (offset.next_multiple_of(align), align)
             }
             BasicTypeEnum::ArrayType(at) => {
-                let elem_size = Self::type_store_size_inner(at.get_element_type(), depth + 1);
-                elem_size * u64::from(at.len())
+                let (elem_size, elem_align) = Self::type_layout(at.get_element_type(), depth + 1);
+                (elem_size * u64::from(at.len()), elem_align)
             }
             // Float (f64), Pointer, Vector, ScalableVector: all 8 bytes
-            _ => 8,
+            _ => (8, 8),
         }
     }
 
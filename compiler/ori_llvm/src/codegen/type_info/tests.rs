@@ -884,6 +884,65 @@ fn resolver_option_with_recursive_resolve() {
     }
 }
 
+#[test]
+fn resolver_result_payload_sized_to_larger_arm() {
+    let mut pool = Pool::new();
+    let bool_str = pool.result(Idx::BOOL, Idx::STR);
+    let opt_opt_int = {
+        let inner = pool.option(Idx::INT);
+        pool.option(inner)
+    };
+    let nested_str = pool.result(opt_opt_int, Idx::STR);
+
+    let store = TypeInfoStore::new(&pool);
+    let ctx = Context::create();
+    let scx = SimpleCx::new(&ctx, "test");
+    let resolver = TypeLayoutResolver::new(&store, &scx);
+
+    // Result<bool, str>: the str arm is both larger and more aligned.
+    let payload = |ty: BasicTypeEnum<'_>| match ty {
+        BasicTypeEnum::StructType(st) => st.get_field_type_at_index(1).unwrap(),
+        other => panic!("Expected StructType for result, got {other:?}"),
+    };
+    assert_eq!(
+        payload(resolver.resolve(bool_str)),
+        resolver.resolve(Idx::STR)
+    );
+
+    // Result<Option<Option<int>>, str>: the padded option layout (24 bytes)
+    // outgrows str (16 bytes) even though its fields sum to less.
+    assert_eq!(
+        TypeLayoutResolver::type_store_size(resolver.resolve(opt_opt_int)),
+        24
+    );
+    assert_eq!(
+        payload(resolver.resolve(nested_str)),
+        resolver.resolve(opt_opt_int)
+    );
+}
+
+#[test]
+fn resolver_result_payload_falls_back_to_words() {
+    let mut pool = Pool::new();
+    let chars = pool.tuple(&[Idx::CHAR, Idx::CHAR, Idx::CHAR]);
+    let res = pool.result(chars, Idx::INT);
+
+    let store = TypeInfoStore::new(&pool);
+    let ctx = Context::create();
+    let scx = SimpleCx::new(&ctx, "test");
+    let resolver = TypeLayoutResolver::new(&store, &scx);
+
+    // (char, char, char) is larger (12 bytes) but less aligned than int,
+    // so neither arm can serve as the slot: use [2 x i64].
+    match resolver.resolve(res) {
+        BasicTypeEnum::StructType(st) => {
+            let slot = st.get_field_type_at_index(1).unwrap();
+            assert_eq!(slot, scx.type_i64().array_type(2).into());
+        }
+        other => panic!("Expected StructType for result, got {other:?}"),
+    }
+}
+
 #[test]
 fn resolver_tuple() {
     let mut pool = Pool::new();
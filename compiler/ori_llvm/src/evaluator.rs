@@ -14,16 +14,23 @@
 //! The legacy `ModuleCompiler` → `CodegenCx` → `Builder` path is still
 //! available via `LLVMEvaluator` for backward compatibility during migration.
 
+use std::cell::Cell;
 use std::mem::ManuallyDrop;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
 
+use inkwell::attributes::{Attribute, AttributeLoc};
 use inkwell::context::Context;
 use inkwell::execution_engine::ExecutionEngine;
+use inkwell::types::{AnyType, BasicTypeEnum};
+use inkwell::OptimizationLevel;
 use rustc_hash::FxHashMap;
 use tracing::{debug, instrument};
 
 use ori_ir::ast::{Function, Module, TestDef};
 use ori_ir::canon::CanonResult;
-use ori_ir::{Name, StringInterner};
+use ori_ir::{LineIndex, Name, StringInterner};
 use ori_types::{FunctionSig, Pool, TypeEntry};
 
 /// A single imported function ready for LLVM compilation.
@@ -40,7 +47,8 @@ pub struct ImportedFunctionForCodegen<'a> {
     pub canon: &'a CanonResult,
 }
 
-use crate::codegen::function_compiler::FunctionCompiler;
+use crate::aot::debug::{DebugContext, DebugInfoConfig, DebugLevel};
+use crate::codegen::function_compiler::{out_thunk_symbol, FunctionCompiler};
 use crate::codegen::ir_builder::IrBuilder;
 use crate::codegen::runtime_decl;
 use crate::codegen::type_info::{TypeInfoStore, TypeLayoutResolver};
@@ -105,12 +113,20 @@ impl std::error::Error for LLVMEvalError {}
 /// `Module::owned_by_ee` field).
 pub struct CompiledTestModule<'ll> {
     /// The JIT execution engine (owns the compiled machine code).
-    engine: ExecutionEngine<'ll>,
+    ///
+    /// Dropped by hand: once a test has been abandoned on timeout, its
+    /// thread may still be executing this code, so the engine is leaked.
+    engine: ManuallyDrop<ExecutionEngine<'ll>>,
     /// Test wrapper function names for lookup.
     /// Maps test `Name` to the wrapper function name string (e.g., `__test_my_test`).
     test_wrappers: FxHashMap<Name, String>,
+    /// Whether a timed-out test thread was left running.
+    abandoned: Cell<bool>,
 }
 
+/// Stack size for test threads; matches the test runner's worker threads.
+const TEST_THREAD_STACK_SIZE: usize = 32 * 1024 * 1024;
+
 impl CompiledTestModule<'_> {
     /// Run a single test from this compiled module.
     ///
@@ -118,62 +134,331 @@ impl CompiledTestModule<'_> {
     /// When JIT code calls `ori_panic` or `ori_panic_cstr`, it `longjmp`s back
     /// here instead of calling `exit(1)`, preserving the test runner process.
     ///
+    /// With a `timeout`, the test runs on its own thread. Native code can't
+    /// be interrupted, so a test still running at the limit is abandoned:
+    /// it fails with a `timeout: ...` message, its thread keeps running
+    /// detached, and the engine is leaked so that thread never executes
+    /// freed code.
+    ///
     /// # Safety
     ///
     /// The test function must exist in the compiled module and have signature `() -> void`.
     #[allow(
         unsafe_code,
-        reason = "JIT execution requires unsafe FFI: get_function, setjmp, and call"
+        reason = "JIT execution requires unsafe FFI: get_function and call"
     )]
-    pub fn run_test(&self, test_name: Name) -> LLVMEvalResult {
-        // Reset panic state before running
-        runtime::reset_panic_state();
-
+    pub fn run_test(&self, test_name: Name, timeout: Option<Duration>) -> LLVMEvalResult {
         // Look up the wrapper function name
         let wrapper_name = self.test_wrappers.get(&test_name).ok_or_else(|| {
             LLVMEvalError::new(format!("Test wrapper not found for test: {test_name:?}"))
         })?;
 
-        // Get function pointer
-        // SAFETY: We compiled this test wrapper with signature () -> void
-        let test_fn = unsafe {
-            self.engine
-                .get_function::<unsafe extern "C" fn()>(wrapper_name)
-                .map_err(|e| LLVMEvalError::new(format!("Test function not found: {e}")))?
+        // Each test starts with an empty `cache` store, independent of order
+        runtime::cache::ori_cache_clear();
+
+        let Some(limit) = timeout else {
+            // Get function pointer
+            // SAFETY: We compiled this test wrapper with signature () -> void
+            let test_fn = unsafe {
+                self.engine
+                    .get_function::<unsafe extern "C" fn()>(wrapper_name)
+                    .map_err(|e| LLVMEvalError::new(format!("Test function not found: {e}")))?
+            };
+
+            // SAFETY: test_fn has signature () -> void, compiled by us
+            call_guarded(|| unsafe { test_fn.call() })?;
+            return Ok(LLVMValue::Void);
         };
 
-        // Set up setjmp/longjmp recovery for JIT panics
-        let mut jmp_buf = runtime::JmpBuf::new();
-        let buf_ptr: *mut runtime::JmpBuf = &raw mut jmp_buf;
-        runtime::enter_jit_mode(buf_ptr);
+        // A raw address is `Send`, unlike inkwell's `JitFunction`.
+        let address = self
+            .engine
+            .get_function_address(wrapper_name)
+            .map_err(|e| LLVMEvalError::new(format!("Test function not found: {e}")))?;
 
-        // SAFETY: jmp_buf is stack-allocated and valid for the duration of this call.
-        // setjmp returns 0 on direct call, non-zero when longjmp fires.
-        let longjmp_fired = unsafe { runtime::jit_setjmp(buf_ptr) } != 0;
+        let (sender, receiver) = mpsc::channel();
+        std::thread::Builder::new()
+            .name(format!("jit-test-{wrapper_name}"))
+            .stack_size(TEST_THREAD_STACK_SIZE)
+            .spawn(move || {
+                // SAFETY: `address` is the test wrapper, compiled with signature
+                // () -> void; the engine owning it outlives this thread
+                // (leaked if the thread is abandoned).
+                let test_fn: unsafe extern "C" fn() = unsafe { std::mem::transmute(address) };
+                // The receiver is gone if the test was abandoned.
+                let _ = sender.send(call_guarded(|| unsafe { test_fn() }));
+            })
+            .map_err(|e| LLVMEvalError::new(format!("failed to spawn test thread: {e}")))?;
 
-        if longjmp_fired {
-            // longjmp returned us here — JIT code hit a panic
-            runtime::leave_jit_mode();
-            let msg = runtime::get_panic_message().unwrap_or_else(|| "unknown panic".to_string());
-            return Err(LLVMEvalError::new(msg));
+        match receiver.recv_timeout(limit) {
+            Ok(result) => result.map(|()| LLVMValue::Void),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                self.abandoned.set(true);
+                Err(LLVMEvalError::new(format!(
+                    "timeout: exceeded time limit of {limit:?}"
+                )))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err(LLVMEvalError::new("test thread exited without a result"))
+            }
         }
+    }
 
-        // Normal path: execute the test
-        // SAFETY: test_fn has signature () -> void, compiled by us
-        unsafe { test_fn.call() };
+    /// Call a zero-argument function returning `int` by its LLVM symbol
+    /// (e.g. `_ori_main`).
+    ///
+    /// The function must use the C calling convention; fails with a
+    /// descriptive error if the declared signature doesn't match.
+    #[allow(
+        unsafe_code,
+        reason = "JIT execution requires unsafe FFI: get_function and call"
+    )]
+    pub fn call_i64(&self, symbol: &str) -> Result<i64, LLVMEvalError> {
+        self.check_entry_signature(symbol, JitReturn::I64)?;
+        // SAFETY: the signature was checked against the module above
+        let func = unsafe {
+            self.engine
+                .get_function::<unsafe extern "C" fn() -> i64>(symbol)
+        }
+        .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+        // SAFETY: func has signature () -> i64
+        call_guarded(|| unsafe { func.call() })
+    }
 
-        runtime::leave_jit_mode();
+    /// Call a zero-argument function returning `float` by its LLVM symbol.
+    ///
+    /// Same requirements as [`call_i64`](Self::call_i64).
+    #[allow(
+        unsafe_code,
+        reason = "JIT execution requires unsafe FFI: get_function and call"
+    )]
+    pub fn call_f64(&self, symbol: &str) -> Result<f64, LLVMEvalError> {
+        self.check_entry_signature(symbol, JitReturn::F64)?;
+        // SAFETY: the signature was checked against the module above
+        let func = unsafe {
+            self.engine
+                .get_function::<unsafe extern "C" fn() -> f64>(symbol)
+        }
+        .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+        // SAFETY: func has signature () -> double
+        call_guarded(|| unsafe { func.call() })
+    }
+
+    /// Call a zero-argument function returning `bool` by its LLVM symbol.
+    ///
+    /// The `i1` result is read as a byte and masked, since the C ABI leaves
+    /// the upper bits of an `i1` return unspecified. Same requirements as
+    /// [`call_i64`](Self::call_i64).
+    #[allow(
+        unsafe_code,
+        reason = "JIT execution requires unsafe FFI: get_function and call"
+    )]
+    pub fn call_bool(&self, symbol: &str) -> Result<bool, LLVMEvalError> {
+        self.check_entry_signature(symbol, JitReturn::Bool)?;
+        // SAFETY: the signature was checked against the module above
+        let func = unsafe {
+            self.engine
+                .get_function::<unsafe extern "C" fn() -> u8>(symbol)
+        }
+        .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+        // SAFETY: func has signature () -> i1, returned in the low byte
+        call_guarded(|| unsafe { func.call() } & 1 != 0)
+    }
+
+    /// Call a zero-argument function returning `void` by its LLVM symbol.
+    ///
+    /// Same requirements as [`call_i64`](Self::call_i64).
+    #[allow(
+        unsafe_code,
+        reason = "JIT execution requires unsafe FFI: get_function and call"
+    )]
+    pub fn call_void(&self, symbol: &str) -> Result<(), LLVMEvalError> {
+        self.check_entry_signature(symbol, JitReturn::Void)?;
+        // SAFETY: the signature was checked against the module above
+        let func = unsafe { self.engine.get_function::<unsafe extern "C" fn()>(symbol) }
+            .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+        // SAFETY: func has signature () -> void
+        call_guarded(|| unsafe { func.call() })
+    }
+
+    /// Call a zero-argument function by its LLVM symbol and return the raw
+    /// bytes of its result, e.g. a `(int, int)` tuple or a struct.
+    ///
+    /// Goes through the function's `<symbol>$out` thunk, which writes the
+    /// result to an out pointer regardless of how the function itself
+    /// returns it (registers or `sret`, any calling convention). `out_size`
+    /// must equal the ABI size of the return type; bytes are in host order,
+    /// laid out as LLVM lays out the type.
+    #[allow(
+        unsafe_code,
+        reason = "JIT execution requires unsafe FFI: get_function and call"
+    )]
+    pub fn call_bytes(&self, symbol: &str, out_size: usize) -> Result<Vec<u8>, LLVMEvalError> {
+        let func = self
+            .engine
+            .get_function_value(symbol)
+            .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+
+        // An sret function returns void and carries its type on parameter 0
+        let ret_ty = match func.get_type().get_return_type() {
+            Some(ty) => ty.as_any_type_enum(),
+            None => func
+                .get_enum_attribute(
+                    AttributeLoc::Param(0),
+                    Attribute::get_named_enum_kind_id("sret"),
+                )
+                .map(|attr| attr.get_type_value())
+                .ok_or_else(|| {
+                    LLVMEvalError::new(format!("function `{symbol}` does not return a value"))
+                })?,
+        };
+        let size = self.engine.get_target_data().get_abi_size(&ret_ty);
+        if u64::try_from(out_size).ok() != Some(size) {
+            return Err(LLVMEvalError::new(format!(
+                "function `{symbol}` returns {size} byte(s), expected {out_size}"
+            )));
+        }
+
+        let thunk_symbol = out_thunk_symbol(symbol);
+        // SAFETY: out-pointer thunks are compiled as `void (ptr)` C functions
+        let thunk = unsafe {
+            self.engine
+                .get_function::<unsafe extern "C" fn(*mut u8)>(&thunk_symbol)
+        }
+        .map_err(|_| {
+            LLVMEvalError::new(format!(
+                "function `{symbol}` has no out-pointer thunk; only functions without parameters get one"
+            ))
+        })?;
+
+        // u64 words keep the buffer aligned for the stored value
+        let mut words = vec![0u64; out_size.div_ceil(8)];
+        let out = words.as_mut_ptr().cast::<u8>();
+        // SAFETY: `words` holds at least `size` bytes, checked above
+        call_guarded(|| unsafe { thunk.call(out) })?;
+        Ok(words
+            .iter()
+            .flat_map(|word| word.to_ne_bytes())
+            .take(out_size)
+            .collect())
+    }
+
+    /// Verify that `symbol` takes no parameters, uses the C calling
+    /// convention, and returns `expected`.
+    fn check_entry_signature(
+        &self,
+        symbol: &str,
+        expected: JitReturn,
+    ) -> Result<(), LLVMEvalError> {
+        let func = self
+            .engine
+            .get_function_value(symbol)
+            .map_err(|e| LLVMEvalError::new(format!("function `{symbol}` not found: {e}")))?;
+
+        let params = func.count_params();
+        if params != 0 {
+            return Err(LLVMEvalError::new(format!(
+                "function `{symbol}` takes {params} parameter(s); JIT entry points take none"
+            )));
+        }
+        // LLVMCCallConv = 0; fastcc functions can't be called from Rust.
+        if func.get_call_conventions() != 0 {
+            return Err(LLVMEvalError::new(format!(
+                "function `{symbol}` does not use the C calling convention"
+            )));
+        }
 
-        // Check if panic occurred via assertions (ori_assert sets state without longjmp)
-        if runtime::did_panic() {
-            let msg = runtime::get_panic_message().unwrap_or_else(|| "unknown panic".to_string());
-            Err(LLVMEvalError::new(msg))
+        let ret = func.get_type().get_return_type();
+        let matches = match (expected, ret) {
+            (JitReturn::Void, None) => true,
+            (JitReturn::I64, Some(BasicTypeEnum::IntType(t))) => t.get_bit_width() == 64,
+            (JitReturn::Bool, Some(BasicTypeEnum::IntType(t))) => t.get_bit_width() == 1,
+            (JitReturn::F64, Some(BasicTypeEnum::FloatType(_))) => true,
+            _ => false,
+        };
+        if matches {
+            Ok(())
         } else {
-            Ok(LLVMValue::Void)
+            let found = ret.map_or_else(|| "void".to_string(), |t| t.print_to_string().to_string());
+            Err(LLVMEvalError::new(format!(
+                "function `{symbol}` returns {found}, expected {expected}"
+            )))
+        }
+    }
+}
+
+impl Drop for CompiledTestModule<'_> {
+    #[allow(unsafe_code, reason = "ManuallyDrop::drop of the owned engine")]
+    fn drop(&mut self) {
+        if !self.abandoned.get() {
+            // SAFETY: the engine is dropped exactly once, here, and no test
+            // thread is still running its code.
+            unsafe { ManuallyDrop::drop(&mut self.engine) };
         }
     }
 }
 
+/// Run `call` with panic recovery.
+///
+/// Uses `setjmp`/`longjmp` to recover from panics in JIT-compiled code:
+/// `ori_panic` and friends `longjmp` back here instead of calling
+/// `exit(1)`, preserving the host process. Assertion failures set the
+/// panic state without unwinding and are reported the same way.
+#[allow(unsafe_code, reason = "JIT panic recovery requires setjmp")]
+fn call_guarded<T>(call: impl FnOnce() -> T) -> Result<T, LLVMEvalError> {
+    // Reset panic state before running
+    runtime::reset_panic_state();
+
+    // Set up setjmp/longjmp recovery for JIT panics
+    let mut jmp_buf = runtime::JmpBuf::new();
+    let buf_ptr: *mut runtime::JmpBuf = &raw mut jmp_buf;
+    runtime::enter_jit_mode(buf_ptr);
+
+    // SAFETY: jmp_buf is stack-allocated and valid for the duration of this call.
+    // setjmp returns 0 on direct call, non-zero when longjmp fires.
+    let longjmp_fired = unsafe { runtime::jit_setjmp(buf_ptr) } != 0;
+
+    if longjmp_fired {
+        // longjmp returned us here — JIT code hit a panic
+        runtime::leave_jit_mode();
+        let msg = runtime::get_panic_message().unwrap_or_else(|| "unknown panic".to_string());
+        return Err(LLVMEvalError::new(msg));
+    }
+
+    // Normal path: execute the JIT code
+    let result = call();
+
+    runtime::leave_jit_mode();
+
+    // Check if panic occurred via assertions (ori_assert sets state without longjmp)
+    if runtime::did_panic() {
+        let msg = runtime::get_panic_message().unwrap_or_else(|| "unknown panic".to_string());
+        Err(LLVMEvalError::new(msg))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Return type expected by a `CompiledTestModule::call_*` entry point.
+#[derive(Clone, Copy, Debug)]
+enum JitReturn {
+    I64,
+    F64,
+    Bool,
+    Void,
+}
+
+impl std::fmt::Display for JitReturn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            JitReturn::I64 => "i64",
+            JitReturn::F64 => "double",
+            JitReturn::Bool => "i1",
+            JitReturn::Void => "void",
+        })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // OwnedLLVMEvaluator (V2 pipeline)
 // ---------------------------------------------------------------------------
@@ -185,16 +470,88 @@ pub struct OwnedLLVMEvaluator<'tcx> {
     context: Context,
     /// Type pool for resolving compound types (List, Map, etc.)
     pool: &'tcx Pool,
+    /// Optimization level for the JIT execution engine.
+    jit_opt_level: OptimizationLevel,
+    /// Source to emit debug info against; `None` disables debug info.
+    debug_source: Option<DebugSource>,
+    /// Line index of the compiled module's source, for panic locations.
+    line_index: Option<LineIndex>,
+    /// Whether `int` arithmetic panics on overflow and zero divisors.
+    overflow_checks: bool,
+}
+
+/// Source file that JIT debug info maps spans back to.
+struct DebugSource {
+    path: PathBuf,
+    text: String,
 }
 
 impl<'tcx> OwnedLLVMEvaluator<'tcx> {
     /// Create an evaluator with a type pool for compound type resolution.
+    ///
+    /// The JIT runs unoptimized (`OptimizationLevel::None`) by default.
     #[must_use]
     pub fn with_pool(pool: &'tcx Pool) -> Self {
         OwnedLLVMEvaluator {
             context: Context::create(),
             pool,
+            jit_opt_level: OptimizationLevel::None,
+            debug_source: None,
+            line_index: None,
+            overflow_checks: false,
+        }
+    }
+
+    /// Set the optimization level used when creating JIT execution engines.
+    ///
+    /// Useful for benchmarking or testing optimized codegen; results must
+    /// not depend on the level.
+    #[must_use]
+    pub fn with_jit_opt_level(mut self, level: OptimizationLevel) -> Self {
+        self.jit_opt_level = level;
+        self
+    }
+
+    /// Emit DWARF debug info (a compile unit, a subprogram per function, and
+    /// a line location per expression) for JIT-compiled code.
+    ///
+    /// Spans are mapped to line/column against `source_text`, so a debugger
+    /// or panic backtrace can point back into `source_path`.
+    #[must_use]
+    pub fn with_debug_info(
+        mut self,
+        source_path: impl Into<PathBuf>,
+        source_text: impl Into<String>,
+    ) -> Self {
+        let text = source_text.into();
+        if self.line_index.is_none() {
+            self.line_index = Some(LineIndex::new(&text));
         }
+        self.debug_source = Some(DebugSource {
+            path: source_path.into(),
+            text,
+        });
+        self
+    }
+
+    /// Report the `line:col` of runtime panics in the main module using
+    /// `line_index` (typically the lexer's, see `ori_lexer::LexOutput`).
+    ///
+    /// [`with_debug_info`](Self::with_debug_info) builds one from its source
+    /// text when none was given.
+    #[must_use]
+    pub fn with_line_index(mut self, line_index: LineIndex) -> Self {
+        self.line_index = Some(line_index);
+        self
+    }
+
+    /// Compile `int` arithmetic with overflow and division-by-zero checks
+    /// that panic, instead of wrapping (see
+    /// [`FunctionCompiler::set_overflow_checks`]).
+    #[must_use]
+    pub fn with_overflow_checks(mut self, enabled: bool) -> Self {
+        self.overflow_checks = enabled;
+        self
     }
 
     /// Compile an entire module with all its tests using the V2 pipeline.
@@ -209,6 +566,10 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
     /// - Old approach: O(M × N) function compilations (each test recompiles all)
     /// - This approach: O(N + M) function compilations (compile once, run many)
     ///
+    /// Each call creates a fresh execution engine at the evaluator's JIT
+    /// optimization level; the returned `CompiledTestModule` owns it and
+    /// reuses it for every test in the module.
+    ///
     /// # Arguments
     ///
     /// - `module`: The parsed module containing functions and type declarations
@@ -236,8 +597,6 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
         impl_sigs: &[(Name, FunctionSig)],
         imported_functions: &[ImportedFunctionForCodegen<'_>],
     ) -> Result<CompiledTestModule<'a>, LLVMEvalError> {
-        use inkwell::OptimizationLevel;
-
         // --- V2 pipeline ---
 
         // 1. Create LLVM module context.
@@ -273,6 +632,17 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
             // 5. Register user-defined types
             type_registration::register_user_types(&resolver, user_types);
 
+            // 5b. Debug info (opt-in via `with_debug_info`)
+            let debug_context = self.debug_source.as_ref().and_then(|src| {
+                DebugContext::new(
+                    &scx_ref.llmod,
+                    &self.context,
+                    DebugInfoConfig::new(DebugLevel::LineTablesOnly),
+                    &src.path,
+                    &src.text,
+                )
+            });
+
             // 6. Two-pass function compilation
             debug!("declaring functions (phase 1)");
             let mut fc = FunctionCompiler::new(
@@ -284,8 +654,10 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
                 "",
                 None,
                 None,
-                None, // No debug info for JIT
+                debug_context.as_ref(),
             );
+            fc.set_overflow_checks(self.overflow_checks);
+            fc.set_line_index(self.line_index.as_ref());
             fc.declare_all(&module.functions, function_sigs);
 
             // 6b. Declare imported functions (phase 1)
@@ -327,6 +699,8 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
             // can resolve calls without a linker.
             if !imported_functions.is_empty() {
                 debug!("defining imported function bodies (phase 2)");
+                // Imported spans index into other files' sources
+                fc.set_line_index(None);
                 for imp_fn in imported_functions {
                     fc.define_all(
                         std::slice::from_ref(imp_fn.function),
@@ -336,6 +710,9 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
                 }
             }
 
+            // 8c. Out-pointer thunks, so `call_bytes` can read any return type
+            fc.compile_out_thunks(&module.functions, function_sigs);
+
             // 9. Compile test wrappers
             debug!("compiling test wrappers");
             let wrappers = fc.compile_tests(tests, canon);
@@ -343,6 +720,11 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
             // Drop fc to release &mut builder borrow
             drop(fc);
 
+            // Resolve debug metadata before verification
+            if let Some(dc) = &debug_context {
+                dc.finalize();
+            }
+
             let errors = builder.codegen_error_count();
             (wrappers, errors)
             // builder, resolver, store dropped here
@@ -387,15 +769,16 @@ impl<'tcx> OwnedLLVMEvaluator<'tcx> {
         let engine = unsafe {
             let module = &*std::ptr::addr_of!(scx.llmod);
             let eng = module
-                .create_jit_execution_engine(OptimizationLevel::None)
+                .create_jit_execution_engine(self.jit_opt_level)
                 .map_err(|e| LLVMEvalError::new(e.to_string()))?;
             add_runtime_mappings_to_engine(&eng, module)?;
             eng
         };
 
         Ok(CompiledTestModule {
-            engine,
+            engine: ManuallyDrop::new(engine),
             test_wrappers,
+            abandoned: Cell::new(false),
         })
     }
 }
@@ -432,20 +815,26 @@ pub(crate) const JIT_MAPPED_RUNTIME_FUNCTIONS: &[&str] = &[
     "ori_print_int",
     "ori_print_float",
     "ori_print_bool",
+    "ori_print_char",
+    "ori_print_byte",
     "ori_panic",
+    "ori_panic_at",
     "ori_panic_cstr",
+    "ori_index_panic",
     "ori_assert",
     "ori_assert_eq_int",
     "ori_assert_eq_bool",
     "ori_assert_eq_float",
     "ori_list_alloc_data",
     "ori_list_free_data",
+    "ori_list_grow_data",
     "ori_list_new",
     "ori_list_free",
     "ori_list_len",
     "ori_compare_int",
     "ori_min_int",
     "ori_max_int",
+    "ori_ipow",
     "ori_str_concat",
     "ori_str_eq",
     "ori_str_ne",
@@ -467,6 +856,18 @@ pub(crate) const JIT_MAPPED_RUNTIME_FUNCTIONS: &[&str] = &[
     "ori_rc_free",
     "ori_args_from_argv",
     "ori_register_panic_handler",
+    "ori_parallel",
+    "ori_run_with_timeout",
+    "ori_catch",
+    "ori_memo_enter",
+    "ori_memo_exit",
+    "ori_memo_lookup",
+    "ori_memo_store",
+    "ori_cache_get",
+    "ori_cache_put",
+    "ori_map_new",
+    "ori_map_insert",
+    "ori_map_find",
     "rust_eh_personality",
 ];
 
@@ -492,11 +893,24 @@ fn add_runtime_mappings_to_engine(
             "ori_print_bool",
             runtime::ori_print_bool as *const () as usize,
         ),
+        (
+            "ori_print_char",
+            runtime::ori_print_char as *const () as usize,
+        ),
+        (
+            "ori_print_byte",
+            runtime::ori_print_byte as *const () as usize,
+        ),
         ("ori_panic", runtime::ori_panic as *const () as usize),
+        ("ori_panic_at", runtime::ori_panic_at as *const () as usize),
         (
             "ori_panic_cstr",
             runtime::ori_panic_cstr as *const () as usize,
         ),
+        (
+            "ori_index_panic",
+            runtime::ori_index_panic as *const () as usize,
+        ),
         ("ori_assert", runtime::ori_assert as *const () as usize),
         (
             "ori_assert_eq_int",
@@ -518,6 +932,10 @@ fn add_runtime_mappings_to_engine(
             "ori_list_free_data",
             runtime::ori_list_free_data as *const () as usize,
         ),
+        (
+            "ori_list_grow_data",
+            runtime::ori_list_grow_data as *const () as usize,
+        ),
         ("ori_list_new", runtime::ori_list_new as *const () as usize),
         (
             "ori_list_free",
@@ -530,6 +948,7 @@ fn add_runtime_mappings_to_engine(
         ),
         ("ori_min_int", runtime::ori_min_int as *const () as usize),
         ("ori_max_int", runtime::ori_max_int as *const () as usize),
+        ("ori_ipow", runtime::ori_ipow as *const () as usize),
         (
             "ori_str_concat",
             runtime::ori_str_concat as *const () as usize,
@@ -594,6 +1013,55 @@ fn add_runtime_mappings_to_engine(
             "ori_register_panic_handler",
             runtime::ori_register_panic_handler as *const () as usize,
         ),
+        // Task runtime (parallel, timeout, catch patterns)
+        (
+            "ori_parallel",
+            runtime::task::ori_parallel as *const () as usize,
+        ),
+        (
+            "ori_run_with_timeout",
+            runtime::task::ori_run_with_timeout as *const () as usize,
+        ),
+        ("ori_catch", runtime::task::ori_catch as *const () as usize),
+        // Memo tables (recurse with memo: true)
+        (
+            "ori_memo_enter",
+            runtime::memo::ori_memo_enter as *const () as usize,
+        ),
+        (
+            "ori_memo_exit",
+            runtime::memo::ori_memo_exit as *const () as usize,
+        ),
+        (
+            "ori_memo_lookup",
+            runtime::memo::ori_memo_lookup as *const () as usize,
+        ),
+        (
+            "ori_memo_store",
+            runtime::memo::ori_memo_store as *const () as usize,
+        ),
+        // Cache store (cache pattern)
+        (
+            "ori_cache_get",
+            runtime::cache::ori_cache_get as *const () as usize,
+        ),
+        (
+            "ori_cache_put",
+            runtime::cache::ori_cache_put as *const () as usize,
+        ),
+        // Map hash index (map literals and lookups)
+        (
+            "ori_map_new",
+            runtime::map::ori_map_new as *const () as usize,
+        ),
+        (
+            "ori_map_insert",
+            runtime::map::ori_map_insert as *const () as usize,
+        ),
+        (
+            "ori_map_find",
+            runtime::map::ori_map_find as *const () as usize,
+        ),
         // Exception handling personality function — required by any function
         // containing `invoke`/`landingpad`. Not in the dynamic symbol table,
         // so MCJIT's dlsym-based resolution can't find it automatically.
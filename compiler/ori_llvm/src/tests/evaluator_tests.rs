@@ -7,10 +7,11 @@
     reason = "test ergonomics — relaxed style for clarity"
 )]
 
-use ori_ir::StringInterner;
-use ori_types::Pool;
+use ori_ir::canon::{CanArena, CanExpr, CanId, CanNode, CanonRoot};
+use ori_ir::{BinaryOp, Span, StringInterner, TypeId};
+use ori_types::{FunctionSig, Idx, Pool};
 
-use crate::evaluator::{LLVMEvalError, LLVMValue, OwnedLLVMEvaluator};
+use crate::evaluator::{CompiledTestModule, LLVMEvalError, LLVMValue, OwnedLLVMEvaluator};
 
 #[test]
 fn test_llvm_value_debug() {
@@ -67,10 +68,10 @@ fn test_owned_llvm_evaluator_with_pool() {
     drop(evaluator);
 }
 
-#[test]
-fn test_compile_module_with_tests_empty() {
+/// Compile an empty module at the given JIT optimization level.
+fn compile_empty_module(level: inkwell::OptimizationLevel) -> Result<(), String> {
     let pool = Pool::new();
-    let evaluator = OwnedLLVMEvaluator::with_pool(&pool);
+    let evaluator = OwnedLLVMEvaluator::with_pool(&pool).with_jit_opt_level(level);
     let interner = StringInterner::new();
 
     let module = ori_ir::ast::Module {
@@ -97,12 +98,361 @@ fn test_compile_module_with_tests_empty() {
         method_roots: vec![],
         problems: vec![],
     };
-    let result =
-        evaluator.compile_module_with_tests(&module, &[], &canon, &interner, &[], &[], &[], &[]);
+    evaluator
+        .compile_module_with_tests(&module, &[], &canon, &interner, &[], &[], &[], &[])
+        .map(|_| ())
+        .map_err(|e| e.message)
+}
 
+#[test]
+fn test_compile_module_with_tests_empty() {
+    let result = compile_empty_module(inkwell::OptimizationLevel::None);
+    assert!(result.is_ok(), "empty module should compile: {result:?}");
+}
+
+/// Compile a module whose only function is a C-ABI `@main` returning
+/// `ret`, with its canonical body built by `build_body`, and hand the
+/// compiled module to `check`.
+fn with_compiled_main<R>(
+    ret: Idx,
+    build_body: impl FnOnce(&mut CanArena) -> CanId,
+    check: impl FnOnce(&CompiledTestModule<'_>) -> R,
+) -> R {
+    with_compiled_main_checked(false, ret, build_body, check)
+}
+
+/// [`with_compiled_main`] with overflow checks set to `overflow_checks`.
+fn with_compiled_main_checked<R>(
+    overflow_checks: bool,
+    ret: Idx,
+    build_body: impl FnOnce(&mut CanArena) -> CanId,
+    check: impl FnOnce(&CompiledTestModule<'_>) -> R,
+) -> R {
+    with_compiled_main_in(
+        &Pool::new(),
+        overflow_checks,
+        inkwell::OptimizationLevel::None,
+        ret,
+        build_body,
+        check,
+    )
+}
+
+/// [`with_compiled_main_checked`] against `pool` at JIT optimization level
+/// `opt_level`, for return types that must be interned first.
+fn with_compiled_main_in<R>(
+    pool: &Pool,
+    overflow_checks: bool,
+    opt_level: inkwell::OptimizationLevel,
+    ret: Idx,
+    build_body: impl FnOnce(&mut CanArena) -> CanId,
+    check: impl FnOnce(&CompiledTestModule<'_>) -> R,
+) -> R {
+    let evaluator = OwnedLLVMEvaluator::with_pool(pool)
+        .with_overflow_checks(overflow_checks)
+        .with_jit_opt_level(opt_level);
+    let interner = StringInterner::new();
+    let main_name = interner.intern("main");
+
+    let module = ori_ir::ast::Module {
+        file_attr: None,
+        imports: vec![],
+        consts: vec![],
+        functions: vec![ori_ir::ast::Function {
+            name: main_name,
+            generics: ori_ir::GenericParamRange::EMPTY,
+            params: ori_ir::ParamRange::EMPTY,
+            return_ty: None,
+            capabilities: vec![],
+            where_clauses: vec![],
+            guard: None,
+            body: ori_ir::ExprId::INVALID,
+            span: Span::DUMMY,
+            visibility: ori_ir::ast::Visibility::Private,
+        }],
+        tests: vec![],
+        types: vec![],
+        traits: vec![],
+        impls: vec![],
+        extends: vec![],
+        def_impls: vec![],
+        extension_imports: vec![],
+        extern_blocks: vec![],
+    };
+    let sig = FunctionSig {
+        name: main_name,
+        type_params: vec![],
+        const_params: vec![],
+        param_names: vec![],
+        param_types: vec![],
+        return_type: ret,
+        capabilities: vec![],
+        is_public: false,
+        is_test: false,
+        is_main: true,
+        type_param_bounds: vec![],
+        where_clauses: vec![],
+        generic_param_mapping: vec![],
+        required_params: 0,
+        param_defaults: vec![],
+    };
+
+    let mut arena = CanArena::default();
+    let body = build_body(&mut arena);
+    let canon = ori_ir::canon::CanonResult {
+        arena,
+        constants: Default::default(),
+        decision_trees: ori_ir::canon::DecisionTreePool::new(),
+        root: body,
+        roots: vec![CanonRoot {
+            name: main_name,
+            body,
+            defaults: vec![],
+        }],
+        method_roots: vec![],
+        problems: vec![],
+    };
+
+    let compiled = evaluator
+        .compile_module_with_tests(&module, &[], &canon, &interner, &[sig], &[], &[], &[])
+        .unwrap_or_else(|e| panic!("module should compile: {}", e.message));
+    check(&compiled)
+}
+
+/// Push `lhs op rhs` over two leaf nodes, typed `ty`.
+fn push_binary(
+    arena: &mut CanArena,
+    op: BinaryOp,
+    lhs: CanExpr,
+    rhs: CanExpr,
+    operand_ty: TypeId,
+    ty: TypeId,
+) -> CanId {
+    let left = arena.push(CanNode::new(lhs, Span::DUMMY, operand_ty));
+    let right = arena.push(CanNode::new(rhs, Span::DUMMY, operand_ty));
+    arena.push(CanNode::new(
+        CanExpr::Binary { op, left, right },
+        Span::DUMMY,
+        ty,
+    ))
+}
+
+#[test]
+fn test_compile_module_with_tests_at_each_jit_opt_level() {
+    use inkwell::OptimizationLevel;
+
+    for level in [
+        OptimizationLevel::None,
+        OptimizationLevel::Less,
+        OptimizationLevel::Default,
+        OptimizationLevel::Aggressive,
+    ] {
+        let result = with_compiled_main_in(
+            &Pool::new(),
+            true,
+            level,
+            Idx::INT,
+            |arena| {
+                push_binary(
+                    arena,
+                    BinaryOp::Mul,
+                    CanExpr::Int(6),
+                    CanExpr::Int(7),
+                    TypeId::INT,
+                    TypeId::INT,
+                )
+            },
+            |compiled| compiled.call_i64("_ori_main"),
+        );
+        assert_eq!(result, Ok(42), "main should run at {level:?}");
+    }
+}
+
+#[test]
+fn test_call_f64_runs_float_function() {
+    let result = with_compiled_main(
+        Idx::FLOAT,
+        |arena| {
+            push_binary(
+                arena,
+                BinaryOp::Add,
+                CanExpr::Float(3.0_f64.to_bits()),
+                CanExpr::Float(0.5_f64.to_bits()),
+                TypeId::FLOAT,
+                TypeId::FLOAT,
+            )
+        },
+        |compiled| compiled.call_f64("_ori_main"),
+    );
+    assert_eq!(result, Ok(3.5));
+}
+
+#[test]
+fn test_call_bool_runs_comparison() {
+    let result = with_compiled_main(
+        Idx::BOOL,
+        |arena| {
+            push_binary(
+                arena,
+                BinaryOp::Lt,
+                CanExpr::Int(2),
+                CanExpr::Int(3),
+                TypeId::INT,
+                TypeId::BOOL,
+            )
+        },
+        |compiled| compiled.call_bool("_ori_main"),
+    );
+    assert_eq!(result, Ok(true));
+}
+
+#[test]
+fn test_call_rejects_mismatched_return_type() {
+    let result = with_compiled_main(
+        Idx::FLOAT,
+        |arena| {
+            arena.push(CanNode::new(
+                CanExpr::Float(1.5_f64.to_bits()),
+                Span::DUMMY,
+                TypeId::FLOAT,
+            ))
+        },
+        |compiled| compiled.call_i64("_ori_main"),
+    );
+    let err = result.expect_err("float function must not run as i64");
+    assert!(
+        err.message.contains("returns double, expected i64"),
+        "unexpected error: {}",
+        err.message
+    );
+}
+
+#[test]
+fn test_call_bytes_reads_tuple_result() {
+    let mut pool = Pool::new();
+    let pair = pool.tuple(&[Idx::INT, Idx::INT]);
+    let result = with_compiled_main_in(
+        &pool,
+        false,
+        inkwell::OptimizationLevel::None,
+        pair,
+        |arena| {
+            let ten = arena.push(CanNode::new(CanExpr::Int(10), Span::DUMMY, TypeId::INT));
+            let twenty = arena.push(CanNode::new(CanExpr::Int(20), Span::DUMMY, TypeId::INT));
+            let elems = arena.push_expr_list(&[ten, twenty]);
+            arena.push(CanNode::new(
+                CanExpr::Tuple(elems),
+                Span::DUMMY,
+                TypeId::from_raw(pair.raw()),
+            ))
+        },
+        |compiled| compiled.call_bytes("_ori_main", 16),
+    );
+    let bytes = result.expect("tuple function must run");
+    let field = |i: usize| {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+        i64::from_ne_bytes(word)
+    };
+    assert_eq!((field(0), field(1)), (10, 20));
+}
+
+#[test]
+fn test_call_bytes_rejects_wrong_size() {
+    let result = with_compiled_main(
+        Idx::INT,
+        |arena| arena.push(CanNode::new(CanExpr::Int(7), Span::DUMMY, TypeId::INT)),
+        |compiled| compiled.call_bytes("_ori_main", 16),
+    );
+    let err = result.expect_err("an int result is 8 bytes");
+    assert!(
+        err.message.contains("returns 8 byte(s), expected 16"),
+        "unexpected error: {}",
+        err.message
+    );
+}
+
+/// `main` returning `lhs op rhs` over two `int` literals.
+fn run_int_op(overflow_checks: bool, op: BinaryOp, lhs: i64, rhs: i64) -> Result<i64, String> {
+    with_compiled_main_checked(
+        overflow_checks,
+        Idx::INT,
+        |arena| {
+            push_binary(
+                arena,
+                op,
+                CanExpr::Int(lhs),
+                CanExpr::Int(rhs),
+                TypeId::INT,
+                TypeId::INT,
+            )
+        },
+        |compiled| compiled.call_i64("_ori_main").map_err(|e| e.message),
+    )
+}
+
+#[test]
+fn test_unchecked_add_wraps() {
+    assert_eq!(run_int_op(false, BinaryOp::Add, i64::MAX, 1), Ok(i64::MIN));
+}
+
+#[test]
+fn test_checked_add_in_range() {
+    assert_eq!(run_int_op(true, BinaryOp::Add, 40, 2), Ok(42));
+}
+
+#[test]
+fn test_checked_add_overflow_panics() {
+    let err = run_int_op(true, BinaryOp::Add, i64::MAX, 1).expect_err("overflow must panic");
+    assert!(
+        err.contains("integer overflow in addition"),
+        "unexpected panic message: {err}"
+    );
+}
+
+#[test]
+fn test_checked_div_by_zero_panics() {
+    let err = run_int_op(true, BinaryOp::Div, 1, 0).expect_err("division by zero must panic");
+    assert!(
+        err.contains("division by zero"),
+        "unexpected panic message: {err}"
+    );
+}
+
+#[test]
+fn test_int_pow() {
+    assert_eq!(run_int_op(false, BinaryOp::Pow, 2, 10), Ok(1024));
+    assert_eq!(run_int_op(false, BinaryOp::Pow, 5, 0), Ok(1));
+}
+
+#[test]
+fn test_int_pow_negative_exponent_panics() {
+    let err = run_int_op(false, BinaryOp::Pow, 2, -1).expect_err("negative exponent must panic");
+    assert!(
+        err.contains("negative exponent"),
+        "unexpected panic message: {err}"
+    );
+}
+
+#[test]
+fn test_float_pow() {
+    let result = with_compiled_main(
+        Idx::FLOAT,
+        |arena| {
+            push_binary(
+                arena,
+                BinaryOp::Pow,
+                CanExpr::Float(2.0_f64.to_bits()),
+                CanExpr::Float(0.5_f64.to_bits()),
+                TypeId::FLOAT,
+                TypeId::FLOAT,
+            )
+        },
+        |compiled| compiled.call_f64("_ori_main"),
+    );
+    let value = result.expect("float pow must run");
     assert!(
-        result.is_ok(),
-        "empty module should compile: {}",
-        result.err().map(|e| e.message).unwrap_or_default()
+        (value - std::f64::consts::SQRT_2).abs() < 1e-12,
+        "got {value}"
     );
 }
@@ -84,6 +84,16 @@ fn test_ori_max_int() {
     assert_eq!(runtime::ori_max_int(-10, 5), 5);
 }
 
+#[test]
+fn test_ori_ipow() {
+    assert_eq!(runtime::ori_ipow(2, 10), 1024);
+    assert_eq!(runtime::ori_ipow(-3, 3), -27);
+    assert_eq!(runtime::ori_ipow(7, 0), 1);
+    assert_eq!(runtime::ori_ipow(0, 0), 1);
+    assert_eq!(runtime::ori_ipow(-1, i64::MAX), -1);
+    assert_eq!(runtime::ori_ipow(2, 62), 1 << 62);
+}
+
 #[test]
 fn test_ori_str_eq() {
     let s1 = make_ori_str(b"hello");
@@ -261,6 +271,43 @@ fn test_ori_str_from_float() {
     assert!(s.starts_with("2.5"));
 }
 
+/// Copy the bytes of a runtime-produced string.
+#[allow(
+    unsafe_code,
+    reason = "runtime FFI returns raw pointers; unsafe needed to read results"
+)]
+fn str_bytes(s: &OriStr) -> Vec<u8> {
+    unsafe { std::slice::from_raw_parts(s.data, s.len as usize) }.to_vec()
+}
+
+#[test]
+fn test_to_str_conversions_produce_expected_bytes() {
+    assert_eq!(str_bytes(&runtime::ori_str_from_int(-42)), b"-42");
+    assert_eq!(
+        str_bytes(&runtime::ori_str_from_int(i64::MIN)),
+        b"-9223372036854775808"
+    );
+    assert_eq!(str_bytes(&runtime::ori_str_from_float(3.5)), b"3.5");
+    assert_eq!(str_bytes(&runtime::ori_str_from_bool(true)), b"true");
+    assert_eq!(str_bytes(&runtime::ori_str_from_bool(false)), b"false");
+}
+
+#[test]
+fn test_ori_str_from_float_uses_shortest_form() {
+    // Shortest round-trip digits, no exponent — matches the interpreter's `to_str()`
+    assert_eq!(str_bytes(&runtime::ori_str_from_float(1.0)), b"1");
+    assert_eq!(str_bytes(&runtime::ori_str_from_float(-0.5)), b"-0.5");
+    assert_eq!(
+        str_bytes(&runtime::ori_str_from_float(0.1 + 0.2)),
+        b"0.30000000000000004"
+    );
+    assert_eq!(
+        str_bytes(&runtime::ori_str_from_float(1e21)),
+        b"1000000000000000000000"
+    );
+    assert_eq!(str_bytes(&runtime::ori_str_from_float(-0.0)), b"-0");
+}
+
 #[test]
 #[allow(
     unsafe_code,
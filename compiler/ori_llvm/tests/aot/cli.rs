@@ -341,6 +341,86 @@ fn test_build_invalid_source() {
     );
 }
 
+/// Test: `ori build` rejects a non-exhaustive match instead of compiling the
+/// missing case to `unreachable`.
+#[test]
+fn test_build_non_exhaustive_match() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = create_test_source(
+        &temp_dir,
+        "nonexhaustive.ori",
+        r#"
+@unwrap_or_zero (opt: Option<int>) -> int = match opt {
+    Some(x) -> x,
+};
+
+@main () -> int = unwrap_or_zero(opt: None);
+"#,
+    );
+    let output = temp_dir.path().join("nonexhaustive");
+
+    let result = Command::new(ori_binary())
+        .args([
+            "build",
+            source.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute ori build");
+
+    assert!(
+        !result.status.success(),
+        "ori build should reject a non-exhaustive match"
+    );
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(
+        stderr.contains("E3002"),
+        "E3002 not found in stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("None"),
+        "missing `None` not reported: {stderr}"
+    );
+    assert!(!output.exists(), "Output binary should not exist");
+}
+
+/// Test: `ori build` accepts a match covering `Some` and `None`.
+#[test]
+fn test_build_exhaustive_option_match() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = create_test_source(
+        &temp_dir,
+        "exhaustive.ori",
+        r#"
+@unwrap_or_zero (opt: Option<int>) -> int = match opt {
+    Some(x) -> x,
+    None -> 0,
+};
+
+@main () -> int = unwrap_or_zero(opt: None);
+"#,
+    );
+    let output = temp_dir.path().join("exhaustive");
+
+    let result = Command::new(ori_binary())
+        .args([
+            "build",
+            source.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute ori build");
+
+    assert!(
+        result.status.success(),
+        "ori build failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    assert!(output.exists(), "Binary was not created");
+}
+
 /// Test: `ori build` with missing file fails gracefully.
 #[test]
 fn test_build_missing_file() {
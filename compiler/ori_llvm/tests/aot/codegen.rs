@@ -21,7 +21,7 @@ use ori_llvm::aot::mangle::{demangle, is_ori_symbol, Mangler, MANGLE_PREFIX};
 use ori_llvm::aot::object::OutputFormat;
 use ori_llvm::aot::passes::{LtoMode, OptimizationLevel};
 
-use super::util::parse_object;
+use super::util::{object_has_symbol, parse_object};
 
 /// Test: Output format extension mapping
 ///
@@ -505,3 +505,70 @@ fn test_optimization_pipelines() {
     // Oz: Aggressive size optimization
     assert_eq!(OptimizationLevel::Oz.pipeline_string(), "default<Oz>");
 }
+
+/// Test: Optimized emission folds redundant IR and still produces an object
+///
+/// Scenario: A function that spills constants through stack slots before
+/// adding them is fully folded at O2 — the emitted IR returns the constant
+/// directly, and the object file built from the same pipeline exports the
+/// function symbol.
+#[test]
+fn test_verify_optimize_emit_folds_constants() {
+    use inkwell::context::Context;
+    use ori_llvm::aot::object::ObjectEmitter;
+    use ori_llvm::aot::passes::OptimizationConfig;
+
+    fn build_foldable(context: &Context) -> inkwell::module::Module<'_> {
+        let module = context.create_module("fold");
+        let i64_ty = context.i64_type();
+        let func = module.add_function("fold", i64_ty.fn_type(&[], false), None);
+        let builder = context.create_builder();
+        builder.position_at_end(context.append_basic_block(func, "entry"));
+
+        let a = builder.build_alloca(i64_ty, "a").unwrap();
+        let b = builder.build_alloca(i64_ty, "b").unwrap();
+        builder.build_store(a, i64_ty.const_int(2, false)).unwrap();
+        builder.build_store(b, i64_ty.const_int(3, false)).unwrap();
+        let lhs = builder.build_load(i64_ty, a, "lhs").unwrap();
+        let rhs = builder.build_load(i64_ty, b, "rhs").unwrap();
+        let sum = builder
+            .build_int_add(lhs.into_int_value(), rhs.into_int_value(), "sum")
+            .unwrap();
+        builder.build_return(Some(&sum)).unwrap();
+        module
+    }
+
+    let Ok(emitter) = ObjectEmitter::native() else {
+        return; // Native target unavailable in this LLVM build
+    };
+    let config = OptimizationConfig::new(OptimizationLevel::O2);
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let context = Context::create();
+    let module = build_foldable(&context);
+    emitter.configure_module(&module).unwrap();
+    let ir_path = dir.path().join("fold.ll");
+    emitter
+        .verify_optimize_emit(&module, &config, &ir_path, OutputFormat::LlvmIr)
+        .unwrap();
+
+    let ir = std::fs::read_to_string(&ir_path).unwrap();
+    assert!(ir.contains("ret i64 5"), "constant not folded:\n{ir}");
+    assert!(!ir.contains("alloca"), "stack slots not promoted:\n{ir}");
+    assert!(!ir.contains("add i64"), "redundant add survived:\n{ir}");
+
+    let context = Context::create();
+    let module = build_foldable(&context);
+    emitter.configure_module(&module).unwrap();
+    let obj_path = dir.path().join("fold.o");
+    emitter
+        .verify_optimize_emit(&module, &config, &obj_path, OutputFormat::Object)
+        .unwrap();
+
+    let bytes = std::fs::read(&obj_path).unwrap();
+    let obj = parse_object(&bytes).unwrap();
+    assert!(
+        object_has_symbol(&obj, "fold"),
+        "optimized object is missing the `fold` symbol"
+    );
+}
@@ -45,6 +45,22 @@ fn test_for_range_inclusive() {
     );
 }
 
+#[test]
+fn test_for_range_inclusive_bound() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let incl = 0;
+    for i in 1..=5 do incl = incl + i;
+    let excl = 0;
+    for i in 1..5 do excl = excl + i;
+    if incl == 15 && excl == 10 then 0 else 1
+}
+"#,
+        "for_range_inclusive_bound",
+    );
+}
+
 #[test]
 fn test_for_range_empty() {
     assert_aot_success(
@@ -145,6 +161,38 @@ fn test_for_list_with_guard() {
     );
 }
 
+#[test]
+fn test_for_list_guard_yield_elements() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let tens = for x in [1, 2, 3, 4, 5, 6] if x % 2 == 0 yield x * 10;
+    if tens.len() == 3 && tens[0] == 20 && tens[1] == 40 && tens[2] == 60 then 0 else 1
+}
+"#,
+        "for_list_guard_yield_elements",
+    );
+}
+
+#[test]
+fn test_for_iter_guard_yield_grows() {
+    // Iterators start the yield buffer empty: 10 elements grow it 4 -> 8 -> 16
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let ys = for x in (0..40).iter() if x % 4 == 1 yield x * x + 1;
+    let ok = ys.len() == 10;
+    for i in 0..10 do {
+        let x = 4 * i + 1;
+        if ys[i] != x * x + 1 then ok = false
+    };
+    if ok then 0 else 1
+}
+"#,
+        "for_iter_guard_yield_grows",
+    );
+}
+
 // -----------------------------------------------------------------------
 // String for-loops (new — character iteration)
 // -----------------------------------------------------------------------
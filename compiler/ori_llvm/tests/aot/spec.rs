@@ -324,6 +324,544 @@ fn test_aot_print_string() {
     );
 }
 
+#[test]
+fn test_aot_print_char_and_byte() {
+    let source = r#"
+@main () -> void = {
+    print(msg: 'a');
+    print(msg: byte(65));
+}
+"#;
+    let (exit_code, stdout, stderr) = compile_and_run_capture(source);
+    assert_eq!(exit_code, 0, "print_char_and_byte failed: {stderr}");
+    assert_eq!(
+        stdout, "a\n0x41\n",
+        "print_char_and_byte stdout mismatch, stderr: '{stderr}'"
+    );
+}
+
+#[test]
+fn test_aot_panic_reports_message() {
+    let source = r#"
+@main () -> void = {
+    print(msg: "before");
+    panic(msg: "boom at runtime")
+}
+"#;
+    let (exit_code, stdout, stderr) = compile_and_run_capture(source);
+    assert_ne!(
+        exit_code, 0,
+        "panic should exit non-zero, stdout: '{stdout}'"
+    );
+    assert!(
+        stdout.contains("before"),
+        "Expected output before the panic, got stdout: '{stdout}'"
+    );
+    assert!(
+        stderr.contains("boom at runtime"),
+        "Expected panic message on stderr, got stderr: '{stderr}'"
+    );
+}
+
+#[test]
+fn test_aot_panic_message_prop_reports_location() {
+    let source = r#"
+@main () -> void = {
+    print(msg: "before");
+    panic(message: "boom at runtime")
+}
+"#;
+    let (exit_code, _stdout, stderr) = compile_and_run_capture(source);
+    assert_ne!(exit_code, 0, "panic should exit non-zero");
+    assert!(
+        stderr.contains("ori panic at 4:5: boom at runtime"),
+        "Expected panic location and message on stderr, got stderr: '{stderr}'"
+    );
+}
+
+#[test]
+fn test_aot_logical_ops_short_circuit() {
+    assert_aot_success(
+        r#"
+@boom () -> bool = panic(msg: "rhs evaluated");
+
+@main () -> int = {
+    let a = false && boom();
+    let b = true || boom();
+    if !a && b then 0 else 1
+}
+"#,
+        "logical_ops_short_circuit",
+    );
+}
+
+#[test]
+fn test_aot_char_comparisons() {
+    assert_aot_success(
+        r#"
+@lt (a: char, b: char) -> bool = a < b;
+@ge (a: char, b: char) -> bool = a >= b;
+@eq (a: char, b: char) -> bool = a == b;
+
+@main () -> int = {
+    let ordered = lt(a: 'a', b: 'b') && lt(a: 'z', b: 'λ') && ge(a: 'λ', b: 'a');
+    let same = eq(a: 'λ', b: 'λ') && !eq(a: 'λ', b: 'μ');
+    if ordered && same && 'a' < 'b' && 'λ' == 'λ' then 0 else 1
+}
+"#,
+        "char_comparisons",
+    );
+}
+
+#[test]
+fn test_aot_list_index_dynamic() {
+    assert_aot_success(
+        r#"
+@at (xs: [int], i: int) -> int = xs[i];
+
+@main () -> int = {
+    let xs = [10, 20, 30];
+    let total = 0;
+    for i in 0..3 do total = total + at(xs: xs, i: i);
+    if total == 60 && xs[# - 1] == 30 then 0 else 1
+}
+"#,
+        "list_index_dynamic",
+    );
+}
+
+#[test]
+fn test_aot_list_index_out_of_bounds_panics() {
+    let source = r#"
+@at (xs: [int], i: int) -> int = xs[i];
+
+@main () -> int = at(xs: [1, 2, 3], i: 5);
+"#;
+    let (exit_code, stdout, stderr) = compile_and_run_capture(source);
+    assert_ne!(
+        exit_code, 0,
+        "out-of-bounds index should exit non-zero, stdout: '{stdout}'"
+    );
+    assert!(
+        stderr.contains("index 5 out of bounds"),
+        "Expected index panic on stderr, got stderr: '{stderr}'"
+    );
+}
+
+#[test]
+fn test_aot_list_slice() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let xs = [10, 20, 30, 40];
+    let mid = xs[1..3];
+    let tail = xs[1..=3];
+    if mid.len() == 2 && mid[0] == 20 && mid[1] == 30 && tail.len() == 3 && tail[2] == 40 then 0 else 1
+}
+"#,
+        "list_slice",
+    );
+}
+
+#[test]
+fn test_aot_list_slice_out_of_range_panics() {
+    let source = r#"
+@slice (xs: [int], end: int) -> [int] = xs[1..end];
+
+@main () -> int = slice(xs: [10, 20, 30, 40], end: 5).len();
+"#;
+    let (exit_code, stdout, stderr) = compile_and_run_capture(source);
+    assert_ne!(
+        exit_code, 0,
+        "out-of-range slice should exit non-zero, stdout: '{stdout}'"
+    );
+    assert!(
+        stderr.contains("index 5 out of bounds"),
+        "Expected index panic on stderr, got stderr: '{stderr}'"
+    );
+}
+
+#[test]
+fn test_aot_catch_wraps_value_in_ok() {
+    assert_aot_success(
+        r#"
+@compute (x: int) -> int = x * 3;
+
+@main () -> int = {
+    let caught = catch(expr: compute(x: 3));
+    match caught {
+        Ok(v) -> if v == 9 then 0 else 1,
+        Err(_) -> 2,
+    }
+}
+"#,
+        "catch_wraps_value_in_ok",
+    );
+}
+
+#[test]
+fn test_aot_catch_str_value() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let caught = catch(expr: "hello");
+    match caught {
+        Ok(s) -> if s.len() == 5 then 0 else 1,
+        Err(_) -> 2,
+    }
+}
+"#,
+        "catch_str_value",
+    );
+}
+
+#[test]
+fn test_aot_catch_panic_becomes_err() {
+    assert_aot_success(
+        r#"
+@risky (n: int) -> int = if n < 0 then panic(msg: "negative input") else n * 2;
+
+@main () -> int = {
+    let base = -4;
+    let caught = catch(expr: risky(n: base));
+    let ok = catch(expr: risky(n: 5));
+    let quotient = catch(expr: 10 / (base + 4));
+    let r1 = match caught {
+        Ok(_) -> 1,
+        Err(m) -> if m == "negative input" then 0 else 2,
+    };
+    let r2 = match ok {
+        Ok(v) -> if v == 10 then 0 else 4,
+        Err(_) -> 8,
+    };
+    let r3 = match quotient {
+        Ok(_) -> 16,
+        Err(_) -> 0,
+    };
+    r1 + r2 + r3
+}
+"#,
+        "catch_panic_becomes_err",
+    );
+}
+
+#[test]
+fn test_aot_assign_struct_field() {
+    assert_aot_success(
+        r#"
+type Point = { x: int, y: int }
+type Line = { a: Point, b: Point }
+
+@main () -> int = {
+    let p = Point { x: 1, y: 2 };
+    let q = p;
+    p.x = 5;
+    let l = Line { a: Point { x: 0, y: 0 }, b: Point { x: 1, y: 1 } };
+    l.b.y = 42;
+    if p.x == 5 && p.y == 2 && q.x == 1 && l.b.y == 42 && l.b.x == 1 then 0 else 1
+}
+"#,
+        "assign_struct_field",
+    );
+}
+
+#[test]
+fn test_aot_assign_list_element() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let xs = [1, 2, 3];
+    let ys = xs;
+    xs[0] = 9;
+    xs[# - 1] = 7;
+    let i = 1;
+    xs[i] = xs[i] * 10;
+    if xs[0] == 9 && xs[1] == 20 && xs[2] == 7 && ys[0] == 1 && ys[2] == 3 then 0 else 1
+}
+"#,
+        "assign_list_element",
+    );
+}
+
+#[test]
+fn test_aot_assign_list_element_copies_only_shared_lists() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let xs = [1, 2, 3];
+    xs[0] = 10;
+    xs[1] = 20;
+    let ys = xs;
+    xs[2] = 30;
+    let first = () -> xs[0];
+    xs[0] = 40;
+    let shared_intact = ys[0] == 10 && ys[1] == 20 && ys[2] == 3 && first() == 10;
+    if shared_intact && xs[0] == 40 && xs[1] == 20 && xs[2] == 30 then 0 else 1
+}
+"#,
+        "assign_list_element_copies_only_shared_lists",
+    );
+}
+
+#[test]
+fn test_aot_assign_list_element_in_loop() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let xs = [0, 0, 0, 0];
+    for i in 0..10000 do xs[i % 4] = xs[i % 4] + i;
+    if xs[0] + xs[1] + xs[2] + xs[3] == 49995000 && xs[3] == 12502500 then 0 else 1
+}
+"#,
+        "assign_list_element_in_loop",
+    );
+}
+
+#[test]
+fn test_aot_map_index() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let m = {1: 100, 2: 200};
+    let hit = m[2].unwrap_or(default: 0);
+    let miss = m[3].is_none();
+    let s = {"a": 1, "b": 2};
+    let by_str = s["b"].unwrap_or(default: 0);
+    if hit == 200 && miss && by_str == 2 then 0 else 1
+}
+"#,
+        "map_index",
+    );
+}
+
+#[test]
+fn test_aot_map_index_hashed_keys() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let dup = {1: 10, 2: 20, 1: 30};
+    let merged = dup.len() == 2 && dup[1].unwrap_or(default: 0) == 30;
+    let chars = {'a': 1, 'b': 2, 'z': 26};
+    let by_char = chars['z'].unwrap_or(default: 0) == 26 && chars['c'].is_none();
+    let words = {"": 0, "one": 1, "two": 2, "three": 3, "four": 4, "five": 5};
+    let by_str = words["four"].unwrap_or(default: -1) == 4
+        && words[""].unwrap_or(default: -1) == 0 && words["six"].is_none();
+    let empty: {str: int} = {};
+    let none = empty["x"].is_none();
+    if merged && by_char && by_str && none then 0 else 1
+}
+"#,
+        "map_index_hashed_keys",
+    );
+}
+
+#[test]
+fn test_aot_list_survives_return() {
+    assert_aot_success(
+        r#"
+@make () -> [int] = [10, 20, 30];
+@pairs () -> [(int, int)] = [(1, 2), (3, 4), (5, 6)];
+@empty () -> [int] = [];
+
+@main () -> int = {
+    let xs = make();
+    let ps = pairs();
+    let (a, b) = ps[2];
+    if xs[1] == 20 && a == 5 && b == 6 && empty().len() == 0 then 0 else 1
+}
+"#,
+        "list_survives_return",
+    );
+}
+
+#[test]
+fn test_aot_string_ordering() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let lt = "abc" < "abd";
+    let prefix = !("abc" < "ab");
+    let empty = "" < "a";
+    let le = "ab" <= "ab";
+    let gt = "b" > "abc";
+    let ge = !("" >= "a");
+    if lt && prefix && empty && le && gt && ge then 0 else 1
+}
+"#,
+        "string_ordering",
+    );
+}
+
+#[test]
+fn test_aot_match_string_literals() {
+    assert_aot_success(
+        r#"
+@classify (s: str) -> int = match s {
+    "" -> 10,
+    "a" -> 20,
+    "ab" -> 30,
+    _ -> 40,
+}
+
+@main () -> int = {
+    let empty = classify(s: "") == 10;
+    let short = classify(s: "a") == 20 && classify(s: "ab") == 30;
+    let other = classify(s: "abc") == 40 && classify(s: "b") == 40;
+    if empty && short && other then 0 else 1
+}
+"#,
+        "match_string_literals",
+    );
+}
+
+#[test]
+fn test_aot_match_float_literals() {
+    assert_aot_success(
+        r#"
+@classify (x: float) -> int = match x {
+    0.0 -> 10,
+    1.5 -> 20,
+    -2.25 -> 30,
+    _ -> 40,
+}
+
+@main () -> int = {
+    let exact = classify(x: 0.0) == 10 && classify(x: 1.5) == 20 && classify(x: -2.25) == 30;
+    let other = classify(x: 2.0) == 40 && classify(x: 1.25) == 40;
+    if exact && other then 0 else 1
+}
+"#,
+        "match_float_literals",
+    );
+}
+
+#[test]
+fn test_aot_match_decision_tree() {
+    assert_aot_success(
+        r#"
+@grade (n: int) -> str = match n {
+    0 -> "zero",
+    1..10 -> "small",
+    x if x < 0 -> "negative",
+    _ -> "large",
+}
+
+@main () -> int = {
+    let ints = grade(n: 0) == "zero" && grade(n: 5) == "small"
+        && grade(n: -3) == "negative" && grade(n: 10) == "large";
+    let opt = match Some(7) { Some(v) if v > 5 -> v, Some(v) -> 0 - v, None -> -1 };
+    let res: Result<int, str> = Err("bad");
+    let err_len = match res { Ok(v) -> v, Err(e) -> e.len() };
+    let pair = (1, "x");
+    let tup = match pair { (0, _) -> 0, (1, "x") -> 7, _ -> 9 };
+    if ints && opt == 7 && err_len == 3 && tup == 7 then 0 else 1
+}
+"#,
+        "match_decision_tree",
+    );
+}
+
+#[test]
+fn test_aot_none_str_payload() {
+    assert_aot_success(
+        r#"
+@find (key: str) -> Option<str> = if key == "a" then Some("apple") else None;
+
+@fallback () -> Option<str> = None;
+
+@main () -> int = {
+    let none: Option<str> = None;
+    let items = [none, find(key: "a"), fallback(), find(key: "z")];
+    let total = 0;
+    for item in items do total = total + match item {
+        Some(s) -> s.len(),
+        None -> 100,
+    };
+    if total == 305 then 0 else 1
+}
+"#,
+        "none_str_payload",
+    );
+}
+
+#[test]
+fn test_aot_result_mixed_payloads() {
+    assert_aot_success(
+        r#"
+@check (ok: bool) -> Result<bool, str> = if ok then Ok(true) else Err("msg");
+
+@forward (ok: bool) -> Result<int, str> = {
+    let b = check(ok: ok)?;
+    Ok(if b then 1 else 0)
+}
+
+@main () -> int = {
+    let good = match check(ok: true) { Ok(b) -> b, Err(_) -> false };
+    let bad = match check(ok: false) { Ok(_) -> 0, Err(e) -> e.len() };
+    let fwd = match forward(ok: false) { Ok(_) -> "", Err(e) -> e };
+    let nested: Result<Option<Option<int>>, str> = Ok(Some(Some(42)));
+    let deep = match nested { Ok(Some(Some(v))) -> v, _ -> 0 };
+    let chars: Result<(char, char, char), int> = Ok(('a', 'b', 'c'));
+    let mid = match chars { Ok((_, c, _)) -> c == 'b', Err(_) -> false };
+    let num: Result<(char, char, char), int> = Err(9);
+    let n = match num { Ok(_) -> 0, Err(v) -> v };
+    if good && bad == 3 && fwd == "msg" && deep == 42 && mid && n == 9 then 0 else 1
+}
+"#,
+        "result_mixed_payloads",
+    );
+}
+
+#[test]
+fn test_aot_match_or_patterns() {
+    assert_aot_success(
+        r#"
+@classify (n: int) -> int = match n { 1 | 2 -> 100, _ -> 200 };
+
+@pick (t: (int, int)) -> int = match t {
+    (x, 0) | (0, x) -> x,
+    (1, 2) | (3, 4) -> 50,
+    _ -> -1,
+}
+
+@main () -> int = {
+    let ints = classify(n: 1) == 100 && classify(n: 2) == 100 && classify(n: 3) == 200;
+    let tuples = pick(t: (7, 0)) == 7 && pick(t: (0, 9)) == 9 && pick(t: (3, 4)) == 50
+        && pick(t: (1, 4)) == -1;
+    if ints && tuples then 0 else 1
+}
+"#,
+        "match_or_patterns",
+    );
+}
+
+#[test]
+fn test_aot_match_tuple_literals() {
+    assert_aot_success(
+        r#"
+@pick (t: (int, int)) -> int = match t {
+    (1, _) -> 10,
+    (2, y) -> 20 + y,
+    _ -> 0,
+}
+
+@nested (t: ((int, bool), int)) -> int = match t {
+    ((1, true), z) -> z,
+    ((1, false), _) -> -1,
+    ((x, _), _) -> x * 100,
+}
+
+@main () -> int = {
+    let flat = pick(t: (1, 5)) == 10 && pick(t: (2, 5)) == 25 && pick(t: (3, 5)) == 0;
+    let deep = nested(t: ((1, true), 7)) == 7 && nested(t: ((1, false), 7)) == -1
+        && nested(t: ((4, true), 7)) == 400;
+    if flat && deep then 0 else 1
+}
+"#,
+        "match_tuple_literals",
+    );
+}
+
 #[test]
 fn test_aot_complex_expression() {
     assert_aot_success(
@@ -940,3 +1478,260 @@ fn test_aot_try_chained_first_fails() {
         "try_chained_first_fails",
     );
 }
+
+// Recursion Patterns
+
+#[test]
+fn test_aot_recurse_memo_caches_self_calls() {
+    // Exponential without the cache; returns at once with it.
+    assert_aot_success(
+        r#"
+@fib (n: int) -> int = recurse(
+    condition: n <= 1,
+    base: n,
+    step: self(n - 1) + self(n - 2),
+    memo: true,
+);
+
+@main () -> int = if fib(n: 80) == 23416728348467685 && fib(n: 10) == 55 then 0 else 1;
+"#,
+        "recurse_memo_caches_self_calls",
+    );
+}
+
+// Cache Pattern
+
+#[test]
+fn test_aot_cache_hit_skips_op() {
+    // The second call hits, so its panicking `op` never runs.
+    assert_aot_success(
+        r#"
+@square_once (key: str, x: int, fresh: bool) -> int =
+    cache(key: key, op: if fresh then x * x else panic(msg: "op re-evaluated on a hit"), ttl: 5m);
+
+@main () -> int = {
+    let first = square_once(key: "hit-7", x: 7, fresh: true);
+    let second = square_once(key: "hit-7", x: 7, fresh: false);
+    if first == 49 && second == 49 then 0 else 1
+}
+"#,
+        "cache_hit_skips_op",
+    );
+}
+
+#[test]
+fn test_aot_cache_sites_independent() {
+    // Equal keys at two `cache` expressions don't see each other's values.
+    assert_aot_success(
+        r#"
+@age_of (id: int) -> int = cache(key: id, op: id * 10, ttl: 5m);
+
+@double_of (id: int) -> int = cache(key: id, op: id * 2, ttl: 5m);
+
+@main () -> int =
+    if age_of(id: 1) == 10 && double_of(id: 1) == 2 && age_of(id: 1) == 10 then 0 else 1;
+"#,
+        "cache_sites_independent",
+    );
+}
+
+#[test]
+fn test_aot_cache_zero_ttl_recomputes() {
+    assert_aot_success(
+        r#"
+@cached (value: int) -> int = cache(key: "zero", op: value, ttl: 0s);
+
+@main () -> int = if cached(value: 4) == 4 && cached(value: 9) == 9 then 0 else 1;
+"#,
+        "cache_zero_ttl_recomputes",
+    );
+}
+
+// Concurrency Patterns
+
+#[test]
+fn test_aot_parallel_combines_results() {
+    assert_aot_success(
+        r#"
+@square (n: int) -> int = n * n;
+
+@main () -> int = {
+    let offset = 100;
+    let results = parallel(tasks: [() -> square(n: 3), () -> square(n: 4) + offset]);
+    let total = 0;
+    for r in results do match r {
+        Ok(v) -> total = total + v,
+        Err(_) -> total = -1000,
+    };
+    if results.len() == 2 && total == 125 then 0 else 1
+}
+"#,
+        "parallel_combines_results",
+    );
+}
+
+#[test]
+fn test_aot_parallel_panicking_task_yields_err() {
+    assert_aot_success(
+        r#"
+@check (n: int) -> int = if n < 0 then panic(msg: "negative") else n;
+
+@main () -> int = {
+    let results = parallel(tasks: [() -> check(n: 1), () -> check(n: -1), () -> check(n: 3)]);
+    let total = 0;
+    let message = "";
+    for r in results do match r {
+        Ok(v) -> total = total + v,
+        Err(e) -> message = e,
+    };
+    if total == 4 && message == "negative" then 0 else 1
+}
+"#,
+        "parallel_panicking_task_yields_err",
+    );
+}
+
+#[test]
+fn test_aot_parallel_computed_task_list_with_limits() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let tasks = for i in 1..=4 yield () -> i * i;
+    let results = parallel(tasks: tasks, max_concurrent: Some(2), timeout: Some(5s));
+    let total = 0;
+    for r in results do match r {
+        Ok(v) -> total = total + v,
+        Err(_) -> total = -1000,
+    };
+    if results.len() == 4 && total == 30 then 0 else 1
+}
+"#,
+        "parallel_computed_task_list_with_limits",
+    );
+}
+
+#[test]
+fn test_aot_timeout_fast_body_returns_ok() {
+    assert_aot_success(
+        r#"
+@square (n: int) -> int = n * n;
+
+@main () -> int = {
+    let base = 20;
+    let r = timeout(op: square(n: 2) * 10 + base / 10, after: 5s);
+    match r {
+        Ok(v) -> if v == 42 then 0 else 1,
+        Err(_) -> 2,
+    }
+}
+"#,
+        "timeout_fast_body_returns_ok",
+    );
+}
+
+// Capability Provision
+
+#[test]
+fn test_aot_with_capability_provides_value() {
+    assert_aot_success(
+        r#"
+type MockCounter = { count: int }
+
+impl MockCounter {
+    @get (self) -> int = self.count;
+}
+
+@main () -> int = {
+    let outer = MockCounter { count: 7 };
+    let inner = MockCounter { count: 900 };
+    let r = with Counter = outer in {
+        let shadowed = with Counter = inner in Counter.get();
+        let f = (x: int) -> x + Counter.get();
+        shadowed + Counter.get() + f(0)
+    };
+    if r == 914 then 0 else 1
+}
+"#,
+        "with_capability_provides_value",
+    );
+}
+
+#[test]
+fn test_aot_capability_reaches_uses_function() {
+    // Mirrors tests/spec/capabilities/providing.ori
+    // `test_capability_through_function`, plus forwarding, shadowing,
+    // capture, and a self tail call inside a `uses` function.
+    assert_aot_success(
+        r#"
+@uses_cap () -> int uses Value = Value;
+@forwards () -> int uses Value = uses_cap() + 1;
+@overrides () -> int uses Value = with Value = 100 in uses_cap();
+@captures () -> int uses Value = {
+    let double = () -> Value * 2;
+    double()
+}
+@count_down (n: int) -> int uses Value = if n == 0 then Value else count_down(n: n - 1);
+
+@main () -> int = {
+    let direct = with Value = 42 in uses_cap();
+    let forwarded = with Value = 5 in forwards();
+    let overridden = with Value = 1 in overrides();
+    let captured = with Value = 4 in captures();
+    let counted = with Value = 3 in count_down(n: 10);
+    if direct == 42 && forwarded == 6 && overridden == 100 && captured == 8 && counted == 3 then 0 else 1
+}
+"#,
+        "capability_reaches_uses_function",
+    );
+}
+
+// Tail Calls
+
+#[test]
+fn test_aot_self_tail_call_runs_in_constant_stack() {
+    // One million frames would overflow the default stack without
+    // tail-call optimization.
+    assert_aot_success(
+        r#"
+@countdown (n: int, acc: int) -> int =
+    if n == 0 then acc else countdown(n: n - 1, acc: acc + 1);
+
+@main () -> int = {
+    let r = countdown(n: 1000000, acc: 0);
+    if r == 1000000 then 0 else 1
+}
+"#,
+        "self_tail_call_constant_stack",
+    );
+}
+
+#[test]
+fn test_aot_template_interpolation() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let x = 5;
+    let s = `x={x}, {{x}}`;
+    if s == "x=5, {x}" then 0 else 1
+}
+"#,
+        "template_interpolation",
+    );
+}
+
+#[test]
+fn test_aot_module_constants() {
+    assert_aot_success(
+        r#"
+let $limit = 10;
+let $double = $limit * 2;
+
+@limit_plus_one () -> int = $limit + 1;
+
+@main () -> int = {
+    if limit_plus_one() == 11 && $double == 20 then 0 else 1
+}
+"#,
+        "module_constants",
+    );
+}
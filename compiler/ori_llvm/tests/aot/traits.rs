@@ -86,6 +86,33 @@ fn test_aot_string_len_empty() {
     );
 }
 
+#[test]
+fn test_aot_map_len() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let m = {"a": 1, "b": 2, "c": 3};
+    if m.len() == 3 then 0 else 1
+}
+"#,
+        "map_len",
+    );
+}
+
+#[test]
+fn test_aot_map_is_empty() {
+    assert_aot_success(
+        r#"
+@main () -> int = {
+    let full = {1: "one"};
+    let empty: {int: str} = {};
+    if !full.is_empty() && empty.is_empty() then 0 else 1
+}
+"#,
+        "map_is_empty",
+    );
+}
+
 // 3.0.2: IsEmpty Trait — .is_empty() codegen
 
 #[test]
@@ -289,6 +289,15 @@ impl<'a> Cursor<'a> {
             && self.next_is_adjacent()
     }
 
+    /// Check if looking at `*` followed immediately by `*` (no whitespace).
+    /// Used for detecting `**` power operator in expression context.
+    pub fn is_power(&self) -> bool {
+        self.current_tag() == TokenKind::TAG_STAR
+            && self.pos + 1 < self.tags.len()
+            && self.tags[self.pos + 1] == TokenKind::TAG_STAR
+            && self.next_is_adjacent()
+    }
+
     /// Consume two adjacent tokens as a compound operator.
     /// Returns the combined span.
     /// Panics if not at the expected tokens.
@@ -0,0 +1,37 @@
+//! Attaching lexed doc comments to parsed declarations.
+
+use ori_ir::Span;
+
+use crate::ParseOutput;
+
+impl ParseOutput {
+    /// Attach each doc comment block in `metadata` to the declaration below it.
+    ///
+    /// [`parse_with_metadata`](crate::parse_with_metadata) runs this; look the
+    /// result up with `metadata.attached_docs(decl.span)`.
+    pub fn attach_doc_comments(&mut self) {
+        let decls = self.declaration_spans();
+        self.metadata.attach_doc_comments(&decls);
+    }
+
+    /// Spans of every top-level declaration, sorted by start position.
+    fn declaration_spans(&self) -> Vec<Span> {
+        let module = &self.module;
+        let mut spans: Vec<Span> = module
+            .consts
+            .iter()
+            .map(|c| c.span)
+            .chain(module.functions.iter().map(|f| f.span))
+            .chain(module.tests.iter().map(|t| t.span))
+            .chain(module.types.iter().map(|t| t.span))
+            .chain(module.traits.iter().map(|t| t.span))
+            .chain(module.impls.iter().map(|i| i.span))
+            .chain(module.def_impls.iter().map(|d| d.span))
+            .chain(module.extends.iter().map(|e| e.span))
+            .chain(module.extension_imports.iter().map(|e| e.span))
+            .chain(module.extern_blocks.iter().map(|e| e.span))
+            .collect();
+        spans.sort_unstable_by_key(|span| span.start);
+        spans
+    }
+}
@@ -63,6 +63,13 @@ pub enum ParseWarning {
         /// The convention string that was used.
         convention: String,
     },
+    /// A line indent that mixes tabs and spaces.
+    ///
+    /// Reported from the opt-in lexer indentation check.
+    MixedIndentation {
+        /// The leading whitespace run of the line.
+        span: Span,
+    },
 }
 
 impl ParseWarning {
@@ -71,11 +78,17 @@ impl ParseWarning {
         ParseWarning::DetachedDocComment { span, reason }
     }
 
+    /// Create a warning for an indent that mixes tabs and spaces.
+    pub fn mixed_indentation(span: Span) -> Self {
+        ParseWarning::MixedIndentation { span }
+    }
+
     /// Get the span of the warning.
     pub fn span(&self) -> Span {
         match self {
             ParseWarning::DetachedDocComment { span, .. }
-            | ParseWarning::UnknownCallingConvention { span, .. } => *span,
+            | ParseWarning::UnknownCallingConvention { span, .. }
+            | ParseWarning::MixedIndentation { span } => *span,
         }
     }
 
@@ -84,6 +97,7 @@ impl ParseWarning {
         match self {
             ParseWarning::DetachedDocComment { .. } => "DETACHED DOC COMMENT",
             ParseWarning::UnknownCallingConvention { .. } => "UNKNOWN CALLING CONVENTION",
+            ParseWarning::MixedIndentation { .. } => "MIXED INDENTATION",
         }
     }
 
@@ -99,6 +113,9 @@ impl ParseWarning {
             ParseWarning::UnknownCallingConvention { convention, .. } => {
                 format!("unknown calling convention \"{convention}\"; expected \"c\" or \"js\"")
             }
+            ParseWarning::MixedIndentation { .. } => {
+                "indentation mixes tabs and spaces; use one or the other".to_string()
+            }
         }
     }
 
@@ -113,6 +130,9 @@ impl ParseWarning {
                     .with_message(self.message())
                     .with_label(self.span(), format!("unknown convention \"{convention}\""))
             }
+            ParseWarning::MixedIndentation { .. } => Diagnostic::warning(ErrorCode::W1003)
+                .with_message(self.message())
+                .with_label(self.span(), "tabs and spaces mixed here"),
         }
     }
 }
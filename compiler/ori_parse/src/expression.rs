@@ -0,0 +1,45 @@
+//! Standalone expression parsing.
+//!
+//! Entry point for tools (REPL, snippet evaluators) that parse a single
+//! expression without a surrounding module.
+
+use ori_ir::{ExprId, SharedArena, StringInterner, TokenList};
+
+use crate::error::ParseErrorKind;
+use crate::{ParseError, Parser};
+
+/// Parse tokens as a single expression.
+///
+/// Returns the root expression, the arena holding it, and any errors. Tokens
+/// left over after the expression are reported as an error rather than
+/// ignored. On empty input or a failed parse the root is [`ExprId::INVALID`].
+pub fn parse_expression(
+    tokens: &TokenList,
+    interner: &StringInterner,
+) -> (ExprId, SharedArena, Vec<ParseError>) {
+    let mut parser = Parser::new(tokens, interner);
+    let mut errors = Vec::new();
+
+    parser.cursor.skip_newlines();
+    let root = match parser.parse_expr().into_result() {
+        Ok(id) => {
+            parser.cursor.skip_newlines();
+            if !parser.cursor.is_at_end() {
+                let kind = ParseErrorKind::UnexpectedToken {
+                    found: parser.cursor.current_kind().clone(),
+                    expected: "end of expression",
+                    context: None,
+                };
+                errors.push(ParseError::from_kind(&kind, parser.cursor.current_span()));
+            }
+            id
+        }
+        Err(error) => {
+            errors.push(error);
+            ExprId::INVALID
+        }
+    };
+
+    errors.append(&mut parser.deferred_errors);
+    (root, SharedArena::new(parser.arena), errors)
+}
@@ -0,0 +1,69 @@
+//! Diagnostics for `await`.
+//!
+//! Ori has no `await` (spec §14, Suspend Capability): a function that may
+//! suspend declares `uses Suspend`, and calling it needs no marker. `await`
+//! lexes as a plain identifier, so both the prefix form (`await fetch(url)`)
+//! and the postfix form (`fetch(url).await`) would otherwise surface as
+//! confusing syntax or field errors. Both are reported here and parsing
+//! continues with the operand alone.
+
+use crate::{ParseError, ParseOutcome, Parser};
+use ori_diagnostic::ErrorCode;
+use ori_ir::{ExprId, Name, Span, TokenKind};
+
+const AWAIT_HELP: &str = "call the function directly; the caller declares `uses Suspend`";
+
+impl Parser<'_> {
+    /// Whether the cursor is at a prefix `await expr`.
+    ///
+    /// Requires an identifier or literal operand on the same line, which
+    /// can never follow a plain identifier, so `await` stays usable as a
+    /// variable or function name (`await`, `await(x)`).
+    pub(crate) fn at_prefix_await(&self) -> bool {
+        let TokenKind::Ident(name) = *self.cursor.current_kind() else {
+            return false;
+        };
+        self.is_await(name)
+            && matches!(
+                self.cursor.peek_next_kind(),
+                TokenKind::Ident(_)
+                    | TokenKind::SelfLower
+                    | TokenKind::Int(_)
+                    | TokenKind::Float(_)
+                    | TokenKind::String(_)
+                    | TokenKind::Char(_)
+            )
+    }
+
+    /// Report a prefix `await` and parse its operand in its place.
+    pub(crate) fn parse_prefix_await(&mut self) -> ParseOutcome<ExprId> {
+        let span = self.cursor.current_span();
+        self.cursor.advance(); // await
+        self.report_await(span);
+        self.parse_primary()
+    }
+
+    /// Report a postfix `.await` field access. The receiver stands in for it.
+    pub(crate) fn report_postfix_await(&mut self, field: Name, dot_span: Span) -> bool {
+        if !self.is_await(field) {
+            return false;
+        }
+        self.report_await(dot_span.merge(self.cursor.previous_span()));
+        true
+    }
+
+    fn is_await(&self, name: Name) -> bool {
+        self.cursor.interner().lookup(name) == "await"
+    }
+
+    fn report_await(&mut self, span: Span) {
+        self.deferred_errors.push(
+            ParseError::new(
+                ErrorCode::E1015,
+                "`await` is not supported: Ori has no async functions to await",
+                span,
+            )
+            .with_help(AWAIT_HELP),
+        );
+    }
+}
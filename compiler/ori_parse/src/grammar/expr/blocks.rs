@@ -5,15 +5,17 @@
 //! `parse_block_expr_body()` (block expressions) and `parse_try_block()`
 //! (try expressions) to eliminate duplicated parsing logic.
 
+use crate::recovery::{expect_closing, unclosed_delimiter_error};
 use crate::{committed, require, ParseError, ParseOutcome, Parser};
 use ori_ir::{BindingPattern, ExprId, Mutability, ParsedTypeId, Span, Stmt, StmtKind, TokenKind};
 
 impl Parser<'_> {
     /// Collect statements inside a `{ ... }` block until `}`.
     ///
-    /// Assumes `{` has already been consumed. Skips initial newlines,
-    /// parses a sequence of let bindings and expression statements, and
-    /// consumes the closing `}`.
+    /// Assumes `{` (at `open_span`) has already been consumed. Skips initial
+    /// newlines, parses a sequence of let bindings and expression statements,
+    /// and consumes the closing `}`. Running into the next function or EOF
+    /// reports the `{` as unclosed.
     ///
     /// Returns `(stmts, result, end_span)` where:
     /// - `stmts`: collected statement nodes (caller must batch-push to arena)
@@ -22,6 +24,7 @@ impl Parser<'_> {
     pub(super) fn collect_block_stmts(
         &mut self,
         block_name: &str,
+        open_span: Span,
     ) -> ParseOutcome<(Vec<Stmt>, ExprId, Span)> {
         self.cursor.skip_newlines();
 
@@ -69,6 +72,10 @@ impl Parser<'_> {
                 } else if self.cursor.check(&TokenKind::RBrace) || self.cursor.is_at_end() {
                     // Expression at end without `;` → this is the result
                     last_expr = Some(expr);
+                } else if let Some(err) =
+                    unclosed_delimiter_error(&self.cursor, &TokenKind::LBrace, open_span)
+                {
+                    return ParseOutcome::consumed_err(err, item_span);
                 } else {
                     return ParseOutcome::consumed_err(
                         ParseError::new(
@@ -87,8 +94,11 @@ impl Parser<'_> {
             self.cursor.skip_newlines();
         }
 
-        let end_span = self.cursor.current_span();
-        committed!(self.cursor.expect(&TokenKind::RBrace));
+        let end_span = committed!(expect_closing(
+            &mut self.cursor,
+            &TokenKind::LBrace,
+            open_span
+        ));
 
         let result = last_expr.unwrap_or(ExprId::INVALID);
         ParseOutcome::consumed_ok((stmts, result, end_span))
@@ -0,0 +1,54 @@
+//! Lambda return type annotations.
+//!
+//! A lambda's return type sits between `->` and `=`: `(x: int) -> int = x + 1`.
+//! Without one, whatever follows `->` is the body. Primitive type keywords
+//! can only start a type, so they commit immediately. Other type forms
+//! (`Point`, `[int]`, `{str: int}`, `(int, int)`) are recognized by parsing
+//! speculatively and checking for the `=` that must follow the type.
+
+use crate::{ParseError, Parser};
+use ori_ir::{ParsedTypeId, TokenKind};
+
+impl Parser<'_> {
+    /// Parse an optional lambda return type, positioned just after `->`.
+    ///
+    /// Returns `ParsedTypeId::INVALID` when the lambda has no annotation.
+    /// A bare lowercase identifier is never taken as a type: `() -> n = n + 1`
+    /// keeps its meaning as a lambda whose body assigns to `n`.
+    pub(crate) fn parse_lambda_ret_ty(&mut self) -> Result<ParsedTypeId, ParseError> {
+        if self.cursor.check_type_keyword() {
+            let ty = self.parse_type();
+            self.cursor.expect(&TokenKind::Eq)?;
+            return Ok(ty.map_or(ParsedTypeId::INVALID, |t| self.arena.alloc_parsed_type(t)));
+        }
+        if !self.may_start_lambda_ret_ty() {
+            return Ok(ParsedTypeId::INVALID);
+        }
+
+        // Speculative: a type followed by `=` is a return annotation
+        let snapshot = self.snapshot();
+        let errors_before = self.deferred_errors.len();
+        if let Some(ty) = self.parse_type() {
+            if self.cursor.check(&TokenKind::Eq) {
+                self.cursor.advance(); // =
+                return Ok(self.arena.alloc_parsed_type(ty));
+            }
+        }
+        self.deferred_errors.truncate(errors_before);
+        self.restore(snapshot);
+        Ok(ParsedTypeId::INVALID)
+    }
+
+    /// Whether the current token can start a non-keyword return type.
+    fn may_start_lambda_ret_ty(&self) -> bool {
+        match self.cursor.current_kind() {
+            TokenKind::Ident(name) => self
+                .cursor
+                .interner()
+                .lookup(*name)
+                .starts_with(|c: char| c.is_ascii_uppercase()),
+            TokenKind::LBracket | TokenKind::LBrace | TokenKind::LParen => true,
+            _ => false,
+        }
+    }
+}
@@ -13,13 +13,16 @@
 //! # Module Structure
 //!
 //! - `mod.rs`: Entry point (`parse_expr`) and Pratt parser for binary operators
+//! - `await_syntax.rs`: Diagnostics for `await`, which Ori does not have
 //! - `blocks.rs`: Shared block-statement parsing (used by block and try)
 //! - `operators.rs`: Operator matching helpers and binding power table
 //! - `primary.rs`: Literals, identifiers, variant constructors
 //! - `postfix.rs`: Call, method call, field, index
 //! - `patterns.rs`: try, match, for, `function_exp`
 
+mod await_syntax;
 mod blocks;
+mod lambda;
 mod operators;
 mod patterns;
 mod postfix;
@@ -60,6 +63,8 @@ pub(super) mod bp {
     pub const ADDITIVE: (u8, u8) = (21, 22);
     /// Multiplicative `*` `/` `%` `div`
     pub const MULTIPLICATIVE: (u8, u8) = (23, 24);
+    /// Power `**` (right-associative)
+    pub const POWER: (u8, u8) = (26, 25);
 
     /// Minimum binding power for parsing without comparison operators.
     /// Used by `parse_non_comparison_expr` for contexts where `<`/`>`
@@ -225,22 +230,18 @@ impl Parser<'_> {
             ExprId::INVALID
         };
 
-        // Compute span from start to end/step
-        let span = if step.is_present() {
-            self.arena
-                .get_expr(left)
-                .span
-                .merge(self.arena.get_expr(step).span)
-        } else if end.is_present() {
-            self.arena
-                .get_expr(left)
-                .span
-                .merge(self.arena.get_expr(end).span)
+        // Span covers the start and whichever of end/step are present
+        let left_span = self.arena.get_expr(left).span;
+        let span = if end.is_present() || step.is_present() {
+            left_span.merge_all(
+                [end, step]
+                    .into_iter()
+                    .filter(|id| id.is_present())
+                    .map(|id| self.arena.get_expr(id).span),
+            )
         } else {
-            self.arena
-                .get_expr(left)
-                .span
-                .merge(self.cursor.previous_span())
+            // Open-ended: cover through the `..` operator
+            left_span.merge(self.cursor.previous_span())
         };
 
         Ok(self.arena.alloc_expr(Expr::new(
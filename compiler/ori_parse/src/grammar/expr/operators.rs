@@ -19,6 +19,9 @@
 //!
 //! - `>` followed by `>` (no whitespace) → `>>` (right shift)
 //! - `>` followed by `=` (no whitespace) → `>=` (greater-equal)
+//!
+//! The lexer has no `**` token either; `*` followed by `*` (no whitespace)
+//! is combined into `**` (power) the same way.
 
 use super::bp;
 use crate::context::ParseContext;
@@ -147,6 +150,11 @@ impl Parser<'_> {
             return None;
         }
 
+        // Special case: Star may be compound **
+        if tag == TokenKind::TAG_STAR && self.cursor.is_power() {
+            return Some((bp::POWER.0, bp::POWER.1, BinaryOp::Pow, 2));
+        }
+
         // Special case: Gt may be compound >= or >>
         if tag == TokenKind::TAG_GT {
             if self.cursor.is_greater_equal() {
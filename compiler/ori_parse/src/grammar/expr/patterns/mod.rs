@@ -5,6 +5,8 @@
 //! Match pattern parsing uses `one_of!` for automatic backtracking across
 //! pattern alternatives (wildcard, literal, ident, struct, list, variant, tuple).
 
+mod range;
+
 use crate::context::ParseContext;
 use crate::recovery::TokenSet;
 use crate::{committed, one_of, require, ParseError, ParseOutcome, Parser};
@@ -19,6 +21,7 @@ use ori_ir::{
 const PATTERN_LITERAL_TOKENS: TokenSet = TokenSet::new()
     .with(TokenKind::Minus)
     .with(TokenKind::Int(0))
+    .with(TokenKind::Float(0))
     .with(TokenKind::True)
     .with(TokenKind::False)
     .with(TokenKind::String(Name::EMPTY))
@@ -55,11 +58,11 @@ impl Parser<'_> {
     /// with auto-unwrap semantics on let bindings.
     fn parse_try_block(&mut self) -> ParseOutcome<ExprId> {
         let start_span = self.cursor.previous_span();
-        committed!(self.cursor.expect(&TokenKind::LBrace));
+        let open_span = committed!(self.cursor.expect(&TokenKind::LBrace)).span;
 
         let (stmts_vec, result, end_span) = require!(
             self,
-            self.collect_block_stmts("try block"),
+            self.collect_block_stmts("try block", open_span),
             "try block body"
         );
 
@@ -433,46 +436,12 @@ impl Parser<'_> {
         ParseOutcome::consumed_ok(MatchPattern::Wildcard)
     }
 
-    /// Parse literal patterns: integers (possibly negative), booleans, strings.
-    /// Also handles range patterns: `1..10`, `1..=10`.
-    #[expect(
-        clippy::too_many_lines,
-        reason = "exhaustive literal and range pattern dispatch across all token kinds"
-    )]
+    /// Parse literal patterns: numbers (possibly negative), booleans, strings.
+    /// Also handles range patterns: `1..10`, `1..=10`, `1..`, `..10`.
     fn parse_pattern_literal(&mut self) -> ParseOutcome<MatchPattern> {
         match *self.cursor.current_kind() {
-            // Negative integer literal: -42
-            TokenKind::Minus => {
-                let start_span = self.cursor.current_span();
-                self.cursor.advance();
-                if let TokenKind::Int(n) = *self.cursor.current_kind() {
-                    self.cursor.advance();
-                    let Ok(value) = i64::try_from(n) else {
-                        return ParseOutcome::consumed_err(
-                            ParseError::new(
-                                ori_diagnostic::ErrorCode::E1002,
-                                "integer literal too large",
-                                start_span,
-                            ),
-                            start_span,
-                        );
-                    };
-                    let span = start_span.merge(self.cursor.previous_span());
-                    ParseOutcome::consumed_ok(MatchPattern::Literal(
-                        self.arena
-                            .alloc_expr(Expr::new(ExprKind::Int(-value), span)),
-                    ))
-                } else {
-                    ParseOutcome::consumed_err(
-                        ParseError::new(
-                            ori_diagnostic::ErrorCode::E1002,
-                            "expected integer after `-` in pattern",
-                            self.cursor.current_span(),
-                        ),
-                        start_span,
-                    )
-                }
-            }
+            // Negative numeric literal: -42, -1.5
+            TokenKind::Minus => self.parse_pattern_negative_literal(),
 
             // Positive integer literal: 42 (with possible range)
             TokenKind::Int(n) => {
@@ -490,31 +459,11 @@ impl Parser<'_> {
                 };
 
                 // Check for range pattern: 1..10 or 1..=10
-                if self.cursor.check(&TokenKind::DotDot) || self.cursor.check(&TokenKind::DotDotEq)
-                {
-                    let inclusive = self.cursor.check(&TokenKind::DotDotEq);
-                    self.cursor.advance();
+                if self.is_range_op() {
                     let start_expr = self
                         .arena
                         .alloc_expr(Expr::new(ExprKind::Int(value), pat_span));
-
-                    // Parse end of range (optional for open-ended ranges)
-                    let end = if self.is_range_bound_start() {
-                        match self.parse_range_bound() {
-                            Ok(e) => Some(e),
-                            Err(err) => {
-                                return ParseOutcome::consumed_err(err, pat_span);
-                            }
-                        }
-                    } else {
-                        None
-                    };
-
-                    return ParseOutcome::consumed_ok(MatchPattern::Range {
-                        start: Some(start_expr),
-                        end,
-                        inclusive,
-                    });
+                    return self.finish_range_pattern(start_expr, pat_span);
                 }
 
                 ParseOutcome::consumed_ok(MatchPattern::Literal(
@@ -522,6 +471,13 @@ impl Parser<'_> {
                         .alloc_expr(Expr::new(ExprKind::Int(value), self.cursor.previous_span())),
                 ))
             }
+            TokenKind::Float(bits) => {
+                self.cursor.advance();
+                ParseOutcome::consumed_ok(MatchPattern::Literal(self.arena.alloc_expr(Expr::new(
+                    ExprKind::Float(bits),
+                    self.cursor.previous_span(),
+                ))))
+            }
             TokenKind::True => {
                 self.cursor.advance();
                 ParseOutcome::consumed_ok(MatchPattern::Literal(
@@ -548,30 +504,11 @@ impl Parser<'_> {
                 self.cursor.advance();
 
                 // Check for range pattern: 'a'..'z' or 'a'..='z'
-                if self.cursor.check(&TokenKind::DotDot) || self.cursor.check(&TokenKind::DotDotEq)
-                {
-                    let inclusive = self.cursor.check(&TokenKind::DotDotEq);
-                    self.cursor.advance();
+                if self.is_range_op() {
                     let start_expr = self
                         .arena
                         .alloc_expr(Expr::new(ExprKind::Char(c), pat_span));
-
-                    let end = if self.is_range_bound_start() {
-                        match self.parse_range_bound() {
-                            Ok(e) => Some(e),
-                            Err(err) => {
-                                return ParseOutcome::consumed_err(err, pat_span);
-                            }
-                        }
-                    } else {
-                        None
-                    };
-
-                    return ParseOutcome::consumed_ok(MatchPattern::Range {
-                        start: Some(start_expr),
-                        end,
-                        inclusive,
-                    });
+                    return self.finish_range_pattern(start_expr, pat_span);
                 }
 
                 ParseOutcome::consumed_ok(MatchPattern::Literal(
@@ -579,6 +516,8 @@ impl Parser<'_> {
                         .alloc_expr(Expr::new(ExprKind::Char(c), self.cursor.previous_span())),
                 ))
             }
+            // Range with no lower bound: ..10 or ..=10
+            TokenKind::DotDot | TokenKind::DotDotEq => self.parse_pattern_prefix_range(),
             _ => ParseOutcome::empty_err(
                 PATTERN_LITERAL_TOKENS,
                 self.cursor.current_span().start as usize,
@@ -586,6 +525,53 @@ impl Parser<'_> {
         }
     }
 
+    /// Parse a negated numeric literal pattern: `-42`, `-1.5`, or a range
+    /// starting at a negative integer (`-10..0`).
+    fn parse_pattern_negative_literal(&mut self) -> ParseOutcome<MatchPattern> {
+        let start_span = self.cursor.current_span();
+        self.cursor.advance();
+        if let TokenKind::Int(n) = *self.cursor.current_kind() {
+            self.cursor.advance();
+            // Negate before narrowing so `-9223372036854775808` fits.
+            let Ok(value) = i64::try_from(-i128::from(n)) else {
+                return ParseOutcome::consumed_err(
+                    ParseError::new(
+                        ori_diagnostic::ErrorCode::E1002,
+                        "integer literal too large",
+                        start_span,
+                    ),
+                    start_span,
+                );
+            };
+            let span = start_span.merge(self.cursor.previous_span());
+            let expr = self.arena.alloc_expr(Expr::new(ExprKind::Int(value), span));
+
+            // Check for range pattern: -10..0 or -10..=-1
+            if self.is_range_op() {
+                return self.finish_range_pattern(expr, span);
+            }
+
+            ParseOutcome::consumed_ok(MatchPattern::Literal(expr))
+        } else if let TokenKind::Float(bits) = *self.cursor.current_kind() {
+            self.cursor.advance();
+            let negated = (-f64::from_bits(bits)).to_bits();
+            let span = start_span.merge(self.cursor.previous_span());
+            ParseOutcome::consumed_ok(MatchPattern::Literal(
+                self.arena
+                    .alloc_expr(Expr::new(ExprKind::Float(negated), span)),
+            ))
+        } else {
+            ParseOutcome::consumed_err(
+                ParseError::new(
+                    ori_diagnostic::ErrorCode::E1002,
+                    "expected number after `-` in pattern",
+                    self.cursor.current_span(),
+                ),
+                start_span,
+            )
+        }
+    }
+
     /// Parse identifier pattern: binding, at-pattern, named variant, or named struct.
     fn parse_pattern_ident(&mut self) -> ParseOutcome<MatchPattern> {
         let TokenKind::Ident(name) = *self.cursor.current_kind() else {
@@ -677,6 +663,10 @@ impl Parser<'_> {
                     rest = Some(Name::EMPTY);
                 }
                 // Rest must be last
+                if !self.cursor.check(&TokenKind::RBracket) {
+                    let err = self.misplaced_rest_pattern_error();
+                    return ParseOutcome::consumed_err(err, self.cursor.current_span());
+                }
                 break;
             }
 
@@ -702,6 +692,46 @@ impl Parser<'_> {
         ParseOutcome::consumed_ok(MatchPattern::List { elements, rest })
     }
 
+    /// Error for `..` inside a tuple pattern; only list patterns have a rest.
+    #[cold]
+    pub(super) fn tuple_rest_pattern_error(&self) -> ParseError {
+        ParseError::new(
+            ori_diagnostic::ErrorCode::E1008,
+            "rest patterns (`..`) are only allowed in list patterns",
+            self.cursor.current_span(),
+        )
+        .with_help("Match each tuple element, using `_` for the ones you don't need")
+    }
+
+    /// Error for anything but `]` after a list rest pattern.
+    ///
+    /// Distinguishes a second `..` from elements following the rest.
+    #[cold]
+    pub(super) fn misplaced_rest_pattern_error(&self) -> ParseError {
+        let after_comma = if self.cursor.check(&TokenKind::Comma) {
+            self.cursor.peek_kind_at(1)
+        } else {
+            self.cursor.current_kind()
+        };
+        let (message, help) = if *after_comma == TokenKind::DotDot {
+            (
+                "a list pattern can have at most one rest pattern (`..`)",
+                "Remove the extra `..`",
+            )
+        } else {
+            (
+                "the rest pattern (`..`) must be the last element of a list pattern",
+                "Move `..` to the end: `[first, second, ..rest]`",
+            )
+        };
+        ParseError::new(
+            ori_diagnostic::ErrorCode::E1008,
+            message,
+            self.cursor.current_span(),
+        )
+        .with_help(help)
+    }
+
     /// Parse builtin variant patterns: `Some(x)`, `None`, `Ok(x)`, `Err(x)`
     fn parse_pattern_builtin_variant(&mut self) -> ParseOutcome<MatchPattern> {
         let (name_str, has_inner) = match *self.cursor.current_kind() {
@@ -758,6 +788,9 @@ impl Parser<'_> {
             if p.cursor.check(&TokenKind::RParen) {
                 return Ok(false);
             }
+            if p.cursor.check(&TokenKind::DotDot) && !p.is_prefix_range_pattern() {
+                return Err(p.tuple_rest_pattern_error());
+            }
             let pat = p.parse_match_pattern()?;
             elements.push(p.arena.alloc_match_pattern(pat));
             Ok(true)
@@ -947,14 +980,6 @@ impl Parser<'_> {
         Ok(MatchPattern::Struct { fields, rest })
     }
 
-    /// Check if current token can start a range bound (integer, char, or minus).
-    fn is_range_bound_start(&self) -> bool {
-        matches!(
-            self.cursor.current_kind(),
-            TokenKind::Int(_) | TokenKind::Char(_) | TokenKind::Minus
-        )
-    }
-
     /// Parse an optional pattern guard: `if condition` or `.match(condition)` (legacy).
     ///
     /// Returns `Some(expr_id)` if a guard is present, `None` otherwise.
@@ -987,56 +1012,4 @@ impl Parser<'_> {
         // Look ahead: . match (
         matches!(self.cursor.peek_next_kind(), TokenKind::Match)
     }
-
-    /// Parse a range bound (integer, possibly negative).
-    fn parse_range_bound(&mut self) -> Result<ExprId, ParseError> {
-        let start_span = self.cursor.current_span();
-
-        if self.cursor.check(&TokenKind::Minus) {
-            self.cursor.advance();
-            if let TokenKind::Int(n) = *self.cursor.current_kind() {
-                self.cursor.advance();
-                let value = i64::try_from(n).map_err(|_| {
-                    ParseError::new(
-                        ori_diagnostic::ErrorCode::E1002,
-                        "integer literal too large",
-                        start_span,
-                    )
-                })?;
-                let span = start_span.merge(self.cursor.previous_span());
-                Ok(self
-                    .arena
-                    .alloc_expr(Expr::new(ExprKind::Int(-value), span)))
-            } else {
-                Err(ParseError::new(
-                    ori_diagnostic::ErrorCode::E1002,
-                    "expected integer after `-` in range pattern",
-                    self.cursor.current_span(),
-                ))
-            }
-        } else if let TokenKind::Int(n) = *self.cursor.current_kind() {
-            self.cursor.advance();
-            let value = i64::try_from(n).map_err(|_| {
-                ParseError::new(
-                    ori_diagnostic::ErrorCode::E1002,
-                    "integer literal too large",
-                    start_span,
-                )
-            })?;
-            Ok(self
-                .arena
-                .alloc_expr(Expr::new(ExprKind::Int(value), self.cursor.previous_span())))
-        } else if let TokenKind::Char(c) = *self.cursor.current_kind() {
-            self.cursor.advance();
-            Ok(self
-                .arena
-                .alloc_expr(Expr::new(ExprKind::Char(c), self.cursor.previous_span())))
-        } else {
-            Err(ParseError::new(
-                ori_diagnostic::ErrorCode::E1002,
-                "expected integer or char literal in range pattern",
-                self.cursor.current_span(),
-            ))
-        }
-    }
 }
@@ -0,0 +1,161 @@
+//! Range pattern parsing: `lo..hi`, `lo..=hi`, `lo..`, `..hi`, `..=hi`.
+//!
+//! Bounds are restricted to integer and char literals (optionally negated),
+//! so a range pattern can be lowered to constant comparisons without
+//! evaluating arbitrary expressions.
+
+use crate::{ParseError, ParseOutcome, Parser};
+use ori_ir::{Expr, ExprId, ExprKind, MatchPattern, Span, TokenKind};
+
+impl Parser<'_> {
+    /// Check if the current token can start a range bound (integer, char, or minus).
+    pub(super) fn is_range_bound_start(&self) -> bool {
+        matches!(
+            self.cursor.current_kind(),
+            TokenKind::Int(_) | TokenKind::Char(_) | TokenKind::Minus
+        )
+    }
+
+    /// Check if the current token is a range operator (`..` or `..=`).
+    pub(super) fn is_range_op(&self) -> bool {
+        self.cursor.check(&TokenKind::DotDot) || self.cursor.check(&TokenKind::DotDotEq)
+    }
+
+    /// Check for a half-open range pattern with no lower bound: `..hi` or `..=hi`.
+    ///
+    /// A bare `..` is a rest pattern, so this only looks at what follows.
+    pub(super) fn is_prefix_range_pattern(&self) -> bool {
+        self.cursor.check(&TokenKind::DotDotEq)
+            || (self.cursor.check(&TokenKind::DotDot)
+                && matches!(
+                    self.cursor.peek_next_kind(),
+                    TokenKind::Int(_) | TokenKind::Char(_) | TokenKind::Minus
+                ))
+    }
+
+    /// Parse a range pattern with no lower bound: `..hi` or `..=hi`.
+    ///
+    /// List, tuple, and struct patterns claim a bare `..` as a rest before
+    /// getting here, so any range operator in this position starts a range.
+    pub(super) fn parse_pattern_prefix_range(&mut self) -> ParseOutcome<MatchPattern> {
+        let op_span = self.cursor.current_span();
+        let inclusive = self.cursor.check(&TokenKind::DotDotEq);
+        self.cursor.advance();
+
+        if !self.is_range_bound_start() {
+            let err = if matches!(self.cursor.current_kind(), TokenKind::Ident(_)) {
+                self.non_literal_range_bound_error()
+            } else {
+                ParseError::new(
+                    ori_diagnostic::ErrorCode::E1008,
+                    "range pattern without a lower bound needs an upper bound",
+                    op_span.merge(self.cursor.current_span()),
+                )
+                .with_help("Write `..=hi` or `..hi`, or use `_` to match anything")
+            };
+            return ParseOutcome::consumed_err(err, op_span);
+        }
+
+        match self.parse_range_bound() {
+            Ok(end) => ParseOutcome::consumed_ok(MatchPattern::Range {
+                start: None,
+                end: Some(end),
+                inclusive,
+            }),
+            Err(err) => ParseOutcome::consumed_err(err, op_span),
+        }
+    }
+
+    /// Finish a range pattern after its lower bound: `lo..hi`, `lo..=hi`, or `lo..`.
+    ///
+    /// Called with the cursor on the range operator.
+    pub(super) fn finish_range_pattern(
+        &mut self,
+        start: ExprId,
+        pat_span: Span,
+    ) -> ParseOutcome<MatchPattern> {
+        let inclusive = self.cursor.check(&TokenKind::DotDotEq);
+        self.cursor.advance();
+
+        // Parse end of range (optional for open-ended ranges)
+        let end = if self.is_range_bound_start() {
+            match self.parse_range_bound() {
+                Ok(e) => Some(e),
+                Err(err) => return ParseOutcome::consumed_err(err, pat_span),
+            }
+        } else if matches!(self.cursor.current_kind(), TokenKind::Ident(_)) {
+            let err = self.non_literal_range_bound_error();
+            return ParseOutcome::consumed_err(err, pat_span);
+        } else {
+            None
+        };
+
+        ParseOutcome::consumed_ok(MatchPattern::Range {
+            start: Some(start),
+            end,
+            inclusive,
+        })
+    }
+
+    /// Parse a range bound (integer or char, integers possibly negative).
+    pub(super) fn parse_range_bound(&mut self) -> Result<ExprId, ParseError> {
+        let start_span = self.cursor.current_span();
+
+        if self.cursor.check(&TokenKind::Minus) {
+            self.cursor.advance();
+            if let TokenKind::Int(n) = *self.cursor.current_kind() {
+                self.cursor.advance();
+                // Negate before narrowing so `-9223372036854775808` fits.
+                let value = i64::try_from(-i128::from(n)).map_err(|_| {
+                    ParseError::new(
+                        ori_diagnostic::ErrorCode::E1002,
+                        "integer literal too large",
+                        start_span,
+                    )
+                })?;
+                let span = start_span.merge(self.cursor.previous_span());
+                Ok(self.arena.alloc_expr(Expr::new(ExprKind::Int(value), span)))
+            } else {
+                Err(ParseError::new(
+                    ori_diagnostic::ErrorCode::E1002,
+                    "expected integer after `-` in range pattern",
+                    self.cursor.current_span(),
+                ))
+            }
+        } else if let TokenKind::Int(n) = *self.cursor.current_kind() {
+            self.cursor.advance();
+            let value = i64::try_from(n).map_err(|_| {
+                ParseError::new(
+                    ori_diagnostic::ErrorCode::E1002,
+                    "integer literal too large",
+                    start_span,
+                )
+            })?;
+            Ok(self
+                .arena
+                .alloc_expr(Expr::new(ExprKind::Int(value), self.cursor.previous_span())))
+        } else if let TokenKind::Char(c) = *self.cursor.current_kind() {
+            self.cursor.advance();
+            Ok(self
+                .arena
+                .alloc_expr(Expr::new(ExprKind::Char(c), self.cursor.previous_span())))
+        } else {
+            Err(ParseError::new(
+                ori_diagnostic::ErrorCode::E1002,
+                "expected integer or char literal in range pattern",
+                self.cursor.current_span(),
+            ))
+        }
+    }
+
+    /// Error for a range bound that names a variable or constant.
+    #[cold]
+    fn non_literal_range_bound_error(&self) -> ParseError {
+        ParseError::new(
+            ori_diagnostic::ErrorCode::E1008,
+            "range pattern bounds must be integer or char literals",
+            self.cursor.current_span(),
+        )
+        .with_help("Use a guard to compare against a variable: `n if n < limit -> ...`")
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Parses call, method call, field access, index expressions, and struct literals.
 
+use crate::recovery::expect_closing;
 use crate::{chain, committed, ParseError, ParseOutcome, Parser};
 use ori_ir::{
-    CallArg, Expr, ExprId, ExprKind, FieldInit, Param, ParsedTypeId, StructLitField, TokenKind,
+    CallArg, Expr, ExprId, ExprKind, FieldInit, Param, ParsedTypeId, Span, StructLitField,
+    TokenKind,
 };
 
 /// Bitset of tags that can start a postfix operation.
@@ -70,15 +72,17 @@ impl Parser<'_> {
             }
 
             if self.cursor.check(&TokenKind::LParen) {
+                let open_span = self.cursor.current_span();
                 self.cursor.advance();
-                expr = self.parse_postfix_call(expr)?;
+                expr = self.parse_postfix_call(expr, open_span)?;
             } else if self.cursor.check(&TokenKind::Dot) {
                 self.cursor.advance();
                 expr = self.parse_postfix_dot(expr)?;
             } else if self.cursor.check(&TokenKind::LBracket) {
+                let open_span = self.cursor.current_span();
                 self.cursor.advance();
                 let index = self.parse_index_expr()?;
-                self.cursor.expect(&TokenKind::RBracket)?;
+                expect_closing(&mut self.cursor, &TokenKind::LBracket, open_span)?;
                 let span = self
                     .arena
                     .get_expr(expr)
@@ -130,9 +134,9 @@ impl Parser<'_> {
     }
 
     /// Parse a function call after the opening `(` has been consumed.
-    fn parse_postfix_call(&mut self, func: ExprId) -> Result<ExprId, ParseError> {
-        let (call_args, has_named) = self.parse_call_args()?;
-        self.cursor.expect(&TokenKind::RParen)?;
+    fn parse_postfix_call(&mut self, func: ExprId, open_span: Span) -> Result<ExprId, ParseError> {
+        let (call_args, has_named) = self.parse_call_args(open_span)?;
+        expect_closing(&mut self.cursor, &TokenKind::LParen, open_span)?;
 
         let call_span = self
             .arena
@@ -172,13 +176,15 @@ impl Parser<'_> {
             return self.parse_match_arms_with_scrutinee(receiver, start_span);
         }
 
+        let dot_span = self.cursor.previous_span();
         let field = self.cursor.expect_member_name()?;
 
         if self.cursor.check(&TokenKind::LParen) {
             // Method call
+            let open_span = self.cursor.current_span();
             self.cursor.advance();
-            let (call_args, has_named) = self.parse_call_args()?;
-            self.cursor.expect(&TokenKind::RParen)?;
+            let (call_args, has_named) = self.parse_call_args(open_span)?;
+            expect_closing(&mut self.cursor, &TokenKind::LParen, open_span)?;
 
             let span = self
                 .arena
@@ -208,6 +214,8 @@ impl Parser<'_> {
                     span,
                 )))
             }
+        } else if self.report_postfix_await(field, dot_span) {
+            Ok(receiver)
         } else {
             // Field access
             let span = self
@@ -239,7 +247,23 @@ impl Parser<'_> {
             let field_span = p.cursor.current_span();
 
             // Check for spread syntax: ...expr
-            if p.cursor.check(&TokenKind::DotDotDot) {
+            // `..expr` (Rust's functional update) is accepted for recovery
+            // with an error pointing at the correct spelling.
+            let is_rust_update = p.cursor.check(&TokenKind::DotDot);
+            if is_rust_update || p.cursor.check(&TokenKind::DotDotDot) {
+                if is_rust_update {
+                    p.deferred_errors.push(
+                        ParseError::new(
+                            ori_diagnostic::ErrorCode::E1001,
+                            "struct spread is written `...`, not `..`",
+                            field_span,
+                        )
+                        .with_context("use `...` here")
+                        .with_help(
+                            "Write `...base` to copy fields from `base`; later fields override earlier ones",
+                        ),
+                    );
+                }
                 p.cursor.advance();
                 has_spread = true;
                 let spread_expr = p.parse_expr().into_result()?;
@@ -364,10 +388,16 @@ impl Parser<'_> {
     }
 
     /// Parse call arguments, supporting both positional and named args.
-    pub(crate) fn parse_call_args(&mut self) -> Result<(Vec<CallArg>, bool), ParseError> {
+    ///
+    /// `open_span` is the already-consumed `(`, reported if it is left unclosed.
+    pub(crate) fn parse_call_args(
+        &mut self,
+        open_span: Span,
+    ) -> Result<(Vec<CallArg>, bool), ParseError> {
         use crate::series::SeriesConfig;
 
-        let args: Vec<CallArg> = self.series(&SeriesConfig::comma(TokenKind::RParen), |p| {
+        let config = SeriesConfig::comma(TokenKind::RParen).opened_by(TokenKind::LParen, open_span);
+        let args: Vec<CallArg> = self.series(&config, |p| {
             if p.cursor.check(&TokenKind::RParen) {
                 return Ok(None);
             }
@@ -7,11 +7,12 @@
 //! backtracking. Each sub-parser returns `EmptyErr` when its leading token
 //! doesn't match, enabling clean ordered alternation.
 
-use crate::recovery::TokenSet;
+use crate::recovery::{expect_closing, TokenSet};
 use crate::{committed, one_of, require, ParseError, ParseOutcome, Parser};
 use ori_ir::{
     BindingPattern, DurationUnit, Expr, ExprId, ExprKind, ExprRange, FieldBinding, FunctionExpKind,
-    Mutability, Name, Param, ParamRange, ParsedTypeId, SizeUnit, TemplatePart, TokenKind,
+    Mutability, Name, NumericSuffix, Param, ParamRange, ParsedTypeId, SizeUnit, Span, TemplatePart,
+    TokenKind,
 };
 use tracing::{debug, trace};
 
@@ -114,6 +115,9 @@ impl Parser<'_> {
                 span,
             );
         }
+        if self.at_prefix_await() {
+            return self.parse_prefix_await();
+        }
         if self.cursor.check(&TokenKind::Try) {
             trace!("parse_primary -> Try");
             self.cursor.advance();
@@ -171,7 +175,10 @@ impl Parser<'_> {
             | TokenKind::TAG_TRUE
             | TokenKind::TAG_FALSE
             | TokenKind::TAG_DURATION
-            | TokenKind::TAG_SIZE => {
+            | TokenKind::TAG_SIZE
+            | TokenKind::TAG_BYTE
+            | TokenKind::TAG_BYTE_STRING
+            | TokenKind::TAG_SUFFIXED_NUMBER => {
                 return self.parse_literal_primary();
             }
             TokenKind::TAG_IDENT | TokenKind::TAG_SUSPEND | TokenKind::TAG_EXTERN => {
@@ -240,7 +247,8 @@ impl Parser<'_> {
     // === Extracted sub-parsers for one_of! dispatch ===
 
     /// Parse literal tokens: `Int`, `Float`, `True`, `False`, `String`, `Char`,
-    /// `Duration`, `Size`.
+    /// `Duration`, `Size`. `Byte`, `ByteString`, and `SuffixedNumber` are
+    /// rejected with a targeted error.
     ///
     /// Returns `EmptyErr` if the current token is not a literal.
     fn parse_literal_primary(&mut self) -> ParseOutcome<ExprId> {
@@ -307,6 +315,36 @@ impl Parser<'_> {
                         .alloc_expr(Expr::new(ExprKind::Size { value, unit }, span)),
                 )
             }
+            // The lexer accepts these, but there is no expression form for
+            // them yet; say so instead of reporting a generic bad token.
+            TokenKind::Byte(b) => {
+                self.cursor.advance();
+                ParseOutcome::consumed_err(
+                    ParseError::new(
+                        ori_diagnostic::ErrorCode::E1002,
+                        "byte literals are not supported yet",
+                        span,
+                    )
+                    .with_help(format!("Convert an integer instead: `byte({b})`")),
+                    span,
+                )
+            }
+            TokenKind::ByteString(_) => {
+                self.cursor.advance();
+                ParseOutcome::consumed_err(
+                    ParseError::new(
+                        ori_diagnostic::ErrorCode::E1002,
+                        "byte string literals are not supported yet",
+                        span,
+                    )
+                    .with_help("Use a string and call `.bytes()` to get a `[byte]`"),
+                    span,
+                )
+            }
+            TokenKind::SuffixedNumber(value, suffix) => {
+                self.cursor.advance();
+                ParseOutcome::consumed_err(suffixed_number_error(value, suffix, span), span)
+            }
             _ => ParseOutcome::empty_err(LITERAL_TOKENS, self.cursor.current_span().start as usize),
         }
     }
@@ -619,10 +657,6 @@ impl Parser<'_> {
         )
     }
 
-    #[expect(
-        clippy::too_many_lines,
-        reason = "multi-case parenthesized expression parser covering tuples, lambdas, and grouped expressions"
-    )]
     fn parse_parenthesized_body(&mut self) -> ParseOutcome<ExprId> {
         let span = self.cursor.current_span();
         self.cursor.advance(); // (
@@ -634,13 +668,7 @@ impl Parser<'_> {
 
             if self.cursor.check(&TokenKind::Arrow) {
                 self.cursor.advance();
-                let ret_ty = if self.cursor.check_type_keyword() {
-                    let ty = self.parse_type();
-                    committed!(self.cursor.expect(&TokenKind::Eq));
-                    ty.map_or(ParsedTypeId::INVALID, |t| self.arena.alloc_parsed_type(t))
-                } else {
-                    ParsedTypeId::INVALID
-                };
+                let ret_ty = committed!(self.parse_lambda_ret_ty());
                 let body = require!(self, self.parse_expr(), "lambda body");
                 let end_span = self.arena.get_expr(body).span;
                 return ParseOutcome::consumed_ok(self.arena.alloc_expr(Expr::new(
@@ -665,14 +693,7 @@ impl Parser<'_> {
             let params = committed!(self.parse_params());
             committed!(self.cursor.expect(&TokenKind::RParen));
             committed!(self.cursor.expect(&TokenKind::Arrow));
-
-            let ret_ty = if self.cursor.check_type_keyword() {
-                let ty = self.parse_type();
-                committed!(self.cursor.expect(&TokenKind::Eq));
-                ty.map_or(ParsedTypeId::INVALID, |t| self.arena.alloc_parsed_type(t))
-            } else {
-                ParsedTypeId::INVALID
-            };
+            let ret_ty = committed!(self.parse_lambda_ret_ty());
 
             let body = require!(self, self.parse_expr(), "lambda body");
             let end_span = self.arena.get_expr(body).span;
@@ -701,7 +722,7 @@ impl Parser<'_> {
                 exprs.push(require!(self, self.parse_expr(), "expression in tuple"));
                 self.cursor.skip_newlines();
             }
-            committed!(self.cursor.expect(&TokenKind::RParen));
+            committed!(expect_closing(&mut self.cursor, &TokenKind::LParen, span));
 
             if self.cursor.check(&TokenKind::Arrow) {
                 self.cursor.advance();
@@ -726,7 +747,7 @@ impl Parser<'_> {
             );
         }
 
-        committed!(self.cursor.expect(&TokenKind::RParen));
+        committed!(expect_closing(&mut self.cursor, &TokenKind::LParen, span));
 
         if self.cursor.check(&TokenKind::Arrow) {
             self.cursor.advance();
@@ -775,7 +796,7 @@ impl Parser<'_> {
         let mut has_spread = false;
         let mut elements: Vec<ListElement> = Vec::new();
 
-        committed!(self.bracket_series_direct(|p| {
+        committed!(self.bracket_series_direct(span, |p| {
             if p.cursor.check(&TokenKind::RBracket) {
                 return Ok(false);
             }
@@ -945,7 +966,7 @@ impl Parser<'_> {
         self.cursor.advance(); // consume `{`
 
         let (stmts_vec, result, end_span) =
-            require!(self, self.collect_block_stmts("block"), "block body");
+            require!(self, self.collect_block_stmts("block", span), "block body");
 
         // Batch-push all statements after nested parsing is complete.
         // (Collected into Vec first to avoid interleaving with nested blocks
@@ -1214,6 +1235,8 @@ impl Parser<'_> {
                     self.series(&SeriesConfig::comma(TokenKind::RParen).no_newlines(), |p| {
                         if p.cursor.check(&TokenKind::RParen) {
                             Ok(None)
+                        } else if p.cursor.check(&TokenKind::DotDot) {
+                            Err(p.tuple_rest_pattern_error())
                         } else {
                             Ok(Some(p.parse_binding_pattern()?))
                         }
@@ -1259,6 +1282,7 @@ impl Parser<'_> {
             TokenKind::LBracket => {
                 // List pattern is special: has optional ..rest at the end
                 // Cannot use simple series combinator
+                let open_span = self.cursor.current_span();
                 self.cursor.advance();
                 let mut elements = Vec::new();
                 let mut rest = None;
@@ -1277,6 +1301,9 @@ impl Parser<'_> {
                             rest = Some((name, rest_mutable));
                             self.cursor.advance();
                         }
+                        if !self.cursor.check(&TokenKind::RBracket) {
+                            return Err(self.misplaced_rest_pattern_error());
+                        }
                         break;
                     }
                     elements.push(self.parse_binding_pattern()?);
@@ -1286,7 +1313,7 @@ impl Parser<'_> {
                         self.cursor.expect(&TokenKind::Comma)?;
                     }
                 }
-                self.cursor.expect(&TokenKind::RBracket)?;
+                expect_closing(&mut self.cursor, &TokenKind::LBracket, open_span)?;
                 Ok(BindingPattern::List { elements, rest })
             }
             _ => Err(ParseError::new(
@@ -1648,3 +1675,27 @@ impl Parser<'_> {
         }
     }
 }
+
+/// Error for a numeric literal with a type suffix (`255u8`, `1.5f32`).
+///
+/// The lexer validates suffixes, but the only numeric types are 64-bit
+/// `int` and `float` (plus `byte`), so the help shows the unsuffixed form.
+fn suffixed_number_error(value: u64, suffix: NumericSuffix, span: Span) -> ParseError {
+    let help = match suffix {
+        NumericSuffix::F32 | NumericSuffix::F64 => format!(
+            "Write `{:?}` without a suffix: float literals are 64-bit `float`s",
+            f64::from_bits(value)
+        ),
+        NumericSuffix::U8 => format!("Convert an integer instead: `byte({value})`"),
+        _ => format!("Write `{value}` without a suffix: integer literals are 64-bit `int`s"),
+    };
+    ParseError::new(
+        ori_diagnostic::ErrorCode::E1002,
+        format!(
+            "numeric literal suffix `{}` is not supported yet",
+            suffix.suffix()
+        ),
+        span,
+    )
+    .with_help(help)
+}
@@ -238,3 +238,6 @@ impl Parser<'_> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests;
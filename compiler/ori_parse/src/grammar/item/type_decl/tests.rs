@@ -0,0 +1,122 @@
+use ori_ir::{ParsedType, StringInterner, TypeDeclKind, TypeId, Variant};
+
+/// Parse a source string and return the kind of its first type declaration.
+fn parse_type_kind(source: &str, interner: &StringInterner) -> TypeDeclKind {
+    let tokens = ori_lexer::lex(source, interner);
+    let parser = crate::Parser::new(&tokens, interner);
+    let output = parser.parse_module();
+    assert!(
+        output.errors.is_empty(),
+        "Parse errors: {:?}",
+        output.errors
+    );
+    output.module.types[0].kind.clone()
+}
+
+fn variant_names<'a>(variants: &[Variant], interner: &'a StringInterner) -> Vec<&'a str> {
+    variants.iter().map(|v| interner.lookup(v.name)).collect()
+}
+
+#[test]
+fn test_sum_type_with_payloads_and_unit() {
+    let interner = StringInterner::new();
+    let kind = parse_type_kind(
+        "type Shape = Circle(r: float) | Rect(w: float, h: float) | Unit;",
+        &interner,
+    );
+    let TypeDeclKind::Sum(variants) = kind else {
+        panic!("expected sum type, got {kind:?}");
+    };
+    assert_eq!(
+        variant_names(&variants, &interner),
+        ["Circle", "Rect", "Unit"]
+    );
+    assert_eq!(variants[0].fields.len(), 1);
+    assert_eq!(interner.lookup(variants[0].fields[0].name), "r");
+    assert_eq!(
+        variants[0].fields[0].ty,
+        ParsedType::Primitive(TypeId::FLOAT)
+    );
+    let rect_fields: Vec<_> = variants[1]
+        .fields
+        .iter()
+        .map(|f| interner.lookup(f.name))
+        .collect();
+    assert_eq!(rect_fields, ["w", "h"]);
+    assert!(variants[2].fields.is_empty());
+}
+
+#[test]
+fn test_single_variant_with_fields_is_sum() {
+    let interner = StringInterner::new();
+    let kind = parse_type_kind("type Wrapper = Wrap(value: int);", &interner);
+    let TypeDeclKind::Sum(variants) = kind else {
+        panic!("expected sum type, got {kind:?}");
+    };
+    assert_eq!(variant_names(&variants, &interner), ["Wrap"]);
+    assert_eq!(variants[0].fields.len(), 1);
+}
+
+#[test]
+fn test_single_bare_identifier_is_newtype() {
+    // Without `|` or a payload, a lone identifier names an existing type
+    let interner = StringInterner::new();
+    let kind = parse_type_kind("type UserId = Id;", &interner);
+    assert!(matches!(
+        kind,
+        TypeDeclKind::Newtype(ParsedType::Named { .. })
+    ));
+}
+
+#[test]
+fn test_tuple_body_is_newtype_over_tuple() {
+    // Positional fields come from the wrapped tuple: `Pair((1, 2)).unwrap().0`
+    let interner = StringInterner::new();
+    let kind = parse_type_kind("type Pair = (int, int);", &interner);
+    let TypeDeclKind::Newtype(ParsedType::Tuple(elems)) = kind else {
+        panic!("expected newtype over a tuple, got {kind:?}");
+    };
+    assert_eq!(elems.len(), 2);
+}
+
+#[test]
+fn test_tuple_body_allows_trailing_comma() {
+    // Same as every other comma series; `(int, str,)` is still two fields
+    let interner = StringInterner::new();
+    let kind = parse_type_kind("type Entry = (int, str,);", &interner);
+    let TypeDeclKind::Newtype(ParsedType::Tuple(elems)) = kind else {
+        panic!("expected newtype over a tuple, got {kind:?}");
+    };
+    assert_eq!(elems.len(), 2);
+}
+
+#[test]
+fn test_multiline_variants() {
+    // A trailing `|` continues the declaration onto the next line
+    let interner = StringInterner::new();
+    let kind = parse_type_kind(
+        "type Status = Pending |\n    Done(code: int) |\n    Failed(reason: str);",
+        &interner,
+    );
+    let TypeDeclKind::Sum(variants) = kind else {
+        panic!("expected sum type, got {kind:?}");
+    };
+    assert_eq!(
+        variant_names(&variants, &interner),
+        ["Pending", "Done", "Failed"]
+    );
+}
+
+#[test]
+fn test_missing_pipe_between_variants_is_error() {
+    let interner = StringInterner::new();
+    let tokens = ori_lexer::lex(
+        "type Shape = Circle(r: float) Rect(w: float, h: float);",
+        &interner,
+    );
+    let output = crate::Parser::new(&tokens, &interner).parse_module();
+    assert!(
+        !output.errors.is_empty(),
+        "expected an error for a missing `|` separator"
+    );
+}
@@ -151,6 +151,11 @@ impl Parser<'_> {
                 return Some(ParsedType::list(elem_id));
             }
 
+            // `[T; N]` is Rust's array syntax; Ori spells it `[T, max N]`
+            if self.cursor.check(&TokenKind::Semicolon) {
+                return Some(self.parse_semicolon_array_type(inner));
+            }
+
             if self.cursor.check(&TokenKind::RBracket) {
                 self.cursor.advance(); // ]
             }
@@ -180,6 +185,41 @@ impl Parser<'_> {
         }
     }
 
+    /// Recover from `[T; N]` after the element type, at the `;`.
+    ///
+    /// Reports an error pointing at `[T, max N]`, then parses the size so
+    /// later stages see the fixed-capacity list the user most likely meant.
+    /// A missing size (`[T;]`) gets its own error and recovers to `[T]`.
+    fn parse_semicolon_array_type(&mut self, elem: ParsedType) -> ParsedType {
+        let semi_span = self.cursor.current().span;
+        self.cursor.advance(); // ;
+        let elem_id = self.arena.alloc_parsed_type(elem);
+
+        if self.cursor.check(&TokenKind::RBracket) {
+            self.cursor.advance(); // ]
+            self.deferred_errors.push(ParseError::new(
+                ErrorCode::E1001,
+                "expected array size after `;`; fixed-capacity lists are written `[T, max N]`",
+                semi_span,
+            ));
+            return ParsedType::list(elem_id);
+        }
+
+        self.deferred_errors.push(ParseError::new(
+            ErrorCode::E1001,
+            "`[T; N]` is not Ori syntax; write a fixed-capacity list as `[T, max N]`",
+            semi_span,
+        ));
+        let capacity = self.parse_non_comparison_expr().into_result().ok();
+        if self.cursor.check(&TokenKind::RBracket) {
+            self.cursor.advance(); // ]
+        }
+        match capacity {
+            Some(capacity) => ParsedType::fixed_list(elem_id, capacity),
+            None => ParsedType::list(elem_id),
+        }
+    }
+
     /// Parse a type and allocate it in the arena, returning its ID.
     ///
     /// This is a convenience method for cases where the parsed type
@@ -393,6 +393,44 @@ fn test_ampersand_named_type_produces_error() {
     assert!(errors[0].contains("reserved for a future version"));
 }
 
+#[test]
+fn test_semicolon_array_type_suggests_max() {
+    // [int; 3] — Rust array syntax; recovers to [int, max 3]
+    let (ty, arena, errors) = parse_type_with_errors("[int; 3]");
+    match ty {
+        Some(ParsedType::FixedList { elem, capacity }) => {
+            assert_eq!(
+                *arena.get_parsed_type(elem),
+                ParsedType::primitive(TypeId::INT)
+            );
+            assert!(matches!(
+                arena.get_expr(capacity).kind,
+                ori_ir::ExprKind::Int(3)
+            ));
+        }
+        _ => panic!("expected FixedList, got {ty:?}"),
+    }
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("[T, max N]"));
+}
+
+#[test]
+fn test_semicolon_array_type_missing_size() {
+    // [int;] — no size; recovers to [int]
+    let (ty, arena, errors) = parse_type_with_errors("[int;]");
+    match ty {
+        Some(ParsedType::List(elem)) => {
+            assert_eq!(
+                *arena.get_parsed_type(elem),
+                ParsedType::primitive(TypeId::INT)
+            );
+        }
+        _ => panic!("expected List, got {ty:?}"),
+    }
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("expected array size"));
+}
+
 #[test]
 fn test_ampersand_alone_recovers_to_infer() {
     // &= (& followed by =, not a type) should recover to Infer
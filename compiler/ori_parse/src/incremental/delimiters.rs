@@ -0,0 +1,59 @@
+//! Delimiter balance check for incremental parsing.
+//!
+//! An edit that opens or closes a `(`, `[`, or `{` without its partner shifts
+//! where every following declaration ends, so nothing after the edit can be
+//! reused from the old tree.
+
+use ori_ir::incremental::TextChange;
+use ori_ir::{TokenKind, TokenList};
+
+/// Net open-minus-close counts for `()`, `[]`, and `{}`.
+type Balance = [i64; 3];
+
+/// Whether `change` alters the delimiter balance of the text it replaces.
+///
+/// The removed text is scanned character by character in `old_source`, while
+/// the inserted text is read from the new `tokens`. Delimiters inside strings
+/// or comments count on the old side only, so the check can report a change
+/// that isn't structural; that only costs reuse, never correctness. A change
+/// range that doesn't fall on character boundaries is treated as unbalanced.
+pub(crate) fn balance_changed(old_source: &str, tokens: &TokenList, change: &TextChange) -> bool {
+    let Some(removed) = old_source.get(change.start as usize..change.old_end as usize) else {
+        return true;
+    };
+    source_balance(removed) != token_balance(tokens, change.start, change.new_end())
+}
+
+fn source_balance(text: &str) -> Balance {
+    let mut balance = Balance::default();
+    for c in text.chars() {
+        match c {
+            '(' => balance[0] += 1,
+            ')' => balance[0] -= 1,
+            '[' => balance[1] += 1,
+            ']' => balance[1] -= 1,
+            '{' => balance[2] += 1,
+            '}' => balance[2] -= 1,
+            _ => {}
+        }
+    }
+    balance
+}
+
+fn token_balance(tokens: &TokenList, start: u32, end: u32) -> Balance {
+    let mut balance = Balance::default();
+    let slice = tokens.as_slice();
+    let first = slice.partition_point(|t| t.span.start < start);
+    for token in slice[first..].iter().take_while(|t| t.span.end <= end) {
+        match token.kind {
+            TokenKind::LParen => balance[0] += 1,
+            TokenKind::RParen => balance[0] -= 1,
+            TokenKind::LBracket => balance[1] += 1,
+            TokenKind::RBracket => balance[1] -= 1,
+            TokenKind::LBrace => balance[2] += 1,
+            TokenKind::RBrace => balance[2] -= 1,
+            _ => {}
+        }
+    }
+    balance
+}
@@ -21,10 +21,12 @@
 mod copier;
 mod cursor;
 mod decl;
+mod delimiters;
 
 pub use copier::AstCopier;
 pub use cursor::{CursorStats, SyntaxCursor};
 pub use decl::{collect_declarations, DeclKind, DeclRef};
+pub(crate) use delimiters::balance_changed;
 
 /// Statistics for incremental parsing.
 #[derive(Clone, Debug, Default)]
@@ -174,7 +174,7 @@ fn test_parse_incremental_basic() {
     // Create a change: replace "42" (2 chars at position 19) with "99" (2 chars)
     let change = TextChange::new(19, 21, 2);
 
-    let new_result = parse_incremental(&new_tokens, &interner, &old_result, change);
+    let new_result = parse_incremental(&new_tokens, &interner, &old_result, source, change);
 
     assert!(!new_result.has_errors());
     assert_eq!(new_result.module.functions.len(), 2);
@@ -202,7 +202,7 @@ fn test_parse_incremental_insert() {
     // Insert at position 28 (after original source)
     let change = TextChange::insert(28, 30); // "\n\n@sub (x: int) -> int = x - 1" is 30 chars
 
-    let new_result = parse_incremental(&new_tokens, &interner, &old_result, change);
+    let new_result = parse_incremental(&new_tokens, &interner, &old_result, source, change);
 
     assert!(!new_result.has_errors());
     assert_eq!(new_result.module.functions.len(), 2);
@@ -231,8 +231,65 @@ fn test_parse_incremental_fresh_parse_on_overlap() {
     // Change: "int" (3 chars) to "float" (5 chars) at position ~18-21
     let change = TextChange::new(18, 21, 5);
 
-    let new_result = parse_incremental(&new_tokens, &interner, &old_result, change);
+    let new_result = parse_incremental(&new_tokens, &interner, &old_result, source, change);
 
     assert!(!new_result.has_errors());
     assert_eq!(new_result.module.functions.len(), 1);
 }
+
+#[test]
+fn test_parse_incremental_reuses_untouched_siblings() {
+    use crate::{parse, parse_incremental_with_stats};
+    use ori_ir::StringInterner;
+
+    let interner = StringInterner::new();
+
+    let source = "@a () -> int = 1 + 2;\n\n@b () -> int = 10;\n\n@c () -> int = 3 * 4;";
+    let old_result = parse(&ori_lexer::lex(source, &interner), &interner);
+    assert!(!old_result.has_errors());
+
+    // Replace "10" in the body of @b with "12345", shifting @c by 3 bytes
+    let new_source = "@a () -> int = 1 + 2;\n\n@b () -> int = 12345;\n\n@c () -> int = 3 * 4;";
+    let start = 38; // position of "10"
+    let change = TextChange::new(start, start + 2, 5);
+    let new_tokens = ori_lexer::lex(new_source, &interner);
+
+    let (new_result, stats) =
+        parse_incremental_with_stats(&new_tokens, &interner, &old_result, source, change);
+
+    assert_eq!(stats.reused_count, 2);
+    assert_eq!(stats.reparsed_count, 1);
+
+    // Reused and reparsed declarations together match a from-scratch parse
+    let full = parse(&new_tokens, &interner);
+    assert_eq!(new_result.module, full.module);
+    assert_eq!(new_result.arena, full.arena);
+    assert!(new_result.errors.is_empty());
+}
+
+#[test]
+fn test_parse_incremental_unbalanced_brace_invalidates_following() {
+    use crate::{parse, parse_incremental_with_stats};
+    use ori_ir::StringInterner;
+
+    let interner = StringInterner::new();
+
+    let source = "@a () -> int = 1 + 2;\n\n@b () -> int = 10;\n\n@c () -> int = 3 * 4;";
+    let old_result = parse(&ori_lexer::lex(source, &interner), &interner);
+
+    // Insert an unmatched `{` before "10" in @b
+    let new_source = "@a () -> int = 1 + 2;\n\n@b () -> int = {10;\n\n@c () -> int = 3 * 4;";
+    let start = 38; // position of "10"
+    let change = TextChange::insert(start, 1);
+    let new_tokens = ori_lexer::lex(new_source, &interner);
+
+    let (new_result, stats) =
+        parse_incremental_with_stats(&new_tokens, &interner, &old_result, source, change);
+
+    // Only @a, which precedes the edit, may be reused
+    assert_eq!(stats.reused_count, 1);
+
+    let full = parse(&new_tokens, &interner);
+    assert_eq!(new_result.module, full.module);
+    assert_eq!(new_result.errors, full.errors);
+}
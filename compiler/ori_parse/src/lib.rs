@@ -4,7 +4,9 @@
 
 mod context;
 mod cursor;
+mod doc_comments;
 mod error;
+mod expression;
 mod foreign_keywords;
 mod grammar;
 pub mod incremental;
@@ -19,6 +21,7 @@ mod tests;
 pub use context::ParseContext;
 pub use cursor::Cursor;
 pub use error::{DetachmentReason, ErrorContext, ParseError, ParseWarning};
+pub use expression::parse_expression;
 pub use outcome::ParseOutcome;
 pub use recovery::{synchronize, TokenSet, FUNCTION_BOUNDARY, STMT_BOUNDARY};
 pub use series::{SeriesConfig, TrailingSeparator};
@@ -45,8 +48,8 @@ pub(crate) use grammar::ParsedAttrs;
 
 /// Pre-interned `Name` values for contextual keywords used in identifier comparisons.
 ///
-/// Avoids acquiring interner read-locks during parsing by comparing `Name` values
-/// (u32 equality) instead of looking up strings via `interner().lookup()`.
+/// Compares `Name` values (u32 equality) instead of looking up strings via
+/// `interner().lookup()` and comparing their bytes.
 pub(crate) struct KnownNames {
     // Channel constructors
     pub channel: Name,
@@ -610,6 +613,15 @@ impl<'a> Parser<'a> {
         module: &mut Module,
         errors: &mut Vec<ParseError>,
     ) {
+        if self.cursor.check(&TokenKind::Async) {
+            // `async @f ...` — report, then parse the declaration without it
+            let kind = error::ParseErrorKind::UnsupportedKeyword {
+                keyword: TokenKind::Async,
+                reason: "Ori has no async functions; a function that may suspend declares `uses Suspend`",
+            };
+            errors.push(ParseError::from_kind(&kind, self.cursor.current_span()));
+            self.cursor.advance();
+        }
         if self.cursor.check(&TokenKind::At) {
             let outcome = self.parse_function_or_test(attrs, visibility);
             match outcome {
@@ -731,16 +743,7 @@ impl<'a> Parser<'a> {
             ));
             // Skip the entire import statement to avoid infinite loop
             self.cursor.advance();
-            while !self.cursor.is_at_end()
-                && !self.cursor.check(&TokenKind::At)
-                && !self.cursor.check(&TokenKind::Trait)
-                && !self.cursor.check(&TokenKind::Impl)
-                && !self.cursor.check(&TokenKind::Type)
-                && !self.cursor.check(&TokenKind::Use)
-                && !self.cursor.check(&TokenKind::Extension)
-            {
-                self.cursor.advance();
-            }
+            recovery::synchronize(&mut self.cursor, recovery::IMPORT_FOLLOW);
         } else if self.cursor.current_tag() == TokenKind::TAG_ERROR {
             // Error tokens from the lexer — skip without emitting a parse error.
             // The real diagnostic was already emitted by the lex error pipeline.
@@ -851,7 +854,7 @@ impl<'a> Parser<'a> {
         mut self,
         mut state: incremental::IncrementalState<'_>,
         old_arena: &ExprArena,
-    ) -> ParseOutput {
+    ) -> (ParseOutput, incremental::IncrementalStats) {
         use incremental::{AstCopier, DeclKind};
 
         let mut module = Module::with_capacity_hint(self.estimated_source_len());
@@ -868,11 +871,13 @@ impl<'a> Parser<'a> {
             }
 
             let pos = self.cursor.current_span().start;
+            let old_pos = state.cursor.marker().old_position(pos);
 
             // Try to find a reusable declaration at this position
-            if let Some(decl_ref) = state.cursor.find_at(pos) {
-                // Check if this declaration is outside the change region
-                if !state.cursor.marker().intersects(decl_ref.span) {
+            if let Some(decl_ref) = state.cursor.find_at(old_pos) {
+                // Only reuse a declaration that starts exactly here; after a
+                // reparse the token stream may resume mid-way through one.
+                if decl_ref.span.start == old_pos {
                     let copier = AstCopier::new(old_arena, state.cursor.marker().clone());
 
                     match decl_ref.kind {
@@ -932,7 +937,9 @@ impl<'a> Parser<'a> {
                     }
 
                     state.stats.reused_count += 1;
-                    self.skip_to_span_end(decl_ref.span);
+                    if let Some(new_span) = state.cursor.marker().adjust_span(decl_ref.span) {
+                        self.skip_to_position(new_span.end);
+                    }
                     // Consume trailing `;` that was eaten by the original parse
                     // but not included in the declaration span.
                     self.eat_optional_semicolon();
@@ -958,7 +965,7 @@ impl<'a> Parser<'a> {
         errors.append(&mut self.deferred_errors);
         let warnings = self.deferred_warnings;
 
-        ParseOutput {
+        let output = ParseOutput {
             module,
             arena: SharedArena::new(self.arena),
             errors,
@@ -967,17 +974,15 @@ impl<'a> Parser<'a> {
             // For now, caller should re-lex with lex_with_comments() and
             // pass to parse_with_metadata() for full metadata support.
             metadata: ModuleExtra::new(),
-        }
+        };
+        (output, state.stats)
     }
 
-    /// Skip tokens until we're past the given span end.
+    /// Skip tokens that start before `end` (a position in the new text).
     ///
     /// Used during incremental parsing to skip over reused declarations.
-    fn skip_to_span_end(&mut self, span: Span) {
-        // Adjust the span end for the change delta to get the new end position
-        let adjusted_end = self.cursor.current_span().start.max(span.end);
-
-        while !self.cursor.is_at_end() && self.cursor.current_span().start < adjusted_end {
+    fn skip_to_position(&mut self, end: u32) {
+        while !self.cursor.is_at_end() && self.cursor.current_span().start < end {
             self.cursor.advance();
         }
     }
@@ -1009,6 +1014,18 @@ impl ParseOutput {
 
     // --- Post-parse analysis ---
 
+    /// Add a warning for each indentation run that mixes tabs and spaces.
+    ///
+    /// `spans` comes from the opt-in lexer check
+    /// (`ori_lexer::LexOutput::mixed_indentation`).
+    pub fn report_mixed_indentation(&mut self, spans: &[Span]) {
+        self.warnings.extend(
+            spans
+                .iter()
+                .map(|&span| ParseWarning::mixed_indentation(span)),
+        );
+    }
+
     /// Generate warnings for detached doc comments.
     ///
     /// Call this after parsing to populate the warnings field with any
@@ -1102,8 +1119,8 @@ pub fn parse(tokens: &TokenList, interner: &StringInterner) -> ParseOutput {
 /// let metadata = lex_output.into_metadata();
 /// let parse_output = ori_parse::parse_with_metadata(&lex_output.tokens, metadata, &interner);
 ///
-/// // Access comments attached to declarations
-/// let docs = parse_output.metadata.doc_comments_for(fn_start);
+/// // Access the doc comment block attached to a declaration
+/// let docs = parse_output.metadata.attached_docs(func.span);
 /// ```
 pub fn parse_with_metadata(
     tokens: &TokenList,
@@ -1115,6 +1132,7 @@ pub fn parse_with_metadata(
 
     // Transfer metadata from lexer
     output.metadata = metadata;
+    output.attach_doc_comments();
 
     output
 }
@@ -1125,11 +1143,15 @@ pub fn parse_with_metadata(
 /// those that overlap with the text change. This can provide significant
 /// speedups for IDE scenarios where only small edits are made.
 ///
+/// An edit that changes the delimiter balance (e.g. adds an unmatched `{`)
+/// invalidates every declaration after it, since their boundaries can move.
+///
 /// # Arguments
 ///
 /// * `tokens` - The new token list after the edit
 /// * `interner` - String interner (must be the same instance used for old result)
 /// * `old_result` - The previous parse result to reuse from
+/// * `old_source` - The source text `old_result` was parsed from
 /// * `change` - Description of the text change
 ///
 /// # Returns
@@ -1139,8 +1161,21 @@ pub fn parse_incremental(
     tokens: &TokenList,
     interner: &StringInterner,
     old_result: &ParseOutput,
+    old_source: &str,
     change: ori_ir::incremental::TextChange,
 ) -> ParseOutput {
+    parse_incremental_with_stats(tokens, interner, old_result, old_source, change).0
+}
+
+/// Like [`parse_incremental`], but also reports how many declarations were
+/// reused from the old tree and how many were reparsed.
+pub fn parse_incremental_with_stats(
+    tokens: &TokenList,
+    interner: &StringInterner,
+    old_result: &ParseOutput,
+    old_source: &str,
+    change: ori_ir::incremental::TextChange,
+) -> (ParseOutput, incremental::IncrementalStats) {
     use incremental::{IncrementalState, SyntaxCursor};
     use ori_ir::incremental::ChangeMarker;
 
@@ -1148,7 +1183,10 @@ pub fn parse_incremental(
     let prev_token_end = find_token_end_before(tokens, change.start);
 
     // Create the change marker with extended region
-    let marker = ChangeMarker::from_change(&change, prev_token_end);
+    let mut marker = ChangeMarker::from_change(&change, prev_token_end);
+    if incremental::balance_changed(old_source, tokens, &change) {
+        marker.extend_to_end();
+    }
 
     // Create syntax cursor for navigating old AST
     let cursor = SyntaxCursor::new(&old_result.module, &old_result.arena, marker);
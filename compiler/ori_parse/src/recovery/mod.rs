@@ -4,7 +4,9 @@
 //! Uses bitset-based O(1) membership testing inspired by Go's parser.
 
 use super::cursor::Cursor;
-use ori_ir::TokenKind;
+use crate::error::mistakes::closing_delimiter;
+use crate::error::{ParseError, ParseErrorKind};
+use ori_ir::{Span, TokenKind};
 
 // Compile-time assertion: TokenSet uses a u128 bitset, so all discriminant
 // indices must fit in 0..127. If this fails, TokenSet needs a wider backing type.
@@ -84,7 +86,15 @@ impl TokenSet {
     /// O(1) bitwise AND operation.
     #[inline]
     pub const fn contains(&self, kind: &TokenKind) -> bool {
-        (self.0 & (1u128 << kind.discriminant_index())) != 0
+        self.contains_tag(kind.discriminant_index())
+    }
+
+    /// Check if this set contains the kind with discriminant index `tag`.
+    ///
+    /// Lets token scans test the dense tag array without touching `TokenKind`.
+    #[inline]
+    pub const fn contains_tag(&self, tag: u8) -> bool {
+        (self.0 & (1u128 << tag)) != 0
     }
 
     /// Check if this set is empty.
@@ -224,6 +234,23 @@ pub const EXPR_FOLLOW: TokenSet = TokenSet::new()
     .with(TokenKind::Comma) // Separator
     .with(TokenKind::Newline); // Line break
 
+/// Recovery set for an import misplaced after declarations.
+/// Skips the rest of the import up to the next declaration or import.
+pub const IMPORT_FOLLOW: TokenSet = TokenSet::new()
+    .with(TokenKind::At) // Function/test definition
+    .with(TokenKind::Trait) // Trait definition
+    .with(TokenKind::Impl) // Impl block
+    .with(TokenKind::Type) // Type declaration
+    .with(TokenKind::Use) // Import statement
+    .with(TokenKind::Extension); // Extension import
+
+/// Tokens showing that an open delimiter was never closed: the next
+/// function, end of file, or a closing delimiter of a different kind.
+const UNCLOSED_BOUNDARY: TokenSet = FUNCTION_BOUNDARY
+    .with(TokenKind::RParen)
+    .with(TokenKind::RBracket)
+    .with(TokenKind::RBrace);
+
 // Additional recovery sets are defined as needed in the parser.
 // See plans/ori_parse_improvements/ for planned additions.
 
@@ -231,13 +258,14 @@ pub const EXPR_FOLLOW: TokenSet = TokenSet::new()
 ///
 /// Returns `true` if a recovery token was found, `false` if EOF was reached.
 pub fn synchronize(cursor: &mut Cursor<'_>, recovery: TokenSet) -> bool {
-    while !cursor.is_at_end() {
-        if recovery.contains(cursor.current_kind()) {
-            return true;
-        }
-        cursor.advance();
-    }
-    false
+    let stop = recovery.with(TokenKind::Eof);
+    let rest = cursor.tokens().slice(cursor.position()..);
+    // The stream always ends with EOF, so the scan stops by the last token
+    let target = rest
+        .find_tag(|tag| stop.contains_tag(tag))
+        .unwrap_or_else(|| rest.end().saturating_sub(1));
+    cursor.set_position(target);
+    !cursor.is_at_end()
 }
 
 /// Advance the cursor until reaching a token in the recovery set or EOF,
@@ -246,15 +274,46 @@ pub fn synchronize(cursor: &mut Cursor<'_>, recovery: TokenSet) -> bool {
 /// Returns `Some(count)` if a recovery token was found, `None` if EOF was reached.
 #[cfg(test)]
 pub fn synchronize_counted(cursor: &mut Cursor<'_>, recovery: TokenSet) -> Option<usize> {
-    let mut count = 0;
-    while !cursor.is_at_end() {
-        if recovery.contains(cursor.current_kind()) {
-            return Some(count);
-        }
-        cursor.advance();
-        count += 1;
+    let start = cursor.position();
+    synchronize(cursor, recovery).then(|| cursor.position() - start)
+}
+
+/// Report `open` as unclosed if the cursor sits on an [`UNCLOSED_BOUNDARY`]
+/// token other than its own closer.
+///
+/// The error points at the opener, so a missing `}` yields one targeted
+/// "unclosed `{`" error instead of a confusing one at the next item.
+pub(crate) fn unclosed_delimiter_error(
+    cursor: &Cursor<'_>,
+    open: &TokenKind,
+    open_span: Span,
+) -> Option<ParseError> {
+    let expected_close = closing_delimiter(open);
+    let current = cursor.current_kind();
+    if *current == expected_close || !UNCLOSED_BOUNDARY.contains(current) {
+        return None;
+    }
+    let kind = ParseErrorKind::UnclosedDelimiter {
+        open: open.clone(),
+        open_span,
+        expected_close,
+    };
+    Some(ParseError::from_kind(&kind, open_span))
+}
+
+/// Consume the closer for `open`, returning its span.
+///
+/// Falls back to the ordinary `expect` error when the current token is not
+/// evidence of an unclosed delimiter (see [`unclosed_delimiter_error`]).
+pub(crate) fn expect_closing(
+    cursor: &mut Cursor<'_>,
+    open: &TokenKind,
+    open_span: Span,
+) -> Result<Span, ParseError> {
+    match cursor.expect(&closing_delimiter(open)) {
+        Ok(token) => Ok(token.span),
+        Err(err) => Err(unclosed_delimiter_error(cursor, open, open_span).unwrap_or(err)),
     }
-    None
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 use super::*;
-use ori_ir::{StringInterner, TokenList};
+use ori_ir::{Span, StringInterner, TokenList};
 
 /// Owns the token list and interner so `Cursor` can borrow them
 /// without `Box::leak`.
@@ -148,6 +148,34 @@ fn test_synchronize_eof() {
     assert!(cursor.is_at_end());
 }
 
+#[test]
+fn test_synchronize_stops_at_eof_in_recovery_set() {
+    let ctx = TestCtx::new("a b c");
+    let mut cursor = ctx.cursor();
+
+    // EOF is in STMT_BOUNDARY, but reaching it still reports "not found"
+    let found = synchronize(&mut cursor, STMT_BOUNDARY);
+    assert!(!found);
+    assert!(cursor.is_at_end());
+}
+
+#[test]
+fn test_token_set_contains_tag() {
+    assert!(IMPORT_FOLLOW.contains_tag(TokenKind::Use.discriminant_index()));
+    assert!(IMPORT_FOLLOW.contains_tag(TokenKind::At.discriminant_index()));
+    assert!(!IMPORT_FOLLOW.contains_tag(TokenKind::Dot.discriminant_index()));
+}
+
+#[test]
+fn test_misplaced_import_skips_to_next_declaration() {
+    let source = "@f () -> int = 1;\nuse std.math { sqrt, abs }\n@g () -> int = 2;\n";
+    let (errors, function_count) = parse_errors(source);
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert!(errors[0].message().contains("must appear at the beginning"));
+    // The whole import is skipped and `@g` still parses
+    assert_eq!(function_count, 2);
+}
+
 #[test]
 fn test_synchronize_counted() {
     let ctx = TestCtx::new("a b c @func");
@@ -269,3 +297,98 @@ fn test_format_expected_multiple() {
     assert!(result.contains("`)`"));
     assert!(result.contains("`}`"));
 }
+
+// === Unclosed delimiters ===
+
+fn parse_errors(source: &str) -> (Vec<ParseError>, usize) {
+    let interner = StringInterner::new();
+    let tokens = ori_lexer::lex(source, &interner);
+    let output = crate::parse(&tokens, &interner);
+    (output.errors, output.module.functions.len())
+}
+
+#[test]
+fn test_unclosed_function_body_brace() {
+    let source =
+        "@main () -> void = {\n    let x = 1;\n    print(msg: \"hi\")\n\n@other () -> int = 42;\n";
+    let (errors, function_count) = parse_errors(source);
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    // Points at the opening `{`, not at `@other`
+    assert_eq!(errors[0].span(), Span::new(19, 20));
+    assert_eq!(errors[0].context(), Some("`{` opened here"));
+    // Parsing resumes at the next function
+    assert_eq!(function_count, 1);
+}
+
+#[test]
+fn test_unclosed_block_at_eof() {
+    let (errors, _) = parse_errors("@main () -> void = {\n    let x = 1;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(19, 20));
+}
+
+#[test]
+fn test_unclosed_tuple_paren() {
+    let (errors, function_count) =
+        parse_errors("@f () -> (int, int) = (1, 2\n\n@g () -> int = 3;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(22, 23));
+    assert_eq!(errors[0].context(), Some("`(` opened here"));
+    assert_eq!(function_count, 1);
+}
+
+#[test]
+fn test_mismatched_closer_reports_unclosed_opener() {
+    let (errors, _) = parse_errors("@f () -> int = (1 + 2];\n\n@g () -> int = 3;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(15, 16));
+}
+
+#[test]
+fn test_unclosed_list_literal() {
+    let (errors, function_count) = parse_errors("@f () -> [int] = [1, 2\n\n@g () -> int = 3;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(17, 18));
+    assert_eq!(errors[0].context(), Some("`[` opened here"));
+    assert_eq!(function_count, 1);
+}
+
+#[test]
+fn test_unclosed_list_literal_at_eof() {
+    let (errors, _) = parse_errors("@f () -> [int] = [1, 2");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(17, 18));
+}
+
+#[test]
+fn test_unclosed_call_arguments() {
+    let (errors, function_count) =
+        parse_errors("@f () -> int = g(a: 1, b: 2\n\n@g (a: int, b: int) -> int = a;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(16, 17));
+    assert_eq!(errors[0].context(), Some("`(` opened here"));
+    assert_eq!(function_count, 1);
+}
+
+#[test]
+fn test_unclosed_index_bracket() {
+    let (errors, _) = parse_errors("@f (xs: [int]) -> int = xs[0\n\n@g () -> int = 3;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1003);
+    assert_eq!(errors[0].span(), Span::new(26, 27));
+}
+
+#[test]
+fn test_non_boundary_token_keeps_expect_error() {
+    // `;` is not evidence of an unclosed `(`; keep the plain expectation error
+    let (errors, _) = parse_errors("@f () -> (int, int) = (1, 2;\n");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E1001);
+}
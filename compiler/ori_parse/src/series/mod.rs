@@ -16,8 +16,9 @@
 //! Inspired by Gleam's `series_of()` combinator which handles comma-separated
 //! lists with trailing separator support.
 
-use ori_ir::TokenKind;
+use ori_ir::{Span, TokenKind};
 
+use crate::recovery::{expect_closing, unclosed_delimiter_error};
 use crate::{ParseError, Parser};
 
 /// Configuration for parsing a series of items.
@@ -35,6 +36,9 @@ pub struct SeriesConfig {
     pub min_count: usize,
     /// Maximum number of items allowed (None = unlimited).
     pub max_count: Option<usize>,
+    /// Opening delimiter and its span, reported as unclosed when the series
+    /// runs into the next item or a mismatched closer.
+    pub opener: Option<(TokenKind, Span)>,
 }
 
 /// Policy for trailing separators.
@@ -62,6 +66,7 @@ impl SeriesConfig {
             skip_newlines: true,
             min_count: 0,
             max_count: None,
+            opener: None,
         }
     }
 
@@ -75,6 +80,7 @@ impl SeriesConfig {
             skip_newlines: false,
             min_count: 1,
             max_count: None,
+            opener: None,
         }
     }
 
@@ -102,6 +108,15 @@ impl SeriesConfig {
         self
     }
 
+    /// Record the opening delimiter so a missing terminator is reported
+    /// as an unclosed `open` at `open_span`.
+    #[inline]
+    #[must_use]
+    pub fn opened_by(mut self, open: TokenKind, open_span: Span) -> Self {
+        self.opener = Some((open, open_span));
+        self
+    }
+
     /// Set trailing separator policy.
     #[inline]
     #[must_use]
@@ -178,6 +193,11 @@ impl Parser<'_> {
                 }
             } else if !self.cursor.check(&config.terminator) && !self.cursor.is_at_end() {
                 // No separator and no terminator — error
+                if let Some(err) = config.opener.as_ref().and_then(|(open, open_span)| {
+                    unclosed_delimiter_error(&self.cursor, open, *open_span)
+                }) {
+                    return Err(err);
+                }
                 return Err(ParseError::expected_separator_or_terminator(
                     self.cursor.current_span(),
                     &config.separator,
@@ -267,13 +287,20 @@ impl Parser<'_> {
 
     /// Parse a comma-separated series in brackets: `[item, item, ...]`
     ///
-    /// Expects `[` to already be consumed. Consumes the closing `]`.
-    pub fn bracket_series<T, F>(&mut self, parse_item: F) -> Result<Vec<T>, ParseError>
+    /// Expects `[` (at `open_span`) to already be consumed. Consumes the
+    /// closing `]`, reporting the `[` as unclosed when it is missing.
+    pub fn bracket_series<T, F>(
+        &mut self,
+        open_span: Span,
+        parse_item: F,
+    ) -> Result<Vec<T>, ParseError>
     where
         F: FnMut(&mut Self) -> Result<Option<T>, ParseError>,
     {
-        let items = self.series(&SeriesConfig::comma(TokenKind::RBracket), parse_item)?;
-        self.cursor.expect(&TokenKind::RBracket)?;
+        let config =
+            SeriesConfig::comma(TokenKind::RBracket).opened_by(TokenKind::LBracket, open_span);
+        let items = self.series(&config, parse_item)?;
+        expect_closing(&mut self.cursor, &TokenKind::LBracket, open_span)?;
         Ok(items)
     }
 
@@ -341,14 +368,20 @@ impl Parser<'_> {
 
     /// Direct-push series in brackets: `[item, item, ...]`
     ///
-    /// Expects `[` to already be consumed. Consumes the closing `]`.
-    pub fn bracket_series_direct<F>(&mut self, parse_and_push: F) -> Result<usize, ParseError>
+    /// Expects `[` (at `open_span`) to already be consumed. Consumes the
+    /// closing `]`, reporting the `[` as unclosed when it is missing.
+    pub fn bracket_series_direct<F>(
+        &mut self,
+        open_span: Span,
+        parse_and_push: F,
+    ) -> Result<usize, ParseError>
     where
         F: FnMut(&mut Self) -> Result<bool, ParseError>,
     {
-        let count =
-            self.series_direct(&SeriesConfig::comma(TokenKind::RBracket), parse_and_push)?;
-        self.cursor.expect(&TokenKind::RBracket)?;
+        let config =
+            SeriesConfig::comma(TokenKind::RBracket).opened_by(TokenKind::LBracket, open_span);
+        let count = self.series_direct(&config, parse_and_push)?;
+        expect_closing(&mut self.cursor, &TokenKind::LBracket, open_span)?;
         Ok(count)
     }
 
@@ -9,6 +9,7 @@ fn series_config_comma_defaults() {
     assert!(config.skip_newlines);
     assert_eq!(config.min_count, 0);
     assert_eq!(config.max_count, None);
+    assert_eq!(config.opener, None);
 }
 
 #[test]
@@ -33,3 +34,10 @@ fn series_config_builder_methods() {
     assert!(!config.skip_newlines);
     assert_eq!(config.trailing, TrailingSeparator::Forbidden);
 }
+
+#[test]
+fn series_config_opened_by() {
+    let span = Span::new(4, 5);
+    let config = SeriesConfig::comma(TokenKind::RBracket).opened_by(TokenKind::LBracket, span);
+    assert_eq!(config.opener, Some((TokenKind::LBracket, span)));
+}
@@ -224,6 +224,10 @@ mod pattern_matrix {
         "0..10",
         "0..=10",
         "1..100",
+        "-10..=-1",
+        "0..",
+        "..0",
+        "..=0",
         // Or patterns
         "1 | 2",
         "1 | 2 | 3",
@@ -311,6 +315,107 @@ mod pattern_matrix {
             );
         }
     }
+
+    #[test]
+    fn test_rest_pattern_forms() {
+        for pat in ["[..]", "[..rest]", "[head, ..]", "[head, ..tail]"] {
+            let source = format!(r"@test () -> int = match value {{ {} -> 1, _ -> 0 }}", pat);
+            let result = parse_source(&source);
+            assert!(
+                !result.has_errors(),
+                "Rest pattern '{}' failed:\n{:?}",
+                pat,
+                result.errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_patterns_in_tuples() {
+        // `..hi` is a range, not a tuple rest
+        for pat in ["(..0, 'a'..)", "(0..=9, ..='z')"] {
+            let source = format!(r"@test () -> int = match value {{ {} -> 1, _ -> 0 }}", pat);
+            let result = parse_source(&source);
+            assert!(
+                !result.has_errors(),
+                "Range pattern '{}' failed:\n{:?}",
+                pat,
+                result.errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_pattern_bound_errors() {
+        let cases = &[
+            ("0..limit", "must be integer or char literals"),
+            ("lo..=9", "expected"),
+            ("..=limit", "must be integer or char literals"),
+            ("..=", "needs an upper bound"),
+            ("..", "needs an upper bound"),
+        ];
+        for (pat, expected) in cases {
+            let source = format!(r"@test () -> int = match value {{ {} -> 1, _ -> 0 }}", pat);
+            let result = parse_source(&source);
+            assert!(
+                result
+                    .errors
+                    .first()
+                    .is_some_and(|e| e.message().contains(expected)),
+                "Pattern '{}' should fail with '{}':\n{:?}",
+                pat,
+                expected,
+                result.errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_misplaced_rest_patterns_rejected() {
+        let cases = &[
+            ("[a, ..b, ..c]", "at most one rest pattern"),
+            ("[.., ..]", "at most one rest pattern"),
+            ("[a, ..rest, z]", "must be the last element"),
+            ("(a, ..)", "only allowed in list patterns"),
+            ("(a, .., z)", "only allowed in list patterns"),
+        ];
+
+        let binding_cases = &[
+            ("[a, ..b, ..c]", "at most one rest pattern"),
+            ("[..rest, z]", "must be the last element"),
+            ("(a, ..)", "only allowed in list patterns"),
+        ];
+        for (pat, expected) in binding_cases {
+            let source = format!(r"@test () -> int = {{ let {} = value; 0 }}", pat);
+            let result = parse_source(&source);
+            assert!(
+                result
+                    .errors
+                    .first()
+                    .is_some_and(|e| e.message().contains(expected)),
+                "Binding pattern '{}' should fail with '{}':\n{:?}",
+                pat,
+                expected,
+                result.errors
+            );
+        }
+
+        for (pat, expected) in cases {
+            let source = format!(r"@test () -> int = match value {{ {} -> 1, _ -> 0 }}", pat);
+            let result = parse_source(&source);
+            assert!(
+                result
+                    .errors
+                    .first()
+                    .is_some_and(|e| e.code() == ori_diagnostic::ErrorCode::E1008
+                        && e.message().contains(expected)),
+                "Pattern '{}' should fail with '{}':\n{:?}",
+                pat,
+                expected,
+                result.errors
+            );
+        }
+    }
 }
 
 mod expression_context {
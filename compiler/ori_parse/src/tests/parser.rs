@@ -9,10 +9,10 @@
     reason = "test assertions use unwrap/expect for clarity"
 )]
 
-use crate::{parse, ParseContext, ParseOutput, Parser};
+use crate::{parse, parse_expression, ParseContext, ParseError, ParseOutput, Parser};
 use ori_ir::{
-    BinaryOp, BindingPattern, ExprKind, FunctionExpKind, FunctionSeq, Mutability, StmtKind,
-    StringInterner,
+    BinaryOp, BindingPattern, ExprKind, FunctionExpKind, FunctionSeq, MatchPattern, Mutability,
+    Name, ParsedType, StmtKind, StringInterner, StructLitField, TraitItem,
 };
 
 fn parse_source(source: &str) -> ParseOutput {
@@ -518,6 +518,38 @@ trait Async {}
     assert_eq!(func.capabilities.len(), 1);
 }
 
+/// `async @f` reports the keyword once and still parses the function.
+#[test]
+fn test_async_function_reports_and_recovers() {
+    let result = parse_source("async @fetch () -> int = 42;\n\n@main () -> void = ();");
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    assert_eq!(result.errors[0].code, ori_diagnostic::ErrorCode::E1015);
+    assert!(result.errors[0].message.contains("uses Suspend"));
+    assert_eq!(result.module.functions.len(), 2);
+}
+
+/// Prefix and postfix `await` are rejected; the operand stays in place.
+#[test]
+fn test_await_is_rejected() {
+    for body in ["await fetch()", "fetch().await"] {
+        let result = parse_source(&format!("@main () -> int = {body};"));
+        assert_eq!(result.errors.len(), 1, "{body}: {:?}", result.errors);
+        assert_eq!(result.errors[0].code, ori_diagnostic::ErrorCode::E1015);
+        let main = &result.module.functions[0];
+        assert!(
+            matches!(result.arena.get_expr(main.body).kind, ExprKind::Call { .. }),
+            "{body}: body should be the call itself"
+        );
+    }
+}
+
+/// `await` remains an ordinary identifier where no operand follows it.
+#[test]
+fn test_await_as_identifier() {
+    let result = parse_source("@main () -> int = { let await = 1; await + await(x: 2) }");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+}
+
 #[test]
 fn test_shift_right_operator() {
     // >> is detected as two adjacent > tokens in expression context
@@ -572,6 +604,69 @@ fn test_greater_equal_operator() {
     }
 }
 
+#[test]
+fn test_power_operator_is_right_associative() {
+    // ** is detected as two adjacent * tokens; 2 ** 3 ** 2 == 2 ** (3 ** 2)
+    let result = parse_source("@test () -> int = 2 ** 3 ** 2;");
+
+    assert!(
+        !result.has_errors(),
+        "Expected no parse errors: {:?}",
+        result.errors
+    );
+
+    let func = &result.module.functions[0];
+    let body = result.arena.get_expr(func.body);
+
+    let ExprKind::Binary {
+        op: BinaryOp::Pow,
+        right,
+        ..
+    } = &body.kind
+    else {
+        panic!("Expected power (**) binary expression, got {:?}", body.kind);
+    };
+    assert!(matches!(
+        result.arena.get_expr(*right).kind,
+        ExprKind::Binary {
+            op: BinaryOp::Pow,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_power_binds_tighter_than_multiply() {
+    // 3 * 2 ** 2 == 3 * (2 ** 2); `* *` with a space is not `**`
+    let result = parse_source("@test () -> int = 3 * 2 ** 2;");
+
+    assert!(
+        !result.has_errors(),
+        "Expected no parse errors: {:?}",
+        result.errors
+    );
+
+    let func = &result.module.functions[0];
+    let body = result.arena.get_expr(func.body);
+
+    let ExprKind::Binary {
+        op: BinaryOp::Mul,
+        right,
+        ..
+    } = &body.kind
+    else {
+        panic!("Expected multiply binary expression, got {:?}", body.kind);
+    };
+    assert!(matches!(
+        result.arena.get_expr(*right).kind,
+        ExprKind::Binary {
+            op: BinaryOp::Pow,
+            ..
+        }
+    ));
+    assert!(parse_source("@test () -> int = 2 * * 2;").has_errors());
+}
+
 #[test]
 fn test_shift_left_operator() {
     // << should still work (single token from lexer)
@@ -764,7 +859,7 @@ fn test_context_methods() {
 
 mod metadata_tests {
     use crate::parse_with_metadata;
-    use ori_ir::{ModuleExtra, StringInterner};
+    use ori_ir::{CommentKind, ModuleExtra, StringInterner};
 
     fn parse_with_comments(source: &str) -> crate::ParseOutput {
         let interner = StringInterner::new();
@@ -901,6 +996,55 @@ mod metadata_tests {
         assert_eq!(bar_docs.len(), 1, "bar should have one doc comment");
     }
 
+    #[test]
+    fn test_metadata_attaches_doc_blocks_to_declarations() {
+        // No blank line separates the two blocks: the end of `inc` does.
+        let source = r"// #Adds one.
+// * x: The input.
+@inc (x: int) -> int = x + 1;
+// #Doubles.
+@double (x: int) -> int = x * 2;
+
+// #Orphan: nothing follows.
+";
+        let output = parse_with_comments(source);
+        let inc = &output.module.functions[0];
+        let double = &output.module.functions[1];
+
+        let inc_docs = output.metadata.attached_docs(inc.span);
+        assert_eq!(inc_docs.len(), 2);
+        assert_eq!(inc_docs[0].kind, CommentKind::DocDescription);
+        assert_eq!(inc_docs[1].kind, CommentKind::DocMember);
+
+        let double_docs = output.metadata.attached_docs(double.span);
+        assert_eq!(double_docs.len(), 1);
+        assert_eq!(
+            double_docs[0].span,
+            output.metadata.comments.as_slice()[2].span
+        );
+
+        assert_eq!(output.metadata.doc_attachments.len(), 2);
+        assert!(
+            !output.metadata.is_attached(3),
+            "trailing orphan stays unattached"
+        );
+    }
+
+    #[test]
+    fn test_metadata_blank_line_breaks_doc_block() {
+        let source = r"// #Detached by the blank line.
+
+// * v: The value.
+type Wrapper = { v: int }
+";
+        let output = parse_with_comments(source);
+        let docs = output.metadata.attached_docs(output.module.types[0].span);
+
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].kind, CommentKind::DocMember);
+        assert!(!output.metadata.is_attached(0));
+    }
+
     #[test]
     fn test_metadata_multiline() {
         let source = "@main () -> int = {\n    let x = 1;\n    x + 1\n}\n";
@@ -961,6 +1105,7 @@ mod metadata_tests {
             blank_lines: lex_output.blank_lines.clone(),
             newlines: lex_output.newlines.clone(),
             trailing_commas: Vec::new(),
+            doc_attachments: Vec::new(),
         };
 
         let output = parse_with_metadata(&lex_output.tokens, metadata, &interner);
@@ -999,7 +1144,8 @@ mod metadata_tests {
             crate::ParseWarning::DetachedDocComment { reason, .. } => {
                 assert_eq!(*reason, crate::DetachmentReason::BlankLine);
             }
-            other @ crate::ParseWarning::UnknownCallingConvention { .. } => {
+            other @ (crate::ParseWarning::UnknownCallingConvention { .. }
+            | crate::ParseWarning::MixedIndentation { .. }) => {
                 panic!("expected DetachedDocComment, got {other:?}")
             }
         }
@@ -1022,12 +1168,35 @@ mod metadata_tests {
             crate::ParseWarning::DetachedDocComment { reason, .. } => {
                 assert_eq!(*reason, crate::DetachmentReason::NoFollowingDeclaration);
             }
-            other @ crate::ParseWarning::UnknownCallingConvention { .. } => {
+            other @ (crate::ParseWarning::UnknownCallingConvention { .. }
+            | crate::ParseWarning::MixedIndentation { .. }) => {
                 panic!("expected DetachedDocComment, got {other:?}")
             }
         }
     }
 
+    #[test]
+    fn test_warning_for_mixed_indentation() {
+        let interner = StringInterner::new();
+        let source = "@main () -> int = {\n \t42\n}\n";
+        let options = ori_lexer::LexOptions {
+            check_indentation: true,
+        };
+        let lex_output = ori_lexer::lex_with_options(source, &interner, options);
+        let mixed = lex_output.mixed_indentation.clone();
+        let (tokens, metadata) = lex_output.into_parts();
+        let mut output = parse_with_metadata(&tokens, metadata, &interner);
+        output.report_mixed_indentation(&mixed);
+
+        assert_eq!(output.warnings.len(), 1);
+        let warning = &output.warnings[0];
+        assert_eq!(warning.span(), ori_ir::Span::new(20, 22));
+        assert_eq!(
+            warning.to_diagnostic().code,
+            ori_diagnostic::ErrorCode::W1003
+        );
+    }
+
     #[test]
     fn test_no_warning_for_regular_comments() {
         let source = r"// Regular comment (not a doc comment)
@@ -1130,6 +1299,24 @@ fn test_valid_declarations_at_module_level() {
     }
 }
 
+#[test]
+fn test_trait_default_and_abstract_methods() {
+    let result = parse_source(
+        "trait Shape {\n    @area (self) -> float = 3.14;\n    @name (self) -> str;\n}\n\
+         type Circle = { r: float }\n\
+         impl Shape for Circle {\n    @name (self) -> str = \"circle\";\n}",
+    );
+    assert!(!result.has_errors(), "{:?}", result.errors);
+
+    let items = &result.module.traits[0].items;
+    assert_eq!(items.len(), 2);
+    assert!(matches!(items[0], TraitItem::DefaultMethod(_)));
+    assert!(matches!(items[1], TraitItem::MethodSig(_)));
+
+    // The impl overrides only the abstract method.
+    assert_eq!(result.module.impls[0].methods.len(), 1);
+}
+
 /// Invalid tokens at module top level must produce errors, not pass silently.
 #[test]
 fn test_invalid_tokens_at_module_level_produce_errors() {
@@ -1202,6 +1389,52 @@ fn test_return_in_function_body_produces_error() {
     );
 }
 
+/// Byte literals lex but have no expression form yet, so the parser names
+/// them instead of reporting an unexpected token.
+#[test]
+fn test_byte_literals_produce_specific_error() {
+    let result = parse_source("@f () -> byte = b'A';");
+    let err = &result.errors[0];
+    assert_eq!(err.code, ori_diagnostic::ErrorCode::E1002);
+    assert_eq!(err.message, "byte literals are not supported yet");
+    assert_eq!(err.help, ["Convert an integer instead: `byte(65)`"]);
+
+    let result = parse_source("@f () -> [byte] = b\"GET\";");
+    let err = &result.errors[0];
+    assert_eq!(err.message, "byte string literals are not supported yet");
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+}
+
+/// Suffixed numeric literals lex but there are no sized numeric types, so
+/// the parser names the suffix and suggests the plain literal.
+#[test]
+fn test_suffixed_number_produces_specific_error() {
+    let cases = [
+        ("255u8", "u8", "Convert an integer instead: `byte(255)`"),
+        (
+            "42i32",
+            "i32",
+            "Write `42` without a suffix: integer literals are 64-bit `int`s",
+        ),
+        (
+            "3.0f32",
+            "f32",
+            "Write `3.0` without a suffix: float literals are 64-bit `float`s",
+        ),
+    ];
+    for (literal, suffix, help) in cases {
+        let result = parse_source(&format!("@f () -> int = {literal};"));
+        assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+        let err = &result.errors[0];
+        assert_eq!(err.code, ori_diagnostic::ErrorCode::E1002);
+        assert_eq!(
+            err.message,
+            format!("numeric literal suffix `{suffix}` is not supported yet")
+        );
+        assert_eq!(err.help, [help]);
+    }
+}
+
 /// `let x = 42` (without `$`) at module level produces a specific error about immutability.
 #[test]
 fn test_mutable_let_at_module_level_rejected() {
@@ -1729,3 +1962,383 @@ fn test_let_expr_default_mutable_on_pattern() {
         "let x (no $): BindingPattern.mutable should be Mutable"
     );
 }
+
+// Struct spread: `P { x: 1, ...base }`
+
+/// Parse `source` and return the struct literal fields of the first function body.
+fn struct_spread_fields(result: &ParseOutput) -> Vec<StructLitField> {
+    let func = &result.module.functions[0];
+    let body = result.arena.get_expr(func.body);
+    let ExprKind::StructWithSpread { fields, .. } = &body.kind else {
+        panic!("expected StructWithSpread, got {body:?}");
+    };
+    result.arena.get_struct_lit_fields(*fields).to_vec()
+}
+
+#[test]
+fn test_struct_spread_after_fields() {
+    let result = parse_source("@f (old: P) -> P = P { x: 1, ...old };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+
+    let fields = struct_spread_fields(&result);
+    assert_eq!(fields.len(), 2);
+    assert!(matches!(fields[0], StructLitField::Field(_)));
+    assert!(matches!(fields[1], StructLitField::Spread { .. }));
+}
+
+#[test]
+fn test_struct_spread_before_fields() {
+    // Spread may appear anywhere; later entries override earlier ones
+    let result = parse_source("@f (old: P) -> P = P { ...old, x: 1 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+
+    let fields = struct_spread_fields(&result);
+    assert!(matches!(fields[0], StructLitField::Spread { .. }));
+    assert!(matches!(fields[1], StructLitField::Field(_)));
+}
+
+#[test]
+fn test_struct_update_with_two_dots_is_error() {
+    // Rust-style `..old` gets one targeted error but still parses as a spread
+    let result = parse_source("@f (old: P) -> P = P { x: 1, ..old };");
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    assert!(
+        result.errors[0].message().contains("`...`"),
+        "message: {}",
+        result.errors[0].message()
+    );
+    assert_eq!(result.errors[0].span(), ori_ir::Span::new(29, 31));
+
+    let fields = struct_spread_fields(&result);
+    assert!(matches!(fields[1], StructLitField::Spread { .. }));
+}
+
+// Range patterns: `lo..hi`, `lo..=hi`, `lo..`, `..hi`, `..=hi`
+
+/// Return the first arm pattern of the first function's match body,
+/// as `(start, end, inclusive)` literal values.
+fn first_range_pattern(result: &ParseOutput) -> (Option<ExprKind>, Option<ExprKind>, bool) {
+    let func = &result.module.functions[0];
+    let body = result.arena.get_expr(func.body);
+    let ExprKind::FunctionSeq(seq_id) = body.kind else {
+        panic!("expected FunctionSeq, got {body:?}");
+    };
+    let FunctionSeq::Match { arms, .. } = result.arena.get_function_seq(seq_id) else {
+        panic!("expected match");
+    };
+    let arm = &result.arena.get_arms(*arms)[0];
+    let MatchPattern::Range {
+        start,
+        end,
+        inclusive,
+    } = arm.pattern
+    else {
+        panic!("expected Range pattern, got {:?}", arm.pattern);
+    };
+    let kind = |id: Option<ori_ir::ExprId>| id.map(|id| result.arena.get_expr(id).kind);
+    (kind(start), kind(end), inclusive)
+}
+
+#[test]
+fn test_range_pattern_exclusive_and_inclusive() {
+    let result = parse_source("@f (n: int) -> int = match n { 1..5 -> 1, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (Some(ExprKind::Int(1)), Some(ExprKind::Int(5)), false)
+    );
+
+    let result = parse_source("@f (n: int) -> int = match n { -5..=-1 -> 1, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (Some(ExprKind::Int(-5)), Some(ExprKind::Int(-1)), true)
+    );
+}
+
+#[test]
+fn test_range_pattern_half_open() {
+    let result = parse_source("@f (n: int) -> int = match n { 10.. -> 1, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (Some(ExprKind::Int(10)), None, false)
+    );
+
+    let result = parse_source("@f (n: int) -> int = match n { ..0 -> 1, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (None, Some(ExprKind::Int(0)), false)
+    );
+
+    let result = parse_source("@f (c: char) -> int = match c { ..='z' -> 1, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (None, Some(ExprKind::Char('z')), true)
+    );
+}
+
+#[test]
+fn test_range_pattern_int_extremes() {
+    let result = parse_source(
+        "@f (n: int) -> int = match n { -9223372036854775808..=9223372036854775807 -> 1, _ -> 0 };",
+    );
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    assert_eq!(
+        first_range_pattern(&result),
+        (
+            Some(ExprKind::Int(i64::MIN)),
+            Some(ExprKind::Int(i64::MAX)),
+            true
+        )
+    );
+
+    let result =
+        parse_source("@f (n: int) -> int = match n { -9223372036854775809..0 -> 1, _ -> 0 };");
+    assert!(result.has_errors());
+}
+
+#[test]
+fn test_float_literal_patterns() {
+    let result = parse_source("@f (x: float) -> int = match x { 1.5 -> 1, -2.25 -> 2, _ -> 0 };");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+    let body = result.arena.get_expr(result.module.functions[0].body);
+    let ExprKind::FunctionSeq(seq_id) = body.kind else {
+        panic!("expected FunctionSeq, got {body:?}");
+    };
+    let FunctionSeq::Match { arms, .. } = result.arena.get_function_seq(seq_id) else {
+        panic!("expected match");
+    };
+    let literals: Vec<ExprKind> = result.arena.get_arms(*arms)[..2]
+        .iter()
+        .map(|arm| {
+            let MatchPattern::Literal(expr) = arm.pattern else {
+                panic!("expected Literal pattern, got {:?}", arm.pattern);
+            };
+            result.arena.get_expr(expr).kind
+        })
+        .collect();
+    assert_eq!(
+        literals,
+        vec![
+            ExprKind::Float(1.5f64.to_bits()),
+            ExprKind::Float((-2.25f64).to_bits())
+        ]
+    );
+}
+
+// Template literals: `text {expr} text`
+
+#[test]
+fn test_template_literal_segments() {
+    let (result, interner) =
+        parse_source_with_interner("@f (x: int, y: int) -> str = `x={x}, sum={x + y:>4}!`;");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+
+    let body = result.arena.get_expr(result.module.functions[0].body);
+    let ExprKind::TemplateLiteral { head, parts } = body.kind else {
+        panic!("expected TemplateLiteral, got {body:?}");
+    };
+    assert_eq!(interner.lookup(head), "x=");
+
+    let parts = result.arena.get_template_parts(parts);
+    assert_eq!(parts.len(), 2);
+
+    assert_eq!(
+        result.arena.get_expr(parts[0].expr).kind,
+        ExprKind::Ident(interner.intern("x"))
+    );
+    assert_eq!(parts[0].format_spec, Name::EMPTY);
+    assert_eq!(interner.lookup(parts[0].text_after), ", sum=");
+
+    assert!(matches!(
+        result.arena.get_expr(parts[1].expr).kind,
+        ExprKind::Binary {
+            op: BinaryOp::Add,
+            ..
+        }
+    ));
+    assert_eq!(interner.lookup(parts[1].format_spec), ">4");
+    assert_eq!(interner.lookup(parts[1].text_after), "!");
+}
+
+#[test]
+fn test_template_literal_escaped_braces() {
+    let (result, interner) = parse_source_with_interner("@f () -> str = `{{literal}}`;");
+    assert!(!result.has_errors(), "errors: {:?}", result.errors);
+
+    let body = result.arena.get_expr(result.module.functions[0].body);
+    let ExprKind::TemplateFull(text) = body.kind else {
+        panic!("expected TemplateFull, got {body:?}");
+    };
+    assert_eq!(interner.lookup(text), "{literal}");
+}
+
+#[test]
+fn test_range_expression_spans() {
+    // The range span covers the start and the last present part
+    for (source, text) in [
+        ("@f () -> int = 0..10 by 2;", "0..10 by 2"),
+        ("@f () -> int = 0..=10;", "0..=10"),
+        ("@f () -> int = (0..);", "0.."),
+    ] {
+        let result = parse_source(source);
+        assert!(!result.has_errors(), "errors: {:?}", result.errors);
+        let body = result.arena.get_expr(result.module.functions[0].body);
+        assert!(matches!(body.kind, ExprKind::Range { .. }), "{source}");
+        assert_eq!(&source[body.span.to_range()], text);
+    }
+}
+
+// Standalone expression entry point
+
+fn parse_expr_source(source: &str) -> (ori_ir::ExprId, ori_ir::SharedArena, Vec<ParseError>) {
+    let interner = StringInterner::new();
+    let tokens = ori_lexer::lex(source, &interner);
+    parse_expression(&tokens, &interner)
+}
+
+#[test]
+fn test_parse_expression_precedence() {
+    let (root, arena, errors) = parse_expr_source("2 + 3 * 4");
+    assert!(errors.is_empty(), "errors: {errors:?}");
+
+    // 2 + (3 * 4)
+    let ExprKind::Binary {
+        op: BinaryOp::Add,
+        left,
+        right,
+    } = arena.get_expr(root).kind
+    else {
+        panic!("expected addition at the root");
+    };
+    assert!(matches!(arena.get_expr(left).kind, ExprKind::Int(2)));
+    let ExprKind::Binary {
+        op: BinaryOp::Mul,
+        left,
+        right,
+    } = arena.get_expr(right).kind
+    else {
+        panic!("expected multiplication on the right");
+    };
+    assert!(matches!(arena.get_expr(left).kind, ExprKind::Int(3)));
+    assert!(matches!(arena.get_expr(right).kind, ExprKind::Int(4)));
+}
+
+#[test]
+fn test_parse_expression_empty_input() {
+    let (root, _, errors) = parse_expr_source("");
+    assert_eq!(root, ori_ir::ExprId::INVALID);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_parse_expression_trailing_tokens() {
+    let (root, arena, errors) = parse_expr_source("1 + 2 3");
+    assert!(matches!(
+        arena.get_expr(root).kind,
+        ExprKind::Binary {
+            op: BinaryOp::Add,
+            ..
+        }
+    ));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span(), ori_ir::Span::new(6, 7));
+}
+
+/// Parse `@test () -> void = <lambda>;` and return the lambda's parts.
+fn parse_lambda(
+    lambda: &str,
+) -> (
+    ParseOutput,
+    StringInterner,
+    ori_ir::ParamRange,
+    ori_ir::ParsedTypeId,
+    ori_ir::ExprId,
+) {
+    let (result, interner) = parse_source_with_interner(&format!("@test () -> void = {lambda};"));
+    assert!(!result.has_errors(), "{lambda}: {:?}", result.errors);
+    let body = result.arena.get_expr(result.module.functions[0].body);
+    let ExprKind::Lambda {
+        params,
+        ret_ty,
+        body,
+    } = body.kind
+    else {
+        panic!("{lambda}: expected lambda, got {:?}", body.kind);
+    };
+    (result, interner, params, ret_ty, body)
+}
+
+#[test]
+fn test_parse_lambda_fully_annotated() {
+    let (result, interner, params, ret_ty, body) = parse_lambda("(x: int, p: Point) -> Point = p");
+
+    let params = result.arena.get_params(params);
+    assert_eq!(params.len(), 2);
+    assert_eq!(interner.lookup(params[0].name), "x");
+    assert_eq!(
+        params[0].ty,
+        Some(ParsedType::primitive(ori_ir::TypeId::INT))
+    );
+    assert!(
+        matches!(params[1].ty, Some(ParsedType::Named { name, .. }) if interner.lookup(name) == "Point")
+    );
+    assert!(matches!(
+        result.arena.get_parsed_type(ret_ty),
+        ParsedType::Named { name, .. } if interner.lookup(*name) == "Point"
+    ));
+    assert!(matches!(
+        result.arena.get_expr(body).kind,
+        ExprKind::Ident(_)
+    ));
+}
+
+#[test]
+fn test_parse_lambda_compound_return_type() {
+    let (result, _, _, ret_ty, _) = parse_lambda("(n: int) -> [int] = [n]");
+    assert!(matches!(
+        result.arena.get_parsed_type(ret_ty),
+        ParsedType::List(_)
+    ));
+}
+
+#[test]
+fn test_parse_lambda_zero_params_block_body() {
+    let (result, _, params, ret_ty, body) = parse_lambda("() -> int = { let a = 1; a + 1 }");
+    assert!(params.is_empty());
+    assert_eq!(
+        *result.arena.get_parsed_type(ret_ty),
+        ParsedType::primitive(ori_ir::TypeId::INT)
+    );
+    assert!(matches!(
+        result.arena.get_expr(body).kind,
+        ExprKind::Block { .. }
+    ));
+}
+
+#[test]
+fn test_parse_lambda_inferred() {
+    let (result, _, params, ret_ty, body) = parse_lambda("(a, b) -> a + b");
+    let params = result.arena.get_params(params);
+    assert_eq!(params.len(), 2);
+    assert!(params.iter().all(|p| p.ty.is_none()));
+    assert!(!ret_ty.is_valid());
+    assert!(matches!(
+        result.arena.get_expr(body).kind,
+        ExprKind::Binary { .. }
+    ));
+}
+
+#[test]
+fn test_parse_lambda_assignment_body_not_return_type() {
+    // `n` is not a return type: the body assigns to a captured binding
+    let (result, _, _, ret_ty, body) = parse_lambda("() -> n = n + 1");
+    assert!(!ret_ty.is_valid());
+    assert!(matches!(
+        result.arena.get_expr(body).kind,
+        ExprKind::Assign { .. }
+    ));
+}
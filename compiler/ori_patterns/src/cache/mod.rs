@@ -15,8 +15,8 @@ use crate::test_helpers::MockPatternExecutor;
 ///
 /// Type: `cache(operation: () -> T) -> T`
 ///
-/// Note: In the interpreter, caching is not implemented. The operation
-/// function is called each time. Actual caching is implemented in compiled output.
+/// Note: This definition does not memoize. The interpreter evaluates `cache`
+/// inline (`ori_eval`'s `cache_pattern`), where results are stored by key.
 #[derive(Clone, Copy)]
 pub struct CachePattern;
 
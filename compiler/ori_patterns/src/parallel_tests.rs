@@ -883,7 +883,7 @@ mod edge_cases {
 
     #[test]
     fn very_long_timeout() {
-        let timeout = Duration::from_secs(3600); // 1 hour
+        let timeout = Duration::from_hours(1);
         assert!(!timeout.is_zero());
         assert_eq!(timeout.as_secs(), 3600);
     }
@@ -448,13 +448,13 @@ impl MemoizedFunctionValue {
     /// Get the number of cached entries.
     #[cfg(test)]
     pub fn cache_size(&self) -> usize {
-        self.cache.read().map(|c| c.len()).unwrap_or(0)
+        self.cache.read().map_or(0, |c| c.len())
     }
 }
 
 impl fmt::Debug for MemoizedFunctionValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let cache_size = self.cache.read().map(|c| c.len()).unwrap_or(0);
+        let cache_size = self.cache.read().map_or(0, |c| c.len());
         f.debug_struct("MemoizedFunctionValue")
             .field("func", &self.func)
             .field("cache_entries", &cache_size)
@@ -123,6 +123,24 @@ impl ScalarInt {
         }
     }
 
+    /// Checked exponentiation. Returns `None` if the exponent is negative or
+    /// the result overflows.
+    ///
+    /// Exponents beyond `u32::MAX` only fit for bases `0`, `1`, and `-1`.
+    pub fn checked_pow(self, exp: Self) -> Option<Self> {
+        if exp.0 < 0 {
+            return None;
+        }
+        match u32::try_from(exp.0) {
+            Ok(exp) => self.0.checked_pow(exp).map(Self),
+            Err(_) => match self.0 {
+                0 | 1 => Some(self),
+                -1 => Some(Self(if exp.0 % 2 == 0 { 1 } else { -1 })),
+                _ => None,
+            },
+        }
+    }
+
     /// Checked left shift. Returns `None` if shift amount is negative, >= 64,
     /// or if the shift causes signed integer overflow.
     ///
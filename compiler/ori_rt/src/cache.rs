@@ -0,0 +1,128 @@
+//! Runtime store for the `cache(key: ..., op: ..., ttl: ...)` pattern in
+//! AOT-compiled programs.
+//!
+//! Entries are grouped by call site: codegen passes the address of a
+//! private marker global per `cache` expression, so equal keys at two
+//! sites never see each other's values. Keys and values are raw bytes —
+//! an `int` key is its eight bytes, a `str` key its UTF-8 data — and codegen
+//! only caches values that need no reference counting, so a stored value
+//! can be copied out as often as needed.
+//!
+//! The store is process-wide (tasks of a `parallel` share it) and guarded
+//! by a mutex. `ori_cache_clear` empties it; the JIT test runner calls it
+//! before each test.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+struct CacheEntry {
+    value: Box<[u8]>,
+    /// `None` when the `ttl` is too large to represent: never expires.
+    expires_at: Option<Instant>,
+}
+
+type CacheSites = HashMap<usize, HashMap<Box<[u8]>, CacheEntry>>;
+
+static CACHE: Mutex<Option<CacheSites>> = Mutex::new(None);
+
+/// Run `f` on the store, creating it on first use.
+///
+/// A panic while the lock is held can't leave an entry half-written, so a
+/// poisoned lock is simply taken over.
+fn with_cache<R>(f: impl FnOnce(&mut CacheSites) -> R) -> R {
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    f(cache.get_or_insert_with(HashMap::new))
+}
+
+/// View `len` bytes at `ptr` (empty when `ptr` is null or `len` is not positive).
+///
+/// # Safety
+/// `ptr` must point to `len` readable bytes when `len > 0`.
+unsafe fn byte_slice<'a>(ptr: *const u8, len: i64) -> &'a [u8] {
+    let len = usize::try_from(len).unwrap_or(0);
+    if ptr.is_null() || len == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(ptr, len)
+}
+
+/// Look up the value stored at `site` for the `key_len` bytes at `key`.
+///
+/// On a hit, copies the stored `size` bytes to `out` and returns `true`.
+/// Misses on expired entries, on a size mismatch, and whenever `ttl_nanos`
+/// is not positive (a zero or negative `ttl` disables caching).
+#[no_mangle]
+pub extern "C" fn ori_cache_get(
+    site: *const u8,
+    key: *const u8,
+    key_len: i64,
+    ttl_nanos: i64,
+    out: *mut u8,
+    size: i64,
+) -> bool {
+    if ttl_nanos <= 0 || out.is_null() {
+        return false;
+    }
+    // SAFETY: key points to key_len bytes (caller contract)
+    let key = unsafe { byte_slice(key, key_len) };
+    let now = Instant::now();
+    with_cache(|sites| {
+        let Some(entry) = sites.get(&site.addr()).and_then(|entries| entries.get(key)) else {
+            return false;
+        };
+        if entry.expires_at.is_some_and(|deadline| now >= deadline)
+            || i64::try_from(entry.value.len()) != Ok(size)
+        {
+            return false;
+        }
+        // SAFETY: out points to size writable bytes (caller contract), and
+        // the stored value is exactly size bytes long
+        unsafe { std::ptr::copy_nonoverlapping(entry.value.as_ptr(), out, entry.value.len()) };
+        true
+    })
+}
+
+/// Store the `size` bytes at `value` at `site` for the `key_len` bytes at
+/// `key`, expiring `ttl_nanos` from now.
+///
+/// Nothing is stored when `ttl_nanos` is not positive.
+#[no_mangle]
+pub extern "C" fn ori_cache_put(
+    site: *const u8,
+    key: *const u8,
+    key_len: i64,
+    ttl_nanos: i64,
+    value: *const u8,
+    size: i64,
+) {
+    let Ok(ttl) = u64::try_from(ttl_nanos) else {
+        return;
+    };
+    if ttl == 0 {
+        return;
+    }
+    // SAFETY: key points to key_len bytes and value to size bytes (caller contract)
+    let (key, value) = unsafe { (byte_slice(key, key_len), byte_slice(value, size)) };
+    let entry = CacheEntry {
+        value: value.into(),
+        expires_at: Instant::now().checked_add(Duration::from_nanos(ttl)),
+    };
+    with_cache(|sites| {
+        sites
+            .entry(site.addr())
+            .or_default()
+            .insert(key.into(), entry);
+    });
+}
+
+/// Drop every stored entry.
+#[no_mangle]
+pub extern "C" fn ori_cache_clear() {
+    with_cache(HashMap::clear);
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests;
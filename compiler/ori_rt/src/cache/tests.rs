@@ -0,0 +1,112 @@
+//! Tests for the runtime `cache` store.
+
+use std::ptr;
+use std::sync::{Mutex, PoisonError};
+
+use super::*;
+
+/// The store is process-wide: tests hold this lock so that
+/// `ori_cache_clear` can't wipe another test's entries mid-run.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+const FIVE_MINUTES: i64 = 300_000_000_000;
+
+/// A distinct address standing in for a `cache` call site.
+fn site_addr(marker: &u8) -> *const u8 {
+    ptr::from_ref(marker)
+}
+
+fn get(site: *const u8, key: &[u8], ttl_nanos: i64) -> Option<i64> {
+    let mut out = 0i64;
+    ori_cache_get(
+        site,
+        key.as_ptr(),
+        key.len() as i64,
+        ttl_nanos,
+        (&raw mut out).cast(),
+        8,
+    )
+    .then_some(out)
+}
+
+fn put(site: *const u8, key: &[u8], ttl_nanos: i64, value: i64) {
+    ori_cache_put(
+        site,
+        key.as_ptr(),
+        key.len() as i64,
+        ttl_nanos,
+        (&raw const value).cast(),
+        8,
+    );
+}
+
+#[test]
+fn stored_value_is_returned() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let marker = 0u8;
+    let site = site_addr(&marker);
+
+    assert_eq!(get(site, b"user-1", FIVE_MINUTES), None);
+    put(site, b"user-1", FIVE_MINUTES, 42);
+    assert_eq!(get(site, b"user-1", FIVE_MINUTES), Some(42));
+    assert_eq!(get(site, b"user-2", FIVE_MINUTES), None);
+}
+
+#[test]
+fn sites_do_not_share_entries() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let (first, second) = (0u8, 0u8);
+    let (first, second) = (site_addr(&first), site_addr(&second));
+
+    put(first, &7i64.to_ne_bytes(), FIVE_MINUTES, 70);
+    put(second, &7i64.to_ne_bytes(), FIVE_MINUTES, 700);
+    assert_eq!(get(first, &7i64.to_ne_bytes(), FIVE_MINUTES), Some(70));
+    assert_eq!(get(second, &7i64.to_ne_bytes(), FIVE_MINUTES), Some(700));
+}
+
+#[test]
+fn non_positive_ttl_disables_caching() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let marker = 0u8;
+    let site = site_addr(&marker);
+
+    put(site, b"zero", 0, 1);
+    assert_eq!(get(site, b"zero", FIVE_MINUTES), None);
+    put(site, b"zero", FIVE_MINUTES, 2);
+    assert_eq!(get(site, b"zero", 0), None);
+    assert_eq!(get(site, b"zero", -1), None);
+}
+
+#[test]
+fn expired_entry_misses() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let marker = 0u8;
+    let site = site_addr(&marker);
+
+    put(site, b"short", 1, 5);
+    std::thread::sleep(Duration::from_millis(1));
+    assert_eq!(get(site, b"short", 1), None);
+}
+
+#[test]
+fn size_mismatch_misses() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let marker = 0u8;
+    let site = site_addr(&marker);
+
+    put(site, b"wide", FIVE_MINUTES, 9);
+    let mut out = [0u8; 4];
+    let hit = ori_cache_get(site, b"wide".as_ptr(), 4, FIVE_MINUTES, out.as_mut_ptr(), 4);
+    assert!(!hit);
+}
+
+#[test]
+fn clear_drops_every_entry() {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let marker = 0u8;
+    let site = site_addr(&marker);
+
+    put(site, b"gone", FIVE_MINUTES, 3);
+    ori_cache_clear();
+    assert_eq!(get(site, b"gone", FIVE_MINUTES), None);
+}
@@ -19,7 +19,8 @@
 //!
 //! # Safety
 //!
-//! All functions use `#[no_mangle]` and `extern "C"` for FFI compatibility.
+//! All functions use `#[no_mangle]` and `extern "C"` for FFI compatibility
+//! (`extern "C-unwind"` for the panic functions, which unwind into callers).
 //! Functions that take raw pointers are called from LLVM-generated code which
 //! guarantees valid pointers. They're not marked `unsafe` because they're
 //! extern "C" FFI entry points, not Rust API functions.
@@ -51,8 +52,12 @@
     reason = "tests use &var to get pointers — intentional for FFI testing"
 )]
 
+pub mod cache;
 pub mod format;
 pub mod iterator;
+pub mod map;
+pub mod memo;
+pub mod task;
 
 use std::cell::{Cell, RefCell};
 use std::ffi::CStr;
@@ -237,6 +242,9 @@ pub unsafe fn jit_setjmp(buf: *mut JmpBuf) -> i32 {
 thread_local! {
     static PANIC_OCCURRED: RefCell<bool> = const { RefCell::new(false) };
     static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    /// Number of `catch(expr: ...)` evaluations active on this thread.
+    static CATCH_DEPTH: Cell<u32> = const { Cell::new(0) };
 }
 
 /// Check if a panic occurred (for test assertions).
@@ -484,15 +492,48 @@ pub extern "C" fn ori_print_bool(b: bool) {
     println!("{b}");
 }
 
+/// Print a character (Unicode scalar value) to stdout.
+///
+/// Invalid scalar values print U+FFFD rather than aborting.
+#[no_mangle]
+pub extern "C" fn ori_print_char(c: i32) {
+    let ch = u32::try_from(c)
+        .ok()
+        .and_then(char::from_u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+    println!("{ch}");
+}
+
+/// Print a byte to stdout in the interpreter's `0x..` form.
+#[no_mangle]
+pub extern "C" fn ori_print_byte(b: u8) {
+    println!("0x{b:02x}");
+}
+
 /// Panic with a message.
 ///
 /// Dispatch order:
 /// 1. Store panic state (for JIT test assertions)
 /// 2. If user `@panic` handler registered and not re-entrant: call trampoline
 /// 3. If JIT mode: `longjmp` back to test runner
-/// 4. AOT default: print to stderr and `exit(1)`
+/// 4. AOT default: print to stderr and unwind
+///
+/// The panic functions are `extern "C-unwind"`: with plain `extern "C"`,
+/// a Rust panic leaving them aborts the process instead of reaching the
+/// caller's landing pads (or `ori_parallel`'s `catch_unwind`).
 #[no_mangle]
-pub extern "C" fn ori_panic(s: *const OriStr) {
+pub extern "C-unwind" fn ori_panic(s: *const OriStr) {
+    ori_panic_at(s, 0, 0);
+}
+
+/// Panic with a message and the source location of the `panic(...)` call.
+///
+/// `line` and `col` are 1-based; `0` means the location is unknown (codegen
+/// without a line map) and only the message is reported. The stored panic
+/// message never includes the location, so JIT assertions on it are
+/// unaffected. Same dispatch order as `ori_panic`.
+#[no_mangle]
+pub extern "C-unwind" fn ori_panic_at(s: *const OriStr, line: i32, col: i32) {
     let msg = if s.is_null() {
         "panic!".to_string()
     } else {
@@ -501,35 +542,15 @@ pub extern "C" fn ori_panic(s: *const OriStr) {
         let text = unsafe { ori_str.as_str() };
         text.to_string()
     };
-
-    // Store panic state in thread-local storage
-    PANIC_OCCURRED.with(|p| *p.borrow_mut() = true);
-    PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(msg.clone()));
-
-    // Call user @panic handler if registered (AOT only, not re-entrant)
-    call_panic_trampoline(&msg);
-
-    // In JIT mode, longjmp back to the test runner instead of terminating
-    if is_jit_mode() {
-        let buf = JIT_RECOVERY_BUF.with(std::cell::Cell::get);
-        if !buf.is_null() {
-            // SAFETY: buf is valid — set by enter_jit_mode, stack-allocated in run_test
-            unsafe { longjmp(buf, 1) };
-        }
-    }
-
-    // AOT path: unwind via Rust panic infrastructure.
-    // LLVM invoke/landingpad in the caller will catch this and run
-    // RC cleanup before re-raising or terminating.
-    eprintln!("ori panic: {msg}");
-    panic::panic_any(OriPanic { message: msg });
+    let location = (line > 0).then_some((line, col));
+    raise_panic(msg, location);
 }
 
 /// Panic with a C string message.
 ///
 /// Same dispatch order as `ori_panic`: user handler → JIT longjmp → unwind.
 #[no_mangle]
-pub extern "C" fn ori_panic_cstr(s: *const i8) {
+pub extern "C-unwind" fn ori_panic_cstr(s: *const i8) {
     let msg = if s.is_null() {
         "panic!".to_string()
     } else {
@@ -537,7 +558,31 @@ pub extern "C" fn ori_panic_cstr(s: *const i8) {
         let cstr = unsafe { CStr::from_ptr(s) };
         cstr.to_string_lossy().to_string()
     };
+    raise_panic(msg, None);
+}
+
+/// Panic for an out-of-bounds index, reporting the offending index.
+///
+/// Emitted by codegen on the failing side of a list bounds check. The
+/// message matches the interpreter's `index N out of bounds`; `line` and
+/// `col` follow `ori_panic_at`. Same dispatch order as `ori_panic`.
+#[no_mangle]
+pub extern "C-unwind" fn ori_index_panic(index: i64, line: i32, col: i32) {
+    let location = (line > 0).then_some((line, col));
+    raise_panic(format!("index {index} out of bounds"), location);
+}
+
+/// Shared panic dispatch for `ori_panic`, `ori_panic_at`, `ori_panic_cstr`, and
+/// `ori_index_panic`.
+fn raise_panic(msg: String, location: Option<(i32, i32)>) {
+    // Inside `catch`, the panic becomes an `Err` value: unwind straight to
+    // `catch_ori_panic` without recording it, calling the user handler, or
+    // leaving JIT code through the recovery buffer.
+    if CATCH_DEPTH.with(Cell::get) > 0 {
+        panic::resume_unwind(Box::new(OriPanic { message: msg }));
+    }
 
+    // Store panic state in thread-local storage
     PANIC_OCCURRED.with(|p| *p.borrow_mut() = true);
     PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(msg.clone()));
 
@@ -553,11 +598,30 @@ pub extern "C" fn ori_panic_cstr(s: *const i8) {
         }
     }
 
-    // AOT path: unwind via Rust panic infrastructure
-    eprintln!("ori panic: {msg}");
+    // AOT path: unwind via Rust panic infrastructure.
+    // LLVM invoke/landingpad in the caller will catch this and run
+    // RC cleanup before re-raising or terminating.
+    match location {
+        Some((line, col)) => eprintln!("ori panic at {line}:{col}: {msg}"),
+        None => eprintln!("ori panic: {msg}"),
+    }
     panic::panic_any(OriPanic { message: msg });
 }
 
+/// Run `f`, turning a panic raised inside it into `Err(message)`.
+///
+/// Panics from `raise_panic` unwind silently while `f` runs; any other
+/// panic is reported as `"panic"`. Nested calls are allowed.
+pub(crate) fn catch_ori_panic(f: impl FnOnce()) -> Result<(), String> {
+    CATCH_DEPTH.with(|d| d.set(d.get() + 1));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    CATCH_DEPTH.with(|d| d.set(d.get() - 1));
+    result.map_err(|payload| match payload.downcast::<OriPanic>() {
+        Ok(p) => p.message,
+        Err(_) => "panic".to_string(),
+    })
+}
+
 /// Assert that a condition is true.
 ///
 /// Sets panic state but does NOT terminate - this allows JIT tests to check `did_panic()`.
@@ -722,6 +786,37 @@ pub extern "C" fn ori_list_free_data(data: *mut u8, capacity: i64, elem_size: i6
     }
 }
 
+/// Grow a raw data buffer allocated by `ori_list_alloc_data`.
+///
+/// Returns a buffer of `new_cap * elem_size` bytes holding the first
+/// `old_cap * elem_size` bytes of `data`. A null `data` (zero capacity)
+/// allocates a fresh buffer. Used by `for ... yield` when the result
+/// length is not known up front (e.g. iterating an iterator).
+#[no_mangle]
+pub extern "C" fn ori_list_grow_data(
+    data: *mut u8,
+    old_cap: i64,
+    new_cap: i64,
+    elem_size: i64,
+) -> *mut u8 {
+    if data.is_null() || old_cap <= 0 {
+        return ori_list_alloc_data(new_cap, elem_size);
+    }
+    if new_cap <= old_cap {
+        return data;
+    }
+    let size = elem_size.max(1) as usize;
+    let (Ok(old_layout), Some(new_bytes)) = (
+        std::alloc::Layout::array::<u8>(old_cap as usize * size),
+        (new_cap as usize).checked_mul(size),
+    ) else {
+        return std::ptr::null_mut();
+    };
+    // SAFETY: data was allocated by ori_list_alloc_data with `old_layout`,
+    // and `new_bytes` is non-zero (new_cap > old_cap > 0, size >= 1).
+    unsafe { std::alloc::realloc(data, old_layout, new_bytes) }
+}
+
 /// Get the length of a list.
 #[no_mangle]
 pub extern "C" fn ori_list_len(list: *const OriList) -> i64 {
@@ -810,9 +905,6 @@ pub extern "C" fn ori_str_compare(a: *const OriStr, b: *const OriStr) -> i8 {
 /// hash values compose correctly via `hash_combine`.
 #[no_mangle]
 pub extern "C" fn ori_str_hash(s: *const OriStr) -> i64 {
-    const FNV_OFFSET_BASIS: u64 = 14_695_981_039_346_656_037;
-    const FNV_PRIME: u64 = 1_099_511_628_211;
-
     let bytes = if s.is_null() {
         &[]
     } else {
@@ -825,12 +917,20 @@ pub extern "C" fn ori_str_hash(s: *const OriStr) -> i64 {
         }
     };
 
+    fnv1a(bytes) as i64
+}
+
+/// FNV-1a (64-bit) hash of `bytes`.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 14_695_981_039_346_656_037;
+    const FNV_PRIME: u64 = 1_099_511_628_211;
+
     let mut hash = FNV_OFFSET_BASIS;
     for &byte in bytes {
         hash ^= u64::from(byte);
         hash = hash.wrapping_mul(FNV_PRIME);
     }
-    hash as i64
+    hash
 }
 
 /// Convert an integer to a string.
@@ -855,6 +955,9 @@ pub extern "C" fn ori_str_from_bool(b: bool) -> OriStr {
 }
 
 /// Convert a float to a string.
+///
+/// Uses the shortest digits that round-trip, without an exponent
+/// (`1.0` → `"1"`, `3.5` → `"3.5"`), matching the interpreter's `to_str()`.
 #[no_mangle]
 pub extern "C" fn ori_str_from_float(f: f64) -> OriStr {
     let result = f.to_string();
@@ -887,6 +990,40 @@ pub extern "C" fn ori_max_int(a: i64, b: i64) -> i64 {
     a.max(b)
 }
 
+/// Integer power `base ** exp` by repeated squaring.
+///
+/// `exp == 0` yields 1 (including `0 ** 0`). Panics with the interpreter's
+/// messages on a negative exponent or when the result overflows `i64`.
+#[no_mangle]
+pub extern "C" fn ori_ipow(base: i64, exp: i64) -> i64 {
+    if exp < 0 {
+        raise_panic(format!("negative exponent {exp} in integer power"), None);
+        return 0;
+    }
+
+    if let Some(result) = checked_ipow(base, exp) {
+        result
+    } else {
+        raise_panic("integer overflow in exponentiation".to_string(), None);
+        0
+    }
+}
+
+/// Square-and-multiply loop behind `ori_ipow`; `None` on overflow.
+fn checked_ipow(mut base: i64, mut exp: i64) -> Option<i64> {
+    let mut result: i64 = 1;
+    loop {
+        if exp & 1 == 1 {
+            result = result.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp == 0 {
+            return Some(result);
+        }
+        base = base.checked_mul(base)?;
+    }
+}
+
 /// Convert C `argc`/`argv` to an Ori `[str]` list.
 ///
 /// Skips `argv[0]` (program name) per the Ori spec: `@main(args)` receives
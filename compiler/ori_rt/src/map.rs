@@ -0,0 +1,184 @@
+//! Hash index for maps built by compiled code.
+//!
+//! Codegen lays a map out as `{len, cap, keys, vals}`, with keys and values
+//! in parallel arrays in insertion order. A keys buffer from `ori_map_new`
+//! holds `cap` key slots followed by an open-addressing table of `u64`
+//! slots, each either empty (`0`) or a dense index plus one. Lookups hash
+//! the probe key instead of scanning, while iteration, equality, and
+//! hashing keep reading `keys[0..len]` as before.
+//!
+//! Keys are either scalars compared and hashed as raw bytes (`int`, `char`,
+//! `bool`, `byte`) or, when `str_keys` is set, `OriStr`s compared and hashed
+//! by their contents.
+
+use std::alloc::Layout;
+
+use crate::{fnv1a, OriStr};
+
+/// Byte offset of the index table: the key slots, rounded up to 8.
+fn table_offset(capacity: usize, key_size: usize) -> usize {
+    (capacity * key_size).next_multiple_of(8)
+}
+
+/// Number of index slots: a power of two at least twice `capacity`, so
+/// probing always reaches an empty slot.
+fn table_slots(capacity: usize) -> usize {
+    (capacity * 2).max(2).next_power_of_two()
+}
+
+/// Allocation layout of a keys buffer for `capacity` keys of `key_size` bytes.
+fn keys_layout(capacity: usize, key_size: usize) -> Option<Layout> {
+    let bytes = table_offset(capacity, key_size) + table_slots(capacity) * 8;
+    Layout::from_size_align(bytes, 8).ok()
+}
+
+/// Borrowed view of a keys buffer and its index table.
+struct KeyTable {
+    keys: *mut u8,
+    slots: *mut u64,
+    slot_count: usize,
+    key_size: usize,
+    str_keys: bool,
+}
+
+impl KeyTable {
+    /// View the keys buffer at `keys`, or `None` for an empty map.
+    ///
+    /// # Safety
+    /// `keys` must be null or come from `ori_map_new(capacity, key_size)`.
+    unsafe fn new(keys: *mut u8, capacity: i64, key_size: i64, str_keys: bool) -> Option<Self> {
+        let capacity = usize::try_from(capacity).ok().filter(|&cap| cap > 0)?;
+        if keys.is_null() {
+            return None;
+        }
+        let key_size = key_size.max(1) as usize;
+        Some(Self {
+            keys,
+            slots: keys.add(table_offset(capacity, key_size)).cast(),
+            slot_count: table_slots(capacity),
+            key_size,
+            str_keys,
+        })
+    }
+
+    /// The bytes that identify the key at `key`: the key itself, or the
+    /// contents of an `OriStr`.
+    ///
+    /// # Safety
+    /// `key` must point to a valid key of this table's kind.
+    unsafe fn key_bytes<'a>(&self, key: *const u8) -> &'a [u8] {
+        if self.str_keys {
+            let s = &*key.cast::<OriStr>();
+            s.as_str().as_bytes()
+        } else {
+            std::slice::from_raw_parts(key, self.key_size)
+        }
+    }
+
+    /// Probe for `key`: `Ok(index)` of an equal stored key, or `Err(slot)`
+    /// with the empty slot where it would go.
+    ///
+    /// # Safety
+    /// `key` must point to a valid key; every filled slot must name a
+    /// written key.
+    unsafe fn probe(&self, key: *const u8) -> Result<usize, usize> {
+        let wanted = self.key_bytes(key);
+        let mask = self.slot_count - 1;
+        let mut slot = fnv1a(wanted) as usize & mask;
+        loop {
+            let entry = *self.slots.add(slot);
+            if entry == 0 {
+                return Err(slot);
+            }
+            let index = (entry - 1) as usize;
+            if self.key_bytes(self.keys.add(index * self.key_size)) == wanted {
+                return Ok(index);
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+}
+
+/// Allocate the keys buffer of a map with room for `capacity` keys of
+/// `key_size` bytes, with an empty index. Returns null when `capacity` is
+/// not positive.
+#[no_mangle]
+pub extern "C" fn ori_map_new(capacity: i64, key_size: i64) -> *mut u8 {
+    let Ok(cap) = usize::try_from(capacity) else {
+        return std::ptr::null_mut();
+    };
+    if cap == 0 {
+        return std::ptr::null_mut();
+    }
+    match keys_layout(cap, key_size.max(1) as usize) {
+        // SAFETY: the layout has a non-zero size
+        Some(layout) => unsafe { std::alloc::alloc_zeroed(layout) },
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Insert `key` into the map whose keys buffer is `keys`, returning the
+/// dense index its value belongs at.
+///
+/// An equal key already present keeps its index, so a later value
+/// overwrites an earlier one. Otherwise the key is copied to `keys[*len]`
+/// and `*len` is incremented. Returns -1 if the buffer is full.
+#[no_mangle]
+pub extern "C" fn ori_map_insert(
+    keys: *mut u8,
+    capacity: i64,
+    len: *mut i64,
+    key: *const u8,
+    key_size: i64,
+    str_keys: bool,
+) -> i64 {
+    // SAFETY: keys comes from ori_map_new(capacity, key_size) (caller contract)
+    let Some(table) = (unsafe { KeyTable::new(keys, capacity, key_size, str_keys) }) else {
+        return -1;
+    };
+    // SAFETY: key points to a valid key and len to the map's length, whose
+    // keys have all been inserted through this function (caller contract)
+    unsafe {
+        let slot = match table.probe(key) {
+            Ok(index) => return index as i64,
+            Err(slot) => slot,
+        };
+        let index = *len;
+        if index >= capacity {
+            return -1;
+        }
+        let dest = keys.add(index as usize * table.key_size);
+        std::ptr::copy_nonoverlapping(key, dest, table.key_size);
+        *table.slots.add(slot) = index as u64 + 1;
+        *len = index + 1;
+        index
+    }
+}
+
+/// Find `key` in the map whose keys buffer is `keys`, returning its dense
+/// index or -1 when it is absent.
+#[no_mangle]
+pub extern "C" fn ori_map_find(
+    keys: *const u8,
+    capacity: i64,
+    key: *const u8,
+    key_size: i64,
+    str_keys: bool,
+) -> i64 {
+    // SAFETY: keys is null or comes from ori_map_new(capacity, key_size)
+    // and was filled by ori_map_insert (caller contract)
+    let Some(table) = (unsafe { KeyTable::new(keys.cast_mut(), capacity, key_size, str_keys) })
+    else {
+        return -1;
+    };
+    // SAFETY: key points to a valid key (caller contract)
+    match unsafe { table.probe(key) } {
+        Ok(index) => index as i64,
+        Err(_) => -1,
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+//! Tests for the native map hash index.
+
+use super::*;
+
+/// Insert `key` (an `i64`) and return its index.
+fn insert_int(keys: *mut u8, cap: i64, len: &mut i64, key: i64) -> i64 {
+    ori_map_insert(keys, cap, len, (&raw const key).cast(), 8, false)
+}
+
+fn find_int(keys: *const u8, cap: i64, key: i64) -> i64 {
+    ori_map_find(keys, cap, (&raw const key).cast(), 8, false)
+}
+
+fn str_key(s: &'static str) -> OriStr {
+    OriStr {
+        len: s.len() as i64,
+        data: s.as_ptr(),
+    }
+}
+
+#[test]
+fn int_keys_insert_and_find() {
+    let keys = ori_map_new(3, 8);
+    let mut len = 0;
+    assert_eq!(insert_int(keys, 3, &mut len, 1), 0);
+    assert_eq!(insert_int(keys, 3, &mut len, 2), 1);
+    assert_eq!(insert_int(keys, 3, &mut len, -7), 2);
+    assert_eq!(len, 3);
+
+    assert_eq!(find_int(keys, 3, 2), 1);
+    assert_eq!(find_int(keys, 3, -7), 2);
+    assert_eq!(find_int(keys, 3, 3), -1);
+
+    // Keys stay dense and in insertion order for iteration
+    let stored = unsafe { std::slice::from_raw_parts(keys.cast::<i64>(), 3) };
+    assert_eq!(stored, &[1, 2, -7]);
+}
+
+#[test]
+fn duplicate_key_keeps_its_index() {
+    let keys = ori_map_new(2, 8);
+    let mut len = 0;
+    assert_eq!(insert_int(keys, 2, &mut len, 5), 0);
+    assert_eq!(insert_int(keys, 2, &mut len, 5), 0);
+    assert_eq!(len, 1);
+}
+
+#[test]
+fn many_colliding_probes_find_every_key() {
+    let cap = 64;
+    let keys = ori_map_new(cap, 8);
+    let mut len = 0;
+    for k in 0..cap {
+        assert_eq!(insert_int(keys, cap, &mut len, k * 1024), k);
+    }
+    for k in 0..cap {
+        assert_eq!(find_int(keys, cap, k * 1024), k);
+    }
+    assert_eq!(find_int(keys, cap, 1), -1);
+}
+
+#[test]
+fn full_buffer_rejects_new_keys() {
+    let keys = ori_map_new(1, 8);
+    let mut len = 0;
+    assert_eq!(insert_int(keys, 1, &mut len, 1), 0);
+    assert_eq!(insert_int(keys, 1, &mut len, 2), -1);
+    assert_eq!(len, 1);
+}
+
+#[test]
+fn narrow_scalar_keys() {
+    let keys = ori_map_new(2, 4);
+    let mut len = 0;
+    let (a, b) = ('a' as i32, 'b' as i32);
+    assert_eq!(
+        ori_map_insert(keys, 2, &mut len, (&raw const a).cast(), 4, false),
+        0
+    );
+    assert_eq!(
+        ori_map_insert(keys, 2, &mut len, (&raw const b).cast(), 4, false),
+        1
+    );
+    assert_eq!(ori_map_find(keys, 2, (&raw const b).cast(), 4, false), 1);
+}
+
+#[test]
+fn str_keys_compare_contents() {
+    let size = std::mem::size_of::<OriStr>() as i64;
+    let keys = ori_map_new(3, size);
+    let mut len = 0;
+    for (i, s) in ["", "a", "ab"].into_iter().enumerate() {
+        let key = str_key(s);
+        let index = ori_map_insert(keys, 3, &mut len, (&raw const key).cast(), size, true);
+        assert_eq!(index, i as i64);
+    }
+
+    // A separately allocated copy of the text matches by contents
+    let owned = String::from("ab");
+    let probe = OriStr {
+        len: owned.len() as i64,
+        data: owned.as_ptr(),
+    };
+    assert_eq!(
+        ori_map_find(keys, 3, (&raw const probe).cast(), size, true),
+        2
+    );
+
+    let empty = str_key("");
+    assert_eq!(
+        ori_map_find(keys, 3, (&raw const empty).cast(), size, true),
+        0
+    );
+    let missing = str_key("b");
+    assert_eq!(
+        ori_map_find(keys, 3, (&raw const missing).cast(), size, true),
+        -1
+    );
+}
+
+#[test]
+fn empty_map_has_no_buffer() {
+    let keys = ori_map_new(0, 8);
+    assert!(keys.is_null());
+    assert_eq!(find_int(keys, 0, 1), -1);
+    let mut len = 0;
+    assert_eq!(insert_int(keys, 0, &mut len, 1), -1);
+}
@@ -0,0 +1,110 @@
+//! Runtime memoization for `recurse(..., memo: true)` in AOT-compiled
+//! programs.
+//!
+//! Each memoized function has a per-thread table from its argument words to
+//! its result, keyed by the function's address. Codegen brackets the body of
+//! every call with `ori_memo_enter`/`ori_memo_exit`: the outermost call
+//! creates the table and discards it on exit, so results are shared across
+//! the recursion but never between top-level calls.
+//!
+//! A panic that unwinds out of a memoized call skips its `ori_memo_exit`,
+//! so the table outlives that call. Memoized functions are pure, so a stale
+//! table only costs memory.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Results of one memoized function for its current top-level call.
+#[derive(Default)]
+struct MemoTable {
+    /// Calls of the function currently on the stack.
+    depth: u32,
+    results: HashMap<Box<[i64]>, i64>,
+}
+
+thread_local! {
+    static MEMO_TABLES: RefCell<HashMap<usize, MemoTable>> = RefCell::new(HashMap::new());
+}
+
+/// View `nargs` argument words at `args` as a cache key.
+///
+/// # Safety
+/// `args` must point to `nargs` initialized `i64`s when `nargs > 0`.
+unsafe fn memo_key<'a>(args: *const i64, nargs: i64) -> &'a [i64] {
+    let len = usize::try_from(nargs).unwrap_or(0);
+    if args.is_null() || len == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(args, len)
+}
+
+/// Enter a call of memoized `func`, creating its table at the top level.
+#[no_mangle]
+pub extern "C" fn ori_memo_enter(func: *const u8) {
+    MEMO_TABLES.with(|tables| {
+        tables.borrow_mut().entry(func.addr()).or_default().depth += 1;
+    });
+}
+
+/// Leave a call of memoized `func`, discarding its table at the top level.
+#[no_mangle]
+pub extern "C" fn ori_memo_exit(func: *const u8) {
+    MEMO_TABLES.with(|tables| {
+        let mut tables = tables.borrow_mut();
+        if let Some(table) = tables.get_mut(&func.addr()) {
+            table.depth = table.depth.saturating_sub(1);
+            if table.depth == 0 {
+                tables.remove(&func.addr());
+            }
+        }
+    });
+}
+
+/// Look up `func`'s result for the `nargs` words at `args`.
+///
+/// Writes the cached result to `out` and returns `true` on a hit.
+#[no_mangle]
+pub extern "C" fn ori_memo_lookup(
+    func: *const u8,
+    args: *const i64,
+    nargs: i64,
+    out: *mut i64,
+) -> bool {
+    // SAFETY: args points to nargs words (caller contract)
+    let key = unsafe { memo_key(args, nargs) };
+    let cached = MEMO_TABLES.with(|tables| {
+        tables
+            .borrow()
+            .get(&func.addr())
+            .and_then(|table| table.results.get(key).copied())
+    });
+    match cached {
+        Some(value) => {
+            if !out.is_null() {
+                // SAFETY: out points to an i64 slot (caller contract)
+                unsafe { out.write(value) };
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Record `value` as `func`'s result for the `nargs` words at `args`.
+///
+/// Ignored outside a call of `func` (no table to record into).
+#[no_mangle]
+pub extern "C" fn ori_memo_store(func: *const u8, args: *const i64, nargs: i64, value: i64) {
+    // SAFETY: args points to nargs words (caller contract)
+    let key = unsafe { memo_key(args, nargs) };
+    MEMO_TABLES.with(|tables| {
+        if let Some(table) = tables.borrow_mut().get_mut(&func.addr()) {
+            table.results.insert(key.into(), value);
+        }
+    });
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests;
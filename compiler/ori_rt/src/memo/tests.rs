@@ -0,0 +1,75 @@
+//! Tests for runtime memoization tables.
+
+use std::ptr;
+
+use super::*;
+
+/// A distinct address standing in for a compiled function.
+fn func_addr(marker: &u8) -> *const u8 {
+    ptr::from_ref(marker)
+}
+
+fn lookup(func: *const u8, args: &[i64]) -> Option<i64> {
+    let mut out = 0;
+    ori_memo_lookup(func, args.as_ptr(), args.len() as i64, &raw mut out).then_some(out)
+}
+
+fn store(func: *const u8, args: &[i64], value: i64) {
+    ori_memo_store(func, args.as_ptr(), args.len() as i64, value);
+}
+
+#[test]
+fn results_are_shared_across_nested_calls() {
+    let marker = 0u8;
+    let func = func_addr(&marker);
+
+    ori_memo_enter(func);
+    assert_eq!(lookup(func, &[5]), None);
+    ori_memo_enter(func);
+    store(func, &[5], 120);
+    ori_memo_exit(func);
+
+    assert_eq!(lookup(func, &[5]), Some(120));
+    assert_eq!(lookup(func, &[6]), None);
+    ori_memo_exit(func);
+}
+
+#[test]
+fn table_is_discarded_after_top_level_call() {
+    let marker = 0u8;
+    let func = func_addr(&marker);
+
+    ori_memo_enter(func);
+    store(func, &[1, 2], 3);
+    ori_memo_exit(func);
+
+    ori_memo_enter(func);
+    assert_eq!(lookup(func, &[1, 2]), None);
+    ori_memo_exit(func);
+}
+
+#[test]
+fn tables_are_per_function() {
+    let (a, b) = (0u8, 0u8);
+    let (fa, fb) = (func_addr(&a), func_addr(&b));
+
+    ori_memo_enter(fa);
+    ori_memo_enter(fb);
+    store(fa, &[1], 10);
+
+    assert_eq!(lookup(fb, &[1]), None);
+    assert_eq!(lookup(fa, &[1]), Some(10));
+    ori_memo_exit(fb);
+    ori_memo_exit(fa);
+}
+
+#[test]
+fn store_outside_a_call_is_ignored() {
+    let marker = 0u8;
+    let func = func_addr(&marker);
+
+    store(func, &[1], 10);
+    ori_memo_enter(func);
+    assert_eq!(lookup(func, &[1]), None);
+    ori_memo_exit(func);
+}
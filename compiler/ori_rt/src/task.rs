@@ -0,0 +1,311 @@
+//! Runtime task support for the `parallel` and `timeout` patterns in
+//! AOT-compiled programs.
+//!
+//! # Architecture
+//!
+//! - Codegen wraps each task closure in a trampoline with the C ABI
+//!   `(ptr %wrapper, ptr %out) -> void`, mirroring the iterator adapters
+//! - `ori_parallel` runs a whole task list on a pool of worker threads. Each
+//!   task writes its result into its own slot of a caller-owned buffer, and
+//!   its outcome into an [`OriTaskStatus`]: a panicking or late task is
+//!   reported there instead of failing the caller
+//! - `ori_run_with_timeout` runs one task and waits at most a deadline; the
+//!   `timeout` pattern maps its result to `Ok`/`Err`
+//! - `ori_catch` runs one closure on the current thread for `catch(expr:)`,
+//!   reporting a panic as a message instead of unwinding further
+//!
+//! Threads whose task misses a deadline are detached: an expired task is
+//! not cancelled and runs to completion, and its result is discarded.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+#[expect(
+    clippy::disallowed_types,
+    reason = "workers are detached threads that may outlive ori_parallel"
+)]
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{OriPanic, OriStr};
+
+/// Trampoline signature: `(wrapper, out)`.
+///
+/// `C-unwind` because a task's panic unwinds out of the trampoline and is
+/// caught on the task's thread.
+pub type TaskFn = extern "C-unwind" fn(*mut u8, *mut u8);
+
+/// Outcome of one `parallel` task: `{ i8 failed, { i64, ptr } message }`.
+///
+/// `message` is the task's panic message, or `"timed out"` for a task that
+/// did not finish before the deadline. It is empty for a completed task.
+#[repr(C)]
+pub struct OriTaskStatus {
+    pub failed: bool,
+    pub message: OriStr,
+}
+
+impl OriTaskStatus {
+    fn completed() -> Self {
+        Self {
+            failed: false,
+            message: OriStr {
+                len: 0,
+                data: ptr::null(),
+            },
+        }
+    }
+
+    fn failed(message: String) -> Self {
+        Self {
+            failed: true,
+            message: OriStr::from_owned(message),
+        }
+    }
+}
+
+/// A task closure `{ fn_ptr, env_ptr }`, copied out of the task list so a
+/// task that outlives `ori_parallel` never reads the caller's list.
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct TaskClosure([*mut u8; 2]);
+
+// SAFETY: Codegen only spawns side-effect-free closures, and each closure
+// is claimed by exactly one worker.
+unsafe impl Send for TaskClosure {}
+unsafe impl Sync for TaskClosure {}
+
+/// Tasks of one `ori_parallel` call, shared by its worker threads.
+struct TaskQueue {
+    closures: Vec<TaskClosure>,
+    /// Index of the next task to start.
+    next: AtomicUsize,
+    /// Set once the call returns; workers then start no further tasks.
+    cancelled: AtomicBool,
+}
+
+impl TaskQueue {
+    /// Claim the next task, in list order, unless the call has returned.
+    fn take(&self) -> Option<(usize, TaskClosure)> {
+        if self.cancelled.load(Ordering::Acquire) {
+            return None;
+        }
+        let i = self.next.fetch_add(1, Ordering::AcqRel);
+        self.closures.get(i).map(|&closure| (i, closure))
+    }
+}
+
+/// Run one task on the current thread into a fresh `size`-byte buffer,
+/// returning the buffer or the task's panic message.
+fn run_task(task_fn: TaskFn, mut closure: TaskClosure, size: usize) -> Result<Vec<u64>, String> {
+    // u64 words keep the buffer aligned for any Ori value.
+    let mut buf = vec![0u64; size.div_ceil(8)];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        task_fn((&raw mut closure).cast(), buf.as_mut_ptr().cast());
+    }));
+    match result {
+        Ok(()) => Ok(buf),
+        Err(payload) => Err(match payload.downcast::<OriPanic>() {
+            Ok(p) => p.message,
+            Err(_) => "task panicked".to_string(),
+        }),
+    }
+}
+
+/// Run `count` tasks and wait for all of them to settle.
+///
+/// `tasks` holds `count` closures `{ fn_ptr, env_ptr }` (a task list's data
+/// buffer); each runs as `task_fn(&closure, out)`. Task `i` writes its
+/// `out_size`-byte result to `out + i * out_size` and its outcome to
+/// `status[i]`. A failed task leaves its result slot untouched.
+///
+/// Tasks start in list order. At most `max_concurrent` run at once, or all
+/// of them if it is not positive. If `timeout_ms` is not negative, tasks
+/// still running when it expires are reported as timed out and tasks not yet
+/// started never start.
+#[no_mangle]
+#[expect(
+    clippy::disallowed_types,
+    reason = "workers are detached threads that may outlive this call"
+)]
+pub extern "C" fn ori_parallel(
+    task_fn: TaskFn,
+    tasks: *const u8,
+    count: i64,
+    out: *mut u8,
+    out_size: i64,
+    status: *mut OriTaskStatus,
+    max_concurrent: i64,
+    timeout_ms: i64,
+) {
+    let count = usize::try_from(count).unwrap_or(0);
+    if count == 0 || tasks.is_null() {
+        return;
+    }
+    let size = usize::try_from(out_size).unwrap_or(0);
+    let workers = match usize::try_from(max_concurrent) {
+        Ok(n) if n > 0 => n.min(count),
+        _ => count,
+    };
+    let deadline = u64::try_from(timeout_ms)
+        .ok()
+        .map(|ms| Instant::now() + Duration::from_millis(ms));
+
+    // SAFETY: tasks points to `count` closures (caller contract)
+    let closures = (0..count)
+        .map(|i| unsafe { tasks.cast::<TaskClosure>().add(i).read() })
+        .collect();
+    let queue = Arc::new(TaskQueue {
+        closures,
+        next: AtomicUsize::new(0),
+        cancelled: AtomicBool::new(false),
+    });
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..workers {
+        let queue = Arc::clone(&queue);
+        let tx = tx.clone();
+        thread::spawn(move || {
+            while let Some((i, closure)) = queue.take() {
+                // The receiver is gone once the deadline has passed.
+                if tx.send((i, run_task(task_fn, closure, size))).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    drop(tx);
+
+    let mut settled = vec![false; count];
+    for _ in 0..count {
+        let received = match deadline {
+            Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let Ok((i, result)) = received else {
+            break;
+        };
+        settled[i] = true;
+        let outcome = match result {
+            Ok(buf) => {
+                if !out.is_null() {
+                    // SAFETY: out holds count * out_size bytes (caller contract)
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            buf.as_ptr().cast::<u8>(),
+                            out.add(i * size),
+                            size,
+                        );
+                    }
+                }
+                OriTaskStatus::completed()
+            }
+            Err(message) => OriTaskStatus::failed(message),
+        };
+        // SAFETY: status holds count entries (caller contract)
+        unsafe { status.add(i).write(outcome) };
+    }
+
+    queue.cancelled.store(true, Ordering::Release);
+    for (i, _) in settled.iter().enumerate().filter(|(_, done)| !**done) {
+        // SAFETY: status holds count entries (caller contract)
+        unsafe {
+            status
+                .add(i)
+                .write(OriTaskStatus::failed("timed out".to_string()));
+        }
+    }
+}
+
+/// Size and alignment of a trampoline wrapper `{ ptr fn_ptr, ptr env_ptr }`,
+/// as allocated by codegen through `ori_alloc`.
+const WRAPPER_SIZE: usize = 16;
+const WRAPPER_ALIGN: usize = 8;
+
+/// Closure wrapper moved into a timed task's thread.
+struct WrapperPtr(*mut u8);
+
+// SAFETY: The wrapper is heap-allocated by codegen and only read by the task.
+unsafe impl Send for WrapperPtr {}
+
+/// Run `task_fn(wrapper, out)` on a new thread, waiting at most `millis`.
+///
+/// The task writes into its own `out_size`-byte buffer, which is copied to
+/// `out` only if the task finishes in time, so a late task never touches the
+/// caller's frame. Returns `true` if the task completed and `false` if the
+/// deadline passed.
+///
+/// The thread is detached. An expired task is not cancelled: it keeps
+/// running until it finishes, and its result is discarded.
+///
+/// Takes ownership of `wrapper`, a `WRAPPER_SIZE`-byte block from
+/// `ori_alloc`. The task's thread frees it once the task finishes, whether
+/// or not the deadline passed, since an expired task may still be reading it.
+#[no_mangle]
+pub extern "C" fn ori_run_with_timeout(
+    task_fn: TaskFn,
+    wrapper: *mut u8,
+    out: *mut u8,
+    out_size: i64,
+    millis: i64,
+) -> bool {
+    let size = usize::try_from(out_size).unwrap_or(0);
+    let wrapper = WrapperPtr(wrapper);
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let wrapper = wrapper;
+        // u64 words keep the buffer aligned for any Ori value.
+        let mut buf = vec![0u64; size.div_ceil(8)];
+        task_fn(wrapper.0, buf.as_mut_ptr().cast());
+        crate::ori_free(wrapper.0, WRAPPER_SIZE, WRAPPER_ALIGN);
+        // The receiver is gone if the deadline already passed.
+        let _ = tx.send(buf);
+    });
+
+    let deadline = Duration::from_millis(u64::try_from(millis).unwrap_or(0));
+    match rx.recv_timeout(deadline) {
+        Ok(buf) => {
+            if !out.is_null() {
+                // SAFETY: out points to at least out_size bytes (caller contract)
+                unsafe { ptr::copy_nonoverlapping(buf.as_ptr().cast::<u8>(), out, size) };
+            }
+            true
+        }
+        Err(RecvTimeoutError::Timeout) => false,
+        Err(RecvTimeoutError::Disconnected) => {
+            crate::raise_panic("timeout task failed".to_string(), None);
+            false
+        }
+    }
+}
+
+/// Run `task_fn(wrapper, out)` on the current thread, catching a panic.
+///
+/// Returns `true` if the task completed and wrote its result to `out`.
+/// If it panicked, writes the panic message to `*message` and returns
+/// `false`; `out` is then left untouched. The `catch` pattern maps the two
+/// cases to `Ok`/`Err`.
+#[no_mangle]
+pub extern "C" fn ori_catch(
+    task_fn: TaskFn,
+    wrapper: *mut u8,
+    out: *mut u8,
+    message: *mut OriStr,
+) -> bool {
+    match crate::catch_ori_panic(|| task_fn(wrapper, out)) {
+        Ok(()) => true,
+        Err(msg) => {
+            if !message.is_null() {
+                // SAFETY: message points to a writable OriStr (caller contract)
+                unsafe { message.write(OriStr::from_owned(msg)) };
+            }
+            false
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests;
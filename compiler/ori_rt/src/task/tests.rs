@@ -0,0 +1,245 @@
+//! Tests for runtime task execution.
+
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use super::*;
+
+/// A task closure whose `fn_ptr` word carries the task's input.
+fn closure(n: i64) -> TaskClosure {
+    TaskClosure([n as usize as *mut u8, ptr::null_mut()])
+}
+
+extern "C-unwind" fn square_into_out(wrapper: *mut u8, out: *mut u8) {
+    // SAFETY: tests pass an i64 as the wrapper's first word and an i64 out slot
+    unsafe {
+        let n = *wrapper.cast::<i64>();
+        *out.cast::<i64>() = n * n;
+    }
+}
+
+extern "C-unwind" fn square_or_panic(wrapper: *mut u8, out: *mut u8) {
+    // SAFETY: the wrapper's first word is an i64
+    if unsafe { *wrapper.cast::<i64>() } < 0 {
+        crate::raise_panic("negative input".to_string(), None);
+    }
+    square_into_out(wrapper, out);
+}
+
+extern "C-unwind" fn square_or_ori_panic(wrapper: *mut u8, out: *mut u8) {
+    // SAFETY: the wrapper's first word is an i64
+    if unsafe { *wrapper.cast::<i64>() } < 0 {
+        let message = "negative input";
+        let message = crate::OriStr {
+            len: message.len() as i64,
+            data: message.as_ptr(),
+        };
+        // Generated code calls the C-ABI entry point, not `raise_panic`
+        crate::ori_panic(&raw const message);
+    }
+    square_into_out(wrapper, out);
+}
+
+extern "C-unwind" fn sleep_then_square(wrapper: *mut u8, out: *mut u8) {
+    // SAFETY: the wrapper's first word is an i64 of milliseconds to sleep
+    let millis = unsafe { *wrapper.cast::<u64>() };
+    std::thread::sleep(Duration::from_millis(millis));
+    square_into_out(wrapper, out);
+}
+
+/// Run `inputs` through `ori_parallel`, returning each result slot and status.
+fn run_parallel(
+    task_fn: TaskFn,
+    inputs: &[i64],
+    max_concurrent: i64,
+    timeout_ms: i64,
+) -> (Vec<i64>, Vec<OriTaskStatus>) {
+    let tasks: Vec<TaskClosure> = inputs.iter().map(|&n| closure(n)).collect();
+    let mut out = vec![-1i64; inputs.len()];
+    let mut status: Vec<OriTaskStatus> = (0..inputs.len())
+        .map(|_| OriTaskStatus::failed(String::new()))
+        .collect();
+    ori_parallel(
+        task_fn,
+        tasks.as_ptr().cast(),
+        inputs.len() as i64,
+        out.as_mut_ptr().cast(),
+        8,
+        status.as_mut_ptr(),
+        max_concurrent,
+        timeout_ms,
+    );
+    (out, status)
+}
+
+#[test]
+fn parallel_writes_results_in_task_order() {
+    let (out, status) = run_parallel(square_into_out, &[3, 4, 5], 0, -1);
+
+    assert_eq!(out, [9, 16, 25]);
+    assert!(status.iter().all(|s| !s.failed));
+}
+
+#[test]
+fn parallel_panicking_task_reports_its_message() {
+    let (out, status) = run_parallel(square_or_panic, &[3, -1, 5], 0, -1);
+
+    assert_eq!((out[0], out[2]), (9, 25));
+    assert!(!status[0].failed && !status[2].failed);
+    assert!(status[1].failed);
+    // SAFETY: the runtime wrote a valid message
+    assert_eq!(unsafe { status[1].message.as_str() }, "negative input");
+}
+
+#[test]
+fn parallel_task_panicking_through_ori_panic_reports_its_message() {
+    let (out, status) = run_parallel(square_or_ori_panic, &[3, -1, 5], 0, -1);
+
+    assert_eq!((out[0], out[2]), (9, 25));
+    assert!(status[1].failed);
+    // SAFETY: the runtime wrote a valid message
+    assert_eq!(unsafe { status[1].message.as_str() }, "negative input");
+}
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+extern "C-unwind" fn count_running(wrapper: *mut u8, out: *mut u8) {
+    let now = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+    PEAK.fetch_max(now, Ordering::SeqCst);
+    std::thread::sleep(Duration::from_millis(20));
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+    square_into_out(wrapper, out);
+}
+
+#[test]
+fn parallel_max_concurrent_limits_running_tasks() {
+    let (out, status) = run_parallel(count_running, &[1, 2, 3, 4, 5, 6], 2, -1);
+
+    assert_eq!(out, [1, 4, 9, 16, 25, 36]);
+    assert!(status.iter().all(|s| !s.failed));
+    assert!(PEAK.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn parallel_timeout_reports_late_tasks() {
+    // Inputs double as sleep times: 1ms finishes, 300ms does not.
+    let (out, status) = run_parallel(sleep_then_square, &[1, 300], 0, 100);
+
+    assert_eq!(out[0], 1);
+    assert!(!status[0].failed);
+    assert!(status[1].failed);
+    // SAFETY: the runtime wrote a valid message
+    assert_eq!(unsafe { status[1].message.as_str() }, "timed out");
+    assert_eq!(out[1], -1, "a late task must not write the caller's slot");
+}
+
+#[test]
+fn parallel_empty_list_is_noop() {
+    let (out, status) = run_parallel(square_into_out, &[], 0, -1);
+
+    assert!(out.is_empty() && status.is_empty());
+}
+
+/// A wrapper-sized `ori_alloc` block holding `n`, as codegen would pass.
+fn alloc_wrapper(n: i64) -> *mut u8 {
+    let wrapper = crate::ori_alloc(WRAPPER_SIZE, WRAPPER_ALIGN);
+    // SAFETY: the block is WRAPPER_SIZE bytes and 8-byte aligned
+    unsafe { wrapper.cast::<i64>().write(n) };
+    wrapper
+}
+
+#[test]
+fn run_with_timeout_fast_task_completes() {
+    let mut out: i64 = 0;
+
+    let done = ori_run_with_timeout(
+        square_into_out,
+        alloc_wrapper(7),
+        (&raw mut out).cast(),
+        8,
+        5_000,
+    );
+
+    assert!(done);
+    assert_eq!(out, 49);
+}
+
+#[test]
+fn run_with_timeout_slow_task_expires() {
+    // Freed by the task's thread after it finishes, past the deadline.
+    let mut out: i64 = -1;
+
+    let done = ori_run_with_timeout(
+        sleep_then_square,
+        alloc_wrapper(200),
+        (&raw mut out).cast(),
+        8,
+        10,
+    );
+
+    assert!(!done);
+    assert_eq!(out, -1, "an expired task must not write the caller's slot");
+}
+
+fn run_catch(task_fn: TaskFn, n: i64) -> Result<i64, String> {
+    let mut closure = closure(n);
+    let mut out: i64 = -1;
+    let mut message = OriStr {
+        len: 0,
+        data: ptr::null(),
+    };
+
+    let done = ori_catch(
+        task_fn,
+        (&raw mut closure).cast(),
+        (&raw mut out).cast(),
+        &raw mut message,
+    );
+
+    if done {
+        Ok(out)
+    } else {
+        assert_eq!(out, -1, "a panicking task must not write its result");
+        // SAFETY: ori_catch wrote an owned message
+        Err(unsafe { message.as_str() }.to_string())
+    }
+}
+
+#[test]
+fn catch_completed_task_returns_its_result() {
+    assert_eq!(run_catch(square_or_ori_panic, 6), Ok(36));
+}
+
+#[test]
+fn catch_ori_panic_reports_its_message() {
+    assert_eq!(
+        run_catch(square_or_ori_panic, -1),
+        Err("negative input".to_string())
+    );
+    assert!(
+        !crate::did_panic(),
+        "a caught panic must not be recorded as a failure"
+    );
+}
+
+#[test]
+fn catch_rust_panic_reports_generic_message() {
+    extern "C-unwind" fn rust_panic(_: *mut u8, _: *mut u8) {
+        panic!("boom");
+    }
+    assert_eq!(run_catch(rust_panic, 0), Err("panic".to_string()));
+}
+
+extern "C-unwind" fn nested_catch(wrapper: *mut u8, out: *mut u8) {
+    // The inner catch handles the panic, so the outer task completes
+    let inner = run_catch(square_or_ori_panic, -1);
+    assert_eq!(inner, Err("negative input".to_string()));
+    square_into_out(wrapper, out);
+}
+
+#[test]
+fn nested_catch_handles_inner_panic() {
+    assert_eq!(run_catch(nested_catch, 3), Ok(9));
+}
@@ -32,8 +32,14 @@ fn infer_const_type(checker: &mut ModuleChecker<'_>, value_id: ExprId) -> Idx {
     let arena = checker.arena();
     let mut engine = checker.create_engine();
     let ty = crate::infer_expr(&mut engine, arena, value_id);
+    let expr_types = engine.take_expr_types();
     let errors = engine.take_errors();
     let warnings = engine.take_warnings();
+    // Canonicalization inlines the initializer at each `$name` use, so its
+    // expressions need recorded types just like function bodies.
+    for (expr_index, ty) in expr_types {
+        checker.store_expr_type(expr_index, ty);
+    }
     for err in errors {
         checker.push_error(err);
     }
@@ -161,6 +161,16 @@ impl TypeEnv {
         self.0.bindings.contains_key(&name)
     }
 
+    /// Iterate over the bindings of the current scope only, as `(name, type)`.
+    ///
+    /// Parent scopes are not included. Iteration order is unspecified.
+    pub fn local_bindings(&self) -> impl Iterator<Item = (Name, Idx)> + '_ {
+        self.0
+            .bindings
+            .iter()
+            .map(|(&name, binding)| (name, binding.ty))
+    }
+
     /// Iterate over all bound names in this environment.
     ///
     /// Includes names from parent scopes. Names may be duplicated
@@ -260,3 +260,17 @@ fn test_find_similar_unresolvable_target() {
     let similar = env.find_similar(name(99), 3, resolver);
     assert!(similar.is_empty());
 }
+
+#[test]
+fn test_local_bindings_excludes_parent() {
+    let mut parent = TypeEnv::new();
+    parent.bind(name(1), Idx::INT);
+
+    let mut child = parent.child();
+    child.bind(name(2), Idx::BOOL);
+    child.bind(name(3), Idx::STR);
+
+    let mut locals: Vec<(Name, Idx)> = child.local_bindings().collect();
+    locals.sort_by_key(|&(n, _)| n);
+    assert_eq!(locals, vec![(name(2), Idx::BOOL), (name(3), Idx::STR)]);
+}
@@ -318,7 +318,7 @@ pub(crate) fn check_where_clauses(
                             };
                             if !satisfies {
                                 let bound_str = engine.lookup_name(bound_name).unwrap_or("?");
-                                errors.push(format!("does not satisfy trait bound `{bound_str}`",));
+                                errors.push(format!("does not satisfy trait bound `{bound_str}`"));
                             }
                         }
                     }
@@ -335,7 +335,7 @@ pub(crate) fn check_where_clauses(
                         };
                         if !satisfies {
                             let bound_str = engine.lookup_name(bound_name).unwrap_or("?");
-                            errors.push(format!("does not satisfy trait bound `{bound_str}`",));
+                            errors.push(format!("does not satisfy trait bound `{bound_str}`"));
                         }
                     }
                 }
@@ -66,25 +66,33 @@ pub(crate) fn infer_recurse(
     base_ty.unwrap_or_else(|| engine.fresh_var())
 }
 
-/// Infer type for `parallel(tasks: [expr])`.
+/// Infer type for `parallel(tasks: [() -> T])`.
+///
+/// Returns `[Result<T, str>]`: each task's value, or the message of the
+/// error it raised. A task list of plain values (not closures) uses the
+/// element type as `T`.
 pub(crate) fn infer_parallel(
     engine: &mut InferEngine<'_>,
     arena: &ExprArena,
     props: &[ori_ir::NamedExpr],
 ) -> Idx {
-    // Parallel takes a list of tasks and returns a list of results
-    // For now, return [?a] with fresh variable
+    let mut task_ty = None;
+
     for prop in props {
         let ty = infer_expr(engine, arena, prop.value);
-        // If it's a list, extract element type and wrap result
         let resolved = engine.resolve(ty);
         if engine.pool().tag(resolved) == Tag::List {
-            let elem_ty = engine.pool().list_elem(resolved);
-            return engine.pool_mut().list(elem_ty);
+            let elem_ty = engine.resolve(engine.pool().list_elem(resolved));
+            task_ty = Some(if engine.pool().tag(elem_ty) == Tag::Function {
+                engine.pool().function_return(elem_ty)
+            } else {
+                elem_ty
+            });
         }
     }
 
-    let result_ty = engine.fresh_var();
+    let ok_ty = task_ty.unwrap_or_else(|| engine.fresh_var());
+    let result_ty = engine.pool_mut().result(ok_ty, Idx::STR);
     engine.pool_mut().list(result_ty)
 }
 
@@ -104,13 +112,16 @@ pub(crate) fn infer_spawn(
     engine.fresh_var()
 }
 
-/// Infer type for `timeout(duration: Duration, task: expr)`.
+/// Infer type for `timeout(op: expr, after: Duration)`.
+///
+/// Returns `Result<T, str>`, like `parallel`'s per-task results: the
+/// operation's value, or the message of a task that ran out of time.
 pub(crate) fn infer_timeout(
     engine: &mut InferEngine<'_>,
     arena: &ExprArena,
     props: &[ori_ir::NamedExpr],
 ) -> Idx {
-    // Returns Option<T> where T is the task result
+    // Returns Result<T, str>: the task result, or the "timed out" message
     let mut task_ty = None;
 
     for prop in props {
@@ -126,7 +137,7 @@ pub(crate) fn infer_timeout(
     }
 
     let inner = task_ty.unwrap_or_else(|| engine.fresh_var());
-    engine.pool_mut().option(inner)
+    engine.pool_mut().result(inner, Idx::STR)
 }
 
 /// Infer type for `cache(key: expr, op: expr, ttl: Duration)`.
@@ -391,13 +391,29 @@ pub(crate) fn check_match_pattern(
             }
         }
 
-        // Or pattern: all alternatives must match the same type
+        // Or pattern: all alternatives must match the same type and bind
+        // the same names. Each alternative is checked in its own scope so
+        // its bindings can be compared; the agreed set is then bound here.
         MatchPattern::Or(alternatives) => {
             let alt_ids = arena.get_match_pattern_list(*alternatives);
+            let outer = engine.env().clone();
+            let mut agreed: Option<Vec<(Name, Idx)>> = None;
             for alt_id in alt_ids {
+                *engine.env_mut() = outer.child();
                 let alt_pattern = arena.get_match_pattern(*alt_id);
                 let nested_key = PatternKey::Nested(alt_id.raw());
                 check_match_pattern(engine, arena, alt_pattern, expected_ty, nested_key, span);
+
+                let mut bindings: Vec<(Name, Idx)> = engine.env().local_bindings().collect();
+                bindings.sort_by_key(|&(name, _)| name);
+                match &agreed {
+                    None => agreed = Some(bindings),
+                    Some(first) => check_or_alternative_bindings(engine, first, &bindings, span),
+                }
+            }
+            *engine.env_mut() = outer;
+            for (name, ty) in agreed.unwrap_or_default() {
+                engine.env_mut().bind(name, ty);
             }
         }
 
@@ -414,6 +430,43 @@ pub(crate) fn check_match_pattern(
     }
 }
 
+/// Compare an or-pattern alternative's bindings against the first
+/// alternative's (both sorted by name).
+///
+/// Names missing from either side are reported (E2040); names present in
+/// both must have the same type.
+fn check_or_alternative_bindings(
+    engine: &mut InferEngine<'_>,
+    first: &[(Name, Idx)],
+    other: &[(Name, Idx)],
+    span: Span,
+) {
+    for &(name, ty) in other {
+        match first.binary_search_by_key(&name, |&(n, _)| n) {
+            Ok(pos) => {
+                let first_ty = first[pos].1;
+                if engine.unify_types(ty, first_ty).is_err() {
+                    engine.push_error(TypeCheckError::mismatch(
+                        span,
+                        first_ty,
+                        ty,
+                        vec![],
+                        crate::ErrorContext::default(),
+                    ));
+                }
+            }
+            Err(_) => {
+                engine.push_error(TypeCheckError::or_pattern_binding_mismatch(span, name));
+            }
+        }
+    }
+    for &(name, _) in first {
+        if other.binary_search_by_key(&name, |&(n, _)| n).is_err() {
+            engine.push_error(TypeCheckError::or_pattern_binding_mismatch(span, name));
+        }
+    }
+}
+
 /// Resolve the field types for a user-defined enum variant in a match pattern.
 ///
 /// Looks up the variant in the `TypeRegistry`, extracts field types, and substitutes
@@ -580,6 +633,7 @@ pub(crate) fn substitute_type_params_with_map(
 pub(crate) fn infer_for(
     engine: &mut InferEngine<'_>,
     arena: &ExprArena,
+    label: Name,
     binding: Name,
     iter: ExprId,
     guard: ExprId,
@@ -653,9 +707,11 @@ pub(crate) fn infer_for(
     }
 
     // Infer body type
+    engine.push_loop(label, None);
     engine.push_context(ContextKind::LoopBody);
     let body_ty = infer_expr(engine, arena, body);
     engine.pop_context();
+    engine.pop_loop();
 
     // Exit loop scope
     engine.exit_scope();
@@ -680,12 +736,13 @@ pub(crate) fn infer_for(
 pub(crate) fn infer_loop(
     engine: &mut InferEngine<'_>,
     arena: &ExprArena,
+    label: Name,
     body: ExprId,
     _span: Span,
 ) -> Idx {
     // Create a fresh type variable for the loop's result (determined by break values)
     let break_ty = engine.fresh_var();
-    engine.push_loop_break_type(break_ty);
+    engine.push_loop(label, Some(break_ty));
 
     // Enter scope for loop
     engine.enter_scope();
@@ -697,7 +754,7 @@ pub(crate) fn infer_loop(
 
     // Exit loop scope
     engine.exit_scope();
-    engine.pop_loop_break_type();
+    engine.pop_loop();
 
     // Resolve the break type — if no break was encountered, the variable
     // stays unresolved (infinite loop returns Never). If breaks exist,
@@ -717,8 +774,9 @@ pub(crate) fn infer_loop(
 pub(crate) fn infer_break(
     engine: &mut InferEngine<'_>,
     arena: &ExprArena,
+    label: Name,
     value: ExprId,
-    _span: Span,
+    span: Span,
 ) -> Idx {
     // Infer the break value's type (unit if no value)
     let value_ty = if value.is_present() {
@@ -727,9 +785,17 @@ pub(crate) fn infer_break(
         Idx::UNIT
     };
 
-    // Unify with the enclosing loop's break type variable
-    if let Some(loop_break_ty) = engine.current_loop_break_type() {
-        let _ = engine.unify_types(value_ty, loop_break_ty);
+    // Unify with the target loop's break type variable
+    match engine.find_loop(label) {
+        Some(target) => {
+            if let Some(loop_break_ty) = target.break_ty {
+                let _ = engine.unify_types(value_ty, loop_break_ty);
+            }
+        }
+        None if label != Name::EMPTY => {
+            engine.push_error(TypeCheckError::undefined_label(span, label));
+        }
+        None => {}
     }
 
     // Break itself is a diverging expression (control transfers to loop exit)
@@ -738,10 +804,14 @@ pub(crate) fn infer_break(
 
 /// Infer the type of a continue expression.
 pub(crate) fn infer_continue(
-    _engine: &mut InferEngine<'_>,
+    engine: &mut InferEngine<'_>,
     _arena: &ExprArena,
+    label: Name,
     _value: ExprId,
-    _span: Span,
+    span: Span,
 ) -> Idx {
+    if label != Name::EMPTY && engine.find_loop(label).is_none() {
+        engine.push_error(TypeCheckError::undefined_label(span, label));
+    }
     Idx::NEVER
 }
@@ -148,11 +148,11 @@ fn infer_expr_inner(engine: &mut InferEngine<'_>, arena: &ExprArena, expr_id: Ex
             guard,
             body,
             is_yield,
-            ..
+            label,
         } => infer_for(
-            engine, arena, *binding, *iter, *guard, *body, *is_yield, span,
+            engine, arena, *label, *binding, *iter, *guard, *body, *is_yield, span,
         ),
-        ExprKind::Loop { body, .. } => infer_loop(engine, arena, *body, span),
+        ExprKind::Loop { label, body } => infer_loop(engine, arena, *label, *body, span),
 
         // Blocks and Bindings
         ExprKind::Block { stmts, result } => infer_block(engine, arena, *stmts, *result, span),
@@ -211,8 +211,8 @@ fn infer_expr_inner(engine: &mut InferEngine<'_>, arena: &ExprArena, expr_id: Ex
         ExprKind::None => infer_none(engine),
 
         // Control Flow Expressions
-        ExprKind::Break { value, .. } => infer_break(engine, arena, *value, span),
-        ExprKind::Continue { value, .. } => infer_continue(engine, arena, *value, span),
+        ExprKind::Break { label, value } => infer_break(engine, arena, *label, *value, span),
+        ExprKind::Continue { label, value } => infer_continue(engine, arena, *label, *value, span),
         ExprKind::Unsafe(inner) => infer_expr(engine, arena, *inner),
         ExprKind::Try(inner) => infer_try(engine, arena, *inner, span),
         ExprKind::Await(inner) => infer_await(engine, arena, *inner, span),
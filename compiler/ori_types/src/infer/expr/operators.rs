@@ -262,6 +262,40 @@ pub(crate) fn infer_binary(
             Idx::INT
         }
 
+        // Power: int ** int -> int, float ** float -> float
+        BinaryOp::Pow => {
+            let left_span = arena.get_expr(left).span;
+            let resolved_left = engine.resolve(left_ty);
+            match engine.pool().tag(resolved_left) {
+                Tag::Int | Tag::Float | Tag::Var => {}
+                Tag::Error => return Idx::ERROR,
+                Tag::Never => return Idx::NEVER,
+                _ => {
+                    engine.push_error(TypeCheckError::bad_binary_operand(
+                        left_span,
+                        "power",
+                        "int or float",
+                        resolved_left,
+                    ));
+                    return Idx::ERROR;
+                }
+            }
+
+            // Exponent has the same type as the base
+            engine.push_context(ContextKind::BinaryOpRight { op: op_str });
+            let expected = Expected {
+                ty: left_ty,
+                origin: ExpectedOrigin::Context {
+                    span: left_span,
+                    kind: ContextKind::BinaryOpLeft { op: op_str },
+                },
+            };
+            let _ = engine.check_type(right_ty, &expected, arena.get_expr(right).span);
+            engine.pop_context();
+
+            engine.resolve(left_ty)
+        }
+
         // Range creation
         BinaryOp::Range | BinaryOp::RangeInclusive => {
             // Both operands should be the same type (typically int)
@@ -659,6 +659,7 @@ pub(crate) fn lookup_struct_field_types(
 ///
 /// Validates that the receiver is indexable and the index type matches:
 /// - `[T]` indexed by `int` returns `T`
+/// - `[T]` indexed by `Range<int>` returns `[T]` (a copied slice)
 /// - `Map<K, V>` indexed by `K` returns `Option<V>`
 /// - `str` indexed by `int` returns `str`
 /// - User-defined types: dispatches via `Index<Key, Value>` trait
@@ -675,6 +676,12 @@ pub(crate) fn infer_index(
 
     match engine.pool().tag(resolved) {
         Tag::List => {
+            let resolved_index = engine.resolve(index_ty);
+            if engine.pool().tag(resolved_index) == Tag::Range {
+                let bound_ty = engine.pool().range_elem(resolved_index);
+                let _ = engine.unify_types(bound_ty, Idx::INT);
+                return resolved;
+            }
             let elem_ty = engine.pool().list_elem(resolved);
             let _ = engine.unify_types(index_ty, Idx::INT);
             elem_ty
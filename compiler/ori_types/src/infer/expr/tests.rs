@@ -844,6 +844,80 @@ fn test_infer_match_arm_type_mismatch() {
     );
 }
 
+/// Build `match (1, 2) { <left> | <right> -> x }`, where each alternative is
+/// a two-element tuple pattern of the given sub-patterns, and infer it.
+fn infer_or_pattern_match(
+    engine: &mut InferEngine<'_>,
+    left: [MatchPattern; 2],
+    right: [MatchPattern; 2],
+) -> Idx {
+    let mut arena = ExprArena::new();
+    let e1 = alloc(&mut arena, ExprKind::Int(1));
+    let e2 = alloc(&mut arena, ExprKind::Int(2));
+    let elems = arena.alloc_expr_list_inline(&[e1, e2]);
+    let scrutinee = alloc(&mut arena, ExprKind::Tuple(elems));
+    let x_ref = alloc(&mut arena, ExprKind::Ident(name(1)));
+
+    let tuple_pattern = |arena: &mut ExprArena, [a, b]: [MatchPattern; 2]| {
+        let a = arena.alloc_match_pattern(a);
+        let b = arena.alloc_match_pattern(b);
+        let elems = arena.alloc_match_pattern_list([a, b]);
+        arena.alloc_match_pattern(MatchPattern::Tuple(elems))
+    };
+    let left = tuple_pattern(&mut arena, left);
+    let right = tuple_pattern(&mut arena, right);
+    let alts = arena.alloc_match_pattern_list([left, right]);
+
+    let arms = arena.alloc_arms([MatchArm {
+        pattern: MatchPattern::Or(alts),
+        guard: None,
+        body: x_ref,
+        span: span(),
+    }]);
+    let match_expr = alloc(&mut arena, ExprKind::Match { scrutinee, arms });
+    infer_expr(engine, &arena, match_expr)
+}
+
+#[test]
+fn test_infer_or_pattern_shared_binding() {
+    // match (1, 2) { (x, _) | (_, x) -> x }
+    let mut pool = Pool::new();
+    let mut engine = InferEngine::new(&mut pool);
+
+    let ty = infer_or_pattern_match(
+        &mut engine,
+        [MatchPattern::Binding(name(1)), MatchPattern::Wildcard],
+        [MatchPattern::Wildcard, MatchPattern::Binding(name(1))],
+    );
+
+    assert_eq!(ty, Idx::INT);
+    assert!(!engine.has_errors(), "errors: {:?}", engine.errors());
+}
+
+#[test]
+fn test_infer_or_pattern_binding_mismatch() {
+    // match (1, 2) { (x, _) | (_, y) -> x }
+    let mut pool = Pool::new();
+    let mut engine = InferEngine::new(&mut pool);
+
+    let _ = infer_or_pattern_match(
+        &mut engine,
+        [MatchPattern::Binding(name(1)), MatchPattern::Wildcard],
+        [MatchPattern::Wildcard, MatchPattern::Binding(name(2))],
+    );
+
+    let mismatched: Vec<Name> = engine
+        .errors()
+        .iter()
+        .filter_map(|e| match e.kind {
+            crate::TypeErrorKind::OrPatternBindingMismatch { name } => Some(name),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(mismatched, vec![name(2), name(1)]);
+    assert_eq!(engine.errors()[0].code(), ori_diagnostic::ErrorCode::E2040);
+}
+
 // ========================================================================
 // For Loop Tests
 // ========================================================================
@@ -1618,6 +1692,96 @@ fn test_infer_continue() {
     assert!(!engine.has_errors());
 }
 
+#[test]
+fn test_infer_labeled_break_targets_outer_loop() {
+    // loop:outer { loop { break:outer 42 } }
+    let mut pool = Pool::new();
+    let mut engine = InferEngine::new(&mut pool);
+    let mut arena = ExprArena::new();
+
+    let value = alloc(&mut arena, ExprKind::Int(42));
+    let break_expr = alloc(
+        &mut arena,
+        ExprKind::Break {
+            label: name(1),
+            value,
+        },
+    );
+    let inner = alloc(
+        &mut arena,
+        ExprKind::Loop {
+            label: Name::EMPTY,
+            body: break_expr,
+        },
+    );
+    let outer = alloc(
+        &mut arena,
+        ExprKind::Loop {
+            label: name(1),
+            body: inner,
+        },
+    );
+    let ty = infer_expr(&mut engine, &arena, outer);
+
+    // The break value types the labeled loop; the inner loop never exits
+    assert_eq!(ty, Idx::INT);
+    assert_eq!(engine.expr_types()[&inner.index()], Idx::NEVER);
+    assert!(!engine.has_errors(), "errors: {:?}", engine.errors());
+}
+
+#[test]
+fn test_infer_break_to_undefined_label() {
+    // loop { break:nope }
+    let mut pool = Pool::new();
+    let mut engine = InferEngine::new(&mut pool);
+    let mut arena = ExprArena::new();
+
+    let break_expr = alloc(
+        &mut arena,
+        ExprKind::Break {
+            label: name(2),
+            value: ExprId::INVALID,
+        },
+    );
+    let loop_expr = alloc(
+        &mut arena,
+        ExprKind::Loop {
+            label: name(1),
+            body: break_expr,
+        },
+    );
+    let _ = infer_expr(&mut engine, &arena, loop_expr);
+
+    let errors = engine.errors();
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert!(matches!(
+        errors[0].kind,
+        crate::TypeErrorKind::UndefinedLabel { label } if label == name(2)
+    ));
+    assert_eq!(errors[0].code(), ori_diagnostic::ErrorCode::E2041);
+}
+
+#[test]
+fn test_infer_continue_to_undefined_label() {
+    let mut pool = Pool::new();
+    let mut engine = InferEngine::new(&mut pool);
+    let mut arena = ExprArena::new();
+
+    let continue_expr = alloc(
+        &mut arena,
+        ExprKind::Continue {
+            label: name(3),
+            value: ExprId::INVALID,
+        },
+    );
+    let _ = infer_expr(&mut engine, &arena, continue_expr);
+
+    assert!(matches!(
+        engine.errors()[..],
+        [ref e] if matches!(e.kind, crate::TypeErrorKind::UndefinedLabel { .. })
+    ));
+}
+
 // ========================================================================
 // Error Expression Test
 // ========================================================================
@@ -1860,11 +2024,11 @@ fn test_infer_function_exp_timeout() {
 
     let ty = infer_expr(&mut engine, &arena, expr_id);
 
-    // timeout returns Option<T>
+    // timeout returns Result<T, str>
     assert_eq!(
         engine.pool().tag(ty),
-        Tag::Option,
-        "timeout should return Option"
+        Tag::Result,
+        "timeout should return Result"
     );
     assert!(!engine.has_errors());
 }
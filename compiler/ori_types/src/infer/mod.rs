@@ -56,6 +56,16 @@ use crate::{
 /// Maps to `ori_ir::ExprId` when integrating with the module checker.
 pub type ExprIndex = usize;
 
+/// A `loop` or `for` enclosing the expression being inferred.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct LoopFrame {
+    /// The loop's label, or `Name::EMPTY` if unlabeled.
+    pub label: Name,
+    /// Type variable that `break` values unify with. `None` for `for` loops,
+    /// whose result comes from the body rather than from `break`.
+    pub break_ty: Option<Idx>,
+}
+
 /// The type inference engine.
 ///
 /// Orchestrates Hindley-Milner type inference:
@@ -115,9 +125,10 @@ pub struct InferEngine<'pool> {
     /// Current impl's `Self` type (for `Self` in type annotations within impl blocks).
     impl_self_type: Option<Idx>,
 
-    /// Stack of expected break value types for nested loops.
-    /// Each `loop()` pushes a fresh type variable; `break expr` unifies with it.
-    loop_break_types: Vec<Idx>,
+    /// Stack of enclosing loops, innermost last.
+    /// Each `loop()` pushes a fresh break type variable; `break expr` unifies
+    /// with the innermost loop's, or with the labeled loop's for `break:label`.
+    loops: Vec<LoopFrame>,
 
     /// Capabilities declared by the current function (`uses` clause).
     current_capabilities: FxHashSet<Name>,
@@ -152,7 +163,7 @@ impl<'pool> InferEngine<'pool> {
             type_registry: None,
             self_type: None,
             impl_self_type: None,
-            loop_break_types: Vec::new(),
+            loops: Vec::new(),
             current_capabilities: FxHashSet::default(),
             provided_capabilities: FxHashSet::default(),
             pattern_resolutions: Vec::new(),
@@ -178,7 +189,7 @@ impl<'pool> InferEngine<'pool> {
             type_registry: None,
             self_type: None,
             impl_self_type: None,
-            loop_break_types: Vec::new(),
+            loops: Vec::new(),
             current_capabilities: FxHashSet::default(),
             provided_capabilities: FxHashSet::default(),
             pattern_resolutions: Vec::new(),
@@ -249,21 +260,29 @@ impl<'pool> InferEngine<'pool> {
         self.impl_self_type
     }
 
-    /// Push a loop break type variable onto the stack.
-    /// Called when entering a `loop()` expression.
-    pub fn push_loop_break_type(&mut self, ty: Idx) {
-        self.loop_break_types.push(ty);
+    /// Push an enclosing loop onto the stack.
+    /// Called when entering a `loop()` or `for` body.
+    pub(crate) fn push_loop(&mut self, label: Name, break_ty: Option<Idx>) {
+        self.loops.push(LoopFrame { label, break_ty });
     }
 
-    /// Pop the loop break type variable.
-    /// Called when exiting a `loop()` expression.
-    pub fn pop_loop_break_type(&mut self) -> Option<Idx> {
-        self.loop_break_types.pop()
+    /// Pop the innermost loop.
+    /// Called when exiting a `loop()` or `for` body.
+    pub(crate) fn pop_loop(&mut self) -> Option<LoopFrame> {
+        self.loops.pop()
     }
 
-    /// Get the current loop's break type variable (innermost loop).
-    pub fn current_loop_break_type(&self) -> Option<Idx> {
-        self.loop_break_types.last().copied()
+    /// Find the loop targeted by `break`/`continue` with `label`.
+    ///
+    /// `Name::EMPTY` targets the innermost loop; any other label targets the
+    /// innermost loop carrying it. Returns `None` if no such loop encloses
+    /// the current expression.
+    pub(crate) fn find_loop(&self, label: Name) -> Option<LoopFrame> {
+        if label == Name::EMPTY {
+            self.loops.last().copied()
+        } else {
+            self.loops.iter().rev().find(|l| l.label == label).copied()
+        }
     }
 
     // ========================================
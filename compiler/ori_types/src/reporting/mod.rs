@@ -265,6 +265,18 @@ impl<'a> TypeErrorRenderer<'a> {
                     self.format_name(*name)
                 )
             }
+            TypeErrorKind::OrPatternBindingMismatch { name } => {
+                format!(
+                    "`{}` is not bound in every alternative",
+                    self.format_name(*name)
+                )
+            }
+            TypeErrorKind::UndefinedLabel { label } => {
+                format!(
+                    "no enclosing loop is labeled `{}`",
+                    self.format_name(*label)
+                )
+            }
         }
     }
 
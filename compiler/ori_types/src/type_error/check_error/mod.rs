@@ -610,6 +610,15 @@ impl TypeCheckError {
                     format_name(*name)
                 )
             }
+            TypeErrorKind::OrPatternBindingMismatch { name } => {
+                format!(
+                    "variable `{}` is not bound in every alternative of this or-pattern",
+                    format_name(*name)
+                )
+            }
+            TypeErrorKind::UndefinedLabel { label } => {
+                format!("no enclosing loop is labeled `{}`", format_name(*label))
+            }
         }
     }
 
@@ -837,6 +846,10 @@ impl TypeCheckError {
             TypeErrorKind::AssignToImmutable { .. } => {
                 "cannot assign to immutable binding".to_string()
             }
+            TypeErrorKind::OrPatternBindingMismatch { .. } => {
+                "variable is not bound in every alternative of this or-pattern".to_string()
+            }
+            TypeErrorKind::UndefinedLabel { .. } => "no enclosing loop has this label".to_string(),
         }
     }
 
@@ -946,6 +959,12 @@ impl TypeCheckError {
 
             // E2039: Cannot assign to immutable binding
             TypeErrorKind::AssignToImmutable { .. } => ErrorCode::E2039,
+
+            // E2040: Or-pattern alternatives bind different names
+            TypeErrorKind::OrPatternBindingMismatch { .. } => ErrorCode::E2040,
+
+            // E2041: break/continue to an undefined loop label
+            TypeErrorKind::UndefinedLabel { .. } => ErrorCode::E2041,
         }
     }
 
@@ -1635,6 +1654,38 @@ impl TypeCheckError {
         }
     }
 
+    /// Create an "or-pattern binding mismatch" error (E2040).
+    ///
+    /// Emitted when `name` is bound by some alternatives of an or-pattern but
+    /// not others — the arm body could not know whether it is in scope.
+    pub fn or_pattern_binding_mismatch(span: Span, name: Name) -> Self {
+        Self {
+            span,
+            kind: TypeErrorKind::OrPatternBindingMismatch { name },
+            context: ErrorContext::default(),
+            suggestions: vec![Suggestion::text(
+                "bind the same names in every alternative, or use `_` instead of a name",
+                0,
+            )],
+        }
+    }
+
+    /// Create an "undefined loop label" error (E2041).
+    ///
+    /// Emitted for `break:label` or `continue:label` when no enclosing `loop`
+    /// or `for` carries `label`.
+    pub fn undefined_label(span: Span, label: Name) -> Self {
+        Self {
+            span,
+            kind: TypeErrorKind::UndefinedLabel { label },
+            context: ErrorContext::default(),
+            suggestions: vec![Suggestion::text(
+                "label an enclosing loop, e.g. `loop:outer { ... }` or `for:outer x in xs do ...`",
+                0,
+            )],
+        }
+    }
+
     /// Create a "format type mismatch" error (E2035).
     ///
     /// Emitted when a format type (e.g., `x`, `b`) is used with an
@@ -1968,6 +2019,18 @@ pub enum TypeErrorKind {
         /// The name of the immutable binding.
         name: Name,
     },
+
+    /// Or-pattern alternatives bind different names (E2040).
+    OrPatternBindingMismatch {
+        /// A name bound by some alternatives but not all.
+        name: Name,
+    },
+
+    /// `break:label` or `continue:label` with no enclosing loop so labeled (E2041).
+    UndefinedLabel {
+        /// The label that no enclosing loop carries.
+        label: Name,
+    },
 }
 
 /// What kind of arity mismatch occurred.
@@ -461,10 +461,8 @@ impl<'pool> UnifyEngine<'pool> {
                 let state = self.pool.var_state_mut(var_id);
 
                 match state {
-                    VarState::Unbound { rank, .. } => {
-                        if *rank > max_rank {
-                            *rank = max_rank;
-                        }
+                    VarState::Unbound { rank, .. } if *rank > max_rank => {
+                        *rank = max_rank;
                     }
                     VarState::Link { target } => {
                         let target = *target;
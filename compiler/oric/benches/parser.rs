@@ -256,7 +256,7 @@ mod incremental_benches {
                 &(&new_tokens, &old_result, change),
                 |b, (tokens, old, change)| {
                     b.iter(|| {
-                        black_box(parse_incremental(tokens, &interner, old, *change));
+                        black_box(parse_incremental(tokens, &interner, old, &source, *change));
                     });
                 },
             );
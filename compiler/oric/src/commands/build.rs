@@ -190,6 +190,16 @@ impl DebugLevel {
             _ => None,
         }
     }
+
+    /// The matching LLVM debug info level.
+    #[cfg(feature = "llvm")]
+    pub fn to_llvm(self) -> ori_llvm::aot::DebugLevel {
+        match self {
+            Self::None => ori_llvm::aot::DebugLevel::None,
+            Self::LineTablesOnly => ori_llvm::aot::DebugLevel::LineTablesOnly,
+            Self::Full => ori_llvm::aot::DebugLevel::Full,
+        }
+    }
 }
 
 /// What to emit.
@@ -461,6 +471,7 @@ fn build_file_single(
         &pool,
         &canon_result,
         path,
+        options.debug_level.to_llvm(),
     );
 
     // Configure module for target
@@ -60,7 +60,8 @@ pub struct ImportedFunctionInfo {
 
 /// Check a source file for parse and type errors, then canonicalize.
 ///
-/// Prints all errors to stderr and returns `None` if any errors occurred.
+/// Prints all errors to stderr and returns `None` if any errors occurred,
+/// including non-exhaustive matches found during canonicalization.
 /// This accumulates all errors before reporting, giving users a complete picture.
 ///
 /// Returns the Pool (as `Arc<Pool>`) and `SharedCanonResult` alongside parse/type
@@ -106,6 +107,23 @@ pub fn check_source(
         &frontend.type_result,
         &frontend.pool,
     );
+
+    // Pattern problems, as `ori check` reports them. A non-exhaustive match
+    // must stop the build: its decision tree lowers the missing case to
+    // `unreachable`. Redundant arms are warnings and don't block codegen.
+    let mut has_pattern_errors = false;
+    for problem in &shared_canon.problems {
+        let diag = oric::problem::semantic::pattern_problem_to_diagnostic(problem, db.interner());
+        has_pattern_errors |= diag.is_error();
+        emitter.emit(&diag);
+    }
+    if !shared_canon.problems.is_empty() {
+        emitter.flush();
+    }
+    if has_pattern_errors {
+        return None;
+    }
+
     Some((
         frontend.parse_result,
         frontend.type_result,
@@ -262,8 +280,15 @@ pub fn run_arc_pipeline_cached(
 /// (e.g., determining which return types need the sret calling convention).
 ///
 /// The `CanonResult` provides canonical IR for both `ori_arc` and `ori_llvm`.
+///
+/// With a `debug_level` other than `None`, functions get DWARF subprograms
+/// and line locations mapped against the source at `source_path`.
 #[cfg(feature = "llvm")]
-#[allow(unsafe_code, reason = "LLVM C API requires unsafe FFI calls")]
+#[allow(
+    unsafe_code,
+    clippy::too_many_arguments,
+    reason = "LLVM C API requires unsafe FFI calls; codegen needs all front-end results"
+)]
 pub fn compile_to_llvm<'ctx>(
     context: &'ctx Context,
     db: &CompilerDb,
@@ -272,7 +297,9 @@ pub fn compile_to_llvm<'ctx>(
     pool: &'ctx Pool,
     canon: &CanonResult,
     source_path: &str,
+    debug_level: ori_llvm::aot::DebugLevel,
 ) -> ori_llvm::inkwell::module::Module<'ctx> {
+    use ori_llvm::aot::{DebugContext, DebugInfoConfig};
     use ori_llvm::codegen::function_compiler::FunctionCompiler;
     use ori_llvm::codegen::ir_builder::IrBuilder;
     use ori_llvm::codegen::runtime_decl;
@@ -312,6 +339,24 @@ pub fn compile_to_llvm<'ctx>(
         // 2. Register user-defined types
         type_registration::register_user_types(&resolver, &type_result.typed.types);
 
+        // 2b. Line index (panic locations) and debug info, both from the lexer
+        let source_file = Path::new(source_path);
+        let line_index = db
+            .load_file(source_file)
+            .map(|file| oric::query::line_index(db, file));
+        let debug_context = line_index
+            .as_ref()
+            .filter(|_| debug_level.is_enabled())
+            .and_then(|index| {
+                DebugContext::with_line_index(
+                    &scx_ref.llmod,
+                    context,
+                    DebugInfoConfig::new(debug_level),
+                    source_file,
+                    index.clone(),
+                )
+            });
+
         // 3. Run ARC borrow inference pipeline (uses same code path as multi-file)
         let function_sigs = oric::typeck::build_function_sigs(parse_result, type_result);
         let classifier = ori_arc::ArcClassifier::new(pool);
@@ -336,8 +381,9 @@ pub fn compile_to_llvm<'ctx>(
             "",
             Some(&annotated_sigs),
             Some(&classifier),
-            None, // Debug info wiring deferred to AOT pipeline integration
+            debug_context.as_ref(),
         );
+        fc.set_line_index(line_index.as_ref());
         fc.declare_all(&parse_result.module.functions, &function_sigs);
 
         // 5. Compile impl methods
@@ -383,6 +429,12 @@ pub fn compile_to_llvm<'ctx>(
                 break;
             }
         }
+
+        // 8. Resolve debug metadata before the module is verified and emitted
+        drop(fc);
+        if let Some(dc) = &debug_context {
+            dc.finalize();
+        }
     }
 
     // Debug IR output
@@ -460,6 +512,11 @@ pub fn compile_to_llvm_with_imports<'ctx>(
         // 2. Register user-defined types
         type_registration::register_user_types(&resolver, &type_result.typed.types);
 
+        // 2b. Line index for panic locations
+        let line_index = db
+            .load_file(Path::new(source_path))
+            .map(|file| oric::query::line_index(db, file));
+
         // 3. Declare imported functions as external symbols
         let import_sigs: Vec<(Name, FunctionSig)> = imported_functions
             .iter()
@@ -512,6 +569,7 @@ pub fn compile_to_llvm_with_imports<'ctx>(
             Some(&classifier),
             None, // Debug info wiring deferred to AOT pipeline integration
         );
+        fc.set_line_index(line_index.as_ref());
 
         // Declare imports first so they're visible to function bodies
         fc.declare_imports(&import_sigs);
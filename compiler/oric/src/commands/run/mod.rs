@@ -221,6 +221,7 @@ pub fn run_file_compiled(path: &str) {
         &pool,
         &canon_result,
         path,
+        ori_llvm::aot::DebugLevel::None,
     );
 
     // Configure module for target
@@ -219,7 +219,7 @@ fn print_llvm_error_breakdown(summary: &TestSummary) {
     }
 
     let mut sorted: Vec<_> = error_counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
     println!("  Reasons:");
     for (error, count) in &sorted {
         let plural = if *count == 1 { "file" } else { "files" };
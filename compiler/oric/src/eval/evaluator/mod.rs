@@ -184,6 +184,18 @@ impl<'a> Evaluator<'a> {
         self.interpreter.enable_counters();
     }
 
+    /// Limit wall-clock time for subsequent evaluation, starting now.
+    ///
+    /// Pass `None` to remove the limit.
+    pub fn set_time_limit(&mut self, limit: Option<std::time::Duration>) {
+        self.interpreter.set_time_limit(limit);
+    }
+
+    /// Drop every stored `cache` result (called before each test).
+    pub fn clear_cache(&mut self) {
+        self.interpreter.clear_cache();
+    }
+
     /// Get the counter report string, if counters are enabled.
     pub fn counters_report(&self) -> Option<String> {
         self.interpreter.counters_report()
@@ -112,6 +112,12 @@ fn main() {
                     config.parallel = false;
                 } else if arg == "--coverage" {
                     config.coverage = true;
+                } else if let Some(secs) = arg.strip_prefix("--timeout=") {
+                    let Ok(secs) = secs.parse::<u64>() else {
+                        eprintln!("Invalid --timeout value '{secs}': expected whole seconds");
+                        std::process::exit(1);
+                    };
+                    config.timeout = (secs > 0).then(|| std::time::Duration::from_secs(secs));
                 } else if arg == "--backend=llvm" {
                     config.backend = oric::test::Backend::LLVM;
                 } else if arg == "--backend=interpreter" {
@@ -295,6 +301,7 @@ fn print_usage() {
     println!("  --filter=<pattern>  Only run tests matching pattern");
     println!("  --verbose, -v       Show detailed output");
     println!("  --no-parallel       Run tests sequentially");
+    println!("  --timeout=<secs>    Per-test time limit (default 60, 0 disables)");
     println!("  --backend=<name>    Use backend: interpreter (default), llvm");
     println!();
     println!("Format options:");
@@ -73,6 +73,10 @@ pub fn render_lex_error(err: &LexError) -> Diagnostic {
             .with_message("unterminated template literal")
             .with_label(span, "template literal not closed"),
 
+        LexErrorKind::UnterminatedBlockComment => Diagnostic::error(ErrorCode::E0016)
+            .with_message("unterminated block comment")
+            .with_label(span, "block comment opened here is never closed"),
+
         LexErrorKind::InvalidStringEscape { escape_char } => Diagnostic::error(ErrorCode::E0005)
             .with_message(format!(
                 "invalid escape sequence `\\{escape_char}` in string"
@@ -91,6 +95,18 @@ pub fn render_lex_error(err: &LexError) -> Diagnostic {
             ))
             .with_label(span, "unknown escape"),
 
+        LexErrorKind::InvalidUnicodeEscape { reason } => Diagnostic::error(ErrorCode::E0005)
+            .with_message(format!("invalid unicode escape: {reason}"))
+            .with_label(span, "malformed `\\u{...}` escape"),
+
+        LexErrorKind::InvalidHexEscape { reason } => Diagnostic::error(ErrorCode::E0005)
+            .with_message(format!("invalid hex escape: {reason}"))
+            .with_label(span, "malformed `\\xNN` escape"),
+
+        LexErrorKind::NonAsciiInByteLiteral { ch } => Diagnostic::error(ErrorCode::E0002)
+            .with_message(format!("non-ASCII character `{ch}` in byte literal"))
+            .with_label(span, "byte literals only hold ASCII characters"),
+
         LexErrorKind::SingleQuoteEscapeInString => Diagnostic::error(ErrorCode::E0005)
             .with_message(r"`\'` is not a valid escape in string literals")
             .with_label(span, "not valid in strings"),
@@ -143,6 +159,14 @@ pub fn render_lex_error(err: &LexError) -> Diagnostic {
             .with_message("consecutive underscores in numeric literal")
             .with_label(span, "use a single underscore"),
 
+        LexErrorKind::InvalidNumericSuffix { reason } => Diagnostic::error(ErrorCode::E0003)
+            .with_message(format!("invalid numeric suffix: {reason}"))
+            .with_label(span, "not a valid type suffix"),
+
+        LexErrorKind::NumericLiteralOutOfRange { suffix } => Diagnostic::error(ErrorCode::E0003)
+            .with_message(format!("literal out of range for `{}`", suffix.suffix()))
+            .with_label(span, format!("value does not fit in `{}`", suffix.suffix())),
+
         LexErrorKind::InvalidByte { byte } => {
             let ch = *byte as char;
             if byte.is_ascii_control() {
@@ -469,10 +469,11 @@ pub fn pattern_problem_to_diagnostic(
         ori_canon::PatternProblem::RedundantArm {
             arm_span,
             match_span,
+            covered_by,
             ..
         } => SemanticProblem::RedundantPattern {
             span: *arm_span,
-            covered_by_span: *match_span,
+            covered_by_span: covered_by.unwrap_or(*match_span),
         },
     };
     semantic.into_diagnostic(interner)
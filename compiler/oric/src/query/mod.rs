@@ -123,6 +123,15 @@ pub fn tokens_with_metadata(db: &dyn Db, file: SourceFile) -> ori_lexer::LexOutp
     ori_lexer::lex_with_comments(text, db.interner())
 }
 
+/// Line-start index for a source file.
+///
+/// Derives from [`tokens_with_metadata()`] — the lexer collects line starts
+/// as it goes, so mapping spans to line/column never rescans the source.
+#[salsa::tracked]
+pub fn line_index(db: &dyn Db, file: SourceFile) -> ori_ir::LineIndex {
+    tokens_with_metadata(db, file).line_index
+}
+
 /// Parse a source file into a module.
 ///
 /// This query demonstrates incremental parsing with early cutoff:
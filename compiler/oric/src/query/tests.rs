@@ -46,6 +46,18 @@ fn test_first_line() {
     assert_eq!(first_line(&db, file), "@main () -> int = 42;");
 }
 
+#[test]
+fn test_line_index_from_lexer() {
+    let db = CompilerDb::new();
+
+    let source = "let a = 1\n/* two\nlines */\nb";
+    let file = SourceFile::new(&db, PathBuf::from("/test.ori"), source.to_string());
+
+    let index = line_index(&db, file);
+    assert_eq!(index, ori_ir::LineIndex::new(source));
+    assert_eq!(index.line_col(26), (4, 1)); // `b`
+}
+
 #[test]
 fn test_incremental_recomputation() {
     let mut db = CompilerDb::new();
@@ -32,6 +32,9 @@ pub enum Backend {
     LLVM,
 }
 
+/// Default per-test wall-clock limit.
+const DEFAULT_TEST_TIMEOUT: Duration = Duration::from_mins(1);
+
 /// Configuration for the test runner.
 #[derive(Clone, Debug)]
 #[expect(
@@ -51,6 +54,11 @@ pub struct TestRunnerConfig {
     pub backend: Backend,
     /// Enable incremental test execution (skip tests whose targets are unchanged).
     pub incremental: bool,
+    /// Wall-clock limit per test; `None` disables it.
+    ///
+    /// A test that runs past the limit fails with a timeout message and the
+    /// remaining tests still run.
+    pub timeout: Option<Duration>,
 }
 
 impl Default for TestRunnerConfig {
@@ -62,6 +70,7 @@ impl Default for TestRunnerConfig {
             coverage: false,
             backend: Backend::Interpreter,
             incremental: false,
+            timeout: Some(DEFAULT_TEST_TIMEOUT),
         }
     }
 }
@@ -456,7 +465,8 @@ impl TestRunner {
                         continue;
                     }
 
-                    let inner_result = Self::run_single_test(&mut evaluator, test, interner);
+                    let inner_result =
+                        Self::run_single_test(&mut evaluator, test, interner, config.timeout);
 
                     // If #[fail] is present, wrap the result
                     let result = if let Some(expected_failure) = test.fail_expected {
@@ -476,6 +486,7 @@ impl TestRunner {
                     &mut summary,
                     &db,
                     path,
+                    crate::query::line_index(&db, file),
                     &parse_result,
                     &regular_tests,
                     &type_result,
@@ -510,6 +521,7 @@ impl TestRunner {
         summary: &mut FileSummary,
         db: &crate::db::CompilerDb,
         file_path: &Path,
+        line_index: ori_ir::LineIndex,
         parse_result: &crate::parser::ParseOutput,
         regular_tests: &[&crate::ir::TestDef],
         type_result: &TypeCheckResult,
@@ -549,7 +561,7 @@ impl TestRunner {
 
         // Create LLVM evaluator with type pool for proper compound type resolution
         // (needed for sret convention on large struct returns like List, Map, etc.)
-        let llvm_eval = OwnedLLVMEvaluator::with_pool(pool);
+        let llvm_eval = OwnedLLVMEvaluator::with_pool(pool).with_line_index(line_index);
 
         // Resolve imports so imported functions can be compiled into the JIT module.
         // Uses the unified import pipeline — same resolution path as the type checker
@@ -725,7 +737,8 @@ impl TestRunner {
 
         // Run each test from the compiled module (no recompilation!)
         for test in &filtered_tests {
-            let inner_result = Self::run_single_test_from_compiled(&compiled, test, interner);
+            let inner_result =
+                Self::run_single_test_from_compiled(&compiled, test, interner, config.timeout);
 
             let result = if let Some(expected_failure) = test.fail_expected {
                 Self::apply_fail_wrapper(inner_result, expected_failure, interner)
@@ -746,6 +759,7 @@ impl TestRunner {
         compiled: &ori_llvm::evaluator::CompiledTestModule,
         test: &TestDef,
         interner: &crate::ir::StringInterner,
+        timeout: Option<Duration>,
     ) -> TestResult {
         // Check if test is skipped
         if let Some(reason) = test.skip_reason {
@@ -757,7 +771,7 @@ impl TestRunner {
         let start = Instant::now();
 
         // Run the test from the compiled module (no recompilation!)
-        match compiled.run_test(test.name) {
+        match compiled.run_test(test.name, timeout) {
             Ok(_) => TestResult::passed(test.name, test.targets.clone(), start.elapsed()),
             Err(e) => {
                 TestResult::failed(test.name, test.targets.clone(), e.message, start.elapsed())
@@ -1007,6 +1021,7 @@ impl TestRunner {
         evaluator: &mut Evaluator,
         test: &TestDef,
         interner: &crate::ir::StringInterner,
+        timeout: Option<Duration>,
     ) -> TestResult {
         // Check if test is skipped
         if let Some(reason) = test.skip_reason {
@@ -1025,7 +1040,11 @@ impl TestRunner {
                 start.elapsed(),
             );
         };
+        // Each test starts with a cold `cache`, independent of test order
+        evaluator.clear_cache();
+        evaluator.set_time_limit(timeout);
         let result = evaluator.eval_can(can_id);
+        evaluator.set_time_limit(None);
         match result {
             Ok(_) => TestResult::passed(test.name, test.targets.clone(), start.elapsed()),
             Err(e) => TestResult::failed(
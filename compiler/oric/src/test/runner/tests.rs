@@ -71,6 +71,104 @@ let _ = 1 / 0;
     assert_eq!(summary.failed, 1);
 }
 
+#[test]
+fn test_runner_failure_does_not_stop_later_tests() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("mixed.ori");
+    // The first test fails at runtime; the second must still run and pass
+    std::fs::write(
+        &path,
+        r#"
+@divide (a: int, b: int) -> int = a / b;
+
+@test_divide_zero tests @divide () -> void = {
+let _ = divide(a: 1, b: 0);
+()
+}
+
+@test_divide tests @divide () -> void = {
+let _ = divide(a: 4, b: 2);
+print(msg: "pass")
+}
+"#,
+    )
+    .unwrap();
+
+    let runner = TestRunner::new();
+    let summary = runner.run_file(&path);
+    assert_eq!(summary.total(), 2);
+    assert_eq!(summary.passed, 1);
+    assert_eq!(summary.failed, 1);
+
+    let failed = summary
+        .results
+        .iter()
+        .find(|r| r.outcome.is_failed())
+        .unwrap();
+    assert_eq!(failed.name_str(runner.interner()), "test_divide_zero");
+    let TestOutcome::Failed(message) = &failed.outcome else {
+        unreachable!("filtered on is_failed");
+    };
+    assert!(message.contains("division by zero"), "message: {message}");
+}
+
+#[test]
+fn test_runner_timeout_does_not_block_suite() {
+    check_timeout_does_not_block_suite(Backend::Interpreter);
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn test_runner_llvm_timeout_does_not_block_suite() {
+    check_timeout_does_not_block_suite(Backend::LLVM);
+}
+
+/// Run a file whose first test never terminates under a short timeout on
+/// `backend`, and check that only that test fails.
+fn check_timeout_does_not_block_suite(backend: Backend) {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("spin.ori");
+    // The first test never terminates; the second must still run and pass
+    std::fs::write(
+        &path,
+        r#"
+@spin () -> void = {
+let i = 0;
+loop {
+i = i + 1
+}
+}
+
+@test_spin tests @spin () -> void = spin();
+
+@test_after_spin tests @spin () -> void = print(msg: "pass");
+"#,
+    )
+    .unwrap();
+
+    let config = TestRunnerConfig {
+        timeout: Some(Duration::from_millis(200)),
+        backend,
+        ..Default::default()
+    };
+    let runner = TestRunner::with_config(config);
+    let summary = runner.run_file(&path);
+    assert_eq!(summary.total(), 2);
+    assert_eq!(summary.passed, 1);
+    assert_eq!(summary.failed, 1);
+
+    let failed = summary
+        .results
+        .iter()
+        .find(|r| r.outcome.is_failed())
+        .unwrap();
+    assert_eq!(failed.name_str(runner.interner()), "test_spin");
+    let TestOutcome::Failed(message) = &failed.outcome else {
+        unreachable!("filtered on is_failed");
+    };
+    assert!(message.contains("timeout"), "message: {message}");
+}
+
 #[test]
 fn test_runner_filter() {
     let dir = tempdir().unwrap();
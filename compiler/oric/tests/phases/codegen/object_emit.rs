@@ -222,6 +222,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_emit_object_for_non_host_triple() {
+        let emitter = ObjectEmitter::for_triple("aarch64-unknown-linux-gnu", None, None)
+            .expect("aarch64 target should be available");
+        assert_eq!(emitter.config().triple(), "aarch64-unknown-linux-gnu");
+
+        let context = Context::create();
+        let module = context.create_module("test_cross");
+        emitter.configure_module(&module).unwrap();
+
+        let i64_type = context.i64_type();
+        let fn_type = i64_type.fn_type(&[], false);
+        let function = module.add_function("cross_func", fn_type, None);
+        let entry = context.append_basic_block(function, "entry");
+        let builder = context.create_builder();
+        builder.position_at_end(entry);
+        builder
+            .build_return(Some(&i64_type.const_int(7, false)))
+            .unwrap();
+
+        let bytes = emitter
+            .emit_object_to_memory(&module)
+            .expect("cross emission should succeed");
+
+        // ELF header: magic, then e_machine at offset 18 (EM_AARCH64 = 183)
+        assert_eq!(&bytes[..4], b"\x7fELF");
+        assert_eq!(u16::from_le_bytes([bytes[18], bytes[19]]), 183);
+    }
+
+    #[test]
+    fn test_for_triple_unknown_triple() {
+        let result = ObjectEmitter::for_triple("sparc-unknown-none", Some("generic"), None);
+        let Err(err) = result else {
+            panic!("unknown triple should be rejected");
+        };
+        assert!(
+            err.to_string().contains("sparc-unknown-none"),
+            "error should name the triple: {err}"
+        );
+    }
+
     #[test]
     fn test_emit_invalid_path() {
         if let Ok(emitter) = ObjectEmitter::native() {
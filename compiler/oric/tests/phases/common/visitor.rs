@@ -7,6 +7,7 @@
 //! - Module and function traversal
 //! - Deeply nested expressions
 //! - Optional children handling
+//! - Enter/leave hooks via `ExprArena::visit`
 
 use ori_ir::ast::{
     BinaryOp, Expr, ExprKind, Function, GenericParamRange, MatchArm, MatchPattern, Module, Param,
@@ -398,3 +399,99 @@ fn test_visit_optional_children() {
     // if + cond + then = 3 (no else)
     assert_eq!(counter.count, 3);
 }
+
+/// Visitor using only the enter/leave hooks: counts node kinds on entry and
+/// records the exit order.
+#[derive(Default)]
+struct KindTally {
+    kinds: std::collections::BTreeMap<&'static str, usize>,
+    depth: usize,
+    max_depth: usize,
+    left: Vec<ExprId>,
+}
+
+impl Visitor<'_> for KindTally {
+    fn enter_expr(&mut self, _id: ExprId, expr: &Expr) {
+        let kind = match expr.kind {
+            ExprKind::Int(_) => "int",
+            ExprKind::Ident(_) => "ident",
+            ExprKind::Call { .. } => "call",
+            ExprKind::List(_) => "list",
+            ExprKind::Range { .. } => "range",
+            ExprKind::Match { .. } => "match",
+            _ => "other",
+        };
+        *self.kinds.entry(kind).or_default() += 1;
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+    }
+
+    fn leave_expr(&mut self, id: ExprId, _expr: &Expr) {
+        self.depth -= 1;
+        self.left.push(id);
+    }
+}
+
+#[test]
+fn test_arena_visit_enter_leave_counts_kinds() {
+    // match f(1, [2, 0..3]) { x if x => x }
+    let mut arena = ExprArena::new();
+    let sp = Span::new(0, 1);
+    let x = Name::new(0, 0);
+
+    let func = arena.alloc_expr(Expr::new(ExprKind::Ident(Name::new(0, 1)), sp));
+    let one = arena.alloc_expr(Expr::new(ExprKind::Int(1), sp));
+    let two = arena.alloc_expr(Expr::new(ExprKind::Int(2), sp));
+    let start = arena.alloc_expr(Expr::new(ExprKind::Int(0), sp));
+    let end = arena.alloc_expr(Expr::new(ExprKind::Int(3), sp));
+    let range = arena.alloc_expr(Expr::new(
+        ExprKind::Range {
+            start,
+            end,
+            step: ExprId::INVALID,
+            inclusive: false,
+        },
+        sp,
+    ));
+    let items = arena.alloc_expr_list_inline(&[two, range]);
+    let list = arena.alloc_expr(Expr::new(ExprKind::List(items), sp));
+    let args = arena.alloc_expr_list_inline(&[one, list]);
+    let call = arena.alloc_expr(Expr::new(ExprKind::Call { func, args }, sp));
+
+    let guard = arena.alloc_expr(Expr::new(ExprKind::Ident(x), sp));
+    let body = arena.alloc_expr(Expr::new(ExprKind::Ident(x), sp));
+    let arms = arena.alloc_arms([MatchArm {
+        pattern: MatchPattern::Binding(x),
+        guard: Some(guard),
+        body,
+        span: sp,
+    }]);
+    let root = arena.alloc_expr(Expr::new(
+        ExprKind::Match {
+            scrutinee: call,
+            arms,
+        },
+        sp,
+    ));
+
+    let mut tally = KindTally::default();
+    arena.visit(root, &mut tally);
+
+    let expected: Vec<(&str, usize)> = vec![
+        ("call", 1),
+        ("ident", 3),
+        ("int", 4),
+        ("list", 1),
+        ("match", 1),
+        ("range", 1),
+    ];
+    assert_eq!(tally.kinds.into_iter().collect::<Vec<_>>(), expected);
+    assert_eq!(tally.depth, 0);
+    // match > call > list > range > int
+    assert_eq!(tally.max_depth, 5);
+    // Post-order, children left to right
+    assert_eq!(
+        tally.left,
+        vec![func, one, two, start, end, range, list, call, guard, body, root]
+    );
+}
@@ -467,6 +467,32 @@ fn test_lex_with_comments_doc_field() {
     );
 }
 
+#[test]
+fn test_lex_with_comments_doc_returns() {
+    let interner = test_interner();
+    let output = lex_with_comments("//   @returns   the sum of a and b", &interner);
+
+    assert_eq!(output.comments.len(), 1);
+    assert_eq!(output.comments[0].kind, CommentKind::DocReturns);
+    assert_eq!(
+        interner.lookup(output.comments[0].content),
+        " @returns the sum of a and b"
+    );
+}
+
+#[test]
+fn test_lex_with_comments_doc_throws() {
+    let interner = test_interner();
+    let output = lex_with_comments("//@throws\tDivisionByZero when b is 0", &interner);
+
+    assert_eq!(output.comments.len(), 1);
+    assert_eq!(output.comments[0].kind, CommentKind::DocThrows);
+    assert_eq!(
+        interner.lookup(output.comments[0].content),
+        " @throws DivisionByZero when b is 0"
+    );
+}
+
 #[test]
 fn test_lex_with_comments_doc_warning() {
     let interner = test_interner();